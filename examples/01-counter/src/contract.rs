@@ -0,0 +1,152 @@
+use crate::error::ContractError;
+use crate::msg::{CountResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{COUNT, OWNER};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    COUNT.save(deps.storage, &msg.count)?;
+    OWNER.save(deps.storage, &info.sender)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Count {} => to_json_binary(&query::count(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Increment {} => exec::increment(deps),
+        Reset { count } => exec::reset(deps, info, count),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn increment(deps: DepsMut) -> Result<Response, ContractError> {
+        let count = COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "increment")
+            .add_attribute("count", count.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn reset(deps: DepsMut, info: MessageInfo, count: u64) -> Result<Response, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        COUNT.save(deps.storage, &count)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "reset")
+            .add_attribute("count", count.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn count(deps: Deps) -> StdResult<CountResp> {
+        let count = COUNT.load(deps.storage)?;
+        Ok(CountResp { count })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    fn contract(app: &mut App, count: u64) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        Proxy::deploy(app, code, "owner", &InstantiateMsg { count }, "Counter").unwrap()
+    }
+
+    #[test]
+    fn instantiation() {
+        let mut app = app();
+        let contract = contract(&mut app, 7);
+
+        let resp: CountResp = contract.query(&app, &QueryMsg::Count {}).unwrap();
+
+        assert_eq!(resp, CountResp { count: 7 });
+    }
+
+    #[test]
+    fn increment() {
+        let mut app = app();
+        let contract = contract(&mut app, 0);
+
+        contract
+            .execute(&mut app, "user1", &ExecuteMsg::Increment {}, &[])
+            .unwrap();
+        let resp = contract
+            .execute(&mut app, "user2", &ExecuteMsg::Increment {}, &[])
+            .unwrap();
+
+        assert_eq!(wasm_attr(&resp, "action"), "increment");
+        assert_eq!(wasm_attr(&resp, "count"), "2");
+
+        let resp: CountResp = contract.query(&app, &QueryMsg::Count {}).unwrap();
+        assert_eq!(resp, CountResp { count: 2 });
+    }
+
+    #[test]
+    fn reset() {
+        let mut app = app();
+        let contract = contract(&mut app, 5);
+
+        contract
+            .execute(&mut app, "owner", &ExecuteMsg::Reset { count: 1 }, &[])
+            .unwrap();
+
+        let resp: CountResp = contract.query(&app, &QueryMsg::Count {}).unwrap();
+        assert_eq!(resp, CountResp { count: 1 });
+    }
+
+    #[test]
+    fn unauthorized_reset() {
+        let mut app = app();
+        let contract = contract(&mut app, 5);
+
+        let err = contract
+            .execute(&mut app, "user1", &ExecuteMsg::Reset { count: 0 }, &[])
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: addr::user(1)
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp: CountResp = contract.query(&app, &QueryMsg::Count {}).unwrap();
+        assert_eq!(resp, CountResp { count: 5 });
+    }
+}
@@ -0,0 +1,11 @@
+use cosmwasm_schema::write_api;
+
+use contract_sylvia::contract::sv::{ContractExecMsg, ContractQueryMsg, InstantiateMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ContractExecMsg,
+        query: ContractQueryMsg,
+    }
+}
@@ -0,0 +1,419 @@
+use cosmwasm_std::{coins, Addr, BankMsg, Event, Response, StdResult};
+use cw_storage_plus::Item;
+use sylvia::contract;
+use sylvia::ctx::{ExecCtx, InstantiateCtx, QueryCtx};
+
+use crate::error::ContractError;
+use crate::msg::{AdminsListResp, ConfigResp, ContractFundsResp, GreetResp};
+
+/// The 03-basics admin contract. Storage lives in the contract struct, and every handler marked
+/// with `#[sv::msg(..)]` becomes a variant of the generated `sv::InstantiateMsg`, `sv::ExecMsg`
+/// or `sv::QueryMsg` - named after the method, with the arguments as fields, so the JSON API is the
+/// same as the hand-written one.
+pub struct AdminContract {
+    pub(crate) admins: Item<Vec<Addr>>,
+    pub(crate) donation_denom: Item<String>,
+    pub(crate) owner: Item<Addr>,
+}
+
+impl Default for AdminContract {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[contract]
+#[sv::error(ContractError)]
+impl AdminContract {
+    pub const fn new() -> Self {
+        Self {
+            admins: Item::new("admins"),
+            donation_denom: Item::new("donation_denom"),
+            owner: Item::new("owner"),
+        }
+    }
+
+    #[sv::msg(instantiate)]
+    pub fn instantiate(
+        &self,
+        ctx: InstantiateCtx,
+        admins: Vec<String>,
+        donation_denom: String,
+    ) -> Result<Response, ContractError> {
+        let admins: StdResult<Vec<_>> = admins
+            .into_iter()
+            .map(|addr| ctx.deps.api.addr_validate(&addr))
+            .collect();
+        self.admins.save(ctx.deps.storage, &admins?)?;
+        self.donation_denom
+            .save(ctx.deps.storage, &donation_denom)?;
+        self.owner.save(ctx.deps.storage, &ctx.info.sender)?;
+
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    pub fn add_members(
+        &self,
+        ctx: ExecCtx,
+        admins: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&ctx.info)?;
+
+        let mut curr_admins = self.admins.load(ctx.deps.storage)?;
+        if !curr_admins.contains(&ctx.info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: ctx.info.sender,
+            });
+        }
+
+        let events = admins
+            .iter()
+            .map(|admin| Event::new("admin_added").add_attribute("addr", admin));
+        let resp = Response::new()
+            .add_events(events)
+            .add_attribute("action", "add_members")
+            .add_attribute("added_count", admins.len().to_string());
+
+        let admins: StdResult<Vec<_>> = admins
+            .into_iter()
+            .map(|addr| ctx.deps.api.addr_validate(&addr))
+            .collect();
+
+        curr_admins.append(&mut admins?);
+        self.admins.save(ctx.deps.storage, &curr_admins)?;
+
+        Ok(resp)
+    }
+
+    #[sv::msg(exec)]
+    pub fn leave(&self, ctx: ExecCtx) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&ctx.info)?;
+
+        let sender = ctx.info.sender;
+        self.admins
+            .update(ctx.deps.storage, move |admins| -> StdResult<_> {
+                let admins = admins
+                    .into_iter()
+                    .filter(|admin| *admin != sender)
+                    .collect();
+                Ok(admins)
+            })?;
+
+        Ok(Response::new())
+    }
+
+    #[sv::msg(exec)]
+    pub fn donate(&self, ctx: ExecCtx) -> Result<Response, ContractError> {
+        let denom = self.donation_denom.load(ctx.deps.storage)?;
+        let admins = self.admins.load(ctx.deps.storage)?;
+
+        let donation = cw_utils::must_pay(&ctx.info, &denom)?.u128();
+
+        let donation_per_admin = donation / (admins.len() as u128);
+
+        let messages = admins.into_iter().map(|admin| BankMsg::Send {
+            to_address: admin.to_string(),
+            amount: coins(donation_per_admin, &denom),
+        });
+
+        let resp = Response::new()
+            .add_messages(messages)
+            .add_attribute("action", "donate")
+            .add_attribute("amount", donation.to_string())
+            .add_attribute("per_admin", donation_per_admin.to_string());
+
+        Ok(resp)
+    }
+
+    #[sv::msg(query)]
+    pub fn greet(&self, _ctx: QueryCtx) -> StdResult<GreetResp> {
+        let resp = GreetResp {
+            message: "Hello World".to_owned(),
+        };
+
+        Ok(resp)
+    }
+
+    #[sv::msg(query)]
+    pub fn admins_list(&self, ctx: QueryCtx) -> StdResult<AdminsListResp> {
+        let admins = self.admins.load(ctx.deps.storage)?;
+        let resp = AdminsListResp { admins };
+        Ok(resp)
+    }
+
+    #[sv::msg(query)]
+    pub fn config(&self, ctx: QueryCtx) -> StdResult<ConfigResp> {
+        let resp = ConfigResp {
+            owner: self.owner.load(ctx.deps.storage)?,
+            donation_denom: self.donation_denom.load(ctx.deps.storage)?,
+        };
+        Ok(resp)
+    }
+
+    /// Donation denom funds held by the contract - the remainders left after splitting
+    #[sv::msg(query)]
+    pub fn contract_funds(&self, ctx: QueryCtx) -> StdResult<ContractFundsResp> {
+        let denom = self.donation_denom.load(ctx.deps.storage)?;
+        let funds = ctx
+            .deps
+            .querier
+            .query_balance(ctx.env.contract.address, denom)?;
+        Ok(ContractFundsResp { funds })
+    }
+}
+
+// The multitest suite is the one from 03-basics, word for word - it only talks to the contract
+// through JSON messages, so passing it unchanged shows both implementations behave the same. The
+// unit tests of 03-basics are not mirrored, as they call the hand-written entry points directly.
+#[cfg(test)]
+mod tests {
+    use cw_utils::PaymentError;
+    use testing::prelude::*;
+
+    use crate::msg::AdminsListResp;
+    use crate::{execute, instantiate, query};
+
+    use super::sv::{ExecMsg as ExecuteMsg, InstantiateMsg, QueryMsg};
+    use super::*;
+
+    /// Contract instantiated by `owner`, with admins given by account name.
+    fn contract(app: &mut App, admins: &[&str]) -> Proxy {
+        let msg = InstantiateMsg {
+            admins: admins
+                .iter()
+                .map(|admin| app.api().addr_make(admin).to_string())
+                .collect(),
+            donation_denom: "eth".to_owned(),
+        };
+        let code = ContractWrapper::new(execute, instantiate, query);
+        Proxy::deploy(app, code, "owner", &msg, "Contract").unwrap()
+    }
+
+    #[test]
+    fn instantiation() {
+        let mut app = app();
+
+        let contract1 = contract(&mut app, &[]);
+        let resp: AdminsListResp = contract1.query(&app, &QueryMsg::AdminsList {}).unwrap();
+        assert_eq!(resp, AdminsListResp { admins: vec![] });
+
+        let contract2 = contract(&mut app, &["admin1", "admin2"]);
+        let resp: AdminsListResp = contract2.query(&app, &QueryMsg::AdminsList {}).unwrap();
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: mock_addrs(&["admin1", "admin2"]),
+            }
+        );
+    }
+
+    #[test]
+    fn greet_query() {
+        let mut app = app();
+        let contract = contract(&mut app, &[]);
+
+        let resp: GreetResp = contract.query(&app, &QueryMsg::Greet {}).unwrap();
+
+        assert_eq!(
+            resp,
+            GreetResp {
+                message: "Hello World".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn config_query() {
+        let mut app = app();
+        let contract = contract(&mut app, &[]);
+
+        let resp: ConfigResp = contract.query(&app, &QueryMsg::Config {}).unwrap();
+
+        assert_eq!(
+            resp,
+            ConfigResp {
+                owner: addr::owner(),
+                donation_denom: "eth".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn unauthorized() {
+        let mut app = app();
+        let contract = contract(&mut app, &[]);
+        let user = addr::user(1);
+
+        let err = contract
+            .execute(
+                &mut app,
+                "user1",
+                &ExecuteMsg::AddMembers {
+                    admins: vec![user.to_string()],
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized { sender: user },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn add_members() {
+        let mut app = app();
+        let contract = contract(&mut app, &["owner"]);
+        let user = addr::user(1);
+
+        let resp = contract
+            .execute(
+                &mut app,
+                "owner",
+                &ExecuteMsg::AddMembers {
+                    admins: vec![user.to_string()],
+                },
+                &[],
+            )
+            .unwrap();
+
+        assert_eq!(wasm_attr(&resp, "action"), "add_members");
+        assert_eq!(wasm_attr(&resp, "added_count"), "1");
+
+        let admin_added = events(&resp, "admin_added");
+        assert_eq!(admin_added.len(), 1);
+        assert_eq!(attr(admin_added[0], "addr"), user);
+    }
+
+    #[test]
+    fn funds_rejected_on_non_payable() {
+        let mut app = app_with_balances(&[("owner", coins(10, "eth"))]);
+        let contract = contract(&mut app, &["owner"]);
+        let owner = addr::owner();
+        let user = addr::user(1);
+
+        let err = contract
+            .execute(
+                &mut app,
+                "owner",
+                &ExecuteMsg::AddMembers {
+                    admins: vec![user.to_string()],
+                },
+                &coins(5, "eth"),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Payment(PaymentError::NonPayable {}),
+            err.downcast().unwrap()
+        );
+
+        let err = contract
+            .execute(&mut app, "owner", &ExecuteMsg::Leave {}, &coins(5, "eth"))
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Payment(PaymentError::NonPayable {}),
+            err.downcast().unwrap()
+        );
+
+        assert_eq!(balance(&app, &owner, "eth"), 10);
+    }
+
+    #[test]
+    fn donations() {
+        let mut app = app_with_balances(&[("user1", coins(5, "eth"))]);
+        let contract = contract(&mut app, &["admin1", "admin2"]);
+
+        let resp = contract
+            .execute(&mut app, "user1", &ExecuteMsg::Donate {}, &coins(5, "eth"))
+            .unwrap();
+
+        assert_event!(resp, "wasm", "action" => "donate", "amount" => 5, "per_admin" => 2);
+        for admin in [addr::admin(1), addr::admin(2)] {
+            assert_event!(
+                resp,
+                "transfer",
+                "sender" => contract.addr(),
+                "recipient" => admin,
+                "amount" => "2eth",
+            );
+        }
+        assert_eq!(
+            find_attr(&resp, "wasm", "_contract_address"),
+            Some(contract.addr().as_str())
+        );
+
+        assert_eq!(balance(&app, addr::user(1), "eth"), 0);
+        assert_eq!(balance(&app, contract.addr(), "eth"), 1);
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 2);
+        assert_eq!(balance(&app, addr::admin(2), "eth"), 2);
+
+        let resp: ContractFundsResp = contract.query(&app, &QueryMsg::ContractFunds {}).unwrap();
+        assert_eq!(resp.funds, coin(1, "eth"));
+    }
+
+    #[test]
+    fn invalid_donations() {
+        let mut app = app_with_balances(&[("user1", vec![coin(5, "eth"), coin(5, "btc")])]);
+        let contract = contract(&mut app, &["admin1"]);
+
+        let err = contract
+            .execute(&mut app, "user1", &ExecuteMsg::Donate {}, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Payment(PaymentError::NoFunds {}),
+            err.downcast().unwrap()
+        );
+
+        let err = contract
+            .execute(&mut app, "user1", &ExecuteMsg::Donate {}, &coins(5, "btc"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Payment(PaymentError::MissingDenom("eth".to_owned())),
+            err.downcast().unwrap()
+        );
+
+        let err = contract
+            .execute(
+                &mut app,
+                "user1",
+                &ExecuteMsg::Donate {},
+                &[coin(5, "btc"), coin(5, "eth")],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Payment(PaymentError::MultipleDenoms {}),
+            err.downcast().unwrap()
+        );
+
+        // Failed transactions are reverted, funds stay with the donor
+        assert_eq!(balance(&app, addr::user(1), "eth"), 5);
+        assert_eq!(balance(&app, addr::user(1), "btc"), 5);
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 0);
+    }
+
+    #[test]
+    fn owner_is_not_admin() {
+        let mut app = app();
+        let contract = contract(&mut app, &["admin1"]);
+        let owner = addr::owner();
+
+        let err = contract
+            .execute(
+                &mut app,
+                "owner",
+                &ExecuteMsg::AddMembers {
+                    admins: vec![owner.to_string()],
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized { sender: owner },
+            err.downcast().unwrap()
+        );
+    }
+}
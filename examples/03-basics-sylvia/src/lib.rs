@@ -0,0 +1,37 @@
+use contract::sv::{ContractExecMsg, ContractQueryMsg, InstantiateMsg};
+use contract::AdminContract;
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use error::ContractError;
+
+pub mod contract;
+pub mod error;
+pub mod msg;
+
+const CONTRACT: AdminContract = AdminContract::new();
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    msg.dispatch(&CONTRACT, (deps, env, info))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ContractExecMsg,
+) -> Result<Response, ContractError> {
+    msg.dispatch(&CONTRACT, (deps, env, info))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: ContractQueryMsg) -> Result<Binary, ContractError> {
+    msg.dispatch(&CONTRACT, (deps, env))
+}
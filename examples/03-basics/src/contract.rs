@@ -1,14 +1,17 @@
 use crate::error::ContractError;
-use crate::msg::{AdminsListResp, ExecuteMsg, GreetResp, InstantiateMsg, QueryMsg};
-use crate::state::{ADMINS, DONATION_DENOM};
+use crate::msg::{
+    AdminsListResp, ConfigResp, ContractFundsResp, ExecuteMsg, GreetResp, InstantiateMsg, QueryMsg,
+};
+use crate::state::{ADMINS, DONATION_DENOM, OWNER};
 use cosmwasm_std::{
-    coins, to_binary, BankMsg, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response, StdResult,
+    coins, to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response,
+    StdResult,
 };
 
 pub fn instantiate(
     deps: DepsMut,
     _env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: InstantiateMsg,
 ) -> StdResult<Response> {
     let admins: StdResult<Vec<_>> = msg
@@ -18,16 +21,19 @@ pub fn instantiate(
         .collect();
     ADMINS.save(deps.storage, &admins?)?;
     DONATION_DENOM.save(deps.storage, &msg.donation_denom)?;
+    OWNER.save(deps.storage, &info.sender)?;
 
     Ok(Response::new())
 }
 
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     use QueryMsg::*;
 
     match msg {
-        Greet {} => to_binary(&query::greet()?),
-        AdminsList {} => to_binary(&query::admins_list(deps)?),
+        Greet {} => to_json_binary(&query::greet()?),
+        AdminsList {} => to_json_binary(&query::admins_list(deps)?),
+        Config {} => to_json_binary(&query::config(deps)?),
+        ContractFunds {} => to_json_binary(&query::contract_funds(deps, env)?),
     }
 }
 
@@ -41,7 +47,7 @@ pub fn execute(
 
     match msg {
         AddMembers { admins } => exec::add_members(deps, info, admins),
-        Leave {} => exec::leave(deps, info).map_err(Into::into),
+        Leave {} => exec::leave(deps, info),
         Donate {} => exec::donate(deps, info),
     }
 }
@@ -54,6 +60,8 @@ mod exec {
         info: MessageInfo,
         admins: Vec<String>,
     ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
         let mut curr_admins = ADMINS.load(deps.storage)?;
         if !curr_admins.contains(&info.sender) {
             return Err(ContractError::Unauthorized {
@@ -80,7 +88,9 @@ mod exec {
         Ok(resp)
     }
 
-    pub fn leave(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
+    pub fn leave(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
         ADMINS.update(deps.storage, move |admins| -> StdResult<_> {
             let admins = admins
                 .into_iter()
@@ -131,97 +141,70 @@ mod query {
         let resp = AdminsListResp { admins };
         Ok(resp)
     }
+
+    pub fn config(deps: Deps) -> StdResult<ConfigResp> {
+        let resp = ConfigResp {
+            owner: OWNER.load(deps.storage)?,
+            donation_denom: DONATION_DENOM.load(deps.storage)?,
+        };
+        Ok(resp)
+    }
+
+    pub fn contract_funds(deps: Deps, env: Env) -> StdResult<ContractFundsResp> {
+        let denom = DONATION_DENOM.load(deps.storage)?;
+        let funds = deps.querier.query_balance(env.contract.address, denom)?;
+        Ok(ContractFundsResp { funds })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::Addr;
-    use cw_multi_test::{App, ContractWrapper, Executor};
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{from_json, CosmosMsg};
+    use cw_utils::PaymentError;
+    use testing::prelude::*;
 
     use crate::msg::AdminsListResp;
 
     use super::*;
 
-    #[test]
-    fn instantiation() {
-        let mut app = App::default();
-
+    /// Contract instantiated by `owner`, with admins given by account name.
+    fn contract(app: &mut App, admins: &[&str]) -> Proxy {
+        let msg = InstantiateMsg {
+            admins: admins
+                .iter()
+                .map(|admin| app.api().addr_make(admin).to_string())
+                .collect(),
+            donation_denom: "eth".to_owned(),
+        };
         let code = ContractWrapper::new(execute, instantiate, query);
-        let code_id = app.store_code(Box::new(code));
-
-        let addr = app
-            .instantiate_contract(
-                code_id,
-                Addr::unchecked("owner"),
-                &InstantiateMsg {
-                    admins: vec![],
-                    donation_denom: "eth".to_owned(),
-                },
-                &[],
-                "Contract",
-                None,
-            )
-            .unwrap();
+        Proxy::deploy(app, code, "owner", &msg, "Contract").unwrap()
+    }
 
-        let resp: AdminsListResp = app
-            .wrap()
-            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
-            .unwrap();
+    #[test]
+    fn instantiation() {
+        let mut app = app();
 
+        let contract1 = contract(&mut app, &[]);
+        let resp: AdminsListResp = contract1.query(&app, &QueryMsg::AdminsList {}).unwrap();
         assert_eq!(resp, AdminsListResp { admins: vec![] });
 
-        let addr = app
-            .instantiate_contract(
-                code_id,
-                Addr::unchecked("owner"),
-                &InstantiateMsg {
-                    admins: vec!["admin1".to_owned(), "admin2".to_owned()],
-                    donation_denom: "eth".to_owned(),
-                },
-                &[],
-                "Contract 2",
-                None,
-            )
-            .unwrap();
-
-        let resp: AdminsListResp = app
-            .wrap()
-            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
-            .unwrap();
-
+        let contract2 = contract(&mut app, &["admin1", "admin2"]);
+        let resp: AdminsListResp = contract2.query(&app, &QueryMsg::AdminsList {}).unwrap();
         assert_eq!(
             resp,
             AdminsListResp {
-                admins: vec![Addr::unchecked("admin1"), Addr::unchecked("admin2")],
+                admins: mock_addrs(&["admin1", "admin2"]),
             }
         );
     }
 
     #[test]
     fn greet_query() {
-        let mut app = App::default();
+        let mut app = app();
+        let contract = contract(&mut app, &[]);
 
-        let code = ContractWrapper::new(execute, instantiate, query);
-        let code_id = app.store_code(Box::new(code));
-
-        let addr = app
-            .instantiate_contract(
-                code_id,
-                Addr::unchecked("owner"),
-                &InstantiateMsg {
-                    admins: vec![],
-                    donation_denom: "eth".to_owned(),
-                },
-                &[],
-                "Contract",
-                None,
-            )
-            .unwrap();
-
-        let resp: GreetResp = app
-            .wrap()
-            .query_wasm_smart(addr, &QueryMsg::Greet {})
-            .unwrap();
+        let resp: GreetResp = contract.query(&app, &QueryMsg::Greet {}).unwrap();
 
         assert_eq!(
             resp,
@@ -232,181 +215,342 @@ mod tests {
     }
 
     #[test]
-    fn unauthorized() {
-        let mut app = App::default();
+    fn config_query() {
+        let mut app = app();
+        let contract = contract(&mut app, &[]);
 
-        let code = ContractWrapper::new(execute, instantiate, query);
-        let code_id = app.store_code(Box::new(code));
-
-        let addr = app
-            .instantiate_contract(
-                code_id,
-                Addr::unchecked("owner"),
-                &InstantiateMsg {
-                    admins: vec![],
-                    donation_denom: "eth".to_owned(),
-                },
-                &[],
-                "Contract",
-                None,
-            )
-            .unwrap();
+        let resp: ConfigResp = contract.query(&app, &QueryMsg::Config {}).unwrap();
 
-        let err = app
-            .execute_contract(
-                Addr::unchecked("user"),
-                addr,
+        assert_eq!(
+            resp,
+            ConfigResp {
+                owner: addr::owner(),
+                donation_denom: "eth".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn unauthorized() {
+        let mut app = app();
+        let contract = contract(&mut app, &[]);
+        let user = addr::user(1);
+
+        let err = contract
+            .execute(
+                &mut app,
+                "user1",
                 &ExecuteMsg::AddMembers {
-                    admins: vec!["user".to_owned()],
+                    admins: vec![user.to_string()],
                 },
                 &[],
             )
             .unwrap_err();
 
         assert_eq!(
-            ContractError::Unauthorized {
-                sender: Addr::unchecked("user")
-            },
+            ContractError::Unauthorized { sender: user },
             err.downcast().unwrap()
         );
     }
 
     #[test]
     fn add_members() {
-        let mut app = App::default();
-
-        let code = ContractWrapper::new(execute, instantiate, query);
-        let code_id = app.store_code(Box::new(code));
-
-        let addr = app
-            .instantiate_contract(
-                code_id,
-                Addr::unchecked("owner"),
-                &InstantiateMsg {
-                    admins: vec!["owner".to_owned()],
-                    donation_denom: "eth".to_owned(),
+        let mut app = app();
+        let contract = contract(&mut app, &["owner"]);
+        let user = addr::user(1);
+
+        let resp = contract
+            .execute(
+                &mut app,
+                "owner",
+                &ExecuteMsg::AddMembers {
+                    admins: vec![user.to_string()],
                 },
                 &[],
-                "Contract",
-                None,
             )
             .unwrap();
 
-        let resp = app
-            .execute_contract(
-                Addr::unchecked("owner"),
-                addr,
+        assert_eq!(wasm_attr(&resp, "action"), "add_members");
+        assert_eq!(wasm_attr(&resp, "added_count"), "1");
+
+        let admin_added = events(&resp, "admin_added");
+        assert_eq!(admin_added.len(), 1);
+        assert_eq!(attr(admin_added[0], "addr"), user);
+    }
+
+    #[test]
+    fn funds_rejected_on_non_payable() {
+        let mut app = app_with_balances(&[("owner", coins(10, "eth"))]);
+        let contract = contract(&mut app, &["owner"]);
+        let owner = addr::owner();
+        let user = addr::user(1);
+
+        let err = contract
+            .execute(
+                &mut app,
+                "owner",
                 &ExecuteMsg::AddMembers {
-                    admins: vec!["user".to_owned()],
+                    admins: vec![user.to_string()],
                 },
-                &[],
+                &coins(5, "eth"),
             )
-            .unwrap();
+            .unwrap_err();
 
-        let wasm = resp.events.iter().find(|ev| ev.ty == "wasm").unwrap();
         assert_eq!(
-            wasm.attributes
-                .iter()
-                .find(|attr| attr.key == "action")
-                .unwrap()
-                .value,
-            "add_members"
-        );
-        assert_eq!(
-            wasm.attributes
-                .iter()
-                .find(|attr| attr.key == "added_count")
-                .unwrap()
-                .value,
-            "1"
+            ContractError::Payment(PaymentError::NonPayable {}),
+            err.downcast().unwrap()
         );
 
-        let admin_added: Vec<_> = resp
-            .events
-            .iter()
-            .filter(|ev| ev.ty == "wasm-admin_added")
-            .collect();
-        assert_eq!(admin_added.len(), 1);
+        let err = contract
+            .execute(&mut app, "owner", &ExecuteMsg::Leave {}, &coins(5, "eth"))
+            .unwrap_err();
 
         assert_eq!(
-            admin_added[0]
-                .attributes
-                .iter()
-                .find(|attr| attr.key == "addr")
-                .unwrap()
-                .value,
-            "user"
+            ContractError::Payment(PaymentError::NonPayable {}),
+            err.downcast().unwrap()
         );
+
+        assert_eq!(balance(&app, &owner, "eth"), 10);
     }
 
     #[test]
     fn donations() {
-        let mut app = App::new(|router, _, storage| {
-            router
-                .bank
-                .init_balance(storage, &Addr::unchecked("user"), coins(5, "eth"))
-                .unwrap()
-        });
+        let mut app = app_with_balances(&[("user1", coins(5, "eth"))]);
+        let contract = contract(&mut app, &["admin1", "admin2"]);
 
-        let code = ContractWrapper::new(execute, instantiate, query);
-        let code_id = app.store_code(Box::new(code));
-
-        let addr = app
-            .instantiate_contract(
-                code_id,
-                Addr::unchecked("owner"),
-                &InstantiateMsg {
-                    admins: vec!["admin1".to_owned(), "admin2".to_owned()],
-                    donation_denom: "eth".to_owned(),
-                },
-                &[],
-                "Contract",
-                None,
-            )
+        let resp = contract
+            .execute(&mut app, "user1", &ExecuteMsg::Donate {}, &coins(5, "eth"))
             .unwrap();
 
-        app.execute_contract(
-            Addr::unchecked("user"),
-            addr.clone(),
-            &ExecuteMsg::Donate {},
-            &coins(5, "eth"),
-        )
-        .unwrap();
+        assert_event!(resp, "wasm", "action" => "donate", "amount" => 5, "per_admin" => 2);
+        for admin in [addr::admin(1), addr::admin(2)] {
+            assert_event!(
+                resp,
+                "transfer",
+                "sender" => contract.addr(),
+                "recipient" => admin,
+                "amount" => "2eth",
+            );
+        }
+        assert_eq!(
+            find_attr(&resp, "wasm", "_contract_address"),
+            Some(contract.addr().as_str())
+        );
+
+        assert_eq!(balance(&app, addr::user(1), "eth"), 0);
+        assert_eq!(balance(&app, contract.addr(), "eth"), 1);
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 2);
+        assert_eq!(balance(&app, addr::admin(2), "eth"), 2);
+
+        let resp: ContractFundsResp = contract.query(&app, &QueryMsg::ContractFunds {}).unwrap();
+        assert_eq!(resp.funds, coin(1, "eth"));
+    }
+
+    #[test]
+    fn invalid_donations() {
+        let mut app = app_with_balances(&[("user1", vec![coin(5, "eth"), coin(5, "btc")])]);
+        let contract = contract(&mut app, &["admin1"]);
+
+        let err = contract
+            .execute(&mut app, "user1", &ExecuteMsg::Donate {}, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Payment(PaymentError::NoFunds {}),
+            err.downcast().unwrap()
+        );
 
+        let err = contract
+            .execute(&mut app, "user1", &ExecuteMsg::Donate {}, &coins(5, "btc"))
+            .unwrap_err();
         assert_eq!(
-            app.wrap()
-                .query_balance("user", "eth")
-                .unwrap()
-                .amount
-                .u128(),
-            0
+            ContractError::Payment(PaymentError::MissingDenom("eth".to_owned())),
+            err.downcast().unwrap()
         );
 
+        let err = contract
+            .execute(
+                &mut app,
+                "user1",
+                &ExecuteMsg::Donate {},
+                &[coin(5, "btc"), coin(5, "eth")],
+            )
+            .unwrap_err();
         assert_eq!(
-            app.wrap()
-                .query_balance(&addr, "eth")
-                .unwrap()
-                .amount
-                .u128(),
-            1
+            ContractError::Payment(PaymentError::MultipleDenoms {}),
+            err.downcast().unwrap()
         );
 
+        // Failed transactions are reverted, funds stay with the donor
+        assert_eq!(balance(&app, addr::user(1), "eth"), 5);
+        assert_eq!(balance(&app, addr::user(1), "btc"), 5);
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 0);
+    }
+
+    #[test]
+    fn owner_is_not_admin() {
+        let mut app = app();
+        let contract = contract(&mut app, &["admin1"]);
+        let owner = addr::owner();
+
+        let err = contract
+            .execute(
+                &mut app,
+                "owner",
+                &ExecuteMsg::AddMembers {
+                    admins: vec![owner.to_string()],
+                },
+                &[],
+            )
+            .unwrap_err();
         assert_eq!(
-            app.wrap()
-                .query_balance("admin1", "eth")
-                .unwrap()
-                .amount
-                .u128(),
-            2
+            ContractError::Unauthorized { sender: owner },
+            err.downcast().unwrap()
         );
+    }
+
+    // The tests below call the entry points directly on mocked dependencies instead of going
+    // through multitest. There is no chain around the contract - messages it returns are not
+    // executed - so they are the right tool for checking a single handler, including the
+    // messages it emits, while multitest covers whole transactions. The mocked API validates
+    // addresses the way the multitest chain does, so the same `mock_addr` names are used.
+
+    #[test]
+    fn unit_instantiation() {
+        let mut deps = mock_dependencies();
+        let owner = addr::owner();
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {
+                admins: vec![addr::admin(1).into(), addr::admin(2).into()],
+                donation_denom: "eth".to_owned(),
+            },
+        )
+        .unwrap();
+
+        let resp = query(deps.as_ref(), mock_env(), QueryMsg::AdminsList {}).unwrap();
+        let resp: AdminsListResp = from_json(resp).unwrap();
+        assert_eq!(resp.admins, mock_addrs(&["admin1", "admin2"]));
+
+        let resp = query(deps.as_ref(), mock_env(), QueryMsg::Config {}).unwrap();
+        let resp: ConfigResp = from_json(resp).unwrap();
+        assert_eq!(resp.owner, owner);
+    }
+
+    #[test]
+    fn unit_unauthorized() {
+        let mut deps = mock_dependencies();
+        let user = addr::user(1);
+        let msg = InstantiateMsg {
+            admins: vec![addr::admin(1).into()],
+            donation_denom: "eth".to_owned(),
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::owner(), &[]),
+            msg,
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            ExecuteMsg::AddMembers {
+                admins: vec![user.to_string()],
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::Unauthorized { sender: user });
+    }
+
+    #[test]
+    fn unit_donation_messages() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            admins: vec![addr::admin(1).into(), addr::admin(2).into()],
+            donation_denom: "eth".to_owned(),
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::owner(), &[]),
+            msg,
+        )
+        .unwrap();
 
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::user(1), &coins(5, "eth")),
+            ExecuteMsg::Donate {},
+        )
+        .unwrap();
+
+        // Nothing was sent yet, the response only says what should be
+        let sent: Vec<_> = resp.messages.into_iter().map(|msg| msg.msg).collect();
         assert_eq!(
-            app.wrap()
-                .query_balance("admin2", "eth")
-                .unwrap()
-                .amount
-                .u128(),
-            2
+            sent,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: addr::admin(1).into(),
+                    amount: coins(2, "eth"),
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: addr::admin(2).into(),
+                    amount: coins(2, "eth"),
+                }),
+            ]
         );
     }
+
+    #[test]
+    fn unit_response_snapshots() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            admins: vec![addr::admin(1).into(), addr::admin(2).into()],
+            donation_denom: "eth".to_owned(),
+        };
+        let resp = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::owner(), &[]),
+            msg,
+        )
+        .unwrap();
+        assert_json_snapshot!("instantiate", resp);
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::admin(1), &[]),
+            ExecuteMsg::AddMembers {
+                admins: vec![addr::admin(3).into()],
+            },
+        )
+        .unwrap();
+        assert_json_snapshot!("add_members", resp);
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::user(1), &coins(10, "eth")),
+            ExecuteMsg::Donate {},
+        )
+        .unwrap();
+        assert_json_snapshot!("donate", resp);
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::admin(3), &[]),
+            ExecuteMsg::Leave {},
+        )
+        .unwrap();
+        assert_json_snapshot!("leave", resp);
+    }
 }
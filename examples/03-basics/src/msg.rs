@@ -1,4 +1,5 @@
-use cosmwasm_std::Addr;
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -31,7 +32,27 @@ pub struct AdminsListResp {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 #[serde(rename_all = "snake_case")]
+pub struct ConfigResp {
+    pub owner: Addr,
+    pub donation_denom: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContractFundsResp {
+    pub funds: Coin,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
+    #[returns(GreetResp)]
     Greet {},
+    #[returns(AdminsListResp)]
     AdminsList {},
+    #[returns(ConfigResp)]
+    Config {},
+    /// Donation denom funds held by the contract - the remainders left after splitting
+    #[returns(ContractFundsResp)]
+    ContractFunds {},
 }
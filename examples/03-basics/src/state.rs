@@ -3,3 +3,4 @@ use cw_storage_plus::Item;
 
 pub const ADMINS: Item<Vec<Addr>> = Item::new("admins");
 pub const DONATION_DENOM: Item<String> = Item::new("donation_denom");
+pub const OWNER: Item<Addr> = Item::new("owner");
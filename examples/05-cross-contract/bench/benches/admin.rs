@@ -0,0 +1,148 @@
+//! Admin contract handlers called directly on mocked dependencies, so only the contract itself
+//! is measured - no multitest routing or JSON round trips through the app.
+
+use admin::contract::{execute, instantiate, query};
+use admin::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use admin::state::SlashingConfig;
+use cosmwasm_std::testing::{
+    message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{coins, Decimal, OwnedDeps};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const ADMINS: [usize; 3] = [10, 100, 1000];
+
+type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+fn instantiate_msg(deps: &Deps, admins: usize) -> InstantiateMsg {
+    InstantiateMsg {
+        owner: None,
+        admins: (0..admins)
+            .map(|n| deps.api.addr_make(&format!("admin{}", n)).to_string())
+            .collect(),
+        donation_denom: "eth".to_owned(),
+        enrollment_until: None,
+        join_fee: None,
+        slashing: None,
+        dispute_window: None,
+        config_revert_window: None,
+    }
+}
+
+fn deploy(deps: &mut Deps, msg: InstantiateMsg) {
+    let owner = deps.api.addr_make("owner");
+    instantiate(deps.as_mut(), mock_env(), message_info(&owner, &[]), msg).unwrap();
+}
+
+fn contract(admins: usize) -> Deps {
+    let mut deps = mock_dependencies();
+    let msg = instantiate_msg(&deps, admins);
+    deploy(&mut deps, msg);
+    deps
+}
+
+fn admins_list(c: &mut Criterion) {
+    let mut group = c.benchmark_group("admins_list");
+    for admins in ADMINS {
+        let deps = contract(admins);
+        group.bench_with_input(BenchmarkId::from_parameter(admins), &deps, |b, deps| {
+            b.iter(|| {
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    black_box(QueryMsg::AdminsList {}),
+                )
+                .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn donate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("donate");
+    for admins in ADMINS {
+        let mut deps = contract(admins);
+        let donor = deps.api.addr_make("donor");
+        let info = message_info(&donor, &coins(admins as u128 * 10, "eth"));
+        group.bench_function(BenchmarkId::from_parameter(admins), |b| {
+            b.iter(|| {
+                execute(
+                    deps.as_mut(),
+                    mock_env(),
+                    info.clone(),
+                    black_box(ExecuteMsg::Donate {}),
+                )
+                .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+/// `ForAddress {}` of an admin with withheld donations and an open report, against the queries
+/// a client would otherwise send to collect the same data. Pending claims have no query of their
+/// own, so the individual queries even do less work.
+fn for_address(c: &mut Criterion) {
+    const DONATIONS: u64 = 5;
+
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        slashing: Some(SlashingConfig {
+            confirmations: 2,
+            slash: Decimal::percent(50),
+        }),
+        dispute_window: Some(100),
+        ..instantiate_msg(&deps, 10)
+    };
+    deploy(&mut deps, msg);
+
+    let admin = deps.api.addr_make("admin1");
+    for _ in 0..DONATIONS {
+        let info = message_info(&admin, &coins(100, "eth"));
+        execute(deps.as_mut(), mock_env(), info, ExecuteMsg::Donate {}).unwrap();
+    }
+    let reporter = deps.api.addr_make("admin0");
+    let msg = ExecuteMsg::ReportMisbehavior {
+        admin: admin.to_string(),
+        evidence: "evidence".to_owned(),
+    };
+    execute(deps.as_mut(), mock_env(), message_info(&reporter, &[]), msg).unwrap();
+
+    let admin = admin.to_string();
+    let mut group = c.benchmark_group("for_address");
+    group.bench_function("single", |b| {
+        b.iter(|| {
+            let msg = QueryMsg::ForAddress {
+                addr: admin.clone(),
+            };
+            query(deps.as_ref(), mock_env(), black_box(msg)).unwrap()
+        })
+    });
+    group.bench_function("individual", |b| {
+        b.iter(|| {
+            let msgs = [
+                QueryMsg::JoinTime {
+                    admin: admin.clone(),
+                },
+                QueryMsg::DonorTotals {
+                    donor: admin.clone(),
+                    start_after: None,
+                    limit: None,
+                },
+                QueryMsg::MisbehaviorReport {
+                    admin: admin.clone(),
+                },
+            ]
+            .into_iter()
+            .chain((0..DONATIONS).map(|id| QueryMsg::Donation { id }));
+
+            msgs.map(|msg| query(deps.as_ref(), mock_env(), black_box(msg)).unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, admins_list, donate, for_address);
+criterion_main!(benches);
@@ -0,0 +1,121 @@
+//! Stable, machine-readable codes for every `ContractError`.
+//!
+//! Error messages are meant for humans and may change between releases. Counterparties of
+//! asynchronous flows (IBC acknowledgements, submessage replies) should branch on the code
+//! instead. Codes are never reused - a removed variant retires its code for good.
+
+use crate::error::ContractError;
+
+/// The match is intentionally exhaustive with no wildcard arm, so adding a variant to
+/// `ContractError` fails to compile until it gets a code.
+pub fn error_code(err: &ContractError) -> u32 {
+    match err {
+        ContractError::StdError(_) => 1,
+        ContractError::Unauthorized { .. } => 2,
+        ContractError::NotOwner { .. } => 3,
+        ContractError::NotAdmin { .. } => 4,
+        ContractError::AlreadyAdmin { .. } => 5,
+        ContractError::EnrollmentClosed => 6,
+        ContractError::InvalidJoinFee { .. } => 7,
+        ContractError::InvalidDenom { .. } => 8,
+        ContractError::Payment(_) => 9,
+        ContractError::NoAdmins => 10,
+        ContractError::NothingToClaim => 11,
+        ContractError::SlashingDisabled => 12,
+        ContractError::SelfReport => 13,
+        ContractError::AlreadyConfirmed { .. } => 14,
+        ContractError::NoAllowance { .. } => 17,
+        ContractError::AllowanceExpired { .. } => 18,
+        ContractError::InsufficientAllowance { .. } => 19,
+        ContractError::InvalidExpiration => 20,
+        ContractError::DonationNotWithheld { .. } => 21,
+        ContractError::AlreadyDisputed { .. } => 22,
+        ContractError::NotDisputed { .. } => 23,
+        ContractError::NothingToRevert => 24,
+        ContractError::ClaimTooHigh { .. } => 25,
+        ContractError::InvalidProxyMessage => 26,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use cosmwasm_std::{coin, Addr, StdError};
+    use cw_utils::PaymentError;
+
+    use super::*;
+
+    fn all_variants() -> Vec<ContractError> {
+        vec![
+            StdError::generic_err("err").into(),
+            ContractError::Unauthorized {
+                sender: Addr::unchecked("user"),
+            },
+            ContractError::NotOwner {
+                sender: Addr::unchecked("user"),
+            },
+            ContractError::NotAdmin {
+                addr: Addr::unchecked("user"),
+            },
+            ContractError::AlreadyAdmin {
+                addr: Addr::unchecked("user"),
+            },
+            ContractError::EnrollmentClosed,
+            ContractError::InvalidJoinFee {
+                expected: coin(10, "eth"),
+            },
+            ContractError::InvalidDenom {
+                denom: "1".to_owned(),
+            },
+            PaymentError::NoFunds {}.into(),
+            ContractError::NoAdmins,
+            ContractError::NothingToClaim,
+            ContractError::SlashingDisabled,
+            ContractError::SelfReport,
+            ContractError::AlreadyConfirmed {
+                addr: Addr::unchecked("user"),
+            },
+            ContractError::NoAllowance {
+                spender: Addr::unchecked("user"),
+                denom: "eth".to_owned(),
+            },
+            ContractError::AllowanceExpired {
+                spender: Addr::unchecked("user"),
+                denom: "eth".to_owned(),
+            },
+            ContractError::InsufficientAllowance {
+                spender: Addr::unchecked("user"),
+                denom: "eth".to_owned(),
+            },
+            ContractError::InvalidExpiration,
+            ContractError::DonationNotWithheld { id: 1 },
+            ContractError::AlreadyDisputed { id: 1 },
+            ContractError::NotDisputed { id: 1 },
+            ContractError::NothingToRevert,
+            ContractError::ClaimTooHigh {
+                requested: coin(10, "eth"),
+                claimable: coin(5, "eth"),
+            },
+            ContractError::InvalidProxyMessage,
+        ]
+    }
+
+    #[test]
+    fn codes_are_unique() {
+        let variants = all_variants();
+        let codes: HashSet<_> = variants.iter().map(error_code).collect();
+
+        assert_eq!(codes.len(), variants.len());
+        assert!(!codes.contains(&0));
+    }
+
+    #[test]
+    fn codes_are_stable() {
+        let codes: Vec<_> = all_variants().iter().map(error_code).collect();
+
+        // 15 and 16 are retired, they were the counterparty timestamp errors
+        let expected: Vec<_> = (1..=14).chain(17..=26).collect();
+        assert_eq!(codes, expected);
+    }
+}
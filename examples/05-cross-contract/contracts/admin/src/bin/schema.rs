@@ -0,0 +1,19 @@
+use std::env::current_dir;
+
+use cosmwasm_schema::{export_schema_with_title, schema_for, write_api};
+
+use admin::msg::{ExecuteMsg, InstantiateMsg, InstantiateResp, MigrateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg,
+        migrate: MigrateMsg,
+    }
+
+    // Not an entry point message, so `write_api!` leaves it out
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    export_schema_with_title(&schema_for!(InstantiateResp), &out_dir, "InstantiateResp");
+}
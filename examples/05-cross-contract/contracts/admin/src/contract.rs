@@ -0,0 +1,3828 @@
+use crate::error::ContractError;
+use crate::events;
+use crate::fmt::fmt_amount;
+use crate::msg::{
+    AdminsCountResp, AdminsListResp, AllowanceInfo, AllowancesResp, ConfigResp, DashboardResp,
+    DonatedDenom, DonationResp, DonorTotalsResp, ExecuteMsg, ForAddressResp, HealthcheckResp,
+    InstantiateMsg, InstantiateResp, JoinTimeResp, MigrateMsg, MisbehaviorReportResp, QueryMsg,
+    RecentAdmin, RecentAdminsResp, WithheldDonation,
+};
+use crate::state::{
+    Allowance, Donation, Enrollment, MisbehaviorReport, PreviousConfig, Rate, ADMINS,
+    ADMINS_BY_JOIN_TIME, ADMINS_COUNT, ALLOWANCES, CLAIMABLE, CONFIG_REVERT_WINDOW, DISPUTE_WINDOW,
+    DONATED, DONATIONS, DONATION_DENOM, DONOR_TOTALS, ENROLLMENT, LAST_ACTIVITY, LEGACY_ADMINS,
+    NEXT_DONATION_ID, OWNER, PREVIOUS_CONFIG, RATES, REPORTS, SLASHING,
+};
+use common::pagination;
+use cosmwasm_std::{
+    coin, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Empty,
+    Env, MessageInfo, Order, Response, StdError, StdResult, Storage, Timestamp, Uint128, WasmMsg,
+};
+use cw_utils::Expiration;
+
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    for addr in msg.admins {
+        let admin = deps.api.addr_validate(&addr)?;
+        add_admin(deps.storage, &admin, env.block.time)?;
+    }
+    let owner = match msg.owner {
+        Some(owner) => deps.api.addr_validate(&owner)?,
+        None => info.sender,
+    };
+    DONATION_DENOM.save(deps.storage, &msg.donation_denom)?;
+    OWNER.save(deps.storage, &owner)?;
+    LAST_ACTIVITY.save(deps.storage, &env.block.time)?;
+
+    if let Some(until) = msg.enrollment_until {
+        let enrollment = Enrollment {
+            until,
+            fee: msg.join_fee,
+        };
+        ENROLLMENT.save(deps.storage, &enrollment)?;
+    }
+
+    if let Some(slashing) = msg.slashing {
+        if slashing.confirmations == 0 || slashing.slash > Decimal::one() {
+            return Err(StdError::generic_err("Invalid slashing config"));
+        }
+        SLASHING.save(deps.storage, &slashing)?;
+    }
+
+    if let Some(window) = msg.dispute_window {
+        DISPUTE_WINDOW.save(deps.storage, &window)?;
+    }
+
+    if let Some(window) = msg.config_revert_window {
+        CONFIG_REVERT_WINDOW.save(deps.storage, &window)?;
+    }
+
+    let data = InstantiateResp {
+        admins_count: ADMINS_COUNT.may_load(deps.storage)?.unwrap_or_default(),
+    };
+
+    Ok(Response::new().set_data(to_json_binary(&data)?))
+}
+
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> StdResult<Response> {
+    // The 03-basics layout keeps admins in a single list, which is moved to the map. The list
+    // has no join times, so the migration time is the best approximation.
+    if let Some(admins) = LEGACY_ADMINS.may_load(deps.storage)? {
+        for admin in &admins {
+            add_admin(deps.storage, admin, env.block.time)?;
+        }
+        LEGACY_ADMINS.remove(deps.storage);
+    }
+
+    if LAST_ACTIVITY.may_load(deps.storage)?.is_none() {
+        LAST_ACTIVITY.save(deps.storage, &env.block.time)?;
+    }
+
+    // Without an owner the configuration could never be updated again
+    if OWNER.may_load(deps.storage)?.is_none() {
+        let owner = deps.api.addr_validate(&msg.owner)?;
+        OWNER.save(deps.storage, &owner)?;
+    }
+
+    Ok(Response::new().add_attribute(events::ACTION, "migrate"))
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        AdminsList {} => to_json_binary(&query::admins_list(deps)?),
+        AdminsCount {} => to_json_binary(&query::admins_count(deps)?),
+        JoinTime { admin } => to_json_binary(&query::join_time(deps, admin)?),
+        RecentAdmins { limit } => to_json_binary(&query::recent_admins(deps, limit)?),
+        ForAddress { addr } => to_json_binary(&query::for_address(deps, addr)?),
+        Config {} => to_json_binary(&query::config(deps, env)?),
+        Healthcheck {} => to_json_binary(&query::healthcheck(deps, env)?),
+        Dashboard {} => to_json_binary(&query::dashboard(deps)?),
+        MisbehaviorReport { admin } => to_json_binary(&query::misbehavior_report(deps, admin)?),
+        Donation { id } => to_json_binary(&query::donation(deps, id)?),
+        Allowances {
+            spender,
+            start_after,
+            limit,
+        } => to_json_binary(&query::allowances(deps, env, spender, start_after, limit)?),
+        DonorTotals {
+            donor,
+            start_after,
+            limit,
+        } => to_json_binary(&query::donor_totals(deps, donor, start_after, limit)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    LAST_ACTIVITY.save(deps.storage, &env.block.time)?;
+    release_donations(deps.storage, env.block.height)?;
+    discard_previous_config(deps.storage, env.block.time)?;
+
+    match msg {
+        AddMembers { admins } => exec::add_members(deps, env, info, admins),
+        RemoveMembers { admins } => exec::remove_members(deps, info, admins),
+        Join {} => exec::join(deps, env, info),
+        Leave {} => exec::leave(deps, info),
+        Donate {} => exec::donate(deps, env, info),
+        Claim { amount } => exec::claim(deps, info, amount),
+        Dispute { id } => exec::dispute(deps, info, id),
+        ResolveDispute { id, refund } => exec::resolve_dispute(deps, info, id, refund),
+        ReportMisbehavior { admin, evidence } => {
+            exec::report_misbehavior(deps, info, admin, evidence)
+        }
+        UpdateConfig { donation_denom } => exec::update_config(deps, env, info, donation_denom),
+        RevertConfig {} => exec::revert_config(deps, env, info),
+        SetRate { denom, rate } => exec::set_rate(deps, env, info, denom, rate),
+        Execute { msgs } => exec::execute(deps, env, info, msgs),
+        IncreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => exec::increase_allowance(deps, env, info, spender, amount, expires),
+        DecreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => exec::decrease_allowance(deps, env, info, spender, amount, expires),
+    }
+}
+
+/// Stores the admin together with its join-time index entry and bumps the admins counter.
+/// Re-adding an existing admin keeps the original join time and returns `false`.
+fn add_admin(storage: &mut dyn Storage, admin: &Addr, joined: Timestamp) -> StdResult<bool> {
+    if ADMINS.has(storage, admin) {
+        return Ok(false);
+    }
+
+    ADMINS.save(storage, admin, &joined)?;
+    ADMINS_BY_JOIN_TIME.save(storage, (joined.nanos(), admin), &Empty {})?;
+
+    let count = ADMINS_COUNT.may_load(storage)?.unwrap_or_default();
+    ADMINS_COUNT.save(storage, &(count + 1))?;
+
+    Ok(true)
+}
+
+fn remove_admin(storage: &mut dyn Storage, admin: &Addr) -> StdResult<()> {
+    if let Some(joined) = ADMINS.may_load(storage, admin)? {
+        ADMINS.remove(storage, admin);
+        ADMINS_BY_JOIN_TIME.remove(storage, (joined.nanos(), admin));
+
+        let count = ADMINS_COUNT.load(storage)?;
+        ADMINS_COUNT.save(storage, &(count - 1))?;
+    }
+
+    Ok(())
+}
+
+/// Splits the amount evenly between the admins as pending claims, returning the share of a
+/// single admin. The remainder stays with the contract.
+fn distribute(storage: &mut dyn Storage, admins: &[Addr], amount: &Coin) -> StdResult<Uint128> {
+    let per_admin = amount.amount / Uint128::from(admins.len() as u128);
+
+    for admin in admins {
+        CLAIMABLE.update(
+            storage,
+            (admin, &amount.denom),
+            |claimable| -> StdResult<_> { Ok(claimable.unwrap_or_default() + per_admin) },
+        )?;
+    }
+
+    Ok(per_admin)
+}
+
+/// Distributes undisputed donations whose dispute window closed before the `height`.
+///
+/// Runs before every execution, so a withheld donation which is not disputed is always still
+/// within its window by the time a handler sees it.
+fn release_donations(storage: &mut dyn Storage, height: u64) -> StdResult<()> {
+    let window = match DISPUTE_WINDOW.may_load(storage)? {
+        Some(window) => window,
+        None => return Ok(()),
+    };
+
+    // Ids grow with the height, so the first donation still in its window ends the scan
+    let mut released = vec![];
+    for donation in DONATIONS.range(storage, None, None, Order::Ascending) {
+        let (id, donation) = donation?;
+        if donation.height + window >= height {
+            break;
+        }
+        if !donation.disputed {
+            released.push((id, donation));
+        }
+    }
+
+    for (id, donation) in released {
+        distribute(storage, &donation.admins, &donation.amount)?;
+        DONATIONS.remove(storage, id);
+    }
+
+    Ok(())
+}
+
+/// Previous config, unless its revert window closed before `now`. Both window bounds are
+/// inclusive.
+fn previous_config(storage: &dyn Storage, now: Timestamp) -> StdResult<Option<PreviousConfig>> {
+    let window = CONFIG_REVERT_WINDOW.may_load(storage)?.unwrap_or_default();
+    let previous = PREVIOUS_CONFIG
+        .may_load(storage)?
+        .filter(|previous| now <= previous.changed_at.plus_seconds(window));
+
+    Ok(previous)
+}
+
+/// Drops the previous config once it can no longer be reverted to. Runs before every
+/// execution, like `release_donations`.
+fn discard_previous_config(storage: &mut dyn Storage, now: Timestamp) -> StdResult<()> {
+    if previous_config(storage, now)?.is_none() {
+        PREVIOUS_CONFIG.remove(storage);
+    }
+
+    Ok(())
+}
+
+/// Takes the amount of the current donation denom out of the address' pending claims, leaving
+/// the rest to accrue.
+fn claim_partial(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    amount: Uint128,
+) -> Result<Coin, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim);
+    }
+
+    let denom = DONATION_DENOM.load(storage)?;
+    let claim = coin(amount.u128(), &denom);
+    take_claim(storage, addr, &claim)?;
+
+    Ok(claim)
+}
+
+/// Takes the amount out of the address' pending claims of its denom.
+fn take_claim(storage: &mut dyn Storage, addr: &Addr, amount: &Coin) -> Result<(), ContractError> {
+    let claimable = CLAIMABLE
+        .may_load(storage, (addr, &amount.denom))?
+        .unwrap_or_default();
+
+    let left = claimable
+        .checked_sub(amount.amount)
+        .map_err(|_| ContractError::ClaimTooHigh {
+            requested: amount.clone(),
+            claimable: coin(claimable.u128(), &amount.denom),
+        })?;
+
+    if left.is_zero() {
+        CLAIMABLE.remove(storage, (addr, &amount.denom));
+    } else {
+        CLAIMABLE.save(storage, (addr, &amount.denom), &left)?;
+    }
+
+    Ok(())
+}
+
+/// All pending claims of the address, ordered by denom.
+fn pending_claims(storage: &dyn Storage, addr: &Addr) -> StdResult<Vec<Coin>> {
+    CLAIMABLE
+        .prefix(addr)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(Coin { denom, amount })
+        })
+        .collect()
+}
+
+/// Checks the denom against the Cosmos SDK rules: 3 to 128 characters, starting with a letter,
+/// followed by alphanumerics or one of `/:._-`.
+fn validate_denom(denom: &str) -> Result<(), ContractError> {
+    let mut chars = denom.chars();
+    let valid = (3..=128).contains(&denom.len())
+        && chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c));
+
+    if !valid {
+        return Err(ContractError::InvalidDenom {
+            denom: denom.to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Execution handlers, one per message variant. They are public so tests can call them directly
+/// with mock dependencies, skipping the message dispatch.
+pub mod exec {
+    use super::*;
+
+    pub fn add_members(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        admins: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let admins = admins
+            .into_iter()
+            .map(|addr| deps.api.addr_validate(&addr))
+            .collect::<StdResult<Vec<_>>>()?;
+
+        // Existing admins and repeated addresses are skipped, only new admins are reported
+        let mut added = vec![];
+        for admin in admins {
+            if add_admin(deps.storage, &admin, env.block.time)? {
+                added.push(admin);
+            }
+        }
+
+        let events = added
+            .iter()
+            .map(|admin| events::admin_added(admin, env.block.time));
+        let resp = Response::new()
+            .add_events(events)
+            .add_attribute(events::ACTION, "add_members")
+            .add_attribute(events::ADDED_COUNT, added.len().to_string());
+
+        Ok(resp)
+    }
+
+    pub fn remove_members(
+        deps: DepsMut,
+        info: MessageInfo,
+        admins: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let admins = admins
+            .into_iter()
+            .map(|addr| deps.api.addr_validate(&addr))
+            .collect::<StdResult<Vec<_>>>()?;
+
+        // Removal is all-or-nothing: a single unknown address rejects the whole batch
+        for admin in &admins {
+            if !ADMINS.has(deps.storage, admin) {
+                return Err(ContractError::NotAdmin {
+                    addr: admin.clone(),
+                });
+            }
+        }
+
+        for admin in &admins {
+            remove_admin(deps.storage, admin)?;
+        }
+
+        let events = admins.iter().map(events::admin_removed);
+        let resp = Response::new()
+            .add_events(events)
+            .add_attribute(events::ACTION, "remove_members")
+            .add_attribute(events::REMOVED_COUNT, admins.len().to_string());
+
+        Ok(resp)
+    }
+
+    pub fn join(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let enrollment = ENROLLMENT
+            .may_load(deps.storage)?
+            .filter(|enrollment| env.block.time < enrollment.until)
+            .ok_or(ContractError::EnrollmentClosed)?;
+
+        match enrollment.fee {
+            Some(fee) => {
+                let paid = cw_utils::must_pay(&info, &fee.denom)?;
+                if paid != fee.amount {
+                    return Err(ContractError::InvalidJoinFee { expected: fee });
+                }
+            }
+            None => {
+                cw_utils::nonpayable(&info)?;
+            }
+        }
+
+        if ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::AlreadyAdmin { addr: info.sender });
+        }
+
+        add_admin(deps.storage, &info.sender, env.block.time)?;
+
+        let resp = Response::new()
+            .add_event(events::admin_added(&info.sender, env.block.time))
+            .add_attribute(events::ACTION, "join");
+
+        Ok(resp)
+    }
+
+    pub fn leave(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        remove_admin(deps.storage, &info.sender)?;
+
+        Ok(Response::new())
+    }
+
+    pub fn donate(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let denom = DONATION_DENOM.load(deps.storage)?;
+        let admins: Vec<_> = ADMINS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        if admins.is_empty() {
+            return Err(ContractError::NoAdmins);
+        }
+
+        let donation = cw_utils::must_pay(&info, &denom)?;
+        DONATED.update(deps.storage, &denom, |donated| -> StdResult<_> {
+            Ok(donated.unwrap_or_default() + donation)
+        })?;
+        DONOR_TOTALS.update(
+            deps.storage,
+            (&info.sender, &denom),
+            |total| -> StdResult<_> { Ok(total.unwrap_or_default() + donation) },
+        )?;
+
+        let resp = Response::new()
+            .add_attribute(events::ACTION, "donate")
+            .add_attribute(events::AMOUNT, fmt_amount(donation, &denom));
+
+        if DISPUTE_WINDOW.may_load(deps.storage)?.is_some() {
+            let id = NEXT_DONATION_ID.may_load(deps.storage)?.unwrap_or_default();
+            NEXT_DONATION_ID.save(deps.storage, &(id + 1))?;
+
+            let donation = Donation {
+                donor: info.sender,
+                amount: coin(donation.u128(), &denom),
+                admins,
+                height: env.block.height,
+                disputed: false,
+            };
+            DONATIONS.save(deps.storage, id, &donation)?;
+
+            return Ok(resp.add_attribute(events::DONATION_ID, id.to_string()));
+        }
+
+        let donation_per_admin = distribute(deps.storage, &admins, &coin(donation.u128(), &denom))?;
+
+        Ok(resp.add_attribute(events::PER_ADMIN, fmt_amount(donation_per_admin, &denom)))
+    }
+
+    pub fn claim(
+        deps: DepsMut,
+        info: MessageInfo,
+        amount: Option<Uint128>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        let claims = match amount {
+            Some(amount) => vec![claim_partial(deps.storage, &info.sender, amount)?],
+            None => {
+                let claims = pending_claims(deps.storage, &info.sender)?;
+                for claim in &claims {
+                    CLAIMABLE.remove(deps.storage, (&info.sender, &claim.denom));
+                }
+                claims
+            }
+        };
+        if claims.is_empty() {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: claims,
+            })
+            .add_attribute(events::ACTION, "claim");
+
+        Ok(resp)
+    }
+
+    pub fn dispute(deps: DepsMut, info: MessageInfo, id: u64) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        // Donations are released once their window closes, so finding one means it is still open
+        let mut donation = DONATIONS
+            .may_load(deps.storage, id)?
+            .ok_or(ContractError::DonationNotWithheld { id })?;
+        if donation.donor != info.sender {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+        if donation.disputed {
+            return Err(ContractError::AlreadyDisputed { id });
+        }
+
+        donation.disputed = true;
+        DONATIONS.save(deps.storage, id, &donation)?;
+
+        let resp = Response::new()
+            .add_attribute(events::ACTION, "dispute")
+            .add_attribute(events::DONATION_ID, id.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn resolve_dispute(
+        deps: DepsMut,
+        info: MessageInfo,
+        id: u64,
+        refund: bool,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let donation = DONATIONS
+            .may_load(deps.storage, id)?
+            .ok_or(ContractError::DonationNotWithheld { id })?;
+        if !donation.disputed {
+            return Err(ContractError::NotDisputed { id });
+        }
+        DONATIONS.remove(deps.storage, id);
+
+        let mut resp = Response::new()
+            .add_attribute(events::ACTION, "resolve_dispute")
+            .add_attribute(events::DONATION_ID, id.to_string())
+            .add_attribute(events::REFUND, refund.to_string());
+
+        if refund {
+            DONATED.update(
+                deps.storage,
+                &donation.amount.denom,
+                |donated| -> StdResult<_> {
+                    Ok(donated.unwrap_or_default() - donation.amount.amount)
+                },
+            )?;
+            let key = (&donation.donor, donation.amount.denom.as_str());
+            let total = DONOR_TOTALS.load(deps.storage, key)? - donation.amount.amount;
+            if total.is_zero() {
+                DONOR_TOTALS.remove(deps.storage, key);
+            } else {
+                DONOR_TOTALS.save(deps.storage, key, &total)?;
+            }
+            resp = resp.add_message(BankMsg::Send {
+                to_address: donation.donor.to_string(),
+                amount: vec![donation.amount],
+            });
+        } else {
+            distribute(deps.storage, &donation.admins, &donation.amount)?;
+        }
+
+        Ok(resp)
+    }
+
+    pub fn set_rate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        denom: String,
+        rate: Option<Decimal>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::NotOwner {
+                sender: info.sender,
+            });
+        }
+
+        validate_denom(&denom)?;
+
+        let resp = Response::new()
+            .add_attribute(events::ACTION, "set_rate")
+            .add_attribute(events::DENOM, &denom);
+
+        let resp = match rate {
+            Some(rate) => {
+                let rate = Rate {
+                    rate,
+                    updated: env.block.time,
+                };
+                RATES.save(deps.storage, &denom, &rate)?;
+                resp.add_attribute(events::RATE, rate.rate.to_string())
+            }
+            None => {
+                RATES.remove(deps.storage, &denom);
+                resp
+            }
+        };
+
+        Ok(resp)
+    }
+
+    pub fn report_misbehavior(
+        deps: DepsMut,
+        info: MessageInfo,
+        admin: String,
+        evidence: String,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        let slashing = SLASHING
+            .may_load(deps.storage)?
+            .ok_or(ContractError::SlashingDisabled)?;
+
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let admin = deps.api.addr_validate(&admin)?;
+        if !ADMINS.has(deps.storage, &admin) {
+            return Err(ContractError::NotAdmin { addr: admin });
+        }
+        if admin == info.sender {
+            return Err(ContractError::SelfReport);
+        }
+
+        let mut report = REPORTS
+            .may_load(deps.storage, &admin)?
+            .unwrap_or(MisbehaviorReport {
+                evidence,
+                confirmations: vec![],
+            });
+        if report.confirmations.contains(&info.sender) {
+            return Err(ContractError::AlreadyConfirmed { addr: info.sender });
+        }
+        report.confirmations.push(info.sender.clone());
+
+        let resp = Response::new()
+            .add_attribute(events::ACTION, "report_misbehavior")
+            .add_event(events::misbehavior_reported(
+                &admin,
+                &info.sender,
+                report.confirmations.len(),
+            ));
+
+        if report.confirmations.len() < slashing.confirmations as usize {
+            REPORTS.save(deps.storage, &admin, &report)?;
+            return Ok(resp);
+        }
+
+        REPORTS.remove(deps.storage, &admin);
+
+        let mut burned = vec![];
+        for claim in pending_claims(deps.storage, &admin)? {
+            let slashed = claim.amount.mul_floor(slashing.slash);
+            if slashed.is_zero() {
+                continue;
+            }
+
+            let left = claim.amount - slashed;
+            if left.is_zero() {
+                CLAIMABLE.remove(deps.storage, (&admin, &claim.denom));
+            } else {
+                CLAIMABLE.save(deps.storage, (&admin, &claim.denom), &left)?;
+            }
+            burned.push(coin(slashed.u128(), claim.denom));
+        }
+
+        let mut resp = resp.add_event(events::admin_slashed(&admin, &burned));
+        if !burned.is_empty() {
+            resp = resp.add_message(BankMsg::Burn { amount: burned });
+        }
+
+        Ok(resp)
+    }
+
+    pub fn update_config(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        donation_denom: Option<String>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::NotOwner {
+                sender: info.sender,
+            });
+        }
+
+        let mut resp = Response::new().add_attribute(events::ACTION, "update_config");
+
+        if let Some(new_denom) = donation_denom {
+            validate_denom(&new_denom)?;
+            let old_denom = DONATION_DENOM.load(deps.storage)?;
+            DONATION_DENOM.save(deps.storage, &new_denom)?;
+
+            if CONFIG_REVERT_WINDOW.may_load(deps.storage)?.is_some() {
+                let previous = PreviousConfig {
+                    donation_denom: old_denom.clone(),
+                    changed_at: env.block.time,
+                };
+                PREVIOUS_CONFIG.save(deps.storage, &previous)?;
+            }
+
+            resp = resp.add_event(events::config_updated(
+                "donation_denom",
+                old_denom,
+                new_denom,
+            ));
+        }
+
+        Ok(resp)
+    }
+
+    pub fn revert_config(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::NotOwner {
+                sender: info.sender,
+            });
+        }
+
+        // `execute` discards an outdated previous config anyway, but the handler does not rely on
+        // running after it
+        let previous =
+            previous_config(deps.storage, env.block.time)?.ok_or(ContractError::NothingToRevert)?;
+        PREVIOUS_CONFIG.remove(deps.storage);
+
+        let old_denom = DONATION_DENOM.load(deps.storage)?;
+        DONATION_DENOM.save(deps.storage, &previous.donation_denom)?;
+
+        let resp = Response::new()
+            .add_attribute(events::ACTION, "revert_config")
+            .add_event(events::config_updated(
+                "donation_denom",
+                old_denom,
+                previous.donation_denom,
+            ));
+
+        Ok(resp)
+    }
+
+    pub fn execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msgs: Vec<CosmosMsg>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        // Funds held by the contract belong to the admins' claims and withheld donations, so
+        // no message may move them beyond what the sender is entitled to
+        if ADMINS.has(deps.storage, &info.sender) {
+            for msg in &msgs {
+                match msg {
+                    CosmosMsg::Wasm(WasmMsg::Execute { funds, .. }) if funds.is_empty() => (),
+                    _ => return Err(ContractError::InvalidProxyMessage),
+                }
+            }
+        } else {
+            for msg in &msgs {
+                match msg {
+                    CosmosMsg::Bank(BankMsg::Send { amount, .. }) => {
+                        for coin in amount {
+                            spend_allowance(deps.storage, &env, &info.sender, coin)?;
+                        }
+                    }
+                    _ => {
+                        return Err(ContractError::Unauthorized {
+                            sender: info.sender,
+                        })
+                    }
+                }
+            }
+        }
+
+        let resp = Response::new()
+            .add_messages(msgs)
+            .add_attribute(events::ACTION, "execute")
+            .add_attribute(events::SENDER, info.sender);
+
+        Ok(resp)
+    }
+
+    /// Charges the spending to the allowance, and to the pending claims of its granter.
+    fn spend_allowance(
+        storage: &mut dyn Storage,
+        env: &Env,
+        spender: &Addr,
+        coin: &Coin,
+    ) -> Result<(), ContractError> {
+        let allowance = ALLOWANCES
+            .may_load(storage, (spender, &coin.denom))?
+            .ok_or_else(|| ContractError::NoAllowance {
+                spender: spender.clone(),
+                denom: coin.denom.clone(),
+            })?;
+
+        if allowance.expires.is_expired(&env.block) {
+            return Err(ContractError::AllowanceExpired {
+                spender: spender.clone(),
+                denom: coin.denom.clone(),
+            });
+        }
+
+        let amount = allowance.amount.checked_sub(coin.amount).map_err(|_| {
+            ContractError::InsufficientAllowance {
+                spender: spender.clone(),
+                denom: coin.denom.clone(),
+            }
+        })?;
+
+        take_claim(storage, &allowance.granter, coin)?;
+
+        if amount.is_zero() {
+            ALLOWANCES.remove(storage, (spender, &coin.denom));
+        } else {
+            let allowance = Allowance {
+                amount,
+                ..allowance
+            };
+            ALLOWANCES.save(storage, (spender, &coin.denom), &allowance)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn increase_allowance(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        spender: String,
+        amount: Coin,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        if expires.as_ref().is_some_and(|e| e.is_expired(&env.block)) {
+            return Err(ContractError::InvalidExpiration);
+        }
+
+        let spender = deps.api.addr_validate(&spender)?;
+        let key = (&spender, amount.denom.as_str());
+        let allowance = match ALLOWANCES.may_load(deps.storage, key)? {
+            Some(allowance) if !allowance.expires.is_expired(&env.block) => Allowance {
+                granter: info.sender,
+                amount: allowance.amount + amount.amount,
+                expires: expires.unwrap_or(allowance.expires),
+            },
+            _ => Allowance {
+                granter: info.sender,
+                amount: amount.amount,
+                expires: expires.unwrap_or_default(),
+            },
+        };
+        ALLOWANCES.save(deps.storage, key, &allowance)?;
+
+        let resp = Response::new()
+            .add_attribute(events::ACTION, "increase_allowance")
+            .add_attribute(events::SPENDER, &spender)
+            .add_attribute(events::AMOUNT, fmt_amount(amount.amount, &amount.denom));
+
+        Ok(resp)
+    }
+
+    pub fn decrease_allowance(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        spender: String,
+        amount: Coin,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        if expires.as_ref().is_some_and(|e| e.is_expired(&env.block)) {
+            return Err(ContractError::InvalidExpiration);
+        }
+
+        let spender = deps.api.addr_validate(&spender)?;
+        let key = (&spender, amount.denom.as_str());
+        let allowance = ALLOWANCES
+            .may_load(deps.storage, key)?
+            .filter(|allowance| !allowance.expires.is_expired(&env.block))
+            .ok_or_else(|| ContractError::NoAllowance {
+                spender: spender.clone(),
+                denom: amount.denom.clone(),
+            })?;
+
+        let left = allowance.amount.saturating_sub(amount.amount);
+        if left.is_zero() {
+            ALLOWANCES.remove(deps.storage, key);
+        } else {
+            let allowance = Allowance {
+                amount: left,
+                expires: expires.unwrap_or(allowance.expires),
+                ..allowance
+            };
+            ALLOWANCES.save(deps.storage, key, &allowance)?;
+        }
+
+        let resp = Response::new()
+            .add_attribute(events::ACTION, "decrease_allowance")
+            .add_attribute(events::SPENDER, &spender)
+            .add_attribute(events::AMOUNT, fmt_amount(amount.amount, &amount.denom));
+
+        Ok(resp)
+    }
+}
+
+/// Query handlers, public for the same reason as `exec`.
+pub mod query {
+    use super::*;
+
+    pub fn admins_list(deps: Deps) -> StdResult<AdminsListResp> {
+        // Until the legacy list is migrated away it is the source of truth, the map may be only
+        // partially filled at that point
+        if let Some(admins) = LEGACY_ADMINS.may_load(deps.storage)? {
+            return Ok(AdminsListResp { admins });
+        }
+
+        let admins = ADMINS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        let resp = AdminsListResp { admins };
+        Ok(resp)
+    }
+
+    pub fn admins_count(deps: Deps) -> StdResult<AdminsCountResp> {
+        let count = ADMINS_COUNT.may_load(deps.storage)?.unwrap_or_default();
+        Ok(AdminsCountResp { count })
+    }
+
+    pub fn join_time(deps: Deps, admin: String) -> StdResult<JoinTimeResp> {
+        let admin = deps.api.addr_validate(&admin)?;
+        let joined = ADMINS.load(deps.storage, &admin)?;
+        Ok(JoinTimeResp { joined })
+    }
+
+    pub fn recent_admins(deps: Deps, limit: Option<u32>) -> StdResult<RecentAdminsResp> {
+        let limit = pagination::limit(limit);
+
+        let admins = ADMINS_BY_JOIN_TIME
+            .keys(deps.storage, None, None, Order::Descending)
+            .take(limit)
+            .map(|key| {
+                let (joined, addr) = key?;
+                Ok(RecentAdmin {
+                    addr,
+                    joined: Timestamp::from_nanos(joined),
+                })
+            })
+            .collect::<StdResult<_>>()?;
+
+        Ok(RecentAdminsResp { admins })
+    }
+
+    pub fn for_address(deps: Deps, addr: String) -> StdResult<ForAddressResp> {
+        let addr = deps.api.addr_validate(&addr)?;
+        let joined = ADMINS.may_load(deps.storage, &addr)?;
+        let claimable = pending_claims(deps.storage, &addr)?;
+        // A donor only ever uses a handful of denoms, so there is nothing to paginate
+        let donated = DONOR_TOTALS
+            .prefix(&addr)
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|item| {
+                let (denom, amount) = item?;
+                Ok(Coin { denom, amount })
+            })
+            .collect::<StdResult<_>>()?;
+        // Donations are not indexed by donor, but only the withheld ones are kept around
+        let withheld = DONATIONS
+            .range(deps.storage, None, None, Order::Ascending)
+            .filter_map(|item| match item {
+                Ok((id, donation)) if donation.donor == addr => Some(Ok(WithheldDonation {
+                    id,
+                    amount: donation.amount,
+                    height: donation.height,
+                    disputed: donation.disputed,
+                })),
+                Ok(_) => None,
+                Err(err) => Some(Err(err)),
+            })
+            .collect::<StdResult<_>>()?;
+        let misbehavior_report = REPORTS.may_load(deps.storage, &addr)?;
+
+        Ok(ForAddressResp {
+            addr,
+            is_admin: joined.is_some(),
+            joined,
+            claimable,
+            donated,
+            withheld,
+            misbehavior_report,
+        })
+    }
+
+    pub fn config(deps: Deps, env: Env) -> StdResult<ConfigResp> {
+        let resp = ConfigResp {
+            owner: OWNER.load(deps.storage)?,
+            donation_denom: DONATION_DENOM.load(deps.storage)?,
+            enrollment: ENROLLMENT.may_load(deps.storage)?,
+            slashing: SLASHING.may_load(deps.storage)?,
+            dispute_window: DISPUTE_WINDOW.may_load(deps.storage)?,
+            config_revert_window: CONFIG_REVERT_WINDOW.may_load(deps.storage)?,
+            previous: previous_config(deps.storage, env.block.time)?,
+        };
+        Ok(resp)
+    }
+
+    pub fn dashboard(deps: Deps) -> StdResult<DashboardResp> {
+        let mut resp = DashboardResp {
+            donated: vec![],
+            total_value: Uint128::zero(),
+            unpriced: vec![],
+        };
+
+        for donated in DONATED.range(deps.storage, None, None, Order::Ascending) {
+            let (denom, amount) = donated?;
+            let rate = RATES.may_load(deps.storage, &denom)?;
+            let value = rate.as_ref().map(|rate| amount.mul_floor(rate.rate));
+
+            match value {
+                Some(value) => resp.total_value += value,
+                None => resp.unpriced.push(denom.clone()),
+            }
+
+            resp.donated.push(DonatedDenom {
+                denom,
+                amount,
+                rate,
+                value,
+            });
+        }
+
+        Ok(resp)
+    }
+
+    pub fn healthcheck(deps: Deps, env: Env) -> StdResult<HealthcheckResp> {
+        let admins_count = ADMINS_COUNT.may_load(deps.storage)?.unwrap_or_default();
+        let enrollment_open = ENROLLMENT
+            .may_load(deps.storage)?
+            .is_some_and(|enrollment| env.block.time < enrollment.until);
+
+        let mut pending_disputes = 0;
+        for donation in DONATIONS.range(deps.storage, None, None, Order::Ascending) {
+            let (_, donation) = donation?;
+            if donation.disputed {
+                pending_disputes += 1;
+            }
+        }
+
+        Ok(HealthcheckResp {
+            donations_enabled: admins_count > 0,
+            enrollment_open,
+            last_activity: LAST_ACTIVITY.load(deps.storage)?,
+            migration_pending: LEGACY_ADMINS.exists(deps.storage),
+            pending_disputes,
+        })
+    }
+
+    pub fn misbehavior_report(deps: Deps, admin: String) -> StdResult<MisbehaviorReportResp> {
+        let admin = deps.api.addr_validate(&admin)?;
+        let report = REPORTS.may_load(deps.storage, &admin)?;
+        Ok(MisbehaviorReportResp { report })
+    }
+
+    pub fn donation(deps: Deps, id: u64) -> StdResult<DonationResp> {
+        let donation = DONATIONS.may_load(deps.storage, id)?;
+        Ok(DonationResp { donation })
+    }
+
+    pub fn allowances(
+        deps: Deps,
+        env: Env,
+        spender: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AllowancesResp> {
+        let spender = deps.api.addr_validate(&spender)?;
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after.as_deref());
+
+        let allowances: Vec<_> = ALLOWANCES
+            .prefix(&spender)
+            .range(deps.storage, start, None, Order::Ascending)
+            .filter(|item| {
+                item.as_ref().map_or(true, |(_, allowance)| {
+                    !allowance.expires.is_expired(&env.block)
+                })
+            })
+            .take(limit)
+            .map(|item| {
+                let (denom, allowance) = item?;
+                Ok(AllowanceInfo {
+                    denom,
+                    granter: allowance.granter,
+                    amount: allowance.amount,
+                    expires: allowance.expires,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after =
+            pagination::next_key(&allowances, limit, |allowance| allowance.denom.clone());
+
+        Ok(AllowancesResp {
+            allowances,
+            next_start_after,
+        })
+    }
+
+    pub fn donor_totals(
+        deps: Deps,
+        donor: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<DonorTotalsResp> {
+        let donor = deps.api.addr_validate(&donor)?;
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after.as_deref());
+
+        // Prefixing with the donor leaves only the denom part of the key to range over
+        let totals: Vec<_> = DONOR_TOTALS
+            .prefix(&donor)
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (denom, amount) = item?;
+                Ok(Coin { denom, amount })
+            })
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after = pagination::next_key(&totals, limit, |total| total.denom.clone());
+
+        Ok(DonorTotalsResp {
+            totals,
+            next_start_after,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::from_json;
+    use cosmwasm_std::testing::{
+        message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::OwnedDeps;
+    use cw_utils::PaymentError;
+    use std::sync::OnceLock;
+
+    use testing::prelude::*;
+
+    use crate::msg::{DashboardResp, DonatedDenom, DonationResp, DonorTotalsResp};
+    use crate::state::{PreviousConfig, Rate, SlashingConfig};
+
+    use super::*;
+
+    /// Message instantiating the contract with the admins, the "eth" donation denom and every
+    /// optional feature disabled. Tests override the fields they need with struct update syntax.
+    fn instantiate_msg(admins: &[Addr]) -> InstantiateMsg {
+        InstantiateMsg {
+            owner: None,
+            admins: admins.iter().map(Addr::to_string).collect(),
+            donation_denom: "eth".to_owned(),
+            enrollment_until: None,
+            join_fee: None,
+            slashing: None,
+            dispute_window: None,
+            config_revert_window: None,
+        }
+    }
+
+    /// Stores the contract code and instantiates it by the owner.
+    fn deploy(app: &mut App, msg: &InstantiateMsg) -> Addr {
+        let code_id = store_code(app, ContractWrapper::new(execute, instantiate, query));
+        app.instantiate_contract(code_id, addr::owner(), msg, &[], "Contract", None)
+            .unwrap()
+    }
+
+    #[test]
+    fn instantiation() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::AdminsList {})
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: sorted_addrs(&["admin1", "admin2"]),
+            }
+        );
+
+        let resp: JoinTimeResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::JoinTime {
+                    admin: addr::admin(1).to_string(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            JoinTimeResp {
+                joined: app.block_info().time
+            }
+        );
+    }
+
+    #[test]
+    fn join_time_across_blocks() {
+        let mut app = app();
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::admin(1)]));
+        let first_joined = app.block_info().time;
+
+        let join_time = |app: &App, admin: Addr| {
+            app.wrap()
+                .query_wasm_smart::<JoinTimeResp>(
+                    &addr,
+                    &QueryMsg::JoinTime {
+                        admin: admin.to_string(),
+                    },
+                )
+                .unwrap()
+                .joined
+        };
+
+        app.advance_blocks(10);
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &ExecuteMsg::AddMembers {
+                admins: vec![addr::admin(2).to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+        let second_joined = app.block_info().time;
+
+        app.advance_time(3600);
+        app.execute_contract(
+            addr::admin(2),
+            addr.clone(),
+            &ExecuteMsg::AddMembers {
+                admins: vec![addr::admin(3).to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+        let third_joined = app.block_info().time;
+
+        assert_eq!(second_joined, first_joined.plus_seconds(10 * BLOCK_TIME));
+        assert_eq!(third_joined, second_joined.plus_seconds(3600));
+
+        assert_eq!(join_time(&app, addr::admin(1)), first_joined);
+        assert_eq!(join_time(&app, addr::admin(2)), second_joined);
+        assert_eq!(join_time(&app, addr::admin(3)), third_joined);
+
+        // Adding an existing admin again keeps the original join time
+        app.advance_blocks(1);
+        app.execute_contract(
+            addr::admin(3),
+            addr.clone(),
+            &ExecuteMsg::AddMembers {
+                admins: vec![addr::admin(1).to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(join_time(&app, addr::admin(1)), first_joined);
+    }
+
+    #[test]
+    fn config_query() {
+        let mut app = app();
+
+        let enrollment_until = app.block_info().time.plus_seconds(100);
+        let addr = deploy(
+            &mut app,
+            &InstantiateMsg {
+                enrollment_until: Some(enrollment_until),
+                ..instantiate_msg(&[])
+            },
+        );
+
+        let resp: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(addr, &QueryMsg::Config {})
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            ConfigResp {
+                owner: addr::owner(),
+                donation_denom: "eth".to_owned(),
+                enrollment: Some(Enrollment {
+                    until: enrollment_until,
+                    fee: None,
+                }),
+                slashing: None,
+                dispute_window: None,
+                config_revert_window: None,
+                previous: None,
+            }
+        );
+    }
+
+    #[test]
+    fn owner_set_on_instantiation() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &InstantiateMsg {
+                owner: Some(addr::user(1).to_string()),
+                ..instantiate_msg(&[])
+            },
+        );
+
+        let resp: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(resp.owner, addr::user(1));
+    }
+
+    #[test]
+    fn update_config() {
+        let mut app = app();
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::admin(1)]));
+
+        let resp = app
+            .execute_contract(
+                addr::owner(),
+                addr.clone(),
+                &ExecuteMsg::UpdateConfig {
+                    donation_denom: Some("atom".to_owned()),
+                },
+                &[],
+            )
+            .unwrap();
+
+        let updated = event(&resp, events::CONFIG_UPDATED);
+        assert_eq!(attr(updated, events::OLD), "eth");
+        assert_eq!(attr(updated, events::NEW), "atom");
+
+        let resp: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(resp.donation_denom, "atom");
+
+        let err = app
+            .execute_contract(
+                addr::owner(),
+                addr,
+                &ExecuteMsg::UpdateConfig {
+                    donation_denom: Some("1nvalid denom".to_owned()),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidDenom {
+                denom: "1nvalid denom".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn revert_config() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &InstantiateMsg {
+                config_revert_window: Some(60),
+                ..instantiate_msg(&[addr::admin(1)])
+            },
+        );
+
+        let exec = |app: &mut App, sender: &str, msg: ExecuteMsg| {
+            app.execute_contract(app.api().addr_make(sender), addr.clone(), &msg, &[])
+        };
+        let update = |denom: &str| ExecuteMsg::UpdateConfig {
+            donation_denom: Some(denom.to_owned()),
+        };
+        let config = |app: &App| -> ConfigResp {
+            app.wrap()
+                .query_wasm_smart(&addr, &QueryMsg::Config {})
+                .unwrap()
+        };
+
+        let err = exec(&mut app, "owner", ExecuteMsg::RevertConfig {}).unwrap_err();
+        assert_eq!(ContractError::NothingToRevert, err.downcast().unwrap());
+
+        let changed_at = app.block_info().time;
+        exec(&mut app, "owner", update("atom")).unwrap();
+        assert_eq!(
+            config(&app).previous,
+            Some(PreviousConfig {
+                donation_denom: "eth".to_owned(),
+                changed_at,
+            })
+        );
+
+        let err = exec(&mut app, "admin1", ExecuteMsg::RevertConfig {}).unwrap_err();
+        assert_eq!(
+            ContractError::NotOwner {
+                sender: addr::admin(1)
+            },
+            err.downcast().unwrap()
+        );
+
+        // The last second of the window still allows reverting
+        app.advance_time(60);
+        let resp = exec(&mut app, "owner", ExecuteMsg::RevertConfig {}).unwrap();
+        let updated = event(&resp, events::CONFIG_UPDATED);
+        assert_eq!(attr(updated, events::OLD), "atom");
+        assert_eq!(attr(updated, events::NEW), "eth");
+
+        let resp = config(&app);
+        assert_eq!(resp.donation_denom, "eth");
+        assert_eq!(resp.previous, None);
+
+        // A revert cannot be reverted
+        let err = exec(&mut app, "owner", ExecuteMsg::RevertConfig {}).unwrap_err();
+        assert_eq!(ContractError::NothingToRevert, err.downcast().unwrap());
+
+        // Only the last change is kept
+        exec(&mut app, "owner", update("atom")).unwrap();
+        exec(&mut app, "owner", update("osmo")).unwrap();
+        assert_eq!(config(&app).previous.unwrap().donation_denom, "atom");
+
+        app.advance_time(61);
+        assert_eq!(config(&app).previous, None);
+
+        let err = exec(&mut app, "owner", ExecuteMsg::RevertConfig {}).unwrap_err();
+        assert_eq!(ContractError::NothingToRevert, err.downcast().unwrap());
+        assert_eq!(config(&app).donation_denom, "osmo");
+
+        // Any successful execution discards the expired version from the storage
+        exec(&mut app, "admin1", ExecuteMsg::Leave {}).unwrap();
+        let raw = app
+            .wrap()
+            .query_wasm_raw(&addr, b"previous_config".to_vec());
+        assert_eq!(raw.unwrap(), None);
+    }
+
+    #[test]
+    fn update_config_unauthorized() {
+        let mut app = app();
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::admin(1)]));
+
+        for sender in ["admin1", "user"] {
+            let err = app
+                .execute_contract(
+                    app.api().addr_make(sender),
+                    addr.clone(),
+                    &ExecuteMsg::UpdateConfig {
+                        donation_denom: Some("atom".to_owned()),
+                    },
+                    &[],
+                )
+                .unwrap_err();
+
+            assert_eq!(
+                ContractError::NotOwner {
+                    sender: app.api().addr_make(sender)
+                },
+                err.downcast().unwrap()
+            );
+        }
+
+        let resp: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(resp.donation_denom, "eth");
+    }
+
+    #[test]
+    fn admins_list_from_legacy_layout() {
+        let mut deps = mock_dependencies();
+        LEGACY_ADMINS
+            .save(&mut deps.storage, &vec![addr::admin(1), addr::admin(2)])
+            .unwrap();
+
+        let resp: AdminsListResp =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::AdminsList {}).unwrap()).unwrap();
+
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: vec![addr::admin(1), addr::admin(2)],
+            }
+        );
+    }
+
+    #[test]
+    fn admins_list_from_map_layout() {
+        let mut deps = mock_dependencies();
+        let now = mock_env().block.time;
+        add_admin(&mut deps.storage, &addr::admin(1), now).unwrap();
+        add_admin(&mut deps.storage, &addr::admin(2), now).unwrap();
+
+        let resp: AdminsListResp =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::AdminsList {}).unwrap()).unwrap();
+
+        // The map lists admins in address order
+        let mut admins = vec![addr::admin(1), addr::admin(2)];
+        admins.sort();
+        assert_eq!(resp, AdminsListResp { admins });
+    }
+
+    #[test]
+    fn admins_list_mid_migration() {
+        let mut deps = mock_dependencies();
+        LEGACY_ADMINS
+            .save(&mut deps.storage, &vec![addr::admin(1), addr::admin(2)])
+            .unwrap();
+        add_admin(&mut deps.storage, &addr::admin(1), mock_env().block.time).unwrap();
+
+        let resp: AdminsListResp =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::AdminsList {}).unwrap()).unwrap();
+
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: vec![addr::admin(1), addr::admin(2)],
+            }
+        );
+    }
+
+    #[test]
+    fn unauthorized() {
+        let mut app = app();
+
+        let addr = deploy(&mut app, &instantiate_msg(&[]));
+
+        let err = app
+            .execute_contract(
+                app.api().addr_make("user"),
+                addr,
+                &ExecuteMsg::AddMembers {
+                    admins: vec![app.api().addr_make("user").to_string()],
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: app.api().addr_make("user")
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn add_members() {
+        let mut app = app();
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::owner()]));
+
+        let resp = app
+            .execute_contract(
+                addr::owner(),
+                addr.clone(),
+                &ExecuteMsg::AddMembers {
+                    admins: vec![app.api().addr_make("user").to_string()],
+                },
+                &[],
+            )
+            .unwrap();
+
+        assert_eq!(wasm_attr(&resp, events::ACTION), "add_members");
+        assert_eq!(
+            attr(event(&resp, events::ADMIN_ADDED), events::JOINED),
+            "2019-10-23T02:23:39.879305533Z"
+        );
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: vec![addr::owner(), app.api().addr_make("user")],
+            }
+        );
+    }
+
+    #[test]
+    fn add_members_skips_existing() {
+        let mut app = app();
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::admin(1)]));
+
+        let resp = app
+            .execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &ExecuteMsg::AddMembers {
+                    admins: vec![
+                        addr::admin(1).to_string(),
+                        addr::admin(2).to_string(),
+                        addr::admin(2).to_string(),
+                    ],
+                },
+                &[],
+            )
+            .unwrap();
+
+        assert_eq!(wasm_attr(&resp, events::ADDED_COUNT), "1");
+        let added = events(&resp, events::ADMIN_ADDED);
+        assert_eq!(added.len(), 1);
+        assert_eq!(attr(added[0], events::ADDR), addr::admin(2).as_str());
+
+        let resp: AdminsCountResp = app
+            .wrap()
+            .query_wasm_smart(addr, &QueryMsg::AdminsCount {})
+            .unwrap();
+        assert_eq!(resp.count, 2);
+    }
+
+    #[test]
+    fn leave() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+
+        app.execute_contract(addr::admin(1), addr.clone(), &ExecuteMsg::Leave {}, &[])
+            .unwrap();
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::AdminsList {})
+            .unwrap();
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: vec![addr::admin(2)],
+            }
+        );
+
+        let resp: RecentAdminsResp = app
+            .wrap()
+            .query_wasm_smart(addr, &QueryMsg::RecentAdmins { limit: None })
+            .unwrap();
+        assert_eq!(
+            resp.admins
+                .into_iter()
+                .map(|admin| admin.addr)
+                .collect::<Vec<_>>(),
+            vec![addr::admin(2)]
+        );
+    }
+
+    #[test]
+    fn admins_count() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+
+        let count = |app: &App| {
+            app.wrap()
+                .query_wasm_smart::<AdminsCountResp>(&addr, &QueryMsg::AdminsCount {})
+                .unwrap()
+                .count
+        };
+        assert_eq!(count(&app), 2);
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &ExecuteMsg::AddMembers {
+                admins: vec![
+                    addr::admin(2).to_string(),
+                    addr::admin(3).to_string(),
+                    addr::admin(4).to_string(),
+                ],
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(count(&app), 4);
+
+        app.execute_contract(addr::admin(2), addr.clone(), &ExecuteMsg::Leave {}, &[])
+            .unwrap();
+        assert_eq!(count(&app), 3);
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &ExecuteMsg::RemoveMembers {
+                admins: vec![addr::admin(3).to_string(), addr::admin(4).to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(count(&app), 1);
+
+        app.execute_contract(
+            app.api().addr_make("user"),
+            addr.clone(),
+            &ExecuteMsg::Leave {},
+            &[],
+        )
+        .unwrap();
+        assert_eq!(count(&app), 1);
+    }
+
+    #[test]
+    fn recent_admins() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+        let first_joined = app.block_info().time;
+
+        app.advance_blocks(1);
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &ExecuteMsg::AddMembers {
+                admins: vec![addr::admin(4).to_string(), addr::admin(3).to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+        let second_joined = app.block_info().time;
+
+        let resp: RecentAdminsResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::RecentAdmins { limit: None })
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            RecentAdminsResp {
+                // Admins joined in the same block come in descending address order
+                admins: vec![
+                    RecentAdmin {
+                        addr: addr::admin(4),
+                        joined: second_joined,
+                    },
+                    RecentAdmin {
+                        addr: addr::admin(3),
+                        joined: second_joined,
+                    },
+                    RecentAdmin {
+                        addr: addr::admin(1),
+                        joined: first_joined,
+                    },
+                    RecentAdmin {
+                        addr: addr::admin(2),
+                        joined: first_joined,
+                    },
+                ]
+            }
+        );
+
+        let resp: RecentAdminsResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::RecentAdmins { limit: Some(3) })
+            .unwrap();
+
+        assert_eq!(
+            resp.admins
+                .into_iter()
+                .map(|admin| admin.addr)
+                .collect::<Vec<_>>(),
+            vec![addr::admin(4), addr::admin(3), addr::admin(1)]
+        );
+    }
+
+    #[test]
+    fn remove_members() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2), addr::admin(3)]),
+        );
+
+        let resp = app
+            .execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &ExecuteMsg::RemoveMembers {
+                    admins: vec![addr::admin(2).to_string(), addr::admin(3).to_string()],
+                },
+                &[],
+            )
+            .unwrap();
+
+        let admin_removed: Vec<_> = events(&resp, events::ADMIN_REMOVED)
+            .into_iter()
+            .map(|ev| attr(ev, events::ADDR))
+            .collect();
+        assert_eq!(admin_removed, mock_addrs(&["admin2", "admin3"]));
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: vec![addr::admin(1)],
+            }
+        );
+    }
+
+    #[test]
+    fn remove_members_partially_unknown() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+
+        let err = app
+            .execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &ExecuteMsg::RemoveMembers {
+                    admins: vec![
+                        addr::admin(2).to_string(),
+                        app.api().addr_make("stranger").to_string(),
+                    ],
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::NotAdmin {
+                addr: app.api().addr_make("stranger")
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: sorted_addrs(&["admin1", "admin2"]),
+            }
+        );
+    }
+
+    #[test]
+    fn join_during_enrollment() {
+        let mut app =
+            app_with_balances(&[("user1", coins(20, "eth")), ("user2", coins(10, "eth"))]);
+
+        let enrollment_until = app.block_info().time.plus_seconds(100);
+        let addr = deploy(
+            &mut app,
+            &InstantiateMsg {
+                enrollment_until: Some(enrollment_until),
+                join_fee: Some(coin(10, "eth")),
+                ..instantiate_msg(&[])
+            },
+        );
+
+        let err = app
+            .execute_contract(
+                addr::user(1),
+                addr.clone(),
+                &ExecuteMsg::Join {},
+                &coins(5, "eth"),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidJoinFee {
+                expected: coin(10, "eth")
+            },
+            err.downcast().unwrap()
+        );
+
+        app.update_block(|block| block.time = enrollment_until.minus_seconds(1));
+
+        app.execute_contract(
+            addr::user(1),
+            addr.clone(),
+            &ExecuteMsg::Join {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                addr::user(1),
+                addr.clone(),
+                &ExecuteMsg::Join {},
+                &coins(10, "eth"),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::AlreadyAdmin {
+                addr: addr::user(1)
+            },
+            err.downcast().unwrap()
+        );
+
+        app.update_block(|block| block.time = enrollment_until);
+
+        let err = app
+            .execute_contract(
+                addr::user(2),
+                addr.clone(),
+                &ExecuteMsg::Join {},
+                &coins(10, "eth"),
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::EnrollmentClosed, err.downcast().unwrap());
+
+        app.execute_contract(
+            addr::user(1),
+            addr.clone(),
+            &ExecuteMsg::AddMembers {
+                admins: vec![addr::user(2).to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::AdminsList {})
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: vec![addr::user(1), addr::user(2)],
+            }
+        );
+
+        assert_eq!(balance(&app, &addr, "eth"), 10);
+    }
+
+    #[test]
+    fn join_without_enrollment() {
+        let mut app = app();
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::admin(1)]));
+
+        let err = app
+            .execute_contract(app.api().addr_make("user"), addr, &ExecuteMsg::Join {}, &[])
+            .unwrap_err();
+
+        assert_eq!(ContractError::EnrollmentClosed, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn for_address() {
+        let mut app = app_with_balances(&[("user", coins(10, "eth"))]);
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::admin(1)]));
+
+        let resp: ForAddressResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::ForAddress {
+                    addr: addr::admin(1).to_string(),
+                },
+            )
+            .unwrap();
+
+        let admins: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::AdminsList {})
+            .unwrap();
+        let join_time: JoinTimeResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::JoinTime {
+                    admin: addr::admin(1).to_string(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            ForAddressResp {
+                addr: addr::admin(1),
+                is_admin: admins.admins.contains(&addr::admin(1)),
+                joined: Some(join_time.joined),
+                claimable: vec![],
+                donated: vec![],
+                withheld: vec![],
+                misbehavior_report: None,
+            }
+        );
+
+        app.execute_contract(
+            app.api().addr_make("user"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+
+        let resp: ForAddressResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::ForAddress {
+                    addr: app.api().addr_make("user").to_string(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            ForAddressResp {
+                addr: app.api().addr_make("user"),
+                is_admin: false,
+                joined: None,
+                claimable: vec![],
+                donated: coins(10, "eth"),
+                withheld: vec![],
+                misbehavior_report: None,
+            }
+        );
+    }
+
+    #[test]
+    fn for_address_withheld_and_reported() {
+        let mut app = app_with_balances(&[("user", coins(30, "eth"))]);
+
+        let addr = deploy(
+            &mut app,
+            &InstantiateMsg {
+                slashing: Some(SlashingConfig {
+                    confirmations: 2,
+                    slash: Decimal::percent(50),
+                }),
+                dispute_window: Some(10),
+                ..instantiate_msg(&[addr::admin(1), addr::admin(2), addr::admin(3)])
+            },
+        );
+        let user = app.api().addr_make("user");
+        let for_address = |app: &App, who: &Addr| -> ForAddressResp {
+            app.wrap()
+                .query_wasm_smart(
+                    &addr,
+                    &QueryMsg::ForAddress {
+                        addr: who.to_string(),
+                    },
+                )
+                .unwrap()
+        };
+
+        app.execute_contract(
+            user.clone(),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+        let height = app.block_info().height;
+        app.advance_blocks(1);
+        app.execute_contract(
+            user.clone(),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(20, "eth"),
+        )
+        .unwrap();
+        app.execute_contract(
+            user.clone(),
+            addr.clone(),
+            &ExecuteMsg::Dispute { id: 1 },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &ExecuteMsg::ReportMisbehavior {
+                admin: addr::admin(2).to_string(),
+                evidence: "evidence".to_owned(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let resp = for_address(&app, &user);
+        assert_eq!(
+            resp.withheld,
+            vec![
+                WithheldDonation {
+                    id: 0,
+                    amount: coin(10, "eth"),
+                    height,
+                    disputed: false,
+                },
+                WithheldDonation {
+                    id: 1,
+                    amount: coin(20, "eth"),
+                    height: height + 1,
+                    disputed: true,
+                },
+            ]
+        );
+        assert_eq!(resp.misbehavior_report, None);
+
+        let resp = for_address(&app, &addr::admin(2));
+        assert!(resp.withheld.is_empty());
+        assert_eq!(
+            resp.misbehavior_report,
+            Some(MisbehaviorReport {
+                evidence: "evidence".to_owned(),
+                confirmations: vec![addr::admin(1)],
+            })
+        );
+
+        // Once released, the undisputed donation is no longer withheld
+        app.advance_blocks(11);
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &ExecuteMsg::Claim { amount: None },
+            &[],
+        )
+        .unwrap();
+        let resp = for_address(&app, &user);
+        assert_eq!(
+            resp.withheld.iter().map(|d| d.id).collect::<Vec<_>>(),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn funds_rejected_on_non_payable() {
+        let mut app = app_with_balances(&[("owner", coins(20, "eth"))]);
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::owner(), addr::admin(1)]));
+
+        let msgs = [
+            ExecuteMsg::AddMembers {
+                admins: vec![app.api().addr_make("user").to_string()],
+            },
+            ExecuteMsg::RemoveMembers {
+                admins: vec![addr::admin(1).to_string()],
+            },
+            ExecuteMsg::Leave {},
+            ExecuteMsg::UpdateConfig {
+                donation_denom: None,
+            },
+        ];
+
+        for msg in msgs {
+            let err = app
+                .execute_contract(addr::owner(), addr.clone(), &msg, &coins(5, "eth"))
+                .unwrap_err();
+
+            assert_eq!(
+                ContractError::Payment(PaymentError::NonPayable {}),
+                err.downcast().unwrap()
+            );
+        }
+
+        assert_eq!(balance(&app, addr::owner(), "eth"), 20);
+    }
+
+    #[test]
+    fn healthcheck() {
+        let mut app = app();
+
+        let instantiated = app.block_info().time;
+        let enrollment_until = instantiated.plus_seconds(100);
+        let addr = deploy(
+            &mut app,
+            &InstantiateMsg {
+                enrollment_until: Some(enrollment_until),
+                ..instantiate_msg(&[])
+            },
+        );
+
+        let health = |app: &App| -> HealthcheckResp {
+            app.wrap()
+                .query_wasm_smart(&addr, &QueryMsg::Healthcheck {})
+                .unwrap()
+        };
+
+        assert_eq!(
+            health(&app),
+            HealthcheckResp {
+                donations_enabled: false,
+                enrollment_open: true,
+                last_activity: instantiated,
+                migration_pending: false,
+                pending_disputes: 0,
+            }
+        );
+
+        let err = app
+            .execute_contract(
+                app.api().addr_make("user"),
+                addr.clone(),
+                &ExecuteMsg::Donate {},
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::NoAdmins, err.downcast().unwrap());
+
+        app.advance_time(10);
+        app.execute_contract(
+            app.api().addr_make("user"),
+            addr.clone(),
+            &ExecuteMsg::Join {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            health(&app),
+            HealthcheckResp {
+                donations_enabled: true,
+                enrollment_open: true,
+                last_activity: instantiated.plus_seconds(10),
+                migration_pending: false,
+                pending_disputes: 0,
+            }
+        );
+
+        app.update_block(|block| block.time = enrollment_until);
+
+        assert_eq!(
+            health(&app),
+            HealthcheckResp {
+                donations_enabled: true,
+                enrollment_open: false,
+                last_activity: instantiated.plus_seconds(10),
+                migration_pending: false,
+                pending_disputes: 0,
+            }
+        );
+
+        app.execute_contract(
+            app.api().addr_make("user"),
+            addr.clone(),
+            &ExecuteMsg::Leave {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            health(&app),
+            HealthcheckResp {
+                donations_enabled: false,
+                enrollment_open: false,
+                last_activity: enrollment_until,
+                migration_pending: false,
+                pending_disputes: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn healthcheck_migration_pending() {
+        let mut deps = mock_dependencies();
+        LEGACY_ADMINS
+            .save(&mut deps.storage, &vec![addr::admin(1)])
+            .unwrap();
+        LAST_ACTIVITY
+            .save(&mut deps.storage, &mock_env().block.time)
+            .unwrap();
+
+        let health = |deps: &OwnedDeps<_, _, _>| -> HealthcheckResp {
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::Healthcheck {}).unwrap()).unwrap()
+        };
+        assert!(health(&deps).migration_pending);
+
+        LEGACY_ADMINS.remove(&mut deps.storage);
+        assert!(!health(&deps).migration_pending);
+    }
+
+    #[test]
+    fn donations() {
+        let mut app = app_with_balances(&[("user", coins(5, "eth"))]);
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+
+        let resp = app
+            .execute_contract(
+                app.api().addr_make("user"),
+                addr.clone(),
+                &ExecuteMsg::Donate {},
+                &coins(5, "eth"),
+            )
+            .unwrap();
+
+        assert_eq!(wasm_attr(&resp, events::AMOUNT), "5eth");
+        assert_eq!(wasm_attr(&resp, events::PER_ADMIN), "2eth");
+
+        for admin in ["admin1", "admin2"] {
+            app.execute_contract(
+                app.api().addr_make(admin),
+                addr.clone(),
+                &ExecuteMsg::Claim { amount: None },
+                &[],
+            )
+            .unwrap();
+        }
+
+        assert_eq!(balance(&app, &addr, "eth"), 1);
+
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 2);
+
+        assert_eq!(balance(&app, addr::admin(2), "eth"), 2);
+    }
+
+    #[test]
+    fn claims() {
+        let mut app = app_with_balances(&[("user", coins(10, "eth"))]);
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+
+        for _ in 0..2 {
+            app.execute_contract(
+                app.api().addr_make("user"),
+                addr.clone(),
+                &ExecuteMsg::Donate {},
+                &coins(5, "eth"),
+            )
+            .unwrap();
+        }
+
+        let resp: ForAddressResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::ForAddress {
+                    addr: addr::admin(1).to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.claimable, coins(4, "eth"));
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &ExecuteMsg::Claim { amount: None },
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 4);
+        assert_eq!(balance(&app, &addr, "eth"), 6);
+
+        let err = app
+            .execute_contract(
+                addr::admin(1),
+                addr,
+                &ExecuteMsg::Claim { amount: None },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::NothingToClaim, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn partial_claims() {
+        let mut app = app_with_balances(&[("user", coins(20, "eth"))]);
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+
+        app.execute_contract(
+            app.api().addr_make("user"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(20, "eth"),
+        )
+        .unwrap();
+
+        let claim = |app: &mut App, amount: u128| {
+            app.execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &ExecuteMsg::Claim {
+                    amount: Some(Uint128::new(amount)),
+                },
+                &[],
+            )
+        };
+        let claimable = |app: &App| {
+            app.wrap()
+                .query_wasm_smart::<ForAddressResp>(
+                    &addr,
+                    &QueryMsg::ForAddress {
+                        addr: addr::admin(1).to_string(),
+                    },
+                )
+                .unwrap()
+                .claimable
+        };
+
+        claim(&mut app, 4).unwrap();
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 4);
+        assert_eq!(claimable(&app), coins(6, "eth"));
+
+        let err = claim(&mut app, 7).unwrap_err();
+        assert_eq!(
+            ContractError::ClaimTooHigh {
+                requested: coin(7, "eth"),
+                claimable: coin(6, "eth"),
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = claim(&mut app, 0).unwrap_err();
+        assert_eq!(ContractError::NothingToClaim, err.downcast().unwrap());
+
+        // Claiming the exact balance clears it
+        claim(&mut app, 6).unwrap();
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 10);
+        assert_eq!(claimable(&app), vec![]);
+
+        let err = claim(&mut app, 1).unwrap_err();
+        assert_eq!(
+            ContractError::ClaimTooHigh {
+                requested: coin(1, "eth"),
+                claimable: coin(0, "eth"),
+            },
+            err.downcast().unwrap()
+        );
+
+        // Other admins' claims are untouched
+        app.execute_contract(
+            addr::admin(2),
+            addr,
+            &ExecuteMsg::Claim { amount: None },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, addr::admin(2), "eth"), 10);
+    }
+
+    #[test]
+    fn misbehavior_slashing() {
+        let mut app = app_with_balances(&[("user", coins(75, "eth"))]);
+
+        let addr = deploy(
+            &mut app,
+            &InstantiateMsg {
+                slashing: Some(SlashingConfig {
+                    confirmations: 2,
+                    slash: Decimal::percent(50),
+                }),
+                ..instantiate_msg(&[addr::admin(1), addr::admin(2), addr::admin(3)])
+            },
+        );
+
+        app.execute_contract(
+            app.api().addr_make("user"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(75, "eth"),
+        )
+        .unwrap();
+
+        let report = |admin: &str, evidence: &str| ExecuteMsg::ReportMisbehavior {
+            admin: mock_addr(admin).to_string(),
+            evidence: evidence.to_owned(),
+        };
+
+        let err = app
+            .execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &report("admin1", "evidence"),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::SelfReport, err.downcast().unwrap());
+
+        let err = app
+            .execute_contract(
+                app.api().addr_make("user"),
+                addr.clone(),
+                &report("admin1", "evidence"),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: app.api().addr_make("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        app.execute_contract(
+            addr::admin(2),
+            addr.clone(),
+            &report("admin1", "evidence"),
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                addr::admin(2),
+                addr.clone(),
+                &report("admin1", "other evidence"),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::AlreadyConfirmed {
+                addr: addr::admin(2)
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp: MisbehaviorReportResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::MisbehaviorReport {
+                    admin: addr::admin(1).to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            resp.report,
+            Some(MisbehaviorReport {
+                evidence: "evidence".to_owned(),
+                confirmations: vec![addr::admin(2)],
+            })
+        );
+
+        app.execute_contract(
+            addr::admin(3),
+            addr.clone(),
+            &report("admin1", "other evidence"),
+            &[],
+        )
+        .unwrap();
+
+        let resp: MisbehaviorReportResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::MisbehaviorReport {
+                    admin: addr::admin(1).to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.report, None);
+
+        // 25eth pending, half of it rounded down is burned
+        assert_eq!(balance(&app, &addr, "eth"), 63);
+
+        let resp: ForAddressResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::ForAddress {
+                    addr: addr::admin(1).to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.claimable, coins(13, "eth"));
+        assert!(resp.is_admin);
+    }
+
+    #[test]
+    fn misbehavior_without_slashing() {
+        let mut app = app();
+
+        let addr = deploy(
+            &mut app,
+            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+        );
+
+        let err = app
+            .execute_contract(
+                addr::admin(2),
+                addr,
+                &ExecuteMsg::ReportMisbehavior {
+                    admin: addr::admin(1).to_string(),
+                    evidence: "evidence".to_owned(),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::SlashingDisabled, err.downcast().unwrap());
+    }
+
+    /// Admin contract with `admin1` and `admin2` as admins, each with 10eth and 10atom to claim.
+    /// The contract holds another 100eth and 100atom, which are no one's claims.
+    fn proxy_app() -> (App, Addr) {
+        static SNAPSHOT: OnceLock<(Snapshot, Addr)> = OnceLock::new();
+
+        let (snapshot, addr) = SNAPSHOT.get_or_init(|| {
+            Snapshot::new(
+                |app| {
+                    store_code(app, ContractWrapper::new(execute, instantiate, query));
+                },
+                |app| {
+                    app.init_modules(|router, _, storage| {
+                        router
+                            .bank
+                            .init_balance(
+                                storage,
+                                &addr::owner(),
+                                vec![coin(120, "eth"), coin(120, "atom")],
+                            )
+                            .unwrap()
+                    });
+
+                    // The only stored code
+                    let addr = app
+                        .instantiate_contract(
+                            1,
+                            addr::owner(),
+                            &instantiate_msg(&[addr::admin(1), addr::admin(2)]),
+                            &[coin(100, "eth"), coin(100, "atom")],
+                            "Contract",
+                            None,
+                        )
+                        .unwrap();
+
+                    let donate = |app: &mut App, amount: Coin| {
+                        app.execute_contract(
+                            addr::owner(),
+                            addr.clone(),
+                            &ExecuteMsg::Donate {},
+                            &[amount],
+                        )
+                        .unwrap();
+                    };
+                    donate(app, coin(20, "eth"));
+                    app.execute_contract(
+                        addr::owner(),
+                        addr.clone(),
+                        &ExecuteMsg::UpdateConfig {
+                            donation_denom: Some("atom".to_owned()),
+                        },
+                        &[],
+                    )
+                    .unwrap();
+                    donate(app, coin(20, "atom"));
+
+                    addr
+                },
+            )
+        });
+
+        (snapshot.app(), addr.clone())
+    }
+
+    /// Contract accepting any message, reporting its sender as the caller.
+    fn external_contract() -> ContractWrapper<Empty, Empty, Empty, StdError, StdError, StdError> {
+        ContractWrapper::new(
+            |_, _, info, _: Empty| -> StdResult<Response> {
+                Ok(Response::new().add_attribute("caller", info.sender))
+            },
+            |_, _, _, _: Empty| -> StdResult<Response> { Ok(Response::new()) },
+            |_, _, _: Empty| -> StdResult<Binary> { to_json_binary(&Empty {}) },
+        )
+    }
+
+    fn forward(contract: &Addr, funds: Vec<Coin>) -> ExecuteMsg {
+        ExecuteMsg::Execute {
+            msgs: vec![WasmMsg::Execute {
+                contract_addr: contract.to_string(),
+                msg: to_json_binary(&Empty {}).unwrap(),
+                funds,
+            }
+            .into()],
+        }
+    }
+
+    fn claimable(app: &App, addr: &Addr, admin: &Addr) -> Vec<Coin> {
+        app.wrap()
+            .query_wasm_smart::<ForAddressResp>(
+                addr,
+                &QueryMsg::ForAddress {
+                    addr: admin.to_string(),
+                },
+            )
+            .unwrap()
+            .claimable
+    }
+
+    fn send(amount: Vec<Coin>) -> ExecuteMsg {
+        ExecuteMsg::Execute {
+            msgs: vec![BankMsg::Send {
+                to_address: mock_addr("recipient").to_string(),
+                amount,
+            }
+            .into()],
+        }
+    }
+
+    fn increase(amount: Coin, expires: Option<Expiration>) -> ExecuteMsg {
+        ExecuteMsg::IncreaseAllowance {
+            spender: mock_addr("spender").to_string(),
+            amount,
+            expires,
+        }
+    }
+
+    fn decrease(amount: Coin, expires: Option<Expiration>) -> ExecuteMsg {
+        ExecuteMsg::DecreaseAllowance {
+            spender: mock_addr("spender").to_string(),
+            amount,
+            expires,
+        }
+    }
+
+    fn allowances(app: &App, addr: &Addr) -> Vec<AllowanceInfo> {
+        app.wrap()
+            .query_wasm_smart::<AllowancesResp>(
+                addr,
+                &QueryMsg::Allowances {
+                    spender: app.api().addr_make("spender").to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+            .allowances
+    }
+
+    fn allowance(denom: &str, amount: u128, expires: Expiration) -> AllowanceInfo {
+        AllowanceInfo {
+            denom: denom.to_owned(),
+            granter: addr::admin(1),
+            amount: Uint128::new(amount),
+            expires,
+        }
+    }
+
+    #[test]
+    fn proxy_admin_execute() {
+        let (mut app, addr) = proxy_app();
+        let code_id = store_code(&mut app, external_contract());
+        let external = app
+            .instantiate_contract(code_id, addr::owner(), &Empty {}, &[], "External", None)
+            .unwrap();
+
+        let resp = app
+            .execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &forward(&external, vec![]),
+                &[],
+            )
+            .unwrap();
+
+        // The external contract sees the admin contract as the sender
+        assert_eq!(find_attr(&resp, "wasm", "caller"), Some(addr.as_str()),);
+    }
+
+    #[test]
+    fn proxy_admin_cannot_move_funds() {
+        let (mut app, addr) = proxy_app();
+        let external = app.api().addr_make("external");
+
+        let msgs = [
+            send(coins(100, "eth")),
+            forward(&external, coins(10, "eth")),
+            ExecuteMsg::Execute {
+                msgs: vec![BankMsg::Burn {
+                    amount: coins(10, "eth"),
+                }
+                .into()],
+            },
+        ];
+        for msg in msgs {
+            let err = app
+                .execute_contract(addr::admin(1), addr.clone(), &msg, &[])
+                .unwrap_err();
+            assert_eq!(ContractError::InvalidProxyMessage, err.downcast().unwrap());
+        }
+
+        // An allowance is backed only by the claims of its granter, never by the claims of
+        // other admins or the rest of the contract balance
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &increase(coin(15, "eth"), None),
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                app.api().addr_make("spender"),
+                addr.clone(),
+                &send(coins(15, "eth")),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ClaimTooHigh {
+                requested: coin(15, "eth"),
+                claimable: coin(10, "eth"),
+            },
+            err.downcast().unwrap()
+        );
+
+        app.execute_contract(
+            app.api().addr_make("spender"),
+            addr.clone(),
+            &send(coins(10, "eth")),
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(claimable(&app, &addr, &addr::admin(1)), coins(10, "atom"));
+        assert_eq!(
+            claimable(&app, &addr, &addr::admin(2)),
+            vec![coin(10, "atom"), coin(10, "eth")]
+        );
+        assert_eq!(balance(&app, &addr, "eth"), 110);
+        assert_eq!(balance(&app, app.api().addr_make("recipient"), "eth"), 10);
+    }
+
+    #[test]
+    fn proxy_spender_limited_to_bank_send() {
+        let (mut app, addr) = proxy_app();
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &increase(coin(10, "eth"), None),
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                app.api().addr_make("spender"),
+                addr.clone(),
+                &ExecuteMsg::Execute {
+                    msgs: vec![BankMsg::Burn {
+                        amount: coins(1, "eth"),
+                    }
+                    .into()],
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: app.api().addr_make("spender")
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn allowance_spending() {
+        let (mut app, addr) = proxy_app();
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &increase(coin(10, "eth"), None),
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            app.api().addr_make("spender"),
+            addr.clone(),
+            &send(coins(4, "eth")),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            allowances(&app, &addr),
+            vec![allowance("eth", 6, Expiration::Never {})]
+        );
+
+        let err = app
+            .execute_contract(
+                app.api().addr_make("spender"),
+                addr.clone(),
+                &send(coins(7, "eth")),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientAllowance {
+                spender: app.api().addr_make("spender"),
+                denom: "eth".to_owned(),
+            },
+            err.downcast().unwrap()
+        );
+
+        // A single coin over the allowance reverts the whole execution
+        let err = app
+            .execute_contract(
+                app.api().addr_make("spender"),
+                addr.clone(),
+                &send(vec![coin(1, "eth"), coin(1, "atom")]),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoAllowance {
+                spender: app.api().addr_make("spender"),
+                denom: "atom".to_owned(),
+            },
+            err.downcast().unwrap()
+        );
+        assert_eq!(
+            allowances(&app, &addr),
+            vec![allowance("eth", 6, Expiration::Never {})]
+        );
+
+        app.execute_contract(
+            app.api().addr_make("spender"),
+            addr.clone(),
+            &send(coins(6, "eth")),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(allowances(&app, &addr), vec![]);
+
+        let err = app
+            .execute_contract(
+                app.api().addr_make("spender"),
+                addr.clone(),
+                &send(coins(1, "eth")),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoAllowance {
+                spender: app.api().addr_make("spender"),
+                denom: "eth".to_owned(),
+            },
+            err.downcast().unwrap()
+        );
+
+        assert_eq!(balance(&app, app.api().addr_make("recipient"), "eth"), 10);
+    }
+
+    #[test]
+    fn allowance_expiry() {
+        let (mut app, addr) = proxy_app();
+        let block = app.block_info();
+        let eth_expires = Expiration::AtTime(block.time.plus_seconds(100));
+        let atom_expires = Expiration::AtHeight(block.height + 5);
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &increase(coin(10, "eth"), Some(eth_expires)),
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &increase(coin(10, "atom"), Some(atom_expires)),
+            &[],
+        )
+        .unwrap();
+
+        app.update_block(|block| {
+            block.time = block.time.plus_seconds(99);
+            block.height += 4;
+        });
+        app.execute_contract(
+            app.api().addr_make("spender"),
+            addr.clone(),
+            &send(vec![coin(1, "eth"), coin(1, "atom")]),
+            &[],
+        )
+        .unwrap();
+
+        // Expiration is reached at the exact time, not after it
+        app.advance_time(1);
+        let err = app
+            .execute_contract(
+                app.api().addr_make("spender"),
+                addr.clone(),
+                &send(coins(1, "eth")),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::AllowanceExpired {
+                spender: app.api().addr_make("spender"),
+                denom: "eth".to_owned(),
+            },
+            err.downcast().unwrap()
+        );
+        assert_eq!(
+            allowances(&app, &addr),
+            vec![allowance("atom", 9, atom_expires)]
+        );
+
+        let err = app
+            .execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &decrease(coin(1, "eth"), None),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoAllowance {
+                spender: app.api().addr_make("spender"),
+                denom: "eth".to_owned(),
+            },
+            err.downcast().unwrap()
+        );
+
+        app.update_block(|block| block.height += 1);
+        let err = app
+            .execute_contract(
+                app.api().addr_make("spender"),
+                addr.clone(),
+                &send(coins(1, "atom")),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::AllowanceExpired {
+                spender: app.api().addr_make("spender"),
+                denom: "atom".to_owned(),
+            },
+            err.downcast().unwrap()
+        );
+        assert_eq!(allowances(&app, &addr), vec![]);
+
+        // Increasing an expired allowance starts over instead of reviving the leftover
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &increase(coin(5, "eth"), None),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            allowances(&app, &addr),
+            vec![allowance("eth", 5, Expiration::Never {})]
+        );
+
+        let err = app
+            .execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &increase(coin(5, "eth"), Some(eth_expires)),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::InvalidExpiration, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn decrease_allowance() {
+        let (mut app, addr) = proxy_app();
+        let expires = Expiration::AtTime(app.block_info().time.plus_seconds(100));
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &increase(coin(10, "eth"), None),
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &decrease(coin(3, "eth"), Some(expires)),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(allowances(&app, &addr), vec![allowance("eth", 7, expires)]);
+
+        for sender in ["spender", "user"] {
+            let err = app
+                .execute_contract(
+                    app.api().addr_make(sender),
+                    addr.clone(),
+                    &decrease(coin(3, "eth"), None),
+                    &[],
+                )
+                .unwrap_err();
+            assert_eq!(
+                ContractError::Unauthorized {
+                    sender: app.api().addr_make(sender)
+                },
+                err.downcast().unwrap()
+            );
+        }
+
+        app.execute_contract(
+            addr::admin(1),
+            addr.clone(),
+            &decrease(coin(100, "eth"), None),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(allowances(&app, &addr), vec![]);
+
+        let err = app
+            .execute_contract(
+                app.api().addr_make("spender"),
+                addr,
+                &increase(coin(10, "eth"), None),
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: app.api().addr_make("spender")
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn allowances_pagination() {
+        let (mut app, addr) = proxy_app();
+
+        for denom in ["btc", "eth", "atom", "osmo"] {
+            app.execute_contract(
+                addr::admin(1),
+                addr.clone(),
+                &increase(coin(10, denom), None),
+                &[],
+            )
+            .unwrap();
+        }
+
+        let page = |start_after: Option<&str>| {
+            app.wrap()
+                .query_wasm_smart::<AllowancesResp>(
+                    &addr,
+                    &QueryMsg::Allowances {
+                        spender: app.api().addr_make("spender").to_string(),
+                        start_after: start_after.map(str::to_owned),
+                        limit: Some(3),
+                    },
+                )
+                .unwrap()
+                .allowances
+                .into_iter()
+                .map(|allowance| allowance.denom)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(page(None), vec!["atom", "btc", "eth"]);
+        assert_eq!(page(Some("eth")), vec!["osmo"]);
+    }
+
+    #[test]
+    fn dashboard() {
+        let mut app = app_with_balances(&[("donor", vec![coin(10, "eth"), coin(20, "atom")])]);
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::admin(1)]));
+
+        let exec = |app: &mut App, sender: &str, msg: ExecuteMsg, funds: &[Coin]| {
+            app.execute_contract(app.api().addr_make(sender), addr.clone(), &msg, funds)
+        };
+        let set_rate = |denom: &str, rate: Option<Decimal>| ExecuteMsg::SetRate {
+            denom: denom.to_owned(),
+            rate,
+        };
+        let dashboard = |app: &App| -> DashboardResp {
+            app.wrap()
+                .query_wasm_smart(&addr, &QueryMsg::Dashboard {})
+                .unwrap()
+        };
+
+        exec(&mut app, "donor", ExecuteMsg::Donate {}, &coins(10, "eth")).unwrap();
+        let update = ExecuteMsg::UpdateConfig {
+            donation_denom: Some("atom".to_owned()),
+        };
+        exec(&mut app, "owner", update, &[]).unwrap();
+        exec(&mut app, "donor", ExecuteMsg::Donate {}, &coins(20, "atom")).unwrap();
+
+        // Without rates nothing can be valued
+        let resp = dashboard(&app);
+        assert_eq!(resp.total_value, Uint128::zero());
+        assert_eq!(resp.unpriced, vec!["atom", "eth"]);
+
+        let eth_updated = app.block_info().time;
+        exec(
+            &mut app,
+            "owner",
+            set_rate("eth", Some(Decimal::percent(250))),
+            &[],
+        )
+        .unwrap();
+
+        let resp = dashboard(&app);
+        assert_eq!(
+            resp.donated,
+            vec![
+                DonatedDenom {
+                    denom: "atom".to_owned(),
+                    amount: Uint128::new(20),
+                    rate: None,
+                    value: None,
+                },
+                DonatedDenom {
+                    denom: "eth".to_owned(),
+                    amount: Uint128::new(10),
+                    rate: Some(Rate {
+                        rate: Decimal::percent(250),
+                        updated: eth_updated,
+                    }),
+                    value: Some(Uint128::new(25)),
+                },
+            ]
+        );
+        assert_eq!(resp.total_value, Uint128::new(25));
+        assert_eq!(resp.unpriced, vec!["atom"]);
+
+        // Each rate keeps its own update time
+        app.advance_time(100);
+        exec(
+            &mut app,
+            "owner",
+            set_rate("atom", Some(Decimal::percent(55))),
+            &[],
+        )
+        .unwrap();
+
+        let resp = dashboard(&app);
+        assert_eq!(
+            resp.donated[0].rate.as_ref().unwrap().updated,
+            eth_updated.plus_seconds(100)
+        );
+        assert_eq!(resp.donated[1].rate.as_ref().unwrap().updated, eth_updated);
+        assert_eq!(resp.donated[0].value, Some(Uint128::new(11)));
+        assert_eq!(resp.total_value, Uint128::new(36));
+        assert!(resp.unpriced.is_empty());
+
+        // Removing a rate falls back to leaving the denom out of the total
+        exec(&mut app, "owner", set_rate("eth", None), &[]).unwrap();
+
+        let resp = dashboard(&app);
+        assert_eq!(resp.donated[1].value, None);
+        assert_eq!(resp.total_value, Uint128::new(11));
+        assert_eq!(resp.unpriced, vec!["eth"]);
+
+        let err = exec(&mut app, "admin1", set_rate("eth", None), &[]).unwrap_err();
+        assert_eq!(
+            ContractError::NotOwner {
+                sender: addr::admin(1)
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = exec(&mut app, "owner", set_rate("1", None), &[]).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidDenom {
+                denom: "1".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn donation_disputes() {
+        let mut app = app_with_balances(&[("donor", coins(100, "eth"))]);
+
+        let addr = deploy(
+            &mut app,
+            &InstantiateMsg {
+                dispute_window: Some(10),
+                ..instantiate_msg(&[addr::admin(1), addr::admin(2)])
+            },
+        );
+
+        let exec = |app: &mut App, sender: &str, msg: ExecuteMsg, funds: &[Coin]| {
+            app.execute_contract(app.api().addr_make(sender), addr.clone(), &msg, funds)
+        };
+        let donation = |app: &App, id: u64| {
+            app.wrap()
+                .query_wasm_smart::<DonationResp>(&addr, &QueryMsg::Donation { id })
+                .unwrap()
+                .donation
+        };
+        let pending_disputes = |app: &App| {
+            app.wrap()
+                .query_wasm_smart::<HealthcheckResp>(&addr, &QueryMsg::Healthcheck {})
+                .unwrap()
+                .pending_disputes
+        };
+
+        let resp = exec(&mut app, "donor", ExecuteMsg::Donate {}, &coins(10, "eth")).unwrap();
+        assert_eq!(wasm_attr(&resp, events::DONATION_ID), "0");
+        exec(&mut app, "donor", ExecuteMsg::Donate {}, &coins(20, "eth")).unwrap();
+
+        // Donations are withheld during the window
+        let err = exec(&mut app, "admin1", ExecuteMsg::Claim { amount: None }, &[]).unwrap_err();
+        assert_eq!(ContractError::NothingToClaim, err.downcast().unwrap());
+
+        let err = exec(&mut app, "admin1", ExecuteMsg::Dispute { id: 1 }, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: addr::admin(1)
+            },
+            err.downcast().unwrap()
+        );
+
+        assert_eq!(pending_disputes(&app), 0);
+        exec(&mut app, "donor", ExecuteMsg::Dispute { id: 1 }, &[]).unwrap();
+        assert_eq!(pending_disputes(&app), 1);
+        let err = exec(&mut app, "donor", ExecuteMsg::Dispute { id: 1 }, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::AlreadyDisputed { id: 1 },
+            err.downcast().unwrap()
+        );
+
+        let resolve = |id, refund| ExecuteMsg::ResolveDispute { id, refund };
+        let err = exec(&mut app, "admin1", resolve(0, false), &[]).unwrap_err();
+        assert_eq!(
+            ContractError::NotDisputed { id: 0 },
+            err.downcast().unwrap()
+        );
+
+        // The undisputed donation is released once the window closes, the disputed one stays
+        app.advance_blocks(10);
+        assert!(donation(&app, 0).is_some());
+        app.advance_blocks(1);
+
+        let err = exec(&mut app, "donor", ExecuteMsg::Dispute { id: 0 }, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::DonationNotWithheld { id: 0 },
+            err.downcast().unwrap()
+        );
+
+        // Released by the first successful transaction after the window
+        exec(&mut app, "admin1", ExecuteMsg::Claim { amount: None }, &[]).unwrap();
+        assert_eq!(balance(&app, addr::admin(1), "eth"), 5);
+        assert_eq!(donation(&app, 0), None);
+        assert!(donation(&app, 1).unwrap().disputed);
+
+        // Rulings either refund the donor or release the donation to admins
+        exec(&mut app, "donor", ExecuteMsg::Donate {}, &coins(30, "eth")).unwrap();
+        exec(&mut app, "donor", ExecuteMsg::Dispute { id: 2 }, &[]).unwrap();
+        assert_eq!(pending_disputes(&app), 2);
+
+        let err = exec(&mut app, "donor", resolve(1, true), &[]).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: app.api().addr_make("donor")
+            },
+            err.downcast().unwrap()
+        );
+
+        exec(&mut app, "admin1", resolve(1, true), &[]).unwrap();
+        exec(&mut app, "admin2", resolve(2, false), &[]).unwrap();
+        assert_eq!(donation(&app, 1), None);
+        assert_eq!(donation(&app, 2), None);
+        assert_eq!(pending_disputes(&app), 0);
+        assert_eq!(balance(&app, app.api().addr_make("donor"), "eth"), 60);
+        let resp: DashboardResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::Dashboard {})
+            .unwrap();
+        assert_eq!(resp.donated[0].amount, Uint128::new(40));
+        let resp: DonorTotalsResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::DonorTotals {
+                    donor: app.api().addr_make("donor").to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.totals, coins(40, "eth"));
+
+        let err = exec(&mut app, "admin1", resolve(2, true), &[]).unwrap_err();
+        assert_eq!(
+            ContractError::DonationNotWithheld { id: 2 },
+            err.downcast().unwrap()
+        );
+
+        exec(&mut app, "admin2", ExecuteMsg::Claim { amount: None }, &[]).unwrap();
+        assert_eq!(balance(&app, addr::admin(2), "eth"), 20);
+        assert_eq!(balance(&app, addr.as_str(), "eth"), 15);
+    }
+
+    #[test]
+    fn donor_totals() {
+        let mut app = app_with_balances(&[
+            ("donor1", vec![coin(10, "eth"), coin(30, "atom")]),
+            ("donor2", coins(5, "atom")),
+        ]);
+
+        let addr = deploy(&mut app, &instantiate_msg(&[addr::admin(1)]));
+
+        let exec = |app: &mut App, sender: &str, msg: ExecuteMsg, funds: &[Coin]| {
+            app.execute_contract(app.api().addr_make(sender), addr.clone(), &msg, funds)
+        };
+        let totals = |app: &App, donor: &str, start_after: Option<&str>, limit: Option<u32>| {
+            app.wrap()
+                .query_wasm_smart::<DonorTotalsResp>(
+                    &addr,
+                    &QueryMsg::DonorTotals {
+                        donor: app.api().addr_make(donor).to_string(),
+                        start_after: start_after.map(str::to_owned),
+                        limit,
+                    },
+                )
+                .unwrap()
+                .totals
+        };
+
+        exec(&mut app, "donor1", ExecuteMsg::Donate {}, &coins(10, "eth")).unwrap();
+        let update = ExecuteMsg::UpdateConfig {
+            donation_denom: Some("atom".to_owned()),
+        };
+        exec(&mut app, "owner", update, &[]).unwrap();
+        exec(
+            &mut app,
+            "donor1",
+            ExecuteMsg::Donate {},
+            &coins(20, "atom"),
+        )
+        .unwrap();
+        exec(
+            &mut app,
+            "donor1",
+            ExecuteMsg::Donate {},
+            &coins(10, "atom"),
+        )
+        .unwrap();
+        exec(&mut app, "donor2", ExecuteMsg::Donate {}, &coins(5, "atom")).unwrap();
+
+        // Every donor only sees the denoms under their own prefix
+        assert_eq!(
+            totals(&app, "donor1", None, None),
+            vec![coin(30, "atom"), coin(10, "eth")]
+        );
+        assert_eq!(totals(&app, "donor2", None, None), coins(5, "atom"));
+        assert_eq!(totals(&app, "admin1", None, None), vec![]);
+
+        assert_eq!(totals(&app, "donor1", None, Some(1)), coins(30, "atom"));
+        assert_eq!(
+            totals(&app, "donor1", Some("atom"), Some(1)),
+            coins(10, "eth")
+        );
+        assert_eq!(totals(&app, "donor1", Some("eth"), None), vec![]);
+    }
+
+    /// Contract instantiated on mock dependencies by `owner`, so single handlers can be called
+    /// directly. Cheaper than the multitest `App` and reaches guards that are awkward to set up
+    /// through full transactions.
+    fn mock_contract(
+        admins: &[&str],
+        slashing: Option<SlashingConfig>,
+    ) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::owner(), &[]),
+            InstantiateMsg {
+                owner: None,
+                admins: admins
+                    .iter()
+                    .map(|admin| mock_addr(admin).to_string())
+                    .collect(),
+                slashing,
+                ..instantiate_msg(&[])
+            },
+        )
+        .unwrap();
+        deps
+    }
+
+    #[test]
+    fn handler_guards() {
+        let mut deps = mock_contract(&["admin1"], None);
+        let user = || message_info(&mock_addr("user"), &[]);
+        let unauthorized = ContractError::Unauthorized {
+            sender: mock_addr("user"),
+        };
+
+        let err = exec::add_members(deps.as_mut(), mock_env(), user(), vec![]).unwrap_err();
+        assert_eq!(err, unauthorized);
+        let err = exec::remove_members(deps.as_mut(), user(), vec![]).unwrap_err();
+        assert_eq!(err, unauthorized);
+        let err = exec::resolve_dispute(deps.as_mut(), user(), 0, true).unwrap_err();
+        assert_eq!(err, unauthorized);
+
+        let err = exec::update_config(deps.as_mut(), mock_env(), user(), None).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NotOwner {
+                sender: mock_addr("user")
+            }
+        );
+
+        let err = exec::join(deps.as_mut(), mock_env(), user()).unwrap_err();
+        assert_eq!(err, ContractError::EnrollmentClosed);
+
+        let err = exec::claim(deps.as_mut(), message_info(&addr::admin(1), &[]), None).unwrap_err();
+        assert_eq!(err, ContractError::NothingToClaim);
+
+        let err = exec::dispute(deps.as_mut(), user(), 0).unwrap_err();
+        assert_eq!(err, ContractError::DonationNotWithheld { id: 0 });
+
+        let err = exec::leave(
+            deps.as_mut(),
+            message_info(&addr::admin(1), &coins(1, "eth")),
+        )
+        .unwrap_err();
+        assert_eq!(err, PaymentError::NonPayable {}.into());
+
+        let err = exec::decrease_allowance(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::admin(1), &[]),
+            mock_addr("spender").to_string(),
+            coin(1, "eth"),
+            None,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoAllowance {
+                spender: mock_addr("spender"),
+                denom: "eth".to_owned()
+            }
+        );
+
+        query::join_time(deps.as_ref(), mock_addr("user").to_string()).unwrap_err();
+    }
+
+    #[test]
+    fn revert_config_window_checked_by_handler() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            config_revert_window: Some(100),
+            ..instantiate_msg(&[])
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::owner(), &[]),
+            msg,
+        )
+        .unwrap();
+
+        let owner = || message_info(&addr::owner(), &[]);
+        exec::update_config(deps.as_mut(), mock_env(), owner(), Some("atom".to_owned())).unwrap();
+
+        // Called directly, without `execute` discarding the outdated config first
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(101);
+        let err = exec::revert_config(deps.as_mut(), env, owner()).unwrap_err();
+        assert_eq!(err, ContractError::NothingToRevert);
+
+        let mut env = mock_env();
+        env.block.time = env.block.time.plus_seconds(100);
+        exec::revert_config(deps.as_mut(), env, owner()).unwrap();
+        assert_eq!(DONATION_DENOM.load(&deps.storage).unwrap(), "eth");
+    }
+
+    #[test]
+    fn migrate_sets_missing_owner() {
+        let mut deps = mock_dependencies();
+        LEGACY_ADMINS
+            .save(&mut deps.storage, &vec![addr::admin(1)])
+            .unwrap();
+        DONATION_DENOM
+            .save(&mut deps.storage, &"eth".to_owned())
+            .unwrap();
+
+        let msg = MigrateMsg {
+            owner: addr::owner().to_string(),
+        };
+        migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(OWNER.load(&deps.storage).unwrap(), addr::owner());
+
+        let update = || ExecuteMsg::UpdateConfig {
+            donation_denom: Some("atom".to_owned()),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::admin(1), &[]),
+            update(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NotOwner {
+                sender: addr::admin(1)
+            }
+        );
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&addr::owner(), &[]),
+            update(),
+        )
+        .unwrap();
+        assert_eq!(DONATION_DENOM.load(&deps.storage).unwrap(), "atom");
+
+        // An owner already in place is never replaced by a migration
+        let msg = MigrateMsg {
+            owner: addr::admin(1).to_string(),
+        };
+        migrate(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(OWNER.load(&deps.storage).unwrap(), addr::owner());
+    }
+
+    #[test]
+    fn report_misbehavior_guards() {
+        let report = |deps: &mut OwnedDeps<_, _, _>, sender: &str, admin: &str| {
+            exec::report_misbehavior(
+                deps.as_mut(),
+                message_info(&mock_addr(sender), &[]),
+                mock_addr(admin).to_string(),
+                "evidence".to_owned(),
+            )
+        };
+
+        let mut deps = mock_contract(&["admin1", "admin2"], None);
+        let err = report(&mut deps, "admin1", "admin2").unwrap_err();
+        assert_eq!(err, ContractError::SlashingDisabled);
+
+        let mut deps = mock_contract(
+            &["admin1", "admin2"],
+            Some(SlashingConfig {
+                confirmations: 2,
+                slash: Decimal::percent(50),
+            }),
+        );
+
+        let err = report(&mut deps, "user", "admin2").unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Unauthorized {
+                sender: mock_addr("user")
+            }
+        );
+
+        let err = report(&mut deps, "admin1", "user").unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NotAdmin {
+                addr: mock_addr("user")
+            }
+        );
+
+        let err = report(&mut deps, "admin1", "admin1").unwrap_err();
+        assert_eq!(err, ContractError::SelfReport);
+
+        report(&mut deps, "admin1", "admin2").unwrap();
+        let err = report(&mut deps, "admin1", "admin2").unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyConfirmed {
+                addr: addr::admin(1)
+            }
+        );
+    }
+
+    #[test]
+    fn donate_gas() {
+        // Storage gas of a donation, charged like the chain KV store does. Limits have about 10%
+        // headroom over the current consumption - raise them only for intended changes.
+        for (admins, limit) in [(1, 27_500), (10, 89_000), (100, 700_000)] {
+            let mut deps = gas_dependencies();
+            let admins: Vec<_> = (0..admins)
+                .map(|n| mock_addr(&format!("admin{}", n)).to_string())
+                .collect();
+            instantiate(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&addr::owner(), &[]),
+                InstantiateMsg {
+                    owner: None,
+                    admins: admins.clone(),
+                    ..instantiate_msg(&[])
+                },
+            )
+            .unwrap();
+
+            deps.storage.reset();
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&mock_addr("donor"), &coins(1000, "eth")),
+                ExecuteMsg::Donate {},
+            )
+            .unwrap();
+
+            let gas = deps.storage.gas_used();
+            assert!(
+                gas <= limit,
+                "Donation to {} admins used {} gas, limit is {}",
+                admins.len(),
+                gas,
+                limit
+            );
+        }
+    }
+}
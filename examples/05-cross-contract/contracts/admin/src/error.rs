@@ -0,0 +1,56 @@
+use cosmwasm_std::{Addr, Coin, StdError};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not contract admin")]
+    Unauthorized { sender: Addr },
+    #[error("{sender} is not contract owner")]
+    NotOwner { sender: Addr },
+    #[error("{addr} is not contract admin")]
+    NotAdmin { addr: Addr },
+    #[error("{addr} is already contract admin")]
+    AlreadyAdmin { addr: Addr },
+    #[error("Enrollment is closed")]
+    EnrollmentClosed,
+    #[error("Invalid join fee, expected {expected}")]
+    InvalidJoinFee { expected: Coin },
+    #[error("Invalid denom: {denom}")]
+    InvalidDenom { denom: String },
+    #[error("There are no admins to receive the donation")]
+    NoAdmins,
+    #[error("Nothing to claim")]
+    NothingToClaim,
+    #[error("Slashing is disabled")]
+    SlashingDisabled,
+    #[error("Admins cannot report themselves")]
+    SelfReport,
+    #[error("{addr} already confirmed this report")]
+    AlreadyConfirmed { addr: Addr },
+    #[error("{spender} has no {denom} allowance")]
+    NoAllowance { spender: Addr, denom: String },
+    #[error("{denom} allowance of {spender} expired")]
+    AllowanceExpired { spender: Addr, denom: String },
+    #[error("{denom} allowance of {spender} is too low")]
+    InsufficientAllowance { spender: Addr, denom: String },
+    #[error("Admins may only execute other contracts, without funds")]
+    InvalidProxyMessage,
+    #[error("Expiration is already in the past")]
+    InvalidExpiration,
+    #[error("Donation {id} is not withheld, it does not exist or was already distributed")]
+    DonationNotWithheld { id: u64 },
+    #[error("Donation {id} is already disputed")]
+    AlreadyDisputed { id: u64 },
+    #[error("Donation {id} is not disputed")]
+    NotDisputed { id: u64 },
+    #[error("No config change to revert")]
+    NothingToRevert,
+    #[error("Cannot claim {requested}, only {claimable} is claimable")]
+    ClaimTooHigh { requested: Coin, claimable: Coin },
+    #[error("Payment error: {0}")]
+    Payment(#[from] PaymentError),
+}
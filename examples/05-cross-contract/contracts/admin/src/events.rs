@@ -0,0 +1,77 @@
+//! Event types and attribute keys emitted by the contract.
+//!
+//! Handlers build events with the constructors below and tests look attributes up by the same
+//! constants, so renaming a key is a single change that indexers can find in one place.
+
+use cosmwasm_std::{Coin, Event, Timestamp};
+
+use crate::fmt::{fmt_amount, fmt_ts};
+
+pub const ACTION: &str = "action";
+pub const ADDED_COUNT: &str = "added_count";
+pub const REMOVED_COUNT: &str = "removed_count";
+pub const AMOUNT: &str = "amount";
+pub const PER_ADMIN: &str = "per_admin";
+pub const SENDER: &str = "sender";
+pub const SPENDER: &str = "spender";
+pub const DONATION_ID: &str = "donation_id";
+pub const REFUND: &str = "refund";
+pub const DENOM: &str = "denom";
+pub const RATE: &str = "rate";
+
+pub const ADMIN_ADDED: &str = "admin_added";
+pub const ADMIN_REMOVED: &str = "admin_removed";
+pub const MISBEHAVIOR_REPORTED: &str = "misbehavior_reported";
+pub const ADMIN_SLASHED: &str = "admin_slashed";
+pub const CONFIG_UPDATED: &str = "config_updated";
+
+pub const ADDR: &str = "addr";
+pub const JOINED: &str = "joined";
+pub const ADMIN: &str = "admin";
+pub const REPORTER: &str = "reporter";
+pub const CONFIRMATIONS: &str = "confirmations";
+pub const BURNED: &str = "burned";
+pub const KEY: &str = "key";
+pub const OLD: &str = "old";
+pub const NEW: &str = "new";
+
+pub fn admin_added(addr: impl Into<String>, joined: Timestamp) -> Event {
+    Event::new(ADMIN_ADDED)
+        .add_attribute(ADDR, addr)
+        .add_attribute(JOINED, fmt_ts(joined))
+}
+
+pub fn admin_removed(addr: impl Into<String>) -> Event {
+    Event::new(ADMIN_REMOVED).add_attribute(ADDR, addr)
+}
+
+pub fn misbehavior_reported(
+    admin: impl Into<String>,
+    reporter: impl Into<String>,
+    confirmations: usize,
+) -> Event {
+    Event::new(MISBEHAVIOR_REPORTED)
+        .add_attribute(ADMIN, admin)
+        .add_attribute(REPORTER, reporter)
+        .add_attribute(CONFIRMATIONS, confirmations.to_string())
+}
+
+/// `burned` lists every burned coin, comma separated, e.g. `12eth,3atom`.
+pub fn admin_slashed(admin: impl Into<String>, burned: &[Coin]) -> Event {
+    let burned = burned
+        .iter()
+        .map(|c| fmt_amount(c.amount, &c.denom))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    Event::new(ADMIN_SLASHED)
+        .add_attribute(ADMIN, admin)
+        .add_attribute(BURNED, burned)
+}
+
+pub fn config_updated(key: &str, old: impl Into<String>, new: impl Into<String>) -> Event {
+    Event::new(CONFIG_UPDATED)
+        .add_attribute(KEY, key)
+        .add_attribute(OLD, old)
+        .add_attribute(NEW, new)
+}
@@ -0,0 +1,79 @@
+//! Canonical formats for attribute values, so indexers can parse every handler's output the
+//! same way.
+
+use cosmwasm_std::{Timestamp, Uint128};
+
+/// Formats an amount like `Coin`'s `Display` does: `<amount><denom>`, e.g. `100uatom`.
+pub fn fmt_amount(amount: Uint128, denom: &str) -> String {
+    format!("{}{}", amount, denom)
+}
+
+/// Formats a timestamp as RFC 3339 in UTC, always with nanosecond precision, e.g.
+/// `2019-10-23T02:23:39.879305533Z`.
+pub fn fmt_ts(ts: Timestamp) -> String {
+    let secs = ts.seconds();
+    let (year, month, day) = civil_from_days(secs / 86_400);
+    let secs_of_day = secs % 86_400;
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60,
+        ts.subsec_nanos()
+    )
+}
+
+/// Converts days since the Unix epoch into a `(year, month, day)` date in the proleptic
+/// Gregorian calendar (Howard Hinnant's `civil_from_days`).
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amount_format() {
+        assert_eq!(fmt_amount(Uint128::new(5), "eth"), "5eth");
+        assert_eq!(fmt_amount(Uint128::zero(), "uatom"), "0uatom");
+        assert_eq!(
+            fmt_amount(Uint128::MAX, "ibc/27394FB092D2ECCD"),
+            "340282366920938463463374607431768211455ibc/27394FB092D2ECCD"
+        );
+    }
+
+    #[test]
+    fn timestamp_format() {
+        assert_eq!(
+            fmt_ts(Timestamp::from_seconds(0)),
+            "1970-01-01T00:00:00.000000000Z"
+        );
+        assert_eq!(
+            fmt_ts(Timestamp::from_nanos(1_571_797_419_879_305_533)),
+            "2019-10-23T02:23:39.879305533Z"
+        );
+        assert_eq!(
+            fmt_ts(Timestamp::from_seconds(1_582_934_400).plus_nanos(5)),
+            "2020-02-29T00:00:00.000000005Z"
+        );
+        assert_eq!(
+            fmt_ts(Timestamp::from_seconds(951_868_799)),
+            "2000-02-29T23:59:59.000000000Z"
+        );
+    }
+}
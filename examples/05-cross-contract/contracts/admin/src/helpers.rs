@@ -0,0 +1,67 @@
+//! Typed access to a deployed admin contract, in the spirit of `cw20::Cw20Contract`.
+//!
+//! Contracts and tests talking to the admin contract wrap its address in [`AdminContract`] and
+//! get messages and query results already typed, instead of serializing `ExecuteMsg` and
+//! deserializing responses by hand.
+
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CosmosMsg, QuerierWrapper, StdResult, Timestamp, WasmMsg,
+};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::msg::{AdminsListResp, ConfigResp, ExecuteMsg, ForAddressResp, JoinTimeResp, QueryMsg};
+
+/// Address of an admin contract. Serialized as the bare address, so it can be stored in place
+/// of an `Addr`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AdminContract(pub Addr);
+
+impl AdminContract {
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    /// Message executing the contract, with the funds sent along.
+    pub fn call(&self, msg: impl Into<ExecuteMsg>, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        let msg = WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_json_binary(&msg.into())?,
+            funds,
+        };
+        Ok(msg.into())
+    }
+
+    fn query<T: DeserializeOwned>(&self, querier: &QuerierWrapper, msg: &QueryMsg) -> StdResult<T> {
+        querier.query_wasm_smart(&self.0, msg)
+    }
+
+    pub fn admins_list(&self, querier: &QuerierWrapper) -> StdResult<Vec<Addr>> {
+        let resp: AdminsListResp = self.query(querier, &QueryMsg::AdminsList {})?;
+        Ok(resp.admins)
+    }
+
+    /// Fails if the address is not an admin.
+    pub fn join_time(
+        &self,
+        querier: &QuerierWrapper,
+        admin: impl Into<String>,
+    ) -> StdResult<Timestamp> {
+        let msg = QueryMsg::JoinTime {
+            admin: admin.into(),
+        };
+        let resp: JoinTimeResp = self.query(querier, &msg)?;
+        Ok(resp.joined)
+    }
+
+    pub fn is_admin(&self, querier: &QuerierWrapper, addr: impl Into<String>) -> StdResult<bool> {
+        let msg = QueryMsg::ForAddress { addr: addr.into() };
+        let resp: ForAddressResp = self.query(querier, &msg)?;
+        Ok(resp.is_admin)
+    }
+
+    pub fn config(&self, querier: &QuerierWrapper) -> StdResult<ConfigResp> {
+        self.query(querier, &QueryMsg::Config {})
+    }
+}
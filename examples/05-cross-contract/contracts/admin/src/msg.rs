@@ -0,0 +1,322 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Decimal, Timestamp, Uint128};
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{Donation, Enrollment, MisbehaviorReport, PreviousConfig, Rate, SlashingConfig};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    /// Owner of the contract, the instantiating address if not set
+    pub owner: Option<String>,
+    pub admins: Vec<String>,
+    pub donation_denom: String,
+    /// Until this time anyone may become an admin with `Join {}`
+    pub enrollment_until: Option<Timestamp>,
+    /// Fee paid on `Join {}` during the enrollment window
+    pub join_fee: Option<Coin>,
+    /// Misbehavior reporting is disabled if not set
+    pub slashing: Option<SlashingConfig>,
+    /// Number of blocks during which donors may dispute their donations. Donations are
+    /// withheld from admins until the window passes, and are distributed immediately if not set.
+    pub dispute_window: Option<u64>,
+    /// Number of seconds after a config change during which the owner may revert it with
+    /// `RevertConfig {}`. Changes cannot be reverted if not set.
+    pub config_revert_window: Option<u64>,
+}
+
+/// Migrates contracts of this code as well as ones still in the 03-basics layout
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MigrateMsg {
+    /// Owner set on contracts created before the owner was stored. Ignored if the contract
+    /// already has one.
+    pub owner: String,
+}
+
+/// Set as the instantiation response data, so a contract instantiating this one can read it
+/// in its `reply`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct InstantiateResp {
+    pub admins_count: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ExecuteMsg {
+    AddMembers {
+        admins: Vec<String>,
+    },
+    RemoveMembers {
+        admins: Vec<String>,
+    },
+    Join {},
+    Leave {},
+    Donate {},
+    /// Withdraws the given amount of the current donation denom, or all pending claims in
+    /// every denom if not set
+    Claim {
+        amount: Option<Uint128>,
+    },
+    /// Withholds the donation from admins until `ResolveDispute {}`, only the donor may
+    /// dispute and only within the dispute window
+    Dispute {
+        id: u64,
+    },
+    /// Either refunds a disputed donation to the donor, or releases it to admins
+    ResolveDispute {
+        id: u64,
+        refund: bool,
+    },
+    /// Reports an admin, or confirms an already open report against them
+    ReportMisbehavior {
+        admin: String,
+        evidence: String,
+    },
+    UpdateConfig {
+        donation_denom: Option<String>,
+    },
+    /// Restores the config from before the last `UpdateConfig {}`, if still within the revert
+    /// window. Only the last change can be reverted, and a revert cannot be undone.
+    RevertConfig {},
+    /// Sets the exchange rate of the denom used by the `Dashboard {}` query, or removes it
+    SetRate {
+        denom: String,
+        rate: Option<Decimal>,
+    },
+    /// Executes messages as the contract. Admins may only execute other contracts, without
+    /// attaching funds. Other senders may only send tokens within their allowances, taken from
+    /// the pending claims of the granting admin.
+    Execute {
+        msgs: Vec<CosmosMsg>,
+    },
+    /// Resets the allowance instead if the current one is expired. The sender becomes the
+    /// granter of the whole allowance, paying for it from their own claims.
+    IncreaseAllowance {
+        spender: String,
+        amount: Coin,
+        expires: Option<Expiration>,
+    },
+    DecreaseAllowance {
+        spender: String,
+        amount: Coin,
+        expires: Option<Expiration>,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct AdminsListResp {
+    pub admins: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct AdminsCountResp {
+    pub count: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct JoinTimeResp {
+    pub joined: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct RecentAdmin {
+    pub addr: Addr,
+    pub joined: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct RecentAdminsResp {
+    pub admins: Vec<RecentAdmin>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct ConfigResp {
+    pub owner: Addr,
+    pub donation_denom: String,
+    pub enrollment: Option<Enrollment>,
+    pub slashing: Option<SlashingConfig>,
+    pub dispute_window: Option<u64>,
+    pub config_revert_window: Option<u64>,
+    /// Config `RevertConfig {}` would restore
+    pub previous: Option<PreviousConfig>,
+}
+
+/// Everything the contract knows about a single address, so wallets can
+/// fetch it in one round-trip.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct ForAddressResp {
+    pub addr: Addr,
+    pub is_admin: bool,
+    pub joined: Option<Timestamp>,
+    pub claimable: Vec<Coin>,
+    /// Total donated by the address, per denom
+    pub donated: Vec<Coin>,
+    /// Donations of the address not distributed yet, in their dispute window or disputed
+    pub withheld: Vec<WithheldDonation>,
+    /// Open report against the address, if it is a reported admin
+    pub misbehavior_report: Option<MisbehaviorReport>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct WithheldDonation {
+    pub id: u64,
+    pub amount: Coin,
+    /// Height of the donation block, the dispute window counts from it
+    pub height: u64,
+    pub disputed: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct MisbehaviorReportResp {
+    pub report: Option<MisbehaviorReport>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DonationResp {
+    /// `None` once the donation is distributed or refunded
+    pub donation: Option<Donation>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DonatedDenom {
+    pub denom: String,
+    pub amount: Uint128,
+    /// Rate used to compute the value, with the time it was last updated
+    pub rate: Option<Rate>,
+    /// `None` if there is no rate for the denom
+    pub value: Option<Uint128>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DashboardResp {
+    /// Ordered by denom
+    pub donated: Vec<DonatedDenom>,
+    /// Value of all donations in the reference currency. Denoms without a rate are left out, so
+    /// this is a lower bound unless `unpriced` is empty.
+    pub total_value: Uint128,
+    /// Donated denoms without a rate
+    pub unpriced: Vec<String>,
+}
+
+/// Status report for monitoring bots, every field is derived from the contract state.
+///
+/// Failed donation hooks are not included - hooks are called by peer contracts, which the admin
+/// contract does not track. Each peer counts its own failures in its `FailedHooks {}` query.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct HealthcheckResp {
+    /// There is at least one admin to receive donations
+    pub donations_enabled: bool,
+    /// Anyone can currently `Join {}`
+    pub enrollment_open: bool,
+    /// Time of the last successful instantiate or execute
+    pub last_activity: Timestamp,
+    /// Admins are still stored in the legacy layout, waiting for a `Migrate {}`
+    pub migration_pending: bool,
+    /// Disputed donations waiting for a ruling
+    pub pending_disputes: u32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct AllowanceInfo {
+    pub denom: String,
+    pub granter: Addr,
+    pub amount: Uint128,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct AllowancesResp {
+    pub allowances: Vec<AllowanceInfo>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub struct DonorTotalsResp {
+    pub totals: Vec<Coin>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum QueryMsg {
+    #[returns(AdminsListResp)]
+    AdminsList {},
+    #[returns(AdminsCountResp)]
+    AdminsCount {},
+    #[returns(JoinTimeResp)]
+    JoinTime { admin: String },
+    /// Most recently joined admins, newest first. Admins who joined in the
+    /// same block are ordered by address, descending.
+    #[returns(RecentAdminsResp)]
+    RecentAdmins { limit: Option<u32> },
+    #[returns(ForAddressResp)]
+    ForAddress { addr: String },
+    #[returns(ConfigResp)]
+    Config {},
+    #[returns(HealthcheckResp)]
+    Healthcheck {},
+    /// Donation totals per denom, and their value normalized with the owner-maintained rates
+    #[returns(DashboardResp)]
+    Dashboard {},
+    /// Open report against the admin, if any
+    #[returns(MisbehaviorReportResp)]
+    MisbehaviorReport { admin: String },
+    /// Donation still withheld from admins
+    #[returns(DonationResp)]
+    Donation { id: u64 },
+    /// Unexpired allowances of the spender, ordered by denom
+    #[returns(AllowancesResp)]
+    Allowances {
+        spender: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Totals donated by the donor, ordered by denom
+    #[returns(DonorTotalsResp)]
+    DonorTotals {
+        donor: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
@@ -0,0 +1,18 @@
+//! Everything a contract talking to the admin contract needs: `use admin::prelude::*;`.
+//!
+//! Message enums, response structs and errors are `#[non_exhaustive]`, so new variants and
+//! fields are not breaking changes for consumers. Matching on them needs a wildcard arm, and
+//! responses can only be deserialized, not built, outside this crate.
+
+pub use crate::error::ContractError;
+pub use crate::events;
+pub use crate::helpers::AdminContract;
+pub use crate::msg::{
+    AdminsCountResp, AdminsListResp, AllowanceInfo, AllowancesResp, ConfigResp, DashboardResp,
+    DonatedDenom, DonationResp, DonorTotalsResp, ExecuteMsg, ForAddressResp, HealthcheckResp,
+    InstantiateMsg, InstantiateResp, JoinTimeResp, MigrateMsg, MisbehaviorReportResp, QueryMsg,
+    RecentAdmin, RecentAdminsResp, WithheldDonation,
+};
+pub use crate::state::{
+    Donation, Enrollment, MisbehaviorReport, PreviousConfig, Rate, SlashingConfig,
+};
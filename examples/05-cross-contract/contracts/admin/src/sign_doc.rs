@@ -0,0 +1,246 @@
+//! Sign-doc fixtures for hardware wallet integrations.
+//!
+//! Wallets sign the amino JSON of a transaction: keys sorted, no whitespace. A wallet that
+//! reorders or reformats the embedded contract message produces a different signature than the
+//! chain expects, so integration tests should compare the exact bytes against these fixtures.
+//! Every fixture embeds the contract message the same way `wasm/MsgExecuteContract` does - as a
+//! raw JSON object - and can be decoded back into the `ExecuteMsg` the contract deserializes.
+
+use cosmwasm_std::Addr;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+/// Transaction details surrounding the signed contract message.
+pub struct SignDocFixture {
+    pub chain_id: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    pub sender: Addr,
+    pub contract: Addr,
+    pub gas: u64,
+}
+
+impl SignDocFixture {
+    /// Canonical sign-doc JSON for executing `msg` with no funds and no fee.
+    pub fn sign_doc(&self, msg: &impl Serialize) -> String {
+        let doc = json!({
+            "chain_id": self.chain_id,
+            "account_number": self.account_number.to_string(),
+            "sequence": self.sequence.to_string(),
+            "fee": {
+                "amount": [],
+                "gas": self.gas.to_string(),
+            },
+            "memo": "",
+            "msgs": [{
+                "type": "wasm/MsgExecuteContract",
+                "value": {
+                    "sender": self.sender,
+                    "contract": self.contract,
+                    "msg": msg,
+                    "funds": [],
+                },
+            }],
+        });
+
+        canonical_json(&doc)
+    }
+}
+
+/// Serializes the value with object keys sorted and without any whitespace.
+pub fn canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_canonical(value, &mut out);
+    out
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (idx, item) in items.iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<_> = map.keys().collect();
+            keys.sort();
+
+            out.push('{');
+            for (idx, key) in keys.into_iter().enumerate() {
+                if idx > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::from(key.as_str()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+        value => out.push_str(&value.to_string()),
+    }
+}
+
+/// The digest a secp256k1 wallet signs: sha256 of the sign-doc bytes.
+pub fn digest(sign_doc: &str) -> [u8; 32] {
+    Sha256::digest(sign_doc.as_bytes()).into()
+}
+
+pub fn digest_hex(sign_doc: &str) -> String {
+    digest(sign_doc)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Extracts the embedded contract message bytes, as the chain passes them to the contract.
+pub fn embedded_msg(sign_doc: &str) -> Vec<u8> {
+    let doc: Value = serde_json::from_str(sign_doc).unwrap();
+    serde_json::to_vec(&doc["msgs"][0]["value"]["msg"]).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coin, from_json, BankMsg, Decimal, Uint128};
+    use cw_utils::Expiration;
+
+    use crate::msg::ExecuteMsg;
+
+    use super::*;
+
+    fn fixture() -> SignDocFixture {
+        SignDocFixture {
+            chain_id: "testing".to_owned(),
+            account_number: 7,
+            sequence: 3,
+            sender: Addr::unchecked("sender"),
+            contract: Addr::unchecked("contract"),
+            gas: 200_000,
+        }
+    }
+
+    fn all_msgs() -> Vec<ExecuteMsg> {
+        vec![
+            ExecuteMsg::AddMembers {
+                admins: vec!["admin1".to_owned(), "admin2".to_owned()],
+            },
+            ExecuteMsg::RemoveMembers {
+                admins: vec!["admin1".to_owned()],
+            },
+            ExecuteMsg::Join {},
+            ExecuteMsg::Leave {},
+            ExecuteMsg::Donate {},
+            ExecuteMsg::Claim { amount: None },
+            ExecuteMsg::Claim {
+                amount: Some(Uint128::new(5)),
+            },
+            ExecuteMsg::Dispute { id: 3 },
+            ExecuteMsg::ResolveDispute {
+                id: 3,
+                refund: true,
+            },
+            ExecuteMsg::ReportMisbehavior {
+                admin: "admin1".to_owned(),
+                evidence: "double \"signed\"\n".to_owned(),
+            },
+            ExecuteMsg::UpdateConfig {
+                donation_denom: Some("atom".to_owned()),
+            },
+            ExecuteMsg::UpdateConfig {
+                donation_denom: None,
+            },
+            ExecuteMsg::RevertConfig {},
+            ExecuteMsg::SetRate {
+                denom: "atom".to_owned(),
+                rate: Some(Decimal::percent(125)),
+            },
+            ExecuteMsg::Execute {
+                msgs: vec![BankMsg::Send {
+                    to_address: "recipient".to_owned(),
+                    amount: vec![coin(5, "eth")],
+                }
+                .into()],
+            },
+            ExecuteMsg::IncreaseAllowance {
+                spender: "spender".to_owned(),
+                amount: coin(10, "eth"),
+                expires: Some(Expiration::AtHeight(100)),
+            },
+            ExecuteMsg::DecreaseAllowance {
+                spender: "spender".to_owned(),
+                amount: coin(10, "eth"),
+                expires: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn sign_doc_bytes() {
+        let doc = fixture().sign_doc(&ExecuteMsg::UpdateConfig {
+            donation_denom: Some("atom".to_owned()),
+        });
+
+        assert_eq!(
+            doc,
+            concat!(
+                r#"{"account_number":"7","chain_id":"testing","fee":{"amount":[],"gas":"200000"},"#,
+                r#""memo":"","msgs":[{"type":"wasm/MsgExecuteContract","value":{"contract":"#,
+                r#""contract","funds":[],"msg":{"update_config":{"donation_denom":"atom"}},"#,
+                r#""sender":"sender"}}],"sequence":"3"}"#,
+            )
+        );
+        assert_eq!(
+            digest_hex(&doc),
+            "2d8492efafd8392b57deb9a216f870ab4f4d979ab37755111ab6e15632536cfd"
+        );
+    }
+
+    #[test]
+    fn sign_docs_are_canonical() {
+        for msg in all_msgs() {
+            let doc = fixture().sign_doc(&msg);
+            let value: Value = serde_json::from_str(&doc).unwrap();
+
+            assert_eq!(canonical_json(&value), doc);
+            assert!(!doc.contains(": ") && !doc.contains(", ") && !doc.contains('\n'));
+        }
+    }
+
+    #[test]
+    fn sign_docs_round_trip() {
+        for msg in all_msgs() {
+            let doc = fixture().sign_doc(&msg);
+            let decoded: ExecuteMsg = from_json(embedded_msg(&doc)).unwrap();
+
+            assert_eq!(decoded, msg);
+        }
+    }
+
+    #[test]
+    fn canonical_json_sorts_nested_keys() {
+        let value = json!({ "b": { "z": 1, "a": [{ "y": true, "x": null }] }, "a": "1" });
+
+        assert_eq!(
+            canonical_json(&value),
+            r#"{"a":"1","b":{"a":[{"x":null,"y":true}],"z":1}}"#
+        );
+    }
+
+    #[test]
+    fn digests_differ_per_sequence() {
+        let msg = ExecuteMsg::Join {};
+        let doc = fixture().sign_doc(&msg);
+        let next = SignDocFixture {
+            sequence: 4,
+            ..fixture()
+        }
+        .sign_doc(&msg);
+
+        assert_ne!(digest(&doc), digest(&next));
+    }
+}
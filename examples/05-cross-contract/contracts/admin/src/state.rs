@@ -0,0 +1,97 @@
+use cosmwasm_std::{Addr, Coin, Decimal, Empty, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Enrollment {
+    pub until: Timestamp,
+    pub fee: Option<Coin>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SlashingConfig {
+    /// Number of distinct admins which have to report the same admin before it is slashed
+    pub confirmations: u32,
+    /// Part of the pending claims burned on slashing
+    pub slash: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MisbehaviorReport {
+    pub evidence: String,
+    pub confirmations: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Allowance {
+    /// Admin whose pending claims pay for the spending
+    pub granter: Addr,
+    pub amount: Uint128,
+    pub expires: Expiration,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Donation {
+    pub donor: Addr,
+    pub amount: Coin,
+    /// Admins at the time of the donation, who split it once released
+    pub admins: Vec<Addr>,
+    /// Height of the donation block, the dispute window counts from it
+    pub height: u64,
+    pub disputed: bool,
+}
+
+/// Config before the last `UpdateConfig {}`, kept while the change can still be reverted
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PreviousConfig {
+    pub donation_denom: String,
+    pub changed_at: Timestamp,
+}
+
+/// Owner-maintained value of one unit of a denom, in units of the common reference currency
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Rate {
+    pub rate: Decimal,
+    pub updated: Timestamp,
+}
+
+pub const ADMINS: Map<&Addr, Timestamp> = Map::new("admins");
+/// Admins in the 03-basics layout. Map entries are stored under a length-prefixed namespace, so
+/// both layouts can coexist in the same storage while a deployment is being migrated.
+// TODO: remove one release after the map layout migration ships
+pub const LEGACY_ADMINS: Item<Vec<Addr>> = Item::new("admins");
+pub const ADMINS_COUNT: Item<u64> = Item::new("admins_count");
+pub const ADMINS_BY_JOIN_TIME: Map<(u64, &Addr), Empty> = Map::new("admins_by_join_time");
+pub const DONATION_DENOM: Item<String> = Item::new("donation_denom");
+pub const OWNER: Item<Addr> = Item::new("owner");
+pub const ENROLLMENT: Item<Enrollment> = Item::new("enrollment");
+pub const LAST_ACTIVITY: Item<Timestamp> = Item::new("last_activity");
+pub const CLAIMABLE: Map<(&Addr, &str), Uint128> = Map::new("claimable");
+pub const SLASHING: Item<SlashingConfig> = Item::new("slashing");
+pub const REPORTS: Map<&Addr, MisbehaviorReport> = Map::new("reports");
+/// Tokens non-admin spenders may send out of the contract with `Execute {}`, per denom
+pub const ALLOWANCES: Map<(&Addr, &str), Allowance> = Map::new("allowances");
+/// Number of blocks after a donation during which the donor may dispute it. Donations are
+/// distributed immediately if not set.
+pub const DISPUTE_WINDOW: Item<u64> = Item::new("dispute_window");
+/// Donations withheld from distribution - either still in their dispute window, or disputed and
+/// waiting for a ruling
+pub const DONATIONS: Map<u64, Donation> = Map::new("donations");
+pub const NEXT_DONATION_ID: Item<u64> = Item::new("next_donation_id");
+/// Number of seconds after a config change during which the owner may revert it
+pub const CONFIG_REVERT_WINDOW: Item<u64> = Item::new("config_revert_window");
+pub const PREVIOUS_CONFIG: Item<PreviousConfig> = Item::new("previous_config");
+/// Total ever donated, per denom. Refunded donations are not counted.
+pub const DONATED: Map<&str, Uint128> = Map::new("donated");
+/// Total donated by every donor, per denom. Refunded donations are not counted.
+pub const DONOR_TOTALS: Map<(&Addr, &str), Uint128> = Map::new("donor_totals");
+pub const RATES: Map<&str, Rate> = Map::new("rates");
@@ -0,0 +1,287 @@
+use crate::error::ContractError;
+use crate::msg::{ConfigResp, CreatedContract, CreatedResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{CreatedAdmin, ADMIN_CODE_ID, CREATED, PENDING_CREATOR};
+use admin::prelude::InstantiateResp as AdminInstantiateResp;
+use common::pagination;
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, SubMsg, WasmMsg,
+};
+use cw_utils::{parse_instantiate_response_data, ParseReplyError};
+
+const INSTANTIATE_ADMIN_REPLY_ID: u64 = 1;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    ADMIN_CODE_ID.save(deps.storage, &msg.admin_code_id)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Config {} => to_json_binary(&query::config(deps)?),
+        Created { start_after, limit } => {
+            to_json_binary(&query::created(deps, start_after, limit)?)
+        }
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        CreateAdmin { msg, label } => exec::create_admin(deps, info, msg, label),
+    }
+}
+
+pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
+    match reply.id {
+        INSTANTIATE_ADMIN_REPLY_ID => reply::admin_instantiated(deps, reply),
+        id => Err(ContractError::UnknownReply { id }),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn create_admin(
+        deps: DepsMut,
+        info: MessageInfo,
+        msg: admin::msg::InstantiateMsg,
+        label: String,
+    ) -> Result<Response, ContractError> {
+        let code_id = ADMIN_CODE_ID.load(deps.storage)?;
+        PENDING_CREATOR.save(deps.storage, &info.sender)?;
+
+        // The creator owns the contract and may migrate it, not the factory instantiating it
+        let msg = admin::msg::InstantiateMsg {
+            owner: Some(info.sender.to_string()),
+            ..msg
+        };
+        let msg = WasmMsg::Instantiate {
+            admin: Some(info.sender.to_string()),
+            code_id,
+            msg: to_json_binary(&msg)?,
+            funds: vec![],
+            label,
+        };
+
+        let resp = Response::new()
+            .add_submessage(SubMsg::reply_on_success(msg, INSTANTIATE_ADMIN_REPLY_ID))
+            .add_attribute("action", "create_admin");
+
+        Ok(resp)
+    }
+}
+
+mod reply {
+    use super::*;
+
+    pub fn admin_instantiated(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
+        // The submessage result wraps `MsgInstantiateContractResponse`, with whatever the child
+        // passed to `Response::set_data` in its `data` field. Chains on CosmWasm 2 return it in
+        // `msg_responses`, older ones in the deprecated `data`.
+        let resp = reply
+            .result
+            .into_result()
+            .map_err(ParseReplyError::SubMsgFailure)?;
+        #[allow(deprecated)]
+        let resp = match resp.msg_responses.into_iter().next() {
+            Some(msg) => msg.value,
+            None => resp
+                .data
+                .ok_or_else(|| ParseReplyError::ParseFailure("Missing reply data".to_owned()))?,
+        };
+        let resp = parse_instantiate_response_data(&resp)?;
+        let data = resp.data.ok_or(ContractError::MissingInstantiateData)?;
+        let data: AdminInstantiateResp = from_json(data)?;
+
+        let addr = deps.api.addr_validate(&resp.contract_address)?;
+        let creator = PENDING_CREATOR.load(deps.storage)?;
+        PENDING_CREATOR.remove(deps.storage);
+
+        let created = CreatedAdmin {
+            creator,
+            admins_count: data.admins_count,
+        };
+        CREATED.save(deps.storage, &addr, &created)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "admin_instantiated")
+            .add_attribute("contract_address", resp.contract_address)
+            .add_attribute("admins_count", data.admins_count.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn config(deps: Deps) -> StdResult<ConfigResp> {
+        let admin_code_id = ADMIN_CODE_ID.load(deps.storage)?;
+        Ok(ConfigResp { admin_code_id })
+    }
+
+    pub fn created(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<CreatedResp> {
+        let limit = pagination::limit(limit);
+        let start_after = start_after
+            .map(|addr| deps.api.addr_validate(&addr))
+            .transpose()?;
+        let start = pagination::start_after(start_after.as_ref());
+
+        let contracts: Vec<_> = CREATED
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (addr, info) = item?;
+                Ok(CreatedContract { addr, info })
+            })
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after =
+            pagination::next_key(&contracts, limit, |contract| contract.addr.clone());
+
+        Ok(CreatedResp {
+            contracts,
+            next_start_after,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use admin::prelude::AdminContract;
+    use testing::prelude::*;
+
+    use super::*;
+
+    fn factory(app: &mut App) -> Proxy {
+        let admin_code = ContractWrapper::new(
+            admin::contract::execute,
+            admin::contract::instantiate,
+            admin::contract::query,
+        );
+        let admin_code_id = store_code(app, admin_code);
+
+        let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+        let msg = InstantiateMsg { admin_code_id };
+        Proxy::deploy(app, code, "owner", &msg, "Factory").unwrap()
+    }
+
+    fn create_admin_msg(admins: &[&str]) -> ExecuteMsg {
+        ExecuteMsg::CreateAdmin {
+            msg: admin::msg::InstantiateMsg {
+                owner: None,
+                admins: admins
+                    .iter()
+                    .map(|admin| mock_addr(admin).to_string())
+                    .collect(),
+                donation_denom: "eth".to_owned(),
+                enrollment_until: None,
+                join_fee: None,
+                slashing: None,
+                dispute_window: None,
+                config_revert_window: None,
+            },
+            label: "Admin".to_owned(),
+        }
+    }
+
+    /// Event emitted by the factory once the admin contract is instantiated.
+    fn instantiated(resp: &AppResponse) -> &cosmwasm_std::Event {
+        wasm_event(resp, "admin_instantiated")
+    }
+
+    #[test]
+    fn create_admin() {
+        let mut app = app();
+        let factory = factory(&mut app);
+
+        let resp = factory
+            .execute(
+                &mut app,
+                "user",
+                &create_admin_msg(&["admin1", "admin2"]),
+                &[],
+            )
+            .unwrap();
+
+        let replied = instantiated(&resp);
+        assert_eq!(attr(replied, "admins_count"), "2");
+
+        let admin = AdminContract(Addr::unchecked(attr(replied, "contract_address")));
+        let config = admin.config(&app.wrap()).unwrap();
+        assert_eq!(config.owner, mock_addr("user"));
+        assert_eq!(
+            admin.admins_list(&app.wrap()).unwrap(),
+            sorted_addrs(&["admin1", "admin2"])
+        );
+    }
+
+    #[test]
+    fn created_registry() {
+        let mut app = app();
+        let factory = factory(&mut app);
+
+        let mut created: Vec<_> = [
+            ("user1", &["admin1"][..]),
+            ("user2", &["admin1", "admin2"]),
+            ("user1", &["admin1", "admin2", "admin3"]),
+        ]
+        .into_iter()
+        .map(|(creator, admins)| {
+            let resp = factory
+                .execute(&mut app, creator, &create_admin_msg(admins), &[])
+                .unwrap();
+            CreatedContract {
+                addr: Addr::unchecked(attr(instantiated(&resp), "contract_address")),
+                info: CreatedAdmin {
+                    creator: app.api().addr_make(creator),
+                    admins_count: admins.len() as u64,
+                },
+            }
+        })
+        .collect();
+        // The registry is keyed by the contract address, not by the creation order
+        created.sort_by(|a, b| a.addr.cmp(&b.addr));
+
+        let resp: CreatedResp = factory
+            .query(
+                &app,
+                &QueryMsg::Created {
+                    start_after: None,
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.contracts, created[..2]);
+
+        let resp: CreatedResp = factory
+            .query(
+                &app,
+                &QueryMsg::Created {
+                    start_after: Some(created[1].addr.to_string()),
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.contracts, created[2..]);
+    }
+}
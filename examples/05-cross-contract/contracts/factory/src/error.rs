@@ -0,0 +1,15 @@
+use cosmwasm_std::StdError;
+use cw_utils::ParseReplyError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Unknown reply id: {id}")]
+    UnknownReply { id: u64 },
+    #[error("Instantiated contract returned no data")]
+    MissingInstantiateData,
+    #[error("Invalid reply: {0}")]
+    ParseReply(#[from] ParseReplyError),
+}
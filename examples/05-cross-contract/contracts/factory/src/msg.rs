@@ -0,0 +1,56 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::CreatedAdmin;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub admin_code_id: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Instantiates a new admin contract, owned by the factory and migratable by the sender
+    CreateAdmin {
+        msg: admin::msg::InstantiateMsg,
+        label: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ConfigResp {
+    pub admin_code_id: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CreatedContract {
+    pub addr: Addr,
+    pub info: CreatedAdmin,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CreatedResp {
+    pub contracts: Vec<CreatedContract>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(ConfigResp)]
+    Config {},
+    /// Admin contracts created by the factory, ordered by address
+    #[returns(CreatedResp)]
+    Created {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
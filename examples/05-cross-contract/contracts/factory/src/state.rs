@@ -0,0 +1,16 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CreatedAdmin {
+    pub creator: Addr,
+    pub admins_count: u64,
+}
+
+pub const ADMIN_CODE_ID: Item<u64> = Item::new("admin_code_id");
+/// Sender of the `CreateAdmin` being processed, read back in the reply
+pub const PENDING_CREATOR: Item<Addr> = Item::new("pending_creator");
+pub const CREATED: Map<&Addr, CreatedAdmin> = Map::new("created");
@@ -0,0 +1,336 @@
+use crate::error::ContractError;
+use crate::msg::{
+    AdminContractResp, AdminJoinedResp, ExecuteMsg, FailedHooksResp, HookMsg, InstantiateMsg,
+    Member, MemberSinceResp, MembersResp, QueryMsg,
+};
+use crate::response::{self, HOOK_REPLY_ID};
+use crate::state::{ADMIN_CONTRACT, FAILED_HOOKS, FEE, HOOKS, MEMBERS, OWNER};
+use admin::prelude::AdminContract;
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Order, Reply, Response, StdError, StdResult, SubMsgResult,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    let admin_contract = deps.api.addr_validate(&msg.admin_contract)?;
+    ADMIN_CONTRACT.save(deps.storage, &AdminContract(admin_contract))?;
+    OWNER.save(deps.storage, &info.sender)?;
+
+    if let Some(fee) = msg.fee {
+        // Whole donation taken as a fee would leave nothing to forward
+        if fee >= Decimal::one() {
+            return Err(StdError::generic_err("Invalid fee"));
+        }
+        FEE.save(deps.storage, &fee)?;
+    }
+
+    let hooks = msg
+        .hooks
+        .iter()
+        .map(|hook| deps.api.addr_validate(hook))
+        .collect::<StdResult<_>>()?;
+    HOOKS.save(deps.storage, &hooks)?;
+    FAILED_HOOKS.save(deps.storage, &0)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        AdminContract {} => to_json_binary(&query::admin_contract(deps)?),
+        MemberSince { addr } => to_json_binary(&query::member_since(deps, addr)?),
+        Members {} => to_json_binary(&query::members(deps)?),
+        AdminJoined { addr } => to_json_binary(&query::admin_joined(deps, addr)?),
+        FailedHooks {} => to_json_binary(&query::failed_hooks(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Donate {} => exec::donate(deps, info),
+        Register {} => exec::register(deps, info),
+    }
+}
+
+pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
+    match reply.id {
+        HOOK_REPLY_ID => reply::hook_failed(deps, reply),
+        id => Err(ContractError::UnknownReply { id }),
+    }
+}
+
+/// Storage key of the admin contract's `ADMINS` entry for the address.
+///
+/// Built by hand the way `cw-storage-plus` lays out `Map` keys: the namespace length as two
+/// big-endian bytes, the namespace, then the key. Nothing checks this against the admin contract
+/// at compile time - renaming the map, changing its key type or the storage layout there breaks
+/// this silently, so raw queries are only worth it for hot paths where a smart query is too
+/// expensive.
+fn admins_key(addr: &Addr) -> Vec<u8> {
+    let namespace = b"admins";
+
+    let mut key = Vec::with_capacity(2 + namespace.len() + addr.as_str().len());
+    key.extend_from_slice(&(namespace.len() as u16).to_be_bytes());
+    key.extend_from_slice(namespace);
+    key.extend_from_slice(addr.as_bytes());
+    key
+}
+
+mod exec {
+    use super::*;
+
+    pub fn donate(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        let admin_contract = ADMIN_CONTRACT.load(deps.storage)?;
+        // The admin contract would reject anything else anyway, but only after the fee is taken
+        let denom = admin_contract.config(&deps.querier)?.donation_denom;
+        let amount = cw_utils::must_pay(&info, &denom)?;
+
+        let fee = FEE.may_load(deps.storage)?.unwrap_or_default();
+        let fee = math::mul_decimal(amount, fee)?;
+        let donation = coins((amount - fee).u128(), &denom);
+
+        let fee = if fee.is_zero() {
+            None
+        } else {
+            let owner = OWNER.load(deps.storage)?;
+            Some(response::fee(&owner, coins(fee.u128(), &denom)))
+        };
+        let payout = response::payout(&admin_contract, donation.clone())?;
+
+        let hook_msg = HookMsg::Donation {
+            donor: info.sender.clone(),
+            amount: donation,
+        };
+        let hooks = HOOKS
+            .load(deps.storage)?
+            .iter()
+            .map(|hook| response::hook(hook, &hook_msg))
+            .collect::<StdResult<_>>()?;
+
+        let resp = response::donation(fee, payout, hooks)
+            .add_attribute("action", "donate")
+            .add_attribute("sender", info.sender);
+
+        Ok(resp)
+    }
+
+    pub fn register(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        cw_utils::nonpayable(&info)?;
+
+        let admin_contract = ADMIN_CONTRACT.load(deps.storage)?;
+        // The admin contract fails the query for non-admins, which is the only way it can fail
+        // once the contract address is known to be correct
+        let joined = admin_contract
+            .join_time(&deps.querier, &info.sender)
+            .map_err(|_| ContractError::NotAdmin {
+                addr: info.sender.clone(),
+            })?;
+
+        MEMBERS.save(deps.storage, &info.sender, &joined)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "register")
+            .add_attribute("sender", info.sender);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn admin_contract(deps: Deps) -> StdResult<AdminContractResp> {
+        let AdminContract(admin_contract) = ADMIN_CONTRACT.load(deps.storage)?;
+        Ok(AdminContractResp { admin_contract })
+    }
+
+    pub fn member_since(deps: Deps, addr: String) -> StdResult<MemberSinceResp> {
+        let admin_contract = ADMIN_CONTRACT.load(deps.storage)?;
+        let joined = admin_contract.join_time(&deps.querier, addr)?;
+
+        Ok(MemberSinceResp { joined })
+    }
+
+    pub fn members(deps: Deps) -> StdResult<MembersResp> {
+        let members = MEMBERS
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|member| member.map(|(addr, joined)| Member { addr, joined }))
+            .collect::<StdResult<_>>()?;
+
+        Ok(MembersResp { members })
+    }
+
+    pub fn admin_joined(deps: Deps, addr: String) -> StdResult<AdminJoinedResp> {
+        let addr = deps.api.addr_validate(&addr)?;
+        let admin_contract = ADMIN_CONTRACT.load(deps.storage)?;
+
+        // Unlike the smart query, a missing key is not an error - the raw query returns `None`
+        let joined = deps
+            .querier
+            .query_wasm_raw(admin_contract.addr(), admins_key(&addr))?
+            .map(|joined| from_json(&joined))
+            .transpose()?;
+
+        Ok(AdminJoinedResp { joined })
+    }
+
+    pub fn failed_hooks(deps: Deps) -> StdResult<FailedHooksResp> {
+        let count = FAILED_HOOKS.load(deps.storage)?;
+        Ok(FailedHooksResp { count })
+    }
+}
+
+mod reply {
+    use super::*;
+
+    pub fn hook_failed(deps: DepsMut, reply: Reply) -> Result<Response, ContractError> {
+        // Hooks only reply on error
+        let err = match reply.result {
+            SubMsgResult::Err(err) => err,
+            SubMsgResult::Ok(_) => return Err(ContractError::UnexpectedReply { id: reply.id }),
+        };
+        FAILED_HOOKS.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+
+        Ok(Response::new().add_attribute("hook_failed", err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use admin::prelude::ExecuteMsg as AdminExecuteMsg;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{BankMsg, ContractResult, SubMsg, SubMsgResponse, SystemResult, WasmMsg};
+    use cw_utils::PaymentError;
+
+    use super::*;
+
+    #[test]
+    fn donation_message_order() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let admin = deps.api.addr_make("admin");
+        let donor = deps.api.addr_make("donor");
+        let hooks = [deps.api.addr_make("hook1"), deps.api.addr_make("hook2")];
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {
+                admin_contract: admin.to_string(),
+                fee: Some(Decimal::percent(10)),
+                hooks: hooks.iter().map(Addr::to_string).collect(),
+            },
+        )
+        .unwrap();
+
+        // Only the donation denom is read from the admin contract config. `ConfigResp` is
+        // non-exhaustive, so the response is given as JSON.
+        let config = format!(r#"{{"owner":"{}","donation_denom":"eth"}}"#, owner);
+        deps.querier.update_wasm(move |_| {
+            SystemResult::Ok(ContractResult::Ok(Binary::from(config.as_bytes())))
+        });
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&donor, &coins(100, "eth")),
+            ExecuteMsg::Donate {},
+        )
+        .unwrap();
+
+        let hook = |addr: &Addr| {
+            let msg = HookMsg::Donation {
+                donor: donor.clone(),
+                amount: coins(90, "eth"),
+            };
+            SubMsg::reply_on_error(
+                WasmMsg::Execute {
+                    contract_addr: addr.to_string(),
+                    msg: to_json_binary(&msg).unwrap(),
+                    funds: vec![],
+                },
+                HOOK_REPLY_ID,
+            )
+        };
+
+        // Fee first, payout second, hooks last
+        assert_eq!(
+            resp.messages,
+            vec![
+                SubMsg::new(BankMsg::Send {
+                    to_address: owner.to_string(),
+                    amount: coins(10, "eth"),
+                }),
+                SubMsg::new(WasmMsg::Execute {
+                    contract_addr: admin.to_string(),
+                    msg: to_json_binary(&AdminExecuteMsg::Donate {}).unwrap(),
+                    funds: coins(90, "eth"),
+                }),
+                hook(&hooks[0]),
+                hook(&hooks[1]),
+            ]
+        );
+    }
+
+    #[test]
+    fn register_rejects_funds() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let admin = deps.api.addr_make("admin1");
+        let admin_contract = deps.api.addr_make("admin");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {
+                admin_contract: admin_contract.to_string(),
+                fee: None,
+                hooks: vec![],
+            },
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &coins(100, "eth")),
+            ExecuteMsg::Register {},
+        )
+        .unwrap_err();
+
+        assert_eq!(err, PaymentError::NonPayable {}.into());
+    }
+
+    #[test]
+    #[allow(deprecated)] // `SubMsgResponse::data` still has to be set
+    fn hook_reply_on_success() {
+        let mut deps = mock_dependencies();
+        let msg = Reply {
+            id: HOOK_REPLY_ID,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+                msg_responses: vec![],
+            }),
+        };
+
+        let err = reply(deps.as_mut(), mock_env(), msg).unwrap_err();
+        assert_eq!(err, ContractError::UnexpectedReply { id: HOOK_REPLY_ID });
+    }
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{Addr, StdError};
+use cw_utils::PaymentError;
+use math::MathError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Payment error: {0}")]
+    Payment(#[from] PaymentError),
+    #[error("{0}")]
+    Math(#[from] MathError),
+    #[error("{addr} is not an admin of the admin contract")]
+    NotAdmin { addr: Addr },
+    #[error("Unknown reply id: {id}")]
+    UnknownReply { id: u64 },
+    #[error("Reply {id} expected only on error, but the submessage succeeded")]
+    UnexpectedReply { id: u64 },
+}
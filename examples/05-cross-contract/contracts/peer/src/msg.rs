@@ -0,0 +1,91 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub admin_contract: String,
+    /// Part of every donation sent to the instantiator instead of the admin contract
+    pub fee: Option<Decimal>,
+    /// Contracts notified with `HookMsg::Donation` about every forwarded donation
+    pub hooks: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Forwards the sent funds as a donation to the admin contract
+    Donate {},
+    /// Registers the sender with the peer, recording when they joined the admin contract
+    Register {},
+}
+
+/// Sent to hook contracts. A failing hook does not revert the donation.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HookMsg {
+    Donation {
+        donor: Addr,
+        /// Amount forwarded to the admin contract, after the fee
+        amount: Vec<Coin>,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AdminContractResp {
+    pub admin_contract: Addr,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MemberSinceResp {
+    pub joined: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Member {
+    pub addr: Addr,
+    pub joined: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct MembersResp {
+    pub members: Vec<Member>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AdminJoinedResp {
+    /// `None` if the address is not an admin
+    pub joined: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FailedHooksResp {
+    pub count: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(AdminContractResp)]
+    AdminContract {},
+    /// When the address joined the admin contract, fails if it is not an admin
+    #[returns(MemberSinceResp)]
+    MemberSince { addr: String },
+    /// Registered admins, ordered by address
+    #[returns(MembersResp)]
+    Members {},
+    /// Same as `MemberSince {}`, but reads the admin contract storage directly with a raw query
+    #[returns(AdminJoinedResp)]
+    AdminJoined { addr: String },
+    /// Number of hook calls which failed so far
+    #[returns(FailedHooksResp)]
+    FailedHooks {},
+}
@@ -0,0 +1,46 @@
+//! Response construction with a fixed message order.
+//!
+//! Messages are executed in the order they are added, so the order is part of the contract
+//! interface: the fee is taken first, the payout goes out second, and hooks run last - when they
+//! are called, the donation is already fully accounted for on both sides. Handlers build their
+//! responses with the functions below instead of adding messages by hand.
+
+use admin::prelude::{AdminContract, ExecuteMsg as AdminExecuteMsg};
+use cosmwasm_std::{to_json_binary, Addr, BankMsg, Coin, Response, StdResult, SubMsg, WasmMsg};
+
+use crate::msg::HookMsg;
+
+/// Reply id of hook submessages. Hooks are fire-and-forget, their failures are only reported.
+pub const HOOK_REPLY_ID: u64 = 1;
+
+/// Fee sent to the peer owner.
+pub fn fee(owner: &Addr, amount: Vec<Coin>) -> SubMsg {
+    SubMsg::new(BankMsg::Send {
+        to_address: owner.to_string(),
+        amount,
+    })
+}
+
+/// Donation forwarded to the admin contract.
+pub fn payout(admin_contract: &AdminContract, amount: Vec<Coin>) -> StdResult<SubMsg> {
+    let msg = admin_contract.call(AdminExecuteMsg::Donate {}, amount)?;
+    Ok(SubMsg::new(msg))
+}
+
+/// Notification of a hook contract, which cannot fail the donation.
+pub fn hook(hook: &Addr, msg: &HookMsg) -> StdResult<SubMsg> {
+    let msg = WasmMsg::Execute {
+        contract_addr: hook.to_string(),
+        msg: to_json_binary(msg)?,
+        funds: vec![],
+    };
+    Ok(SubMsg::reply_on_error(msg, HOOK_REPLY_ID))
+}
+
+/// Response of a forwarded donation: the fee if any, then the payout, then the hooks.
+pub fn donation(fee: Option<SubMsg>, payout: SubMsg, hooks: Vec<SubMsg>) -> Response {
+    Response::new()
+        .add_submessages(fee)
+        .add_submessage(payout)
+        .add_submessages(hooks)
+}
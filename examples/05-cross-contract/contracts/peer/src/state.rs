@@ -0,0 +1,14 @@
+use admin::prelude::AdminContract;
+use cosmwasm_std::{Addr, Decimal, Timestamp};
+use cw_storage_plus::{Item, Map};
+
+pub const ADMIN_CONTRACT: Item<AdminContract> = Item::new("admin_contract");
+pub const OWNER: Item<Addr> = Item::new("owner");
+/// Part of every donation kept by the owner
+pub const FEE: Item<Decimal> = Item::new("fee");
+/// Contracts notified about every forwarded donation
+pub const HOOKS: Item<Vec<Addr>> = Item::new("hooks");
+/// Admins registered with the peer, with the join time read from the admin contract on registration
+pub const MEMBERS: Map<&Addr, Timestamp> = Map::new("members");
+/// Hook calls which failed since instantiation, for monitoring
+pub const FAILED_HOOKS: Item<u64> = Item::new("failed_hooks");
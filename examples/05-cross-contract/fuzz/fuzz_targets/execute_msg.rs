@@ -0,0 +1,22 @@
+#![no_main]
+
+use admin::contract::execute;
+use admin::msg::ExecuteMsg;
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_env;
+use cross_contract_fuzz::{contract, sender};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let (selector, json) = match data.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    let msg: ExecuteMsg = match from_json(json) {
+        Ok(msg) => msg,
+        Err(_) => return,
+    };
+
+    let mut deps = contract();
+    let _ = execute(deps.as_mut(), mock_env(), sender(*selector), msg);
+});
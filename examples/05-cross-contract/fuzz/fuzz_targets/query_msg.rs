@@ -0,0 +1,18 @@
+#![no_main]
+
+use admin::contract::query;
+use admin::msg::QueryMsg;
+use cosmwasm_std::from_json;
+use cosmwasm_std::testing::mock_env;
+use cross_contract_fuzz::contract;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let msg: QueryMsg = match from_json(data) {
+        Ok(msg) => msg,
+        Err(_) => return,
+    };
+
+    let deps = contract();
+    let _ = query(deps.as_ref(), mock_env(), msg);
+});
@@ -0,0 +1,55 @@
+//! Setup shared by the fuzz targets.
+//!
+//! Targets feed arbitrary bytes through the same path a chain does - JSON deserialization first,
+//! then the entry point - so any panic in parsing or dispatch is a finding. Errors are fine, the
+//! contract is expected to reject most of the input.
+
+use admin::contract::instantiate;
+use admin::msg::InstantiateMsg;
+use admin::state::SlashingConfig;
+use cosmwasm_std::testing::{
+    message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{coins, Decimal, MessageInfo, OwnedDeps};
+
+pub type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+/// Admin contract instantiated by `owner` with `admin1` and `admin2`, with every optional
+/// feature enabled so their handlers are reachable.
+pub fn contract() -> Deps {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        owner: None,
+        admins: vec![
+            deps.api.addr_make("admin1").to_string(),
+            deps.api.addr_make("admin2").to_string(),
+        ],
+        donation_denom: "eth".to_owned(),
+        enrollment_until: Some(mock_env().block.time.plus_seconds(60)),
+        join_fee: None,
+        slashing: Some(SlashingConfig {
+            confirmations: 1,
+            slash: Decimal::percent(50),
+        }),
+        dispute_window: Some(10),
+        config_revert_window: Some(60),
+    };
+    let owner = deps.api.addr_make("owner");
+    instantiate(deps.as_mut(), mock_env(), message_info(&owner, &[]), msg).unwrap();
+    deps
+}
+
+/// Sender picked by the first input byte, so every role gets fuzzed, with or without funds.
+pub fn sender(selector: u8) -> MessageInfo {
+    let sender = match selector % 3 {
+        0 => "owner",
+        1 => "admin1",
+        _ => "user",
+    };
+    let funds = if selector & 0x80 != 0 {
+        coins(u128::from(selector), "eth")
+    } else {
+        vec![]
+    };
+    message_info(&MockApi::default().addr_make(sender), &funds)
+}
@@ -0,0 +1,29 @@
+//! Deploys the cross-contract example.
+//!
+//! `cargo run --bin deploy -- --mock` runs the deployment against an in-memory chain. Without the
+//! flag it deploys the optimized contracts from `artifacts/` to a local Juno node, signing with
+//! the mnemonic from the `LOCAL_MNEMONIC` environment variable.
+
+use cross_contract_orch::deploy;
+use cw_orch::daemon::networks::LOCAL_JUNO;
+use cw_orch::prelude::*;
+
+fn main() -> anyhow::Result<()> {
+    let (admin, peer) = if std::env::args().any(|arg| arg == "--mock") {
+        let (admin, peer) = deploy(Mock::new("deployer"), "ujuno")?;
+        (admin.address()?, peer.address()?)
+    } else {
+        let rt = tokio::runtime::Runtime::new()?;
+        let chain = Daemon::builder()
+            .chain(LOCAL_JUNO)
+            .handle(rt.handle())
+            .build()?;
+        let (admin, peer) = deploy(chain, "ujuno")?;
+        (admin.address()?, peer.address()?)
+    };
+
+    println!("admin: {}", admin);
+    println!("peer: {}", peer);
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+//! cw-orchestrator interfaces of the cross-contract example contracts.
+//!
+//! The same interface drives the contracts in a `Mock` environment, backed by multitest and the
+//! contract code compiled natively, and through a `Daemon` against a real node, using the
+//! optimized Wasm from `artifacts/`.
+
+use cw_orch::prelude::*;
+use cw_orch::{interface, ArtifactsDir};
+
+#[interface(
+    admin::msg::InstantiateMsg,
+    admin::msg::ExecuteMsg,
+    admin::msg::QueryMsg,
+    admin::msg::MigrateMsg
+)]
+pub struct Admin;
+
+impl<Chain: CwEnv> Uploadable for Admin<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts().find_wasm_path("admin").unwrap()
+    }
+
+    fn wrapper(&self) -> Box<dyn MockContract<Empty>> {
+        Box::new(
+            ContractWrapper::new_with_empty(
+                admin::contract::execute,
+                admin::contract::instantiate,
+                admin::contract::query,
+            )
+            .with_migrate(admin::contract::migrate),
+        )
+    }
+}
+
+#[interface(
+    peer::msg::InstantiateMsg,
+    peer::msg::ExecuteMsg,
+    peer::msg::QueryMsg,
+    Empty
+)]
+pub struct Peer;
+
+impl<Chain: CwEnv> Uploadable for Peer<Chain> {
+    fn wasm(&self) -> WasmPath {
+        artifacts().find_wasm_path("peer").unwrap()
+    }
+
+    fn wrapper(&self) -> Box<dyn MockContract<Empty>> {
+        Box::new(
+            ContractWrapper::new_with_empty(
+                peer::contract::execute,
+                peer::contract::instantiate,
+                peer::contract::query,
+            )
+            .with_reply(peer::contract::reply),
+        )
+    }
+}
+
+/// Output of the workspace optimizer, run from the `05-cross-contract` directory.
+fn artifacts() -> ArtifactsDir {
+    ArtifactsDir::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../artifacts"))
+}
+
+/// Uploads both contracts and instantiates an admin contract with the sender as its only admin,
+/// and a peer forwarding donations to it.
+pub fn deploy<Chain: CwEnv>(
+    chain: Chain,
+    donation_denom: &str,
+) -> Result<(Admin<Chain>, Peer<Chain>), CwOrchError> {
+    let sender = chain.sender_addr();
+    let admin = Admin::new("admin", chain.clone());
+    let peer = Peer::new("peer", chain);
+
+    admin.upload()?;
+    admin.instantiate(
+        &admin::msg::InstantiateMsg {
+            owner: None,
+            admins: vec![sender.to_string()],
+            donation_denom: donation_denom.to_owned(),
+            enrollment_until: None,
+            join_fee: None,
+            slashing: None,
+            dispute_window: None,
+            config_revert_window: None,
+        },
+        Some(&sender),
+        None,
+    )?;
+
+    peer.upload()?;
+    peer.instantiate(
+        &peer::msg::InstantiateMsg {
+            admin_contract: admin.address()?.to_string(),
+            fee: None,
+            hooks: vec![],
+        },
+        Some(&sender),
+        None,
+    )?;
+
+    Ok((admin, peer))
+}
+
+#[cfg(test)]
+mod tests {
+    use admin::msg::{AdminsListResp, QueryMsg as AdminQueryMsg};
+    use peer::msg::{AdminContractResp, QueryMsg as PeerQueryMsg};
+
+    use super::*;
+
+    #[test]
+    fn deploy_mock() {
+        let chain = Mock::new("sender");
+        let sender = chain.sender_addr();
+
+        let (admin, peer) = deploy(chain, "eth").unwrap();
+
+        let resp: AdminsListResp = admin.query(&AdminQueryMsg::AdminsList {}).unwrap();
+        assert_eq!(resp.admins, vec![sender]);
+
+        let resp: AdminContractResp = peer.query(&PeerQueryMsg::AdminContract {}).unwrap();
+        assert_eq!(resp.admin_contract, admin.address().unwrap());
+    }
+}
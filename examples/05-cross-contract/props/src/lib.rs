@@ -0,0 +1 @@
+//! Property-based tests of the cross-contract example. Everything lives in `tests/`.
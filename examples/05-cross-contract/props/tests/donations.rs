@@ -0,0 +1,118 @@
+//! Donation splitting over random admin counts and amounts.
+//!
+//! Each case deploys a fresh admin contract, so the properties hold for the contract as a whole
+//! and not only for the splitting arithmetic.
+
+use admin::msg::{ExecuteMsg, ForAddressResp, InstantiateMsg, QueryMsg};
+use proptest::prelude::*;
+use testing::prelude::*;
+
+/// Admin contract with `admins` admins and a `donor` holding `funds` eth.
+fn deploy(admins: usize, funds: u128) -> (App, Proxy, Vec<Addr>) {
+    let mut app = app_with_balances(&[("donor", coins(funds, "eth"))]);
+
+    let addrs: Vec<_> = (0..admins)
+        .map(|idx| mock_addr(&format!("admin{}", idx)))
+        .collect();
+    let msg = InstantiateMsg {
+        owner: None,
+        admins: addrs.iter().map(Addr::to_string).collect(),
+        donation_denom: "eth".to_owned(),
+        enrollment_until: None,
+        join_fee: None,
+        slashing: None,
+        dispute_window: None,
+        config_revert_window: None,
+    };
+    let code = ContractWrapper::new(
+        admin::contract::execute,
+        admin::contract::instantiate,
+        admin::contract::query,
+    );
+    let contract = Proxy::deploy(&mut app, code, "owner", &msg, "Admin").unwrap();
+
+    (app, contract, addrs)
+}
+
+fn claimable(app: &App, contract: &Proxy, addr: &Addr) -> u128 {
+    let resp: ForAddressResp = contract
+        .query(
+            app,
+            &QueryMsg::ForAddress {
+                addr: addr.to_string(),
+            },
+        )
+        .unwrap();
+
+    resp.claimable
+        .iter()
+        .find(|coin| coin.denom == "eth")
+        .map(|coin| coin.amount.u128())
+        .unwrap_or_default()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn single_donation(admins in 1usize..=20, amount in 1..=u128::MAX) {
+        let (mut app, contract, addrs) = deploy(admins, amount);
+
+        contract
+            .execute(&mut app, "donor", &ExecuteMsg::Donate {}, &coins(amount, "eth"))
+            .unwrap();
+
+        let payouts: Vec<_> = addrs
+            .iter()
+            .map(|addr| claimable(&app, &contract, addr))
+            .collect();
+        let paid: u128 = payouts.iter().sum();
+        prop_assert!(paid <= amount);
+        let remainder = amount - paid;
+
+        prop_assert!(payouts.iter().all(|payout| *payout == amount / admins as u128));
+        prop_assert!(remainder < admins as u128);
+        // Nothing leaves the contract before it is claimed
+        prop_assert_eq!(balance(&app, contract.addr(), "eth"), amount);
+    }
+
+    #[test]
+    fn donations_accumulate(
+        admins in 1usize..=10,
+        // Bounded so the total always fits, the donor could not hold more anyway
+        amounts in prop::collection::vec(1..=u128::MAX / 8, 1..=8),
+    ) {
+        let total: u128 = amounts.iter().sum();
+        let (mut app, contract, addrs) = deploy(admins, total);
+
+        for amount in &amounts {
+            contract
+                .execute(&mut app, "donor", &ExecuteMsg::Donate {}, &coins(*amount, "eth"))
+                .unwrap();
+        }
+
+        // Every donation is split on its own, remainders are not carried over
+        let share: u128 = amounts.iter().map(|amount| amount / admins as u128).sum();
+        let mut paid = 0;
+        for addr in &addrs {
+            let payout = claimable(&app, &contract, addr);
+            prop_assert_eq!(payout, share);
+            paid += payout;
+        }
+        prop_assert!(total - paid < (admins * amounts.len()) as u128);
+
+        // Claims pay out exactly what was pending, and only the remainders are left behind
+        for (idx, addr) in addrs.iter().enumerate() {
+            contract
+                .execute(
+                    &mut app,
+                    &format!("admin{}", idx),
+                    &ExecuteMsg::Claim { amount: None },
+                    &[],
+                )
+                .unwrap();
+            prop_assert_eq!(balance(&app, addr, "eth"), share);
+        }
+        prop_assert_eq!(balance(&app, contract.addr(), "eth"), total - paid);
+    }
+}
@@ -0,0 +1,4 @@
+//! End-to-end scenarios for the cross-contract example.
+//!
+//! This crate has no code of its own - it only exists to host the `tests/` suite, which deploys
+//! the workspace contracts together and drives them through multitest.
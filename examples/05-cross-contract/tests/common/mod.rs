@@ -0,0 +1,195 @@
+//! Fixtures shared by the end-to-end suites.
+//!
+//! `TestEnv::builder()` deploys the admin contract on a fresh app, and a peer talking to it if
+//! asked for. Everything not set on the builder gets a neutral default: no admins, no balances,
+//! `eth` donations and every optional feature disabled.
+
+// Every suite uses a different part of the harness
+#![allow(dead_code)]
+
+use admin::helpers::AdminContract;
+use admin::msg::InstantiateMsg as AdminInstantiateMsg;
+use peer::msg::InstantiateMsg as PeerInstantiateMsg;
+use testing::prelude::*;
+
+pub fn store_admin(app: &mut App) -> u64 {
+    let code = ContractWrapper::new(
+        admin::contract::execute,
+        admin::contract::instantiate,
+        admin::contract::query,
+    )
+    .with_migrate(admin::contract::migrate);
+    store_code(app, code)
+}
+
+pub fn store_peer(app: &mut App) -> u64 {
+    let code = ContractWrapper::new(
+        peer::contract::execute,
+        peer::contract::instantiate,
+        peer::contract::query,
+    )
+    .with_reply(peer::contract::reply);
+    store_code(app, code)
+}
+
+pub fn store_factory(app: &mut App) -> u64 {
+    let code = ContractWrapper::new(
+        factory::contract::execute,
+        factory::contract::instantiate,
+        factory::contract::query,
+    )
+    .with_reply(factory::contract::reply);
+    store_code(app, code)
+}
+
+pub struct TestEnv {
+    pub app: App,
+    /// Admin contract, instantiated by `owner`
+    pub admin: Proxy,
+    peer: Option<Proxy>,
+}
+
+impl TestEnv {
+    pub fn builder() -> TestEnvBuilder {
+        TestEnvBuilder::new()
+    }
+
+    /// Peer contract, instantiated by `owner`.
+    pub fn peer(&self) -> &Proxy {
+        self.peer
+            .as_ref()
+            .expect("No peer deployed, build the env `with_peer()`")
+    }
+
+    /// Splits the env into the app, the admin contract and the peer.
+    pub fn into_parts(self) -> (App, Proxy, Proxy) {
+        let peer = self
+            .peer
+            .expect("No peer deployed, build the env `with_peer()`");
+        (self.app, self.admin, peer)
+    }
+
+    pub fn admin_contract(&self) -> AdminContract {
+        AdminContract(self.admin.addr().clone())
+    }
+}
+
+struct PeerSetup {
+    fee: Option<Decimal>,
+    admin_as_hook: bool,
+}
+
+pub struct TestEnvBuilder {
+    balances: Vec<(String, Vec<Coin>)>,
+    admin: AdminInstantiateMsg,
+    /// Enrollment length in seconds from the app genesis
+    enrollment: Option<u64>,
+    peer: Option<PeerSetup>,
+}
+
+impl TestEnvBuilder {
+    fn new() -> Self {
+        Self {
+            balances: vec![],
+            admin: AdminInstantiateMsg {
+                owner: None,
+                admins: vec![],
+                donation_denom: "eth".to_owned(),
+                enrollment_until: None,
+                join_fee: None,
+                slashing: None,
+                dispute_window: None,
+                config_revert_window: None,
+            },
+            enrollment: None,
+            peer: None,
+        }
+    }
+
+    pub fn with_balance(mut self, addr: &str, coins: Vec<Coin>) -> Self {
+        self.balances.push((addr.to_owned(), coins));
+        self
+    }
+
+    /// Admins by account name, see `mock_addr`.
+    pub fn with_admins(mut self, admins: &[&str]) -> Self {
+        self.admin.admins = admins
+            .iter()
+            .map(|admin| mock_addr(admin).to_string())
+            .collect();
+        self
+    }
+
+    pub fn with_donation_denom(mut self, denom: &str) -> Self {
+        self.admin.donation_denom = denom.to_owned();
+        self
+    }
+
+    /// Opens enrollment for `secs` seconds, charging the fee on `Join {}`.
+    pub fn with_enrollment(mut self, secs: u64, join_fee: Option<Coin>) -> Self {
+        self.enrollment = Some(secs);
+        self.admin.join_fee = join_fee;
+        self
+    }
+
+    pub fn with_peer(mut self) -> Self {
+        self.peer.get_or_insert(PeerSetup {
+            fee: None,
+            admin_as_hook: false,
+        });
+        self
+    }
+
+    pub fn with_peer_fee(mut self, fee: Decimal) -> Self {
+        self = self.with_peer();
+        if let Some(peer) = &mut self.peer {
+            peer.fee = Some(fee);
+        }
+        self
+    }
+
+    /// Registers the admin contract as a peer hook. It does not understand the hook message,
+    /// so the hook always fails.
+    pub fn with_failing_hook(mut self) -> Self {
+        self = self.with_peer();
+        if let Some(peer) = &mut self.peer {
+            peer.admin_as_hook = true;
+        }
+        self
+    }
+
+    pub fn build(self) -> TestEnv {
+        let balances: Vec<_> = self
+            .balances
+            .iter()
+            .map(|(addr, coins)| (addr.as_str(), coins.clone()))
+            .collect();
+        let mut app = app_with_balances(&balances);
+
+        let mut admin_msg = self.admin;
+        admin_msg.enrollment_until = self
+            .enrollment
+            .map(|secs| app.block_info().time.plus_seconds(secs));
+
+        let admin_code_id = store_admin(&mut app);
+        let admin =
+            Proxy::instantiate(&mut app, admin_code_id, "owner", &admin_msg, &[], "Admin").unwrap();
+
+        let peer = self.peer.map(|setup| {
+            let peer_code_id = store_peer(&mut app);
+            let hooks = if setup.admin_as_hook {
+                vec![admin.addr().to_string()]
+            } else {
+                vec![]
+            };
+            let msg = PeerInstantiateMsg {
+                admin_contract: admin.addr().to_string(),
+                fee: setup.fee,
+                hooks,
+            };
+            Proxy::instantiate(&mut app, peer_code_id, "owner", &msg, &[], "Peer").unwrap()
+        });
+
+        TestEnv { app, admin, peer }
+    }
+}
@@ -0,0 +1,353 @@
+mod common;
+
+use admin::msg::{
+    AdminsCountResp, AdminsListResp, ConfigResp, ExecuteMsg, ForAddressResp, HealthcheckResp,
+    InstantiateMsg as AdminInstantiateMsg, JoinTimeResp, MigrateMsg, QueryMsg, RecentAdminsResp,
+};
+use common::TestEnv;
+use factory::msg::{
+    CreatedResp, ExecuteMsg as FactoryExecuteMsg, InstantiateMsg as FactoryInstantiateMsg,
+    QueryMsg as FactoryQueryMsg,
+};
+use peer::msg::{
+    ExecuteMsg as PeerExecuteMsg, FailedHooksResp, InstantiateMsg as PeerInstantiateMsg,
+    MemberSinceResp, MembersResp, QueryMsg as PeerQueryMsg,
+};
+use testing::prelude::*;
+
+/// Checks everything that must hold after any step, whatever the step was.
+fn assert_invariants(app: &App, admin: &Proxy) {
+    let admins: AdminsListResp = admin.query(app, &QueryMsg::AdminsList {}).unwrap();
+    let count: AdminsCountResp = admin.query(app, &QueryMsg::AdminsCount {}).unwrap();
+    let recent: RecentAdminsResp = admin
+        .query(app, &QueryMsg::RecentAdmins { limit: Some(30) })
+        .unwrap();
+
+    assert_eq!(count.count, admins.admins.len() as u64);
+
+    let mut recent: Vec<_> = recent.admins.into_iter().map(|admin| admin.addr).collect();
+    recent.sort();
+    assert_eq!(recent, admins.admins);
+
+    for addr in admins.admins {
+        let resp: ForAddressResp = admin
+            .query(
+                app,
+                &QueryMsg::ForAddress {
+                    addr: addr.to_string(),
+                },
+            )
+            .unwrap();
+        assert!(resp.is_admin);
+    }
+}
+
+fn admins(app: &App, admin: &Proxy) -> Vec<Addr> {
+    admin
+        .query::<AdminsListResp>(app, &QueryMsg::AdminsList {})
+        .unwrap()
+        .admins
+}
+
+fn claim(app: &mut App, admin: &Proxy, sender: &str) {
+    admin
+        .execute(app, sender, &ExecuteMsg::Claim { amount: None }, &[])
+        .unwrap();
+}
+
+#[test]
+fn admin_lifecycle() {
+    // Bootstrap with no admins and an open enrollment window
+    let TestEnv { mut app, admin, .. } = TestEnv::builder()
+        .with_balance("founder", vec![coin(10, "eth"), coin(10, "atom")])
+        .with_balance("donor", vec![coin(100, "eth"), coin(100, "atom")])
+        .with_enrollment(60, Some(coin(10, "eth")))
+        .build();
+    assert_invariants(&app, &admin);
+    assert!(admins(&app, &admin).is_empty());
+
+    admin
+        .execute(&mut app, "founder", &ExecuteMsg::Join {}, &coins(10, "eth"))
+        .unwrap();
+    assert_invariants(&app, &admin);
+    assert_eq!(balance(&app, admin.addr(), "eth"), 10);
+
+    // Enrollment closes, membership is admin-gated from now on
+    app.advance_blocks(12);
+
+    admin
+        .execute(
+            &mut app,
+            "founder",
+            &ExecuteMsg::AddMembers {
+                admins: vec![
+                    mock_addr("member1").to_string(),
+                    mock_addr("member2").to_string(),
+                    mock_addr("member3").to_string(),
+                ],
+            },
+            &[],
+        )
+        .unwrap();
+    assert_invariants(&app, &admin);
+
+    admin
+        .execute(
+            &mut app,
+            "member1",
+            &ExecuteMsg::RemoveMembers {
+                admins: vec![mock_addr("member3").to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+    assert_invariants(&app, &admin);
+
+    admin
+        .execute(&mut app, "member2", &ExecuteMsg::Leave {}, &[])
+        .unwrap();
+    assert_invariants(&app, &admin);
+    assert_eq!(admins(&app, &admin), sorted_addrs(&["founder", "member1"]));
+
+    // Donations are split evenly and wait to be claimed, the remainder stays with the contract
+    admin
+        .execute(&mut app, "donor", &ExecuteMsg::Donate {}, &coins(51, "eth"))
+        .unwrap();
+    assert_invariants(&app, &admin);
+    assert_eq!(balance(&app, admin.addr(), "eth"), 61);
+    claim(&mut app, &admin, "founder");
+    claim(&mut app, &admin, "member1");
+    assert_eq!(balance(&app, mock_addr("founder"), "eth"), 25);
+    assert_eq!(balance(&app, mock_addr("member1"), "eth"), 25);
+    assert_eq!(balance(&app, admin.addr(), "eth"), 11);
+
+    // The owner switches the donation denom, old denom is no longer accepted
+    admin
+        .execute(
+            &mut app,
+            "owner",
+            &ExecuteMsg::UpdateConfig {
+                donation_denom: Some("atom".to_owned()),
+            },
+            &[],
+        )
+        .unwrap();
+    let config: ConfigResp = admin.query(&app, &QueryMsg::Config {}).unwrap();
+    assert_eq!(config.donation_denom, "atom");
+
+    admin
+        .execute(&mut app, "donor", &ExecuteMsg::Donate {}, &coins(10, "eth"))
+        .unwrap_err();
+
+    admin
+        .execute(
+            &mut app,
+            "donor",
+            &ExecuteMsg::Donate {},
+            &coins(10, "atom"),
+        )
+        .unwrap();
+    assert_invariants(&app, &admin);
+    claim(&mut app, &admin, "founder");
+    claim(&mut app, &admin, "member1");
+    assert_eq!(balance(&app, mock_addr("founder"), "atom"), 15);
+    assert_eq!(balance(&app, mock_addr("member1"), "atom"), 5);
+}
+
+#[test]
+fn factory_admin_peer() {
+    let mut app = app_with_balances(&[("donor", coins(110, "eth"))]);
+    let admin_code_id = common::store_admin(&mut app);
+    let peer_code_id = common::store_peer(&mut app);
+    let factory_code_id = common::store_factory(&mut app);
+
+    // The factory instantiates the admin contract and keeps track of it
+    let factory = Proxy::instantiate(
+        &mut app,
+        factory_code_id,
+        "owner",
+        &FactoryInstantiateMsg { admin_code_id },
+        &[],
+        "Factory",
+    )
+    .unwrap();
+    let create = FactoryExecuteMsg::CreateAdmin {
+        msg: AdminInstantiateMsg {
+            owner: None,
+            admins: vec![addr::admin(1).to_string(), addr::admin(2).to_string()],
+            donation_denom: "eth".to_owned(),
+            enrollment_until: None,
+            join_fee: None,
+            slashing: None,
+            dispute_window: None,
+            config_revert_window: None,
+        },
+        label: "Admin".to_owned(),
+    };
+    factory.execute(&mut app, "founder", &create, &[]).unwrap();
+
+    let created: CreatedResp = factory
+        .query(
+            &app,
+            &FactoryQueryMsg::Created {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(created.contracts.len(), 1);
+    assert_eq!(created.contracts[0].info.creator, mock_addr("founder"));
+    assert_eq!(created.contracts[0].info.admins_count, 2);
+    let admin = Proxy::new(created.contracts[0].addr.clone());
+    assert_invariants(&app, &admin);
+    let config: ConfigResp = admin.query(&app, &QueryMsg::Config {}).unwrap();
+    assert_eq!(config.owner, mock_addr("founder"));
+
+    // A peer forwards donations to it, with the admin contract as a hook which always fails
+    let peer = Proxy::instantiate(
+        &mut app,
+        peer_code_id,
+        "owner",
+        &PeerInstantiateMsg {
+            admin_contract: admin.addr().to_string(),
+            fee: Some(Decimal::percent(10)),
+            hooks: vec![admin.addr().to_string()],
+        },
+        &[],
+        "Peer",
+    )
+    .unwrap();
+
+    peer.execute(&mut app, "admin1", &PeerExecuteMsg::Register {}, &[])
+        .unwrap();
+    peer.execute(&mut app, "donor", &PeerExecuteMsg::Register {}, &[])
+        .unwrap_err();
+
+    // Membership changes in the admin contract are seen by the peer
+    admin
+        .execute(
+            &mut app,
+            "admin1",
+            &ExecuteMsg::AddMembers {
+                admins: vec![addr::admin(3).to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+    assert_invariants(&app, &admin);
+    admin
+        .execute(&mut app, "admin2", &ExecuteMsg::Leave {}, &[])
+        .unwrap();
+    assert_invariants(&app, &admin);
+    assert_eq!(admins(&app, &admin), sorted_addrs(&["admin1", "admin3"]));
+
+    peer.execute(&mut app, "admin3", &PeerExecuteMsg::Register {}, &[])
+        .unwrap();
+    peer.execute(&mut app, "admin2", &PeerExecuteMsg::Register {}, &[])
+        .unwrap_err();
+    let members: MembersResp = peer.query(&app, &PeerQueryMsg::Members {}).unwrap();
+    let members: Vec<_> = members
+        .members
+        .into_iter()
+        .map(|member| member.addr)
+        .collect();
+    assert_eq!(members, sorted_addrs(&["admin1", "admin3"]));
+
+    // Donations through the peer: the fee goes to the peer owner, the rest to the admin
+    // contract, and the failing hook does not revert anything
+    peer.execute(
+        &mut app,
+        "donor",
+        &PeerExecuteMsg::Donate {},
+        &coins(100, "eth"),
+    )
+    .unwrap();
+    assert_invariants(&app, &admin);
+    assert_eq!(balance(&app, addr::owner(), "eth"), 10);
+    assert_eq!(balance(&app, admin.addr(), "eth"), 90);
+    let failed: FailedHooksResp = peer.query(&app, &PeerQueryMsg::FailedHooks {}).unwrap();
+    assert_eq!(failed.count, 1);
+
+    // The admin contract sees the peer as the donor
+    let resp: ForAddressResp = admin
+        .query(
+            &app,
+            &QueryMsg::ForAddress {
+                addr: peer.addr().to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(resp.donated, coins(90, "eth"));
+
+    // The peer only forwards donations, admins claim them from the admin contract
+    claim(&mut app, &admin, "admin1");
+    claim(&mut app, &admin, "admin3");
+    assert_eq!(balance(&app, addr::admin(1), "eth"), 45);
+    assert_eq!(balance(&app, addr::admin(3), "eth"), 45);
+    assert_eq!(balance(&app, addr::admin(2), "eth"), 0);
+    assert_eq!(balance(&app, admin.addr(), "eth"), 0);
+
+    // The creator migrates the admin contract, the owner of the migration message is ignored
+    // as the contract already has one
+    let msg = MigrateMsg {
+        owner: mock_addr("user").to_string(),
+    };
+    app.migrate_contract(
+        mock_addr("founder"),
+        admin.addr().clone(),
+        &msg,
+        admin_code_id,
+    )
+    .unwrap();
+    assert_invariants(&app, &admin);
+    let config: ConfigResp = admin.query(&app, &QueryMsg::Config {}).unwrap();
+    assert_eq!(config.owner, mock_addr("founder"));
+    let resp: MemberSinceResp = peer
+        .query(
+            &app,
+            &PeerQueryMsg::MemberSince {
+                addr: addr::admin(1).to_string(),
+            },
+        )
+        .unwrap();
+    let joined = admin
+        .query::<JoinTimeResp>(
+            &app,
+            &QueryMsg::JoinTime {
+                admin: addr::admin(1).to_string(),
+            },
+        )
+        .unwrap()
+        .joined;
+    assert_eq!(resp.joined, joined);
+
+    // Sunset: once the last admin leaves, the contract stops taking donations, also through
+    // the peer
+    admin
+        .execute(&mut app, "admin1", &ExecuteMsg::Leave {}, &[])
+        .unwrap();
+    admin
+        .execute(&mut app, "admin3", &ExecuteMsg::Leave {}, &[])
+        .unwrap();
+    assert_invariants(&app, &admin);
+    assert!(admins(&app, &admin).is_empty());
+
+    let health: HealthcheckResp = admin.query(&app, &QueryMsg::Healthcheck {}).unwrap();
+    assert!(!health.donations_enabled);
+
+    peer.execute(
+        &mut app,
+        "donor",
+        &PeerExecuteMsg::Donate {},
+        &coins(10, "eth"),
+    )
+    .unwrap_err();
+    assert_eq!(balance(&app, mock_addr("donor"), "eth"), 10);
+    peer.query::<MemberSinceResp>(
+        &app,
+        &PeerQueryMsg::MemberSince {
+            addr: addr::admin(1).to_string(),
+        },
+    )
+    .unwrap_err();
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use admin::msg::{
+    AdminsCountResp, AdminsListResp, ConfigResp, ExecuteMsg, HealthcheckResp, JoinTimeResp,
+    MigrateMsg, QueryMsg,
+};
+use basics::msg::InstantiateMsg as BasicsInstantiateMsg;
+use testing::prelude::*;
+
+#[test]
+fn migrate_from_basics() {
+    let mut app = app_with_balances(&[("donor", coins(10, "eth"))]);
+
+    let basics_code = ContractWrapper::new(
+        basics::contract::execute,
+        basics::contract::instantiate,
+        basics::contract::query,
+    );
+    let basics_code_id = store_code(&mut app, basics_code);
+
+    let admin_code_id = common::store_admin(&mut app);
+
+    let addr = app
+        .instantiate_contract(
+            basics_code_id,
+            addr::owner(),
+            &BasicsInstantiateMsg {
+                admins: vec![addr::admin(1).to_string(), addr::admin(2).to_string()],
+                donation_denom: "eth".to_owned(),
+            },
+            &[],
+            "Contract",
+            Some(addr::owner().to_string()),
+        )
+        .unwrap();
+    let contract = Proxy::new(addr.clone());
+
+    app.advance_blocks(10);
+    let migrated_at = app.block_info().time;
+    let msg = MigrateMsg {
+        owner: addr::owner().to_string(),
+    };
+    app.migrate_contract(addr::owner(), addr.clone(), &msg, admin_code_id)
+        .unwrap();
+
+    // The legacy list is gone, admins live in the map with the migration time as join time
+    let raw = app
+        .wrap()
+        .query_wasm_raw(&addr, b"admins".to_vec())
+        .unwrap();
+    assert_eq!(raw, None);
+
+    let resp: AdminsListResp = contract.query(&app, &QueryMsg::AdminsList {}).unwrap();
+    assert_eq!(resp.admins, sorted_addrs(&["admin1", "admin2"]));
+    let resp: AdminsCountResp = contract.query(&app, &QueryMsg::AdminsCount {}).unwrap();
+    assert_eq!(resp.count, 2);
+    let resp: JoinTimeResp = contract
+        .query(
+            &app,
+            &QueryMsg::JoinTime {
+                admin: addr::admin(2).to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(resp.joined, migrated_at);
+
+    // State shared by both layouts is kept as is
+    let resp: ConfigResp = contract.query(&app, &QueryMsg::Config {}).unwrap();
+    assert_eq!(resp.owner, addr::owner());
+    assert_eq!(resp.donation_denom, "eth");
+    let resp: HealthcheckResp = contract.query(&app, &QueryMsg::Healthcheck {}).unwrap();
+    assert_eq!(resp.last_activity, migrated_at);
+
+    contract
+        .execute(&mut app, "donor", &ExecuteMsg::Donate {}, &coins(10, "eth"))
+        .unwrap();
+    contract
+        .execute(&mut app, "admin1", &ExecuteMsg::Claim { amount: None }, &[])
+        .unwrap();
+    assert_eq!(balance(&app, addr::admin(1), "eth"), 5);
+
+    // Migrating again, e.g. to a newer code, leaves the map layout untouched
+    app.migrate_contract(addr::owner(), addr, &msg, admin_code_id)
+        .unwrap();
+    let resp: AdminsCountResp = contract.query(&app, &QueryMsg::AdminsCount {}).unwrap();
+    assert_eq!(resp.count, 2);
+}
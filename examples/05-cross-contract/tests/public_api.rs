@@ -0,0 +1,91 @@
+//! Uses the admin contract only through `admin::prelude`, the way a downstream contract would.
+//! If this stops compiling, the public API changed. The `ui` cases check the other direction:
+//! private items stay unreachable and non-exhaustive types can't be built or matched exhaustively.
+
+mod common;
+
+use admin::prelude::*;
+use testing::prelude::{
+    addr, app_with_balances, attr, coin, coins, event, mock_addr, sorted_addrs, App, Proxy,
+};
+
+fn deploy(app: &mut App) -> Proxy {
+    let code_id = common::store_admin(app);
+
+    Proxy::instantiate(
+        app,
+        code_id,
+        "owner",
+        &InstantiateMsg {
+            owner: None,
+            admins: vec![addr::admin(1).to_string()],
+            donation_denom: "eth".to_owned(),
+            enrollment_until: None,
+            join_fee: None,
+            slashing: None,
+            dispute_window: None,
+            config_revert_window: None,
+        },
+        &[],
+        "Admin",
+    )
+    .unwrap()
+}
+
+#[test]
+fn prelude_surface() {
+    let mut app = app_with_balances(&[("donor", coins(10, "eth"))]);
+    let admin = deploy(&mut app);
+
+    let resp = admin
+        .execute(
+            &mut app,
+            "admin1",
+            &ExecuteMsg::AddMembers {
+                admins: vec![addr::admin(2).to_string()],
+            },
+            &[],
+        )
+        .unwrap();
+    let added = event(&resp, events::ADMIN_ADDED);
+    assert_eq!(attr(added, events::ADDR), addr::admin(2).as_str());
+
+    let resp: AdminsListResp = admin.query(&app, &QueryMsg::AdminsList {}).unwrap();
+    assert_eq!(resp.admins, sorted_addrs(&["admin1", "admin2"]));
+
+    let config: ConfigResp = admin.query(&app, &QueryMsg::Config {}).unwrap();
+    assert_eq!(config.donation_denom, "eth");
+
+    let err = admin
+        .execute(
+            &mut app,
+            "donor",
+            &ExecuteMsg::AddMembers {
+                admins: vec![mock_addr("donor").to_string()],
+            },
+            &[],
+        )
+        .unwrap_err();
+    // Errors are non-exhaustive, downstream matches always need a wildcard arm
+    match err.downcast().unwrap() {
+        ContractError::Unauthorized { sender } => assert_eq!(sender, mock_addr("donor")),
+        err => panic!("Unexpected error: {}", err),
+    }
+
+    admin
+        .execute(
+            &mut app,
+            "donor",
+            &ExecuteMsg::Donate {},
+            &[coin(10, "eth")],
+        )
+        .unwrap();
+    let resp: DashboardResp = admin.query(&app, &QueryMsg::Dashboard {}).unwrap();
+    assert_eq!(resp.donated.len(), 1);
+    assert_eq!(resp.donated[0].denom, "eth");
+}
+
+#[test]
+fn private_surface() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
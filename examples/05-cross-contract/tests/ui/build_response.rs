@@ -0,0 +1,6 @@
+// Responses are non-exhaustive, so they can only be deserialized outside the admin crate
+use admin::prelude::*;
+
+fn main() {
+    let _ = AdminsCountResp { count: 1 };
+}
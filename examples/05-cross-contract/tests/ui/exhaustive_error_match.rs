@@ -0,0 +1,11 @@
+// Errors are non-exhaustive, so matching on them needs a wildcard arm
+use admin::prelude::*;
+
+fn is_auth_error(err: &ContractError) -> bool {
+    match err {
+        ContractError::Unauthorized { .. } => true,
+        ContractError::StdError(_) => false,
+    }
+}
+
+fn main() {}
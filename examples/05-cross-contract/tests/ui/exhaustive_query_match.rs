@@ -0,0 +1,23 @@
+// Listing every current variant is not enough, new queries must not break downstream matches
+use admin::prelude::*;
+
+fn name(msg: &QueryMsg) -> &'static str {
+    match msg {
+        QueryMsg::AdminsList {} => "admins_list",
+        QueryMsg::AdminsCount {} => "admins_count",
+        QueryMsg::JoinTime { .. } => "join_time",
+        QueryMsg::RecentAdmins { .. } => "recent_admins",
+        QueryMsg::ForAddress { .. } => "for_address",
+        QueryMsg::Config {} => "config",
+        QueryMsg::Healthcheck {} => "healthcheck",
+        QueryMsg::Dashboard {} => "dashboard",
+        QueryMsg::MisbehaviorReport { .. } => "misbehavior_report",
+        QueryMsg::Donation { .. } => "donation",
+        QueryMsg::Allowances { .. } => "allowances",
+        QueryMsg::DonorTotals { .. } => "donor_totals",
+    }
+}
+
+fn main() {
+    name(&QueryMsg::Config {});
+}
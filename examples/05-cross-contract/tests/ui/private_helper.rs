@@ -0,0 +1,4 @@
+// Storage helpers stay private, downstream contracts go through the messages
+use admin::contract::distribute;
+
+fn main() {}
@@ -0,0 +1,318 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, ListResp, QueryMsg, SwapResp};
+use crate::state::{Swap, SWAPS};
+use common::pagination;
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+};
+use cw_utils::Expiration;
+use sha2::{Digest, Sha256};
+
+pub fn instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Swap { id } => to_json_binary(&query::swap(deps, id)?),
+        List { start_after, limit } => to_json_binary(&query::list(deps, start_after, limit)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Create {
+            id,
+            hash,
+            recipient,
+            expires,
+        } => exec::create(deps, env, info, id, hash, recipient, expires),
+        Release { id, preimage } => exec::release(deps, env, id, preimage),
+        Refund { id } => exec::refund(deps, env, id),
+    }
+}
+
+fn parse_hash(hash: &str) -> Result<[u8; 32], ContractError> {
+    let mut parsed = [0; 32];
+    hex::decode_to_slice(hash, &mut parsed).map_err(|_| ContractError::InvalidHash)?;
+    Ok(parsed)
+}
+
+mod exec {
+    use super::*;
+
+    pub fn create(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: String,
+        hash: String,
+        recipient: String,
+        expires: Expiration,
+    ) -> Result<Response, ContractError> {
+        let hash = parse_hash(&hash)?;
+        if info.funds.is_empty() {
+            return Err(ContractError::NoFunds);
+        }
+        if expires.is_expired(&env.block) {
+            return Err(ContractError::Expired);
+        }
+        if SWAPS.has(deps.storage, &id) {
+            return Err(ContractError::SwapExists { id });
+        }
+
+        let swap = Swap {
+            hash,
+            source: info.sender,
+            recipient: deps.api.addr_validate(&recipient)?,
+            expires,
+            balance: info.funds,
+        };
+        SWAPS.save(deps.storage, &id, &swap)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "create")
+            .add_attribute("id", id);
+
+        Ok(resp)
+    }
+
+    pub fn release(
+        deps: DepsMut,
+        env: Env,
+        id: String,
+        preimage: String,
+    ) -> Result<Response, ContractError> {
+        let swap = SWAPS.load(deps.storage, &id)?;
+        if swap.expires.is_expired(&env.block) {
+            return Err(ContractError::Expired);
+        }
+
+        let preimage = hex::decode(&preimage).map_err(|_| ContractError::InvalidPreimage)?;
+        if Sha256::digest(&preimage).as_slice() != swap.hash {
+            return Err(ContractError::InvalidPreimage);
+        }
+        SWAPS.remove(deps.storage, &id);
+
+        // The preimage is public now - the source of the swap can use it on the other chain
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: swap.recipient.into_string(),
+                amount: swap.balance,
+            })
+            .add_attribute("action", "release")
+            .add_attribute("id", id)
+            .add_attribute("preimage", hex::encode(preimage));
+
+        Ok(resp)
+    }
+
+    pub fn refund(deps: DepsMut, env: Env, id: String) -> Result<Response, ContractError> {
+        let swap = SWAPS.load(deps.storage, &id)?;
+        if !swap.expires.is_expired(&env.block) {
+            return Err(ContractError::NotExpired {
+                expires: swap.expires,
+            });
+        }
+        SWAPS.remove(deps.storage, &id);
+
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: swap.source.into_string(),
+                amount: swap.balance,
+            })
+            .add_attribute("action", "refund")
+            .add_attribute("id", id);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn swap(deps: Deps, id: String) -> StdResult<SwapResp> {
+        let swap = SWAPS.load(deps.storage, &id)?;
+        Ok(SwapResp {
+            id,
+            hash: hex::encode(swap.hash),
+            source: swap.source,
+            recipient: swap.recipient,
+            expires: swap.expires,
+            balance: swap.balance,
+        })
+    }
+
+    pub fn list(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<ListResp> {
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after.as_deref());
+
+        let swaps: Vec<_> = SWAPS
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after = pagination::next_key(&swaps, limit, String::clone);
+
+        Ok(ListResp {
+            swaps,
+            next_start_after,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    const PREIMAGE: &[u8] = b"swap secret";
+    const DURATION: u64 = 100;
+
+    fn atomic_swap(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        Proxy::deploy(app, code, "owner", &InstantiateMsg {}, "Atomic swap").unwrap()
+    }
+
+    fn funded_app() -> App {
+        app_with_balances(&[("alice", coins(1000, "atom"))])
+    }
+
+    fn create(app: &mut App, swap: &Proxy, hash: String) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Create {
+            id: "swap".to_owned(),
+            hash,
+            recipient: mock_addr("bob").to_string(),
+            expires: Expiration::AtTime(app.block_info().time.plus_seconds(DURATION)),
+        };
+        swap.execute(app, "alice", &msg, &coins(100, "atom"))
+    }
+
+    fn release(app: &mut App, swap: &Proxy, preimage: &[u8]) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Release {
+            id: "swap".to_owned(),
+            preimage: hex::encode(preimage),
+        };
+        swap.execute(app, "anyone", &msg, &[])
+    }
+
+    fn hash(preimage: &[u8]) -> String {
+        hex::encode(Sha256::digest(preimage))
+    }
+
+    #[test]
+    fn release_with_preimage() {
+        let mut app = funded_app();
+        let swap = atomic_swap(&mut app);
+
+        create(&mut app, &swap, hash(PREIMAGE)).unwrap();
+        assert_eq!(balance(&app, swap.addr(), "atom"), 100);
+
+        let err = create(&mut app, &swap, hash(PREIMAGE)).unwrap_err();
+        assert_eq!(
+            ContractError::SwapExists {
+                id: "swap".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = release(&mut app, &swap, b"guess").unwrap_err();
+        assert_eq!(ContractError::InvalidPreimage, err.downcast().unwrap());
+
+        let resp = release(&mut app, &swap, PREIMAGE).unwrap();
+        assert_eq!(wasm_attr(&resp, "preimage"), hex::encode(PREIMAGE));
+        assert_eq!(balance(&app, mock_addr("bob"), "atom"), 100);
+
+        let resp: ListResp = swap
+            .query(
+                &app,
+                &QueryMsg::List {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(resp.swaps.is_empty());
+    }
+
+    #[test]
+    fn refund_after_timeout() {
+        let mut app = funded_app();
+        let swap = atomic_swap(&mut app);
+
+        create(&mut app, &swap, hash(PREIMAGE)).unwrap();
+        let resp: SwapResp = swap
+            .query(
+                &app,
+                &QueryMsg::Swap {
+                    id: "swap".to_owned(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.hash, hash(PREIMAGE));
+        assert_eq!(resp.balance, coins(100, "atom"));
+
+        let refund = ExecuteMsg::Refund {
+            id: "swap".to_owned(),
+        };
+        let err = swap.execute(&mut app, "alice", &refund, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::NotExpired {
+                expires: resp.expires
+            },
+            err.downcast().unwrap()
+        );
+
+        app.advance_time(DURATION);
+        let err = release(&mut app, &swap, PREIMAGE).unwrap_err();
+        assert_eq!(ContractError::Expired, err.downcast().unwrap());
+
+        // Anyone can trigger the refund, funds always go back to the source
+        swap.execute(&mut app, "anyone", &refund, &[]).unwrap();
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 1000);
+        swap.execute(&mut app, "alice", &refund, &[]).unwrap_err();
+    }
+
+    #[test]
+    fn invalid_swaps() {
+        let mut app = funded_app();
+        let swap = atomic_swap(&mut app);
+
+        for hash in ["abcd".to_owned(), "x".repeat(64)] {
+            let err = create(&mut app, &swap, hash).unwrap_err();
+            assert_eq!(ContractError::InvalidHash, err.downcast().unwrap());
+        }
+
+        let msg = ExecuteMsg::Create {
+            id: "swap".to_owned(),
+            hash: hash(PREIMAGE),
+            recipient: mock_addr("bob").to_string(),
+            expires: Expiration::AtTime(app.block_info().time),
+        };
+        let err = swap.execute(&mut app, "alice", &msg, &[]).unwrap_err();
+        assert_eq!(ContractError::NoFunds, err.downcast().unwrap());
+        let err = swap
+            .execute(&mut app, "alice", &msg, &coins(100, "atom"))
+            .unwrap_err();
+        assert_eq!(ContractError::Expired, err.downcast().unwrap());
+    }
+}
@@ -0,0 +1,21 @@
+use cosmwasm_std::StdError;
+use cw_utils::Expiration;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Hash has to be 32 bytes, hex encoded")]
+    InvalidHash,
+    #[error("Preimage does not match the hash")]
+    InvalidPreimage,
+    #[error("Swap {id} already exists")]
+    SwapExists { id: String },
+    #[error("Swap has to lock some funds")]
+    NoFunds,
+    #[error("Swap expired")]
+    Expired,
+    #[error("Swap cannot be refunded until {expires}")]
+    NotExpired { expires: Expiration },
+}
@@ -0,0 +1,63 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin};
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Locks the funds sent until the preimage of `hash` is revealed, or the swap expires
+    Create {
+        id: String,
+        /// Hex encoded SHA-256 hash
+        hash: String,
+        recipient: String,
+        expires: Expiration,
+    },
+    /// Sends the funds to the recipient, by anyone knowing the preimage
+    Release {
+        id: String,
+        /// Hex encoded preimage
+        preimage: String,
+    },
+    /// Sends the funds back to the source after the swap expires
+    Refund { id: String },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SwapResp {
+    pub id: String,
+    /// Hex encoded SHA-256 hash
+    pub hash: String,
+    pub source: Addr,
+    pub recipient: Addr,
+    pub expires: Expiration,
+    pub balance: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ListResp {
+    pub swaps: Vec<String>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(SwapResp)]
+    Swap { id: String },
+    /// Lists ids of the swaps waiting for release or refund
+    #[returns(ListResp)]
+    List {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
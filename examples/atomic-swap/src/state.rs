@@ -0,0 +1,18 @@
+use cosmwasm_std::{Addr, Coin};
+use cw_storage_plus::Map;
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Swap {
+    /// SHA-256 of the preimage releasing the funds
+    pub hash: [u8; 32],
+    /// Creator of the swap, gets the funds back after it expires
+    pub source: Addr,
+    pub recipient: Addr,
+    pub expires: Expiration,
+    pub balance: Vec<Coin>,
+}
+
+pub const SWAPS: Map<&str, Swap> = Map::new("swaps");
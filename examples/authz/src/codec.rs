@@ -0,0 +1,98 @@
+//! Conversions between `GranterMsg` and the protobuf messages the chain executes. Decoding is
+//! the inverse of encoding, so tests - and off-chain tools - can read back what the contract sent.
+
+use cosmwasm_std::{AnyMsg, Coin, CosmosMsg, StdError, StdResult};
+use prost::Message;
+use stargate::proto::{Any, MsgSend, ProtoCoin};
+
+use crate::msg::GranterMsg;
+use crate::proto::{MsgDelegate, MsgExec};
+
+/// Packs `msg` as signed by `granter`.
+pub fn encode_msg(granter: &str, msg: GranterMsg) -> Any {
+    match msg {
+        GranterMsg::Send { to_address, amount } => Any {
+            type_url: MsgSend::TYPE_URL.to_owned(),
+            value: MsgSend {
+                from_address: granter.to_owned(),
+                to_address,
+                amount: amount.into_iter().map(Into::into).collect(),
+            }
+            .encode_to_vec(),
+        },
+        GranterMsg::Delegate { validator, amount } => Any {
+            type_url: MsgDelegate::TYPE_URL.to_owned(),
+            value: MsgDelegate {
+                delegator_address: granter.to_owned(),
+                validator_address: validator,
+                amount: Some(amount.into()),
+            }
+            .encode_to_vec(),
+        },
+    }
+}
+
+/// Unpacks a message packed by `encode_msg`, returning it with its signer.
+pub fn decode_msg(any: &Any) -> StdResult<(String, GranterMsg)> {
+    match any.type_url.as_str() {
+        MsgSend::TYPE_URL => {
+            let msg = MsgSend::decode(any.value.as_slice()).map_err(parse_err)?;
+            let amount = msg
+                .amount
+                .into_iter()
+                .map(decode_coin)
+                .collect::<StdResult<_>>()?;
+            let send = GranterMsg::Send {
+                to_address: msg.to_address,
+                amount,
+            };
+            Ok((msg.from_address, send))
+        }
+        MsgDelegate::TYPE_URL => {
+            let msg = MsgDelegate::decode(any.value.as_slice()).map_err(parse_err)?;
+            let amount = msg
+                .amount
+                .ok_or_else(|| StdError::parse_err("MsgDelegate", "missing amount"))?;
+            let delegate = GranterMsg::Delegate {
+                validator: msg.validator_address,
+                amount: decode_coin(amount)?,
+            };
+            Ok((msg.delegator_address, delegate))
+        }
+        type_url => Err(StdError::parse_err(type_url, "unsupported message type")),
+    }
+}
+
+/// `MsgExec` of the packed messages on behalf of their signers.
+pub fn exec_msg(grantee: &str, msgs: Vec<Any>) -> CosmosMsg {
+    let exec = MsgExec {
+        grantee: grantee.to_owned(),
+        msgs,
+    };
+    CosmosMsg::Any(AnyMsg {
+        type_url: MsgExec::TYPE_URL.to_owned(),
+        value: exec.encode_to_vec().into(),
+    })
+}
+
+/// Reads back the content of a message built by `exec_msg`.
+pub fn decode_exec(msg: &CosmosMsg) -> StdResult<MsgExec> {
+    match msg {
+        CosmosMsg::Any(AnyMsg { type_url, value }) if type_url == MsgExec::TYPE_URL => {
+            MsgExec::decode(value.as_slice()).map_err(parse_err)
+        }
+        _ => Err(StdError::parse_err("MsgExec", "not an authz exec message")),
+    }
+}
+
+fn decode_coin(coin: ProtoCoin) -> StdResult<Coin> {
+    let amount = coin.amount.parse()?;
+    Ok(Coin {
+        denom: coin.denom,
+        amount,
+    })
+}
+
+fn parse_err(err: prost::DecodeError) -> StdError {
+    StdError::parse_err("protobuf", err)
+}
@@ -0,0 +1,214 @@
+use crate::codec::{encode_msg, exec_msg};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, GranterMsg, InstantiateMsg, OwnerResp, QueryMsg};
+use crate::state::OWNER;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    OWNER.save(deps.storage, &info.sender)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Owner {} => to_json_binary(&query::owner(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Exec { granter, msgs } => exec::exec(deps, env, info, granter, msgs),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn exec(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        granter: String,
+        msgs: Vec<GranterMsg>,
+    ) -> Result<Response, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+        if msgs.is_empty() {
+            return Err(ContractError::NoMessages);
+        }
+
+        // Whether the granter authorized the contract is checked by the chain - without a grant
+        // the whole transaction fails
+        let granter = deps.api.addr_validate(&granter)?;
+        let msgs = msgs
+            .into_iter()
+            .map(|msg| encode_msg(granter.as_str(), msg))
+            .collect();
+
+        let resp = Response::new()
+            .add_message(exec_msg(env.contract.address.as_str(), msgs))
+            .add_attribute("action", "exec")
+            .add_attribute("granter", granter);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn owner(deps: Deps) -> StdResult<OwnerResp> {
+        let owner = OWNER.load(deps.storage)?;
+        Ok(OwnerResp { owner })
+    }
+}
+
+// As in the `stargate` example, `cw-multi-test` cannot execute the produced messages - tests
+// decode them back instead.
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coin;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MOCK_CONTRACT_ADDR};
+    use prost::Message;
+    use stargate::proto::{Any, MsgSend};
+
+    use crate::codec::{decode_exec, decode_msg};
+
+    use super::*;
+
+    fn granter_msgs() -> Vec<GranterMsg> {
+        vec![
+            GranterMsg::Send {
+                to_address: "user".to_owned(),
+                amount: vec![coin(100, "atom"), coin(5, "btc")],
+            },
+            GranterMsg::Delegate {
+                validator: "cosmwasmvaloper1validator".to_owned(),
+                amount: coin(200, "atom"),
+            },
+        ]
+    }
+
+    #[test]
+    fn exec_on_behalf_of_granter() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let granter = deps.api.addr_make("granter");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            ExecuteMsg::Exec {
+                granter: granter.to_string(),
+                msgs: granter_msgs(),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(resp.messages.len(), 1);
+        let exec = decode_exec(&resp.messages[0].msg).unwrap();
+        assert_eq!(exec.grantee, MOCK_CONTRACT_ADDR);
+
+        let msgs: Vec<_> = exec
+            .msgs
+            .iter()
+            .map(|msg| decode_msg(msg).unwrap())
+            .collect();
+        let expected: Vec<_> = granter_msgs()
+            .into_iter()
+            .map(|msg| (granter.to_string(), msg))
+            .collect();
+        assert_eq!(msgs, expected);
+    }
+
+    #[test]
+    fn packed_messages() {
+        let any = encode_msg(
+            "granter",
+            GranterMsg::Send {
+                to_address: "user".to_owned(),
+                amount: vec![coin(100, "atom")],
+            },
+        );
+        assert_eq!(any.type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(
+            MsgSend::decode(any.value.as_slice()).unwrap(),
+            MsgSend {
+                from_address: "granter".to_owned(),
+                to_address: "user".to_owned(),
+                amount: vec![coin(100, "atom").into()],
+            }
+        );
+
+        let any = Any {
+            type_url: "/cosmos.gov.v1beta1.MsgVote".to_owned(),
+            value: vec![],
+        };
+        decode_msg(&any).unwrap_err();
+    }
+
+    #[test]
+    fn owner_only() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let user = deps.api.addr_make("user");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            ExecuteMsg::Exec {
+                granter: "granter".to_owned(),
+                msgs: granter_msgs(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { sender: user });
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            ExecuteMsg::Exec {
+                granter: "granter".to_owned(),
+                msgs: vec![],
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::NoMessages);
+    }
+}
@@ -0,0 +1,12 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not contract owner")]
+    Unauthorized { sender: Addr },
+    #[error("No messages to execute")]
+    NoMessages,
+}
@@ -0,0 +1,44 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+/// Message executed on behalf of a granter, which has to authorize the contract for it first.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GranterMsg {
+    /// Bank `MsgSend` from the granter account
+    Send {
+        to_address: String,
+        amount: Vec<Coin>,
+    },
+    /// Staking `MsgDelegate` of the granter tokens
+    Delegate { validator: String, amount: Coin },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Executes messages as `granter` with an authz `MsgExec`, only by the owner
+    Exec {
+        granter: String,
+        msgs: Vec<GranterMsg>,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OwnerResp {
+    pub owner: Addr,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(OwnerResp)]
+    Owner {},
+}
@@ -0,0 +1,34 @@
+//! Protobuf messages of the authz flow, on top of the common ones of the `stargate` example.
+
+use stargate::proto::{Any, ProtoCoin};
+
+/// `cosmos.staking.v1beta1.MsgDelegate`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgDelegate {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+    #[prost(message, optional, tag = "3")]
+    pub amount: Option<ProtoCoin>,
+}
+
+impl MsgDelegate {
+    pub const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.MsgDelegate";
+}
+
+/// `cosmos.authz.v1beta1.MsgExec`
+///
+/// Every message in `msgs` is executed as sent by its own signer - the granter - provided it
+/// granted `grantee` an authorization for the message type.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgExec {
+    #[prost(string, tag = "1")]
+    pub grantee: String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: Vec<Any>,
+}
+
+impl MsgExec {
+    pub const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgExec";
+}
@@ -0,0 +1,3 @@
+//! Helpers shared by the example contracts which do not fit any single one of them.
+
+pub mod pagination;
@@ -0,0 +1,55 @@
+//! Pagination of range queries.
+//!
+//! A paginated query takes an optional `start_after` key and `limit`, returns at most `limit`
+//! entries following the key, and the key to pass as `start_after` for the next page. Clients
+//! keep asking for pages until there is no next key.
+
+use cw_storage_plus::{Bound, PrimaryKey};
+
+/// Page size used when the query does not set one.
+pub const DEFAULT_LIMIT: u32 = 10;
+/// Largest page size, so a single query cannot read an unbounded part of the storage.
+pub const MAX_LIMIT: u32 = 30;
+
+/// Number of entries to take for the requested `limit`.
+pub fn limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize
+}
+
+/// Lower bound of a range starting right after the `start_after` key.
+pub fn start_after<'a, K: PrimaryKey<'a>>(
+    start_after: Option<impl Into<K>>,
+) -> Option<Bound<'a, K>> {
+    start_after.map(Bound::exclusive)
+}
+
+/// Key of the last entry of a full page, `None` if the page ended the range.
+///
+/// A full page does not tell if anything follows it without reading one more entry, so when
+/// the entries end exactly at the page boundary the next page is empty.
+pub fn next_key<T, K>(page: &[T], limit: usize, key: impl FnOnce(&T) -> K) -> Option<K> {
+    if page.len() < limit {
+        return None;
+    }
+    page.last().map(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits() {
+        assert_eq!(limit(None), DEFAULT_LIMIT as usize);
+        assert_eq!(limit(Some(5)), 5);
+        assert_eq!(limit(Some(100)), MAX_LIMIT as usize);
+    }
+
+    #[test]
+    fn next_keys() {
+        let key = |id: &u64| *id;
+        assert_eq!(next_key(&[1, 2, 3], 3, key), Some(3));
+        assert_eq!(next_key(&[1, 2], 3, key), None);
+        assert_eq!(next_key(&[], 0, key), None);
+    }
+}
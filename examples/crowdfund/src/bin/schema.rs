@@ -0,0 +1,11 @@
+use cosmwasm_schema::write_api;
+
+use crowdfund::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg,
+    }
+}
@@ -0,0 +1,351 @@
+use crate::error::ContractError;
+use crate::msg::{CampaignResp, ContributionResp, ExecuteMsg, InstantiateMsg, QueryMsg, Status};
+use crate::state::{Config, CONFIG, CONTRIBUTIONS, PAID_OUT, TOTAL};
+use cosmwasm_std::{
+    coins, to_json_binary, BankMsg, Binary, BlockInfo, Deps, DepsMut, Env, MessageInfo, Response,
+    StdResult, Storage, Uint128,
+};
+use cw_utils::Expiration;
+
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    if matches!(msg.deadline, Expiration::Never {}) || msg.deadline.is_expired(&env.block) {
+        return Err(ContractError::InvalidDeadline);
+    }
+
+    let config = Config {
+        creator: info.sender,
+        goal: msg.goal,
+        deadline: msg.deadline,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    TOTAL.save(deps.storage, &Uint128::zero())?;
+    PAID_OUT.save(deps.storage, &false)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Campaign {} => to_json_binary(&query::campaign(deps, env)?),
+        Contribution { address } => to_json_binary(&query::contribution(deps, address)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Contribute {} => exec::contribute(deps, env, info),
+        Payout {} => exec::payout(deps, env),
+        Refund {} => exec::refund(deps, env, info),
+    }
+}
+
+/// The campaign is settled by the deadline only - reaching the goal earlier does not close it.
+fn status(storage: &dyn Storage, config: &Config, block: &BlockInfo) -> StdResult<Status> {
+    let status = if PAID_OUT.load(storage)? {
+        Status::PaidOut
+    } else if !config.deadline.is_expired(block) {
+        Status::Open
+    } else if TOTAL.load(storage)? >= config.goal.amount {
+        Status::Succeeded
+    } else {
+        Status::Failed
+    };
+
+    Ok(status)
+}
+
+mod exec {
+    use super::*;
+
+    pub fn contribute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        if config.deadline.is_expired(&env.block) {
+            return Err(ContractError::Closed);
+        }
+        let amount = cw_utils::must_pay(&info, &config.goal.denom)?;
+
+        CONTRIBUTIONS.update(deps.storage, &info.sender, |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default() + amount)
+        })?;
+        TOTAL.update(deps.storage, |total| -> StdResult<_> { Ok(total + amount) })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "contribute")
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn payout(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        let total = TOTAL.load(deps.storage)?;
+
+        match status(deps.storage, &config, &env.block)? {
+            Status::Open => return Err(ContractError::Open),
+            Status::Failed => {
+                return Err(ContractError::GoalNotReached {
+                    goal: config.goal,
+                    total,
+                })
+            }
+            Status::PaidOut => return Err(ContractError::PaidOut),
+            Status::Succeeded => (),
+        }
+        PAID_OUT.save(deps.storage, &true)?;
+
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: config.creator.to_string(),
+                amount: coins(total.u128(), &config.goal.denom),
+            })
+            .add_attribute("action", "payout")
+            .add_attribute("amount", total.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn refund(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+
+        match status(deps.storage, &config, &env.block)? {
+            Status::Open => return Err(ContractError::Open),
+            Status::Succeeded | Status::PaidOut => return Err(ContractError::GoalReached),
+            Status::Failed => (),
+        }
+
+        // Every contributor withdraws on their own, so the refund cost does not grow with the
+        // number of contributors
+        let amount = CONTRIBUTIONS
+            .may_load(deps.storage, &info.sender)?
+            .ok_or(ContractError::NothingToRefund)?;
+        CONTRIBUTIONS.remove(deps.storage, &info.sender);
+
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(amount.u128(), &config.goal.denom),
+            })
+            .add_attribute("action", "refund")
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn campaign(deps: Deps, env: Env) -> StdResult<CampaignResp> {
+        let config = CONFIG.load(deps.storage)?;
+        let status = status(deps.storage, &config, &env.block)?;
+
+        Ok(CampaignResp {
+            creator: config.creator,
+            goal: config.goal,
+            deadline: config.deadline,
+            total: TOTAL.load(deps.storage)?,
+            status,
+        })
+    }
+
+    pub fn contribution(deps: Deps, address: String) -> StdResult<ContributionResp> {
+        let address = deps.api.addr_validate(&address)?;
+        let amount = CONTRIBUTIONS
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default();
+        Ok(ContributionResp { amount })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cw_utils::PaymentError;
+    use testing::prelude::*;
+
+    use super::*;
+
+    const DURATION: u64 = 100;
+
+    /// Campaign of `creator` raising 100 atom, with the deadline `DURATION` seconds from now.
+    fn crowdfund(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            goal: coin(100, "atom"),
+            deadline: Expiration::AtTime(app.block_info().time.plus_seconds(DURATION)),
+        };
+        Proxy::deploy(app, code, "creator", &msg, "Crowdfund").unwrap()
+    }
+
+    fn funded_app() -> App {
+        app_with_balances(&[("alice", coins(100, "atom")), ("bob", coins(100, "atom"))])
+    }
+
+    fn contribute(
+        app: &mut App,
+        crowdfund: &Proxy,
+        sender: &str,
+        amount: u128,
+    ) -> anyhow::Result<AppResponse> {
+        crowdfund.execute(
+            app,
+            sender,
+            &ExecuteMsg::Contribute {},
+            &coins(amount, "atom"),
+        )
+    }
+
+    fn status(app: &App, crowdfund: &Proxy) -> Status {
+        let resp: CampaignResp = crowdfund.query(app, &QueryMsg::Campaign {}).unwrap();
+        resp.status
+    }
+
+    #[test]
+    fn successful_campaign() {
+        let mut app = funded_app();
+        let crowdfund = crowdfund(&mut app);
+
+        contribute(&mut app, &crowdfund, "alice", 60).unwrap();
+        contribute(&mut app, &crowdfund, "bob", 30).unwrap();
+        contribute(&mut app, &crowdfund, "alice", 20).unwrap();
+
+        let msg = QueryMsg::Contribution {
+            address: mock_addr("alice").to_string(),
+        };
+        let resp: ContributionResp = crowdfund.query(&app, &msg).unwrap();
+        assert_eq!(resp.amount, Uint128::new(80));
+
+        // Goal is reached, but contributions are accepted until the deadline
+        assert_eq!(status(&app, &crowdfund), Status::Open);
+        let err = crowdfund
+            .execute(&mut app, "creator", &ExecuteMsg::Payout {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::Open, err.downcast().unwrap());
+
+        app.advance_time(DURATION);
+        assert_eq!(status(&app, &crowdfund), Status::Succeeded);
+        let err = contribute(&mut app, &crowdfund, "bob", 10).unwrap_err();
+        assert_eq!(ContractError::Closed, err.downcast().unwrap());
+        let err = crowdfund
+            .execute(&mut app, "alice", &ExecuteMsg::Refund {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::GoalReached, err.downcast().unwrap());
+
+        // Anyone can trigger the payout, the funds always go to the creator
+        let resp = crowdfund
+            .execute(&mut app, "bob", &ExecuteMsg::Payout {}, &[])
+            .unwrap();
+        assert_eq!(wasm_attr(&resp, "amount"), "110");
+        assert_eq!(balance(&app, mock_addr("creator"), "atom"), 110);
+        assert_eq!(status(&app, &crowdfund), Status::PaidOut);
+
+        let err = crowdfund
+            .execute(&mut app, "creator", &ExecuteMsg::Payout {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::PaidOut, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn failed_campaign() {
+        let mut app = funded_app();
+        let crowdfund = crowdfund(&mut app);
+
+        contribute(&mut app, &crowdfund, "alice", 30).unwrap();
+        contribute(&mut app, &crowdfund, "bob", 50).unwrap();
+
+        let err = crowdfund
+            .execute(&mut app, "alice", &ExecuteMsg::Refund {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::Open, err.downcast().unwrap());
+
+        app.advance_time(DURATION);
+        assert_eq!(status(&app, &crowdfund), Status::Failed);
+        let err = crowdfund
+            .execute(&mut app, "creator", &ExecuteMsg::Payout {}, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::GoalNotReached {
+                goal: coin(100, "atom"),
+                total: Uint128::new(80)
+            },
+            err.downcast().unwrap()
+        );
+
+        crowdfund
+            .execute(&mut app, "alice", &ExecuteMsg::Refund {}, &[])
+            .unwrap();
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 100);
+        assert_eq!(balance(&app, crowdfund.addr(), "atom"), 50);
+
+        let err = crowdfund
+            .execute(&mut app, "alice", &ExecuteMsg::Refund {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::NothingToRefund, err.downcast().unwrap());
+
+        crowdfund
+            .execute(&mut app, "bob", &ExecuteMsg::Refund {}, &[])
+            .unwrap();
+        assert_eq!(balance(&app, mock_addr("bob"), "atom"), 100);
+    }
+
+    #[test]
+    fn invalid_contributions() {
+        let mut app = app_with_balances(&[("alice", vec![coin(100, "atom"), coin(100, "btc")])]);
+        let crowdfund = crowdfund(&mut app);
+
+        let err = crowdfund
+            .execute(
+                &mut app,
+                "alice",
+                &ExecuteMsg::Contribute {},
+                &coins(10, "btc"),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Payment(PaymentError::MissingDenom("atom".to_owned())),
+            err.downcast().unwrap()
+        );
+
+        let err = crowdfund
+            .execute(&mut app, "alice", &ExecuteMsg::Contribute {}, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Payment(PaymentError::NoFunds {}),
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_deadline() {
+        let mut app = app();
+        let code_id = store_code(&mut app, ContractWrapper::new(execute, instantiate, query));
+        let now = app.block_info().time;
+
+        for deadline in [Expiration::Never {}, Expiration::AtTime(now)] {
+            let msg = InstantiateMsg {
+                goal: coin(100, "atom"),
+                deadline,
+            };
+            let err = Proxy::instantiate(&mut app, code_id, "creator", &msg, &[], "Crowdfund")
+                .unwrap_err();
+            assert_eq!(ContractError::InvalidDeadline, err.downcast().unwrap());
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use cosmwasm_std::{Coin, StdError, Uint128};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+    #[error("Deadline has to be a point in the future")]
+    InvalidDeadline,
+    #[error("Campaign is closed")]
+    Closed,
+    #[error("Campaign is still open")]
+    Open,
+    #[error("Goal of {goal} not reached, only {total} raised")]
+    GoalNotReached { goal: Coin, total: Uint128 },
+    #[error("Goal reached, contributions are not refunded")]
+    GoalReached,
+    #[error("Funds are already paid out")]
+    PaidOut,
+    #[error("Nothing to refund")]
+    NothingToRefund,
+}
@@ -0,0 +1,58 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin, Uint128};
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The sender becomes the creator receiving the funds.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub goal: Coin,
+    pub deadline: Expiration,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Adds the tokens sent to the sender's contribution, only before the deadline
+    Contribute {},
+    /// Sends everything raised to the creator, after the deadline if the goal is reached
+    Payout {},
+    /// Sends the sender's contribution back, after the deadline if the goal is not reached
+    Refund {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Open,
+    Succeeded,
+    Failed,
+    PaidOut,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct CampaignResp {
+    pub creator: Addr,
+    pub goal: Coin,
+    pub deadline: Expiration,
+    pub total: Uint128,
+    pub status: Status,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContributionResp {
+    pub amount: Uint128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(CampaignResp)]
+    Campaign {},
+    #[returns(ContributionResp)]
+    Contribution { address: String },
+}
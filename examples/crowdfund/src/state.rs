@@ -0,0 +1,18 @@
+use cosmwasm_std::{Addr, Coin, Uint128};
+use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub creator: Addr,
+    /// Minimal amount to be raised, its denom is the only one accepted
+    pub goal: Coin,
+    pub deadline: Expiration,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const TOTAL: Item<Uint128> = Item::new("total");
+pub const CONTRIBUTIONS: Map<&Addr, Uint128> = Map::new("contributions");
+pub const PAID_OUT: Item<bool> = Item::new("paid_out");
@@ -0,0 +1,13 @@
+use cosmwasm_std::{Coin, CustomMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Message extension of an example chain which lets contracts mint native tokens. It is sent as
+/// `CosmosMsg::Custom` and handled by the chain, not by any contract.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MintMsg {
+    Mint { recipient: String, amount: Coin },
+}
+
+impl CustomMsg for MintMsg {}
@@ -0,0 +1,150 @@
+use crate::chain::MintMsg;
+use crate::error::ContractError;
+use crate::msg::{ClaimedResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{CLAIMED, REWARD};
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response<MintMsg>> {
+    REWARD.save(deps.storage, &msg.reward)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Claimed { addr } => to_json_binary(&query::claimed(deps, addr)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response<MintMsg>, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Claim {} => exec::claim(deps, info),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn claim(deps: DepsMut, info: MessageInfo) -> Result<Response<MintMsg>, ContractError> {
+        if CLAIMED.has(deps.storage, &info.sender) {
+            return Err(ContractError::AlreadyClaimed { addr: info.sender });
+        }
+        CLAIMED.save(deps.storage, &info.sender, &Empty {})?;
+
+        let reward = REWARD.load(deps.storage)?;
+        let mint = CosmosMsg::Custom(MintMsg::Mint {
+            recipient: info.sender.to_string(),
+            amount: reward.clone(),
+        });
+
+        let resp = Response::new()
+            .add_message(mint)
+            .add_attribute("action", "claim")
+            .add_attribute("amount", reward.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn claimed(deps: Deps, addr: String) -> StdResult<ClaimedResp> {
+        let addr = deps.api.addr_validate(&addr)?;
+        let claimed = CLAIMED.has(deps.storage, &addr);
+        Ok(ClaimedResp { claimed })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coin;
+    use cw_multi_test::{ContractWrapper, Executor};
+    use testing::prelude::app_builder;
+
+    use crate::multitest::MintModule;
+
+    use super::*;
+
+    #[test]
+    fn claim_mints_reward() {
+        let mut app = app_builder::<MintMsg, Empty>()
+            .with_custom(MintModule)
+            .build(|_, _, _| {});
+        let owner = app.api().addr_make("owner");
+        let user = app.api().addr_make("user");
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                owner,
+                &InstantiateMsg {
+                    reward: coin(100, "reward"),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(user.clone(), addr.clone(), &ExecuteMsg::Claim {}, &[])
+            .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(&user, "reward")
+                .unwrap()
+                .amount
+                .u128(),
+            100
+        );
+
+        let resp: ClaimedResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::Claimed {
+                    addr: user.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp, ClaimedResp { claimed: true });
+
+        let err = app
+            .execute_contract(user.clone(), addr, &ExecuteMsg::Claim {}, &[])
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::AlreadyClaimed { addr: user.clone() },
+            err.downcast().unwrap()
+        );
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(&user, "reward")
+                .unwrap()
+                .amount
+                .u128(),
+            100
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{Addr, Api, Binary, BlockInfo, CustomMsg, CustomQuery, Empty, Querier, Storage};
+use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Module};
+use serde::de::DeserializeOwned;
+
+use crate::chain::MintMsg;
+
+/// Stands in for the chain module handling `MintMsg`, minting through the multitest bank.
+pub struct MintModule;
+
+impl Module for MintModule {
+    type ExecT = MintMsg;
+    type QueryT = Empty;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        _sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            MintMsg::Mint { recipient, amount } => {
+                let mint = BankSudo::Mint {
+                    to_address: recipient,
+                    amount: vec![amount],
+                };
+                router.sudo(api, storage, block, mint.into())
+            }
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        msg: Self::SudoT,
+    ) -> AnyResult<AppResponse> {
+        bail!("Unexpected sudo msg {:?}", msg)
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        bail!("Unexpected custom query {:?}", request)
+    }
+}
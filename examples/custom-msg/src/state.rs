@@ -0,0 +1,5 @@
+use cosmwasm_std::{Addr, Coin, Empty};
+use cw_storage_plus::{Item, Map};
+
+pub const REWARD: Item<Coin> = Item::new("reward");
+pub const CLAIMED: Map<&Addr, Empty> = Map::new("claimed");
@@ -0,0 +1,31 @@
+use cosmwasm_std::{CustomQuery, Decimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The part of the chain query extension the contract relies on. Any chain-specific
+/// `CustomQuery` which can ask for an exchange rate can host the contract.
+pub trait ExchangeRateQuery: CustomQuery {
+    fn exchange_rate(base: String, quote: String) -> Self;
+}
+
+/// Response the chain is expected to send for the exchange rate query.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ExchangeRateResp {
+    pub rate: Decimal,
+}
+
+/// Query extension of an example chain with a native price oracle module.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OracleQuery {
+    ExchangeRate { base: String, quote: String },
+}
+
+impl CustomQuery for OracleQuery {}
+
+impl ExchangeRateQuery for OracleQuery {
+    fn exchange_rate(base: String, quote: String) -> Self {
+        OracleQuery::ExchangeRate { base, quote }
+    }
+}
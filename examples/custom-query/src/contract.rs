@@ -0,0 +1,256 @@
+use crate::chain::{ExchangeRateQuery, ExchangeRateResp};
+use crate::msg::{ExecuteMsg, InstantiateMsg, PriceResp, QueryMsg};
+use crate::state::{QUOTE_DENOM, RECORDED_PRICES};
+use cosmwasm_std::{
+    to_json_binary, Binary, CustomQuery, Decimal, Deps, DepsMut, Env, MessageInfo, QuerierWrapper,
+    QueryRequest, Response, StdResult,
+};
+
+pub fn instantiate<Q: CustomQuery>(
+    deps: DepsMut<Q>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    QUOTE_DENOM.save(deps.storage, &msg.quote_denom)?;
+
+    Ok(Response::new())
+}
+
+pub fn query<Q: ExchangeRateQuery>(deps: Deps<Q>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Price { base } => to_json_binary(&query::price(deps, base)?),
+        RecordedPrice { base } => to_json_binary(&query::recorded_price(deps, base)?),
+    }
+}
+
+pub fn execute<Q: ExchangeRateQuery>(
+    deps: DepsMut<Q>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    use ExecuteMsg::*;
+
+    match msg {
+        RecordPrice { base } => exec::record_price(deps, base),
+    }
+}
+
+fn chain_price<Q: ExchangeRateQuery>(
+    querier: &QuerierWrapper<Q>,
+    base: String,
+    quote: String,
+) -> StdResult<Decimal> {
+    let request = QueryRequest::Custom(Q::exchange_rate(base, quote));
+    let resp: ExchangeRateResp = querier.query(&request)?;
+    Ok(resp.rate)
+}
+
+mod exec {
+    use super::*;
+
+    pub fn record_price<Q: ExchangeRateQuery>(
+        deps: DepsMut<Q>,
+        base: String,
+    ) -> StdResult<Response> {
+        let quote = QUOTE_DENOM.load(deps.storage)?;
+        let price = chain_price(&deps.querier, base.clone(), quote)?;
+        RECORDED_PRICES.save(deps.storage, &base, &price)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "record_price")
+            .add_attribute("base", base)
+            .add_attribute("price", price.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn price<Q: ExchangeRateQuery>(deps: Deps<Q>, base: String) -> StdResult<PriceResp> {
+        let quote = QUOTE_DENOM.load(deps.storage)?;
+        let price = chain_price(&deps.querier, base, quote)?;
+        Ok(PriceResp { price })
+    }
+
+    pub fn recorded_price<Q: CustomQuery>(deps: Deps<Q>, base: String) -> StdResult<PriceResp> {
+        let price = RECORDED_PRICES.load(deps.storage, &base)?;
+        Ok(PriceResp { price })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Empty;
+    use cw_multi_test::{ContractWrapper, Executor};
+    use testing::prelude::app_builder;
+
+    use crate::chain::OracleQuery;
+    use crate::multitest::OracleModule;
+
+    use super::*;
+
+    #[test]
+    fn price_from_custom_query() {
+        let oracle = OracleModule::new().with_rate("eth", "usd", Decimal::percent(150_000));
+        let mut app = app_builder::<Empty, OracleQuery>()
+            .with_custom(oracle)
+            .build(|_, _, _| {});
+
+        let code = ContractWrapper::new(
+            execute::<OracleQuery>,
+            instantiate::<OracleQuery>,
+            query::<OracleQuery>,
+        );
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                app.api().addr_make("owner"),
+                &InstantiateMsg {
+                    quote_denom: "usd".to_owned(),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let resp: PriceResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::Price {
+                    base: "eth".to_owned(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            PriceResp {
+                price: Decimal::percent(150_000)
+            }
+        );
+
+        app.wrap()
+            .query_wasm_smart::<PriceResp>(
+                &addr,
+                &QueryMsg::Price {
+                    base: "btc".to_owned(),
+                },
+            )
+            .unwrap_err();
+    }
+
+    #[test]
+    fn record_price() {
+        let oracle = OracleModule::new().with_rate("eth", "usd", Decimal::percent(150_000));
+        let mut app = app_builder::<Empty, OracleQuery>()
+            .with_custom(oracle)
+            .build(|_, _, _| {});
+
+        let code = ContractWrapper::new(
+            execute::<OracleQuery>,
+            instantiate::<OracleQuery>,
+            query::<OracleQuery>,
+        );
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                app.api().addr_make("owner"),
+                &InstantiateMsg {
+                    quote_denom: "usd".to_owned(),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            app.api().addr_make("user"),
+            addr.clone(),
+            &ExecuteMsg::RecordPrice {
+                base: "eth".to_owned(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let resp: PriceResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::RecordedPrice {
+                    base: "eth".to_owned(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            PriceResp {
+                price: Decimal::percent(150_000)
+            }
+        );
+    }
+
+    #[test]
+    fn missing_exchange_rate() {
+        let oracle = OracleModule::new().with_rate("eth", "usd", Decimal::percent(150_000));
+        let mut app = app_builder::<Empty, OracleQuery>()
+            .with_custom(oracle)
+            .build(|_, _, _| {});
+
+        let code = ContractWrapper::new(
+            execute::<OracleQuery>,
+            instantiate::<OracleQuery>,
+            query::<OracleQuery>,
+        );
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app
+            .instantiate_contract(
+                code_id,
+                app.api().addr_make("owner"),
+                &InstantiateMsg {
+                    quote_denom: "usd".to_owned(),
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .wrap()
+            .query_wasm_smart::<PriceResp>(
+                &addr,
+                &QueryMsg::Price {
+                    base: "atom".to_owned(),
+                },
+            )
+            .unwrap_err();
+
+        assert!(err.to_string().contains("No exchange rate for atom/usd"));
+
+        app.execute_contract(
+            app.api().addr_make("user"),
+            addr,
+            &ExecuteMsg::RecordPrice {
+                base: "atom".to_owned(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    }
+}
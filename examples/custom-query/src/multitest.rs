@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, Binary, BlockInfo, Decimal, Empty, Querier, Storage,
+};
+use cw_multi_test::{AppResponse, CosmosRouter, Module};
+
+use crate::chain::{ExchangeRateResp, OracleQuery};
+
+/// Stands in for the chain oracle module, answering exchange rate queries from a fixed table.
+#[derive(Default)]
+pub struct OracleModule {
+    rates: HashMap<(String, String), Decimal>,
+}
+
+impl OracleModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rate(mut self, base: &str, quote: &str, rate: Decimal) -> Self {
+        self.rates.insert((base.to_owned(), quote.to_owned()), rate);
+        self
+    }
+}
+
+impl Module for OracleModule {
+    type ExecT = Empty;
+    type QueryT = OracleQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse> {
+        bail!("Unexpected exec msg {:?} from {:?}", msg, sender)
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        msg: Self::SudoT,
+    ) -> AnyResult<AppResponse> {
+        bail!("Unexpected sudo msg {:?}", msg)
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        match request {
+            OracleQuery::ExchangeRate { base, quote } => {
+                let rate = match self.rates.get(&(base.clone(), quote.clone())) {
+                    Some(rate) => *rate,
+                    None => bail!("No exchange rate for {}/{}", base, quote),
+                };
+                Ok(to_json_binary(&ExchangeRateResp { rate })?)
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use cosmwasm_std::Decimal;
+use cw_storage_plus::{Item, Map};
+
+pub const QUOTE_DENOM: Item<String> = Item::new("quote_denom");
+pub const RECORDED_PRICES: Map<&str, Decimal> = Map::new("recorded_prices");
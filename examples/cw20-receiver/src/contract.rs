@@ -0,0 +1,257 @@
+use crate::error::ContractError;
+use crate::msg::{DepositResp, ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg};
+use crate::state::{DEPOSITS, TOKEN};
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Uint128, WasmMsg,
+};
+use cw20_token::msg::Cw20ReceiveMsg;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let token = deps.api.addr_validate(&msg.token)?;
+    TOKEN.save(deps.storage, &token)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Deposit { address } => to_json_binary(&query::deposit(deps, address)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Receive(msg) => exec::receive(deps, info, msg),
+        Withdraw { amount } => exec::withdraw(deps, info, amount),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn receive(
+        deps: DepsMut,
+        info: MessageInfo,
+        msg: Cw20ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        // Anyone can call `Receive` - only the token contract proves the tokens were transferred
+        let token = TOKEN.load(deps.storage)?;
+        if info.sender != token {
+            return Err(ContractError::UnknownToken { token: info.sender });
+        }
+
+        // `msg.sender` is the account which sent the tokens, `info.sender` is the token contract
+        let sender = deps.api.addr_validate(&msg.sender)?;
+        let beneficiary = match from_json(&msg.msg)? {
+            ReceiveMsg::Deposit {} => sender,
+            ReceiveMsg::DepositFor { beneficiary } => deps.api.addr_validate(&beneficiary)?,
+        };
+
+        DEPOSITS.update(deps.storage, &beneficiary, |deposit| -> StdResult<_> {
+            Ok(deposit.unwrap_or_default() + msg.amount)
+        })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "deposit")
+            .add_attribute("beneficiary", beneficiary)
+            .add_attribute("amount", msg.amount);
+
+        Ok(resp)
+    }
+
+    pub fn withdraw(
+        deps: DepsMut,
+        info: MessageInfo,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        let deposit = DEPOSITS
+            .may_load(deps.storage, &info.sender)?
+            .unwrap_or_default();
+        if deposit < amount {
+            return Err(ContractError::InsufficientDeposit { amount, deposit });
+        }
+        DEPOSITS.save(deps.storage, &info.sender, &(deposit - amount))?;
+
+        let transfer = WasmMsg::Execute {
+            contract_addr: TOKEN.load(deps.storage)?.into_string(),
+            msg: to_json_binary(&cw20_token::msg::ExecuteMsg::Transfer {
+                recipient: info.sender.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        };
+
+        let resp = Response::new()
+            .add_message(transfer)
+            .add_attribute("action", "withdraw")
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn deposit(deps: Deps, address: String) -> StdResult<DepositResp> {
+        let address = deps.api.addr_validate(&address)?;
+        let deposit = DEPOSITS
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default();
+        Ok(DepositResp { deposit })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cw20_token::msg::{BalanceResp, Cw20Coin};
+    use testing::prelude::*;
+
+    use super::*;
+
+    fn deploy_token(app: &mut App, symbol: &str) -> Proxy {
+        let code = ContractWrapper::new(
+            cw20_token::contract::execute,
+            cw20_token::contract::instantiate,
+            cw20_token::contract::query,
+        );
+        let msg = cw20_token::msg::InstantiateMsg {
+            name: "Token".to_owned(),
+            symbol: symbol.to_owned(),
+            decimals: 6,
+            initial_balances: vec![Cw20Coin {
+                address: mock_addr("alice").to_string(),
+                amount: Uint128::new(1000),
+            }],
+        };
+        Proxy::deploy(app, code, "owner", &msg, symbol).unwrap()
+    }
+
+    fn receiver(app: &mut App, token: &Proxy) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            token: token.addr().to_string(),
+        };
+        Proxy::deploy(app, code, "owner", &msg, "Receiver").unwrap()
+    }
+
+    /// Sends tokens from alice with the action as the payload.
+    fn send(
+        app: &mut App,
+        token: &Proxy,
+        receiver: &Proxy,
+        amount: u128,
+        msg: Binary,
+    ) -> anyhow::Result<AppResponse> {
+        let msg = cw20_token::msg::ExecuteMsg::Send {
+            contract: receiver.addr().to_string(),
+            amount: Uint128::new(amount),
+            msg,
+        };
+        token.execute(app, "alice", &msg, &[])
+    }
+
+    fn deposit(app: &App, receiver: &Proxy, name: &str) -> u128 {
+        let msg = QueryMsg::Deposit {
+            address: mock_addr(name).to_string(),
+        };
+        let resp: DepositResp = receiver.query(app, &msg).unwrap();
+        resp.deposit.u128()
+    }
+
+    fn token_balance(app: &App, token: &Proxy, addr: &Addr) -> u128 {
+        let msg = cw20_token::msg::QueryMsg::Balance {
+            address: addr.to_string(),
+        };
+        let resp: BalanceResp = token.query(app, &msg).unwrap();
+        resp.balance.u128()
+    }
+
+    #[test]
+    fn deposit_with_payload() {
+        let mut app = app();
+        let token = deploy_token(&mut app, "TKN");
+        let receiver = receiver(&mut app, &token);
+
+        let msg = to_json_binary(&ReceiveMsg::Deposit {}).unwrap();
+        send(&mut app, &token, &receiver, 100, msg).unwrap();
+        let msg = to_json_binary(&ReceiveMsg::DepositFor {
+            beneficiary: mock_addr("bob").to_string(),
+        })
+        .unwrap();
+        send(&mut app, &token, &receiver, 50, msg).unwrap();
+
+        assert_eq!(deposit(&app, &receiver, "alice"), 100);
+        assert_eq!(deposit(&app, &receiver, "bob"), 50);
+        assert_eq!(token_balance(&app, &token, receiver.addr()), 150);
+
+        // A payload the receiver does not understand reverts the whole send
+        send(&mut app, &token, &receiver, 100, Binary::from(b"{}")).unwrap_err();
+        assert_eq!(token_balance(&app, &token, &mock_addr("alice")), 850);
+
+        let msg = ExecuteMsg::Withdraw {
+            amount: Uint128::new(30),
+        };
+        receiver.execute(&mut app, "bob", &msg, &[]).unwrap();
+        assert_eq!(deposit(&app, &receiver, "bob"), 20);
+        assert_eq!(token_balance(&app, &token, &mock_addr("bob")), 30);
+
+        let err = receiver.execute(&mut app, "bob", &msg, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientDeposit {
+                amount: Uint128::new(30),
+                deposit: Uint128::new(20)
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn only_configured_token() {
+        let mut app = app();
+        let token = deploy_token(&mut app, "TKN");
+        let other = deploy_token(&mut app, "OTH");
+        let receiver = receiver(&mut app, &token);
+
+        let msg = to_json_binary(&ReceiveMsg::Deposit {}).unwrap();
+        let err = send(&mut app, &other, &receiver, 100, msg.clone()).unwrap_err();
+        assert_eq!(
+            ContractError::UnknownToken {
+                token: other.addr().clone()
+            },
+            err.downcast().unwrap()
+        );
+
+        // Calling the hook directly does not transfer anything, so it is rejected too
+        let receive = ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: mock_addr("alice").to_string(),
+            amount: Uint128::new(100),
+            msg,
+        });
+        let err = receiver
+            .execute(&mut app, "alice", &receive, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::UnknownToken {
+                token: mock_addr("alice")
+            },
+            err.downcast().unwrap()
+        );
+        assert_eq!(deposit(&app, &receiver, "alice"), 0);
+    }
+}
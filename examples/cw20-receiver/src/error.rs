@@ -0,0 +1,12 @@
+use cosmwasm_std::{Addr, StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Tokens of {token} are not accepted")]
+    UnknownToken { token: Addr },
+    #[error("Cannot withdraw {amount}, only {deposit} deposited")]
+    InsufficientDeposit { amount: Uint128, deposit: Uint128 },
+}
@@ -0,0 +1,43 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::Uint128;
+use cw20_token::msg::Cw20ReceiveMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Called by the token contract when tokens are sent here, `msg` is a `ReceiveMsg`
+    Receive(Cw20ReceiveMsg),
+    /// Sends deposited tokens back to the sender
+    Withdraw { amount: Uint128 },
+}
+
+/// Action embedded in the `msg` of the cw20 `Send`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiveMsg {
+    /// Deposits the tokens for the sender
+    Deposit {},
+    /// Deposits the tokens for someone else
+    DepositFor { beneficiary: String },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DepositResp {
+    pub deposit: Uint128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(DepositResp)]
+    Deposit { address: String },
+}
@@ -0,0 +1,6 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+/// The only cw20 contract whose tokens are accepted
+pub const TOKEN: Item<Addr> = Item::new("token");
+pub const DEPOSITS: Map<&Addr, Uint128> = Map::new("deposits");
@@ -0,0 +1,18 @@
+use std::env::current_dir;
+
+use cosmwasm_schema::{export_schema_with_title, schema_for, write_api};
+
+use cw20_token::msg::{Cw20ReceiveMsg, ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg,
+    }
+
+    // Not an entry point message, so `write_api!` leaves it out
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    export_schema_with_title(&schema_for!(Cw20ReceiveMsg), &out_dir, "Cw20ReceiveMsg");
+}
@@ -0,0 +1,697 @@
+use crate::error::ContractError;
+use crate::msg::{
+    AllowanceResp, BalanceResp, Cw20ReceiveMsg, ExecuteMsg, InstantiateMsg, QueryMsg, TokenInfoResp,
+};
+use crate::state::{TokenInfo, ALLOWANCES, BALANCES, TOKEN_INFO};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage,
+    Uint128,
+};
+use cw_utils::Expiration;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let mut total_supply = Uint128::zero();
+    for coin in msg.initial_balances {
+        let addr = deps.api.addr_validate(&coin.address)?;
+        add_balance(deps.storage, &addr, coin.amount)?;
+        total_supply += coin.amount;
+    }
+
+    let info = TokenInfo {
+        name: msg.name,
+        symbol: msg.symbol,
+        decimals: msg.decimals,
+        total_supply,
+    };
+    TOKEN_INFO.save(deps.storage, &info)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Balance { address } => to_json_binary(&query::balance(deps, address)?),
+        TokenInfo {} => to_json_binary(&query::token_info(deps)?),
+        Allowance { owner, spender } => {
+            to_json_binary(&query::allowance(deps, env, owner, spender)?)
+        }
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Transfer { recipient, amount } => exec::transfer(deps, info, recipient, amount),
+        Burn { amount } => exec::burn(deps, info, amount),
+        Send {
+            contract,
+            amount,
+            msg,
+        } => exec::send(deps, info, contract, amount, msg),
+        IncreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => exec::increase_allowance(deps, env, info, spender, amount, expires),
+        DecreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => exec::decrease_allowance(deps, env, info, spender, amount, expires),
+        TransferFrom {
+            owner,
+            recipient,
+            amount,
+        } => exec::transfer_from(deps, env, info, owner, recipient, amount),
+    }
+}
+
+fn add_balance(storage: &mut dyn Storage, addr: &Addr, amount: Uint128) -> StdResult<()> {
+    BALANCES.update(storage, addr, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + amount)
+    })?;
+    Ok(())
+}
+
+fn sub_balance(
+    storage: &mut dyn Storage,
+    addr: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    BALANCES.update(storage, addr, |balance| {
+        let balance = balance.unwrap_or_default();
+        balance
+            .checked_sub(amount)
+            .map_err(|_| ContractError::InsufficientFunds {
+                balance,
+                required: amount,
+            })
+    })?;
+    Ok(())
+}
+
+/// Moves tokens between accounts, the common part of all transfers.
+fn move_tokens(
+    storage: &mut dyn Storage,
+    from: &Addr,
+    to: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount);
+    }
+
+    sub_balance(storage, from, amount)?;
+    add_balance(storage, to, amount)?;
+    Ok(())
+}
+
+mod exec {
+    use super::*;
+
+    pub fn transfer(
+        deps: DepsMut,
+        info: MessageInfo,
+        recipient: String,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        let recipient = deps.api.addr_validate(&recipient)?;
+        move_tokens(deps.storage, &info.sender, &recipient, amount)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "transfer")
+            .add_attribute("from", info.sender.as_str())
+            .add_attribute("to", recipient.as_str())
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn burn(
+        deps: DepsMut,
+        info: MessageInfo,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        if amount.is_zero() {
+            return Err(ContractError::InvalidZeroAmount);
+        }
+
+        sub_balance(deps.storage, &info.sender, amount)?;
+        TOKEN_INFO.update(deps.storage, |mut info| -> StdResult<_> {
+            info.total_supply -= amount;
+            Ok(info)
+        })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "burn")
+            .add_attribute("from", info.sender.as_str())
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn send(
+        deps: DepsMut,
+        info: MessageInfo,
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    ) -> Result<Response, ContractError> {
+        let contract = deps.api.addr_validate(&contract)?;
+        move_tokens(deps.storage, &info.sender, &contract, amount)?;
+
+        let receive = Cw20ReceiveMsg {
+            sender: info.sender.to_string(),
+            amount,
+            msg,
+        };
+
+        let resp = Response::new()
+            .add_message(receive.into_cosmos_msg(contract.as_str())?)
+            .add_attribute("action", "send")
+            .add_attribute("from", info.sender.as_str())
+            .add_attribute("to", contract.as_str())
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn increase_allowance(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        let spender = deps.api.addr_validate(&spender)?;
+        if spender == info.sender {
+            return Err(ContractError::OwnAllowance);
+        }
+
+        ALLOWANCES.update(
+            deps.storage,
+            (&info.sender, &spender),
+            |allowance| -> Result<_, ContractError> {
+                let mut allowance = allowance.unwrap_or_default();
+                if let Some(expires) = expires {
+                    if expires.is_expired(&env.block) {
+                        return Err(ContractError::Expired);
+                    }
+                    allowance.expires = expires;
+                }
+                allowance.allowance += amount;
+                Ok(allowance)
+            },
+        )?;
+
+        let resp = Response::new()
+            .add_attribute("action", "increase_allowance")
+            .add_attribute("owner", info.sender.as_str())
+            .add_attribute("spender", spender.as_str())
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn decrease_allowance(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        let spender = deps.api.addr_validate(&spender)?;
+        if spender == info.sender {
+            return Err(ContractError::OwnAllowance);
+        }
+
+        let key = (&info.sender, &spender);
+        let mut allowance = ALLOWANCES.may_load(deps.storage, key)?.unwrap_or_default();
+        // Decreasing below zero just removes the allowance
+        allowance.allowance = allowance.allowance.saturating_sub(amount);
+
+        if allowance.allowance.is_zero() {
+            ALLOWANCES.remove(deps.storage, key);
+        } else {
+            if let Some(expires) = expires {
+                if expires.is_expired(&env.block) {
+                    return Err(ContractError::Expired);
+                }
+                allowance.expires = expires;
+            }
+            ALLOWANCES.save(deps.storage, key, &allowance)?;
+        }
+
+        let resp = Response::new()
+            .add_attribute("action", "decrease_allowance")
+            .add_attribute("owner", info.sender.as_str())
+            .add_attribute("spender", spender.as_str())
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn transfer_from(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        let owner = deps.api.addr_validate(&owner)?;
+        let recipient = deps.api.addr_validate(&recipient)?;
+
+        ALLOWANCES.update(
+            deps.storage,
+            (&owner, &info.sender),
+            |allowance| -> Result<_, ContractError> {
+                let mut allowance = allowance.unwrap_or_default();
+                if allowance.expires.is_expired(&env.block) {
+                    return Err(ContractError::Expired);
+                }
+                allowance.allowance = allowance.allowance.checked_sub(amount).map_err(|_| {
+                    ContractError::InsufficientAllowance {
+                        allowance: allowance.allowance,
+                        required: amount,
+                    }
+                })?;
+                Ok(allowance)
+            },
+        )?;
+
+        move_tokens(deps.storage, &owner, &recipient, amount)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "transfer_from")
+            .add_attribute("from", owner.as_str())
+            .add_attribute("to", recipient.as_str())
+            .add_attribute("by", info.sender.as_str())
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn balance(deps: Deps, address: String) -> StdResult<BalanceResp> {
+        let address = deps.api.addr_validate(&address)?;
+        let balance = BALANCES
+            .may_load(deps.storage, &address)?
+            .unwrap_or_default();
+        Ok(BalanceResp { balance })
+    }
+
+    pub fn token_info(deps: Deps) -> StdResult<TokenInfoResp> {
+        let info = TOKEN_INFO.load(deps.storage)?;
+        Ok(TokenInfoResp {
+            name: info.name,
+            symbol: info.symbol,
+            decimals: info.decimals,
+            total_supply: info.total_supply,
+        })
+    }
+
+    pub fn allowance(
+        deps: Deps,
+        env: Env,
+        owner: String,
+        spender: String,
+    ) -> StdResult<AllowanceResp> {
+        let owner = deps.api.addr_validate(&owner)?;
+        let spender = deps.api.addr_validate(&spender)?;
+        let allowance = ALLOWANCES
+            .may_load(deps.storage, (&owner, &spender))?
+            .filter(|allowance| !allowance.expires.is_expired(&env.block))
+            .unwrap_or_default();
+
+        Ok(AllowanceResp {
+            allowance: allowance.allowance,
+            expires: allowance.expires,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, Empty};
+    use cw_storage_plus::Item;
+    use testing::prelude::*;
+
+    use crate::msg::{Cw20Coin, ReceiverExecuteMsg};
+
+    use super::*;
+
+    /// Token with 1000 tokens of `alice`.
+    fn token(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            name: "Book Token".to_owned(),
+            symbol: "BOOK".to_owned(),
+            decimals: 6,
+            initial_balances: vec![Cw20Coin {
+                address: mock_addr("alice").to_string(),
+                amount: Uint128::new(1000),
+            }],
+        };
+        Proxy::deploy(app, code, "owner", &msg, "Token").unwrap()
+    }
+
+    fn balance_of(app: &App, token: &Proxy, addr: &str) -> u128 {
+        let resp: BalanceResp = token
+            .query(
+                app,
+                &QueryMsg::Balance {
+                    address: mock_addr(addr).to_string(),
+                },
+            )
+            .unwrap();
+        resp.balance.u128()
+    }
+
+    /// Receiver contract remembering the last `Cw20ReceiveMsg`, returned by any query.
+    const RECEIVED: Item<Cw20ReceiveMsg> = Item::new("received");
+
+    fn receiver_execute(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: ReceiverExecuteMsg,
+    ) -> StdResult<Response> {
+        let ReceiverExecuteMsg::Receive(msg) = msg;
+        RECEIVED.save(deps.storage, &msg)?;
+        Ok(Response::new())
+    }
+
+    fn receiver_instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn receiver_query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_json_binary(&RECEIVED.load(deps.storage)?)
+    }
+
+    #[test]
+    fn instantiation() {
+        let mut app = app();
+        let token = token(&mut app);
+
+        let resp: TokenInfoResp = token.query(&app, &QueryMsg::TokenInfo {}).unwrap();
+        assert_eq!(
+            resp,
+            TokenInfoResp {
+                name: "Book Token".to_owned(),
+                symbol: "BOOK".to_owned(),
+                decimals: 6,
+                total_supply: Uint128::new(1000),
+            }
+        );
+
+        assert_eq!(balance_of(&app, &token, "alice"), 1000);
+        assert_eq!(balance_of(&app, &token, "bob"), 0);
+    }
+
+    #[test]
+    fn transfer() {
+        let mut app = app();
+        let token = token(&mut app);
+
+        let resp = token
+            .execute(
+                &mut app,
+                "alice",
+                &ExecuteMsg::Transfer {
+                    recipient: mock_addr("bob").to_string(),
+                    amount: Uint128::new(300),
+                },
+                &[],
+            )
+            .unwrap();
+        assert_eq!(wasm_attr(&resp, "action"), "transfer");
+
+        assert_eq!(balance_of(&app, &token, "alice"), 700);
+        assert_eq!(balance_of(&app, &token, "bob"), 300);
+
+        let err = token
+            .execute(
+                &mut app,
+                "bob",
+                &ExecuteMsg::Transfer {
+                    recipient: mock_addr("alice").to_string(),
+                    amount: Uint128::new(301),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientFunds {
+                balance: Uint128::new(300),
+                required: Uint128::new(301),
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = token
+            .execute(
+                &mut app,
+                "bob",
+                &ExecuteMsg::Transfer {
+                    recipient: mock_addr("alice").to_string(),
+                    amount: Uint128::zero(),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::InvalidZeroAmount, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn burn() {
+        let mut app = app();
+        let token = token(&mut app);
+
+        token
+            .execute(
+                &mut app,
+                "alice",
+                &ExecuteMsg::Burn {
+                    amount: Uint128::new(400),
+                },
+                &[],
+            )
+            .unwrap();
+
+        assert_eq!(balance_of(&app, &token, "alice"), 600);
+        let resp: TokenInfoResp = token.query(&app, &QueryMsg::TokenInfo {}).unwrap();
+        assert_eq!(resp.total_supply, Uint128::new(600));
+    }
+
+    #[test]
+    fn send() {
+        let mut app = app();
+        let token = token(&mut app);
+        let receiver = Proxy::deploy(
+            &mut app,
+            ContractWrapper::new(receiver_execute, receiver_instantiate, receiver_query),
+            "owner",
+            &Empty {},
+            "Receiver",
+        )
+        .unwrap();
+
+        token
+            .execute(
+                &mut app,
+                "alice",
+                &ExecuteMsg::Send {
+                    contract: receiver.addr().to_string(),
+                    amount: Uint128::new(100),
+                    msg: to_json_binary("hook").unwrap(),
+                },
+                &[],
+            )
+            .unwrap();
+
+        assert_eq!(balance_of(&app, &token, "alice"), 900);
+        let resp: BalanceResp = token
+            .query(
+                &app,
+                &QueryMsg::Balance {
+                    address: receiver.addr().to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.balance, Uint128::new(100));
+
+        let received: Cw20ReceiveMsg = receiver.query(&app, &Empty {}).unwrap();
+        assert_eq!(received.sender, mock_addr("alice").as_str());
+        assert_eq!(received.amount, Uint128::new(100));
+        assert_eq!(from_json::<String>(&received.msg).unwrap(), "hook");
+    }
+
+    #[test]
+    fn allowances() {
+        let mut app = app();
+        let token = token(&mut app);
+        let expires = Expiration::AtHeight(app.block_info().height + 10);
+
+        let allowance = |app: &App| -> AllowanceResp {
+            token
+                .query(
+                    app,
+                    &QueryMsg::Allowance {
+                        owner: mock_addr("alice").to_string(),
+                        spender: mock_addr("bob").to_string(),
+                    },
+                )
+                .unwrap()
+        };
+
+        token
+            .execute(
+                &mut app,
+                "alice",
+                &ExecuteMsg::IncreaseAllowance {
+                    spender: mock_addr("bob").to_string(),
+                    amount: Uint128::new(500),
+                    expires: Some(expires),
+                },
+                &[],
+            )
+            .unwrap();
+        token
+            .execute(
+                &mut app,
+                "alice",
+                &ExecuteMsg::DecreaseAllowance {
+                    spender: mock_addr("bob").to_string(),
+                    amount: Uint128::new(200),
+                    expires: None,
+                },
+                &[],
+            )
+            .unwrap();
+        assert_eq!(
+            allowance(&app),
+            AllowanceResp {
+                allowance: Uint128::new(300),
+                expires,
+            }
+        );
+
+        token
+            .execute(
+                &mut app,
+                "bob",
+                &ExecuteMsg::TransferFrom {
+                    owner: mock_addr("alice").to_string(),
+                    recipient: mock_addr("carol").to_string(),
+                    amount: Uint128::new(200),
+                },
+                &[],
+            )
+            .unwrap();
+        assert_eq!(balance_of(&app, &token, "alice"), 800);
+        assert_eq!(balance_of(&app, &token, "carol"), 200);
+        assert_eq!(allowance(&app).allowance, Uint128::new(100));
+
+        let err = token
+            .execute(
+                &mut app,
+                "bob",
+                &ExecuteMsg::TransferFrom {
+                    owner: mock_addr("alice").to_string(),
+                    recipient: mock_addr("bob").to_string(),
+                    amount: Uint128::new(101),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientAllowance {
+                allowance: Uint128::new(100),
+                required: Uint128::new(101),
+            },
+            err.downcast().unwrap()
+        );
+
+        app.advance_blocks(10);
+
+        let err = token
+            .execute(
+                &mut app,
+                "bob",
+                &ExecuteMsg::TransferFrom {
+                    owner: mock_addr("alice").to_string(),
+                    recipient: mock_addr("bob").to_string(),
+                    amount: Uint128::new(1),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::Expired, err.downcast().unwrap());
+        assert_eq!(allowance(&app).allowance, Uint128::zero());
+    }
+
+    #[test]
+    fn no_allowance() {
+        let mut app = app();
+        let token = token(&mut app);
+
+        let err = token
+            .execute(
+                &mut app,
+                "bob",
+                &ExecuteMsg::TransferFrom {
+                    owner: mock_addr("alice").to_string(),
+                    recipient: mock_addr("bob").to_string(),
+                    amount: Uint128::new(1),
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientAllowance {
+                allowance: Uint128::zero(),
+                required: Uint128::new(1),
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = token
+            .execute(
+                &mut app,
+                "alice",
+                &ExecuteMsg::IncreaseAllowance {
+                    spender: mock_addr("alice").to_string(),
+                    amount: Uint128::new(1),
+                    expires: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+        assert_eq!(ContractError::OwnAllowance, err.downcast().unwrap());
+    }
+}
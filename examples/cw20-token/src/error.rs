@@ -0,0 +1,21 @@
+use cosmwasm_std::{StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Invalid zero amount")]
+    InvalidZeroAmount,
+    #[error("Insufficient funds: balance {balance}, required {required}")]
+    InsufficientFunds { balance: Uint128, required: Uint128 },
+    #[error("Insufficient allowance: allowance {allowance}, required {required}")]
+    InsufficientAllowance {
+        allowance: Uint128,
+        required: Uint128,
+    },
+    #[error("Allowance is expired")]
+    Expired,
+    #[error("Cannot set allowance to own account")]
+    OwnAllowance,
+}
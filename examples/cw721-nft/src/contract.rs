@@ -0,0 +1,574 @@
+use crate::error::ContractError;
+use crate::msg::{
+    ContractInfoResp, ExecuteMsg, InstantiateMsg, NftInfoResp, OwnerOfResp, QueryMsg, TokensResp,
+};
+use crate::state::{tokens, Approval, Config, TokenInfo, CONFIG};
+use common::pagination;
+use cosmwasm_std::{
+    to_json_binary, Binary, BlockInfo, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+};
+use cw_utils::Expiration;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        name: msg.name,
+        symbol: msg.symbol,
+        minter: deps.api.addr_validate(&msg.minter)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        ContractInfo {} => to_json_binary(&query::contract_info(deps)?),
+        OwnerOf { token_id } => to_json_binary(&query::owner_of(deps, env, token_id)?),
+        NftInfo { token_id } => to_json_binary(&query::nft_info(deps, token_id)?),
+        Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_json_binary(&query::owner_tokens(deps, owner, start_after, limit)?),
+        AllTokens { start_after, limit } => {
+            to_json_binary(&query::all_tokens(deps, start_after, limit)?)
+        }
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Mint {
+            token_id,
+            owner,
+            token_uri,
+        } => exec::mint(deps, info, token_id, owner, token_uri),
+        TransferNft {
+            recipient,
+            token_id,
+        } => exec::transfer_nft(deps, env, info, recipient, token_id),
+        Approve {
+            spender,
+            token_id,
+            expires,
+        } => exec::approve(deps, env, info, spender, token_id, expires),
+        Revoke { spender, token_id } => exec::revoke(deps, info, spender, token_id),
+    }
+}
+
+/// Checks if the sender owns the token or has a valid approval for it.
+fn can_transfer(block: &BlockInfo, info: &MessageInfo, token: &TokenInfo) -> bool {
+    token.owner == info.sender
+        || token
+            .approvals
+            .iter()
+            .any(|approval| approval.spender == info.sender && !approval.is_expired(block))
+}
+
+mod exec {
+    use super::*;
+
+    pub fn mint(
+        deps: DepsMut,
+        info: MessageInfo,
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+    ) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        if info.sender != config.minter {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let token = TokenInfo {
+            owner: deps.api.addr_validate(&owner)?,
+            approvals: vec![],
+            token_uri,
+        };
+        tokens().update(deps.storage, &token_id, |old| match old {
+            Some(_) => Err(ContractError::Claimed {
+                token_id: token_id.clone(),
+            }),
+            None => Ok(token),
+        })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "mint")
+            .add_attribute("owner", owner)
+            .add_attribute("token_id", token_id);
+
+        Ok(resp)
+    }
+
+    pub fn transfer_nft(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: String,
+        token_id: String,
+    ) -> Result<Response, ContractError> {
+        let recipient = deps.api.addr_validate(&recipient)?;
+        let mut token = tokens().load(deps.storage, &token_id)?;
+        if !can_transfer(&env.block, &info, &token) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        token.owner = recipient;
+        token.approvals.clear();
+        // `save` updates the owner index as well
+        tokens().save(deps.storage, &token_id, &token)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "transfer_nft")
+            .add_attribute("sender", info.sender.as_str())
+            .add_attribute("recipient", token.owner.as_str())
+            .add_attribute("token_id", token_id);
+
+        Ok(resp)
+    }
+
+    pub fn approve(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        spender: String,
+        token_id: String,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        let spender = deps.api.addr_validate(&spender)?;
+        let expires = expires.unwrap_or_default();
+        if expires.is_expired(&env.block) {
+            return Err(ContractError::Expired);
+        }
+
+        let mut token = tokens().load(deps.storage, &token_id)?;
+        if token.owner != info.sender {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        token
+            .approvals
+            .retain(|approval| approval.spender != spender);
+        token.approvals.push(Approval {
+            spender: spender.clone(),
+            expires,
+        });
+        tokens().save(deps.storage, &token_id, &token)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "approve")
+            .add_attribute("spender", spender.as_str())
+            .add_attribute("token_id", token_id);
+
+        Ok(resp)
+    }
+
+    pub fn revoke(
+        deps: DepsMut,
+        info: MessageInfo,
+        spender: String,
+        token_id: String,
+    ) -> Result<Response, ContractError> {
+        let spender = deps.api.addr_validate(&spender)?;
+        let mut token = tokens().load(deps.storage, &token_id)?;
+        if token.owner != info.sender {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        token
+            .approvals
+            .retain(|approval| approval.spender != spender);
+        tokens().save(deps.storage, &token_id, &token)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "revoke")
+            .add_attribute("spender", spender.as_str())
+            .add_attribute("token_id", token_id);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn contract_info(deps: Deps) -> StdResult<ContractInfoResp> {
+        let config = CONFIG.load(deps.storage)?;
+        Ok(ContractInfoResp {
+            name: config.name,
+            symbol: config.symbol,
+        })
+    }
+
+    pub fn owner_of(deps: Deps, env: Env, token_id: String) -> StdResult<OwnerOfResp> {
+        let token = tokens().load(deps.storage, &token_id)?;
+        let approvals = token
+            .approvals
+            .into_iter()
+            .filter(|approval| !approval.is_expired(&env.block))
+            .collect();
+
+        Ok(OwnerOfResp {
+            owner: token.owner.into_string(),
+            approvals,
+        })
+    }
+
+    pub fn nft_info(deps: Deps, token_id: String) -> StdResult<NftInfoResp> {
+        let token = tokens().load(deps.storage, &token_id)?;
+        Ok(NftInfoResp {
+            token_uri: token.token_uri,
+        })
+    }
+
+    fn tokens_resp(tokens: Vec<String>, limit: usize) -> TokensResp {
+        let next_start_after = pagination::next_key(&tokens, limit, String::clone);
+        TokensResp {
+            tokens,
+            next_start_after,
+        }
+    }
+
+    pub fn owner_tokens(
+        deps: Deps,
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokensResp> {
+        let owner = deps.api.addr_validate(&owner)?;
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after);
+
+        let tokens: Vec<_> = tokens()
+            .idx
+            .owner
+            .prefix(owner)
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<_>>()?;
+
+        Ok(tokens_resp(tokens, limit))
+    }
+
+    pub fn all_tokens(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokensResp> {
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after.as_deref());
+
+        let tokens: Vec<_> = tokens()
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<_>>()?;
+
+        Ok(tokens_resp(tokens, limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    fn nft(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            name: "Book Collection".to_owned(),
+            symbol: "BOOKS".to_owned(),
+            minter: mock_addr("minter").to_string(),
+        };
+        Proxy::deploy(app, code, "owner", &msg, "NFT").unwrap()
+    }
+
+    fn mint(app: &mut App, nft: &Proxy, token_id: &str, owner: &str) -> AppResponse {
+        nft.execute(
+            app,
+            "minter",
+            &ExecuteMsg::Mint {
+                token_id: token_id.to_owned(),
+                owner: mock_addr(owner).to_string(),
+                token_uri: Some(format!("https://example.com/{}", token_id)),
+            },
+            &[],
+        )
+        .unwrap()
+    }
+
+    fn transfer(
+        app: &mut App,
+        nft: &Proxy,
+        sender: &str,
+        recipient: &str,
+        token_id: &str,
+    ) -> anyhow::Result<AppResponse> {
+        nft.execute(
+            app,
+            sender,
+            &ExecuteMsg::TransferNft {
+                recipient: mock_addr(recipient).to_string(),
+                token_id: token_id.to_owned(),
+            },
+            &[],
+        )
+    }
+
+    fn owner_of(app: &App, nft: &Proxy, token_id: &str) -> OwnerOfResp {
+        nft.query(
+            app,
+            &QueryMsg::OwnerOf {
+                token_id: token_id.to_owned(),
+            },
+        )
+        .unwrap()
+    }
+
+    fn tokens_of(app: &App, nft: &Proxy, owner: &str, start_after: Option<&str>) -> Vec<String> {
+        let resp: TokensResp = nft
+            .query(
+                app,
+                &QueryMsg::Tokens {
+                    owner: mock_addr(owner).to_string(),
+                    start_after: start_after.map(str::to_owned),
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+        resp.tokens
+    }
+
+    #[test]
+    fn minting() {
+        let mut app = app();
+        let nft = nft(&mut app);
+
+        let resp = mint(&mut app, &nft, "book1", "alice");
+        assert_event!(resp, "wasm", "action" => "mint", "token_id" => "book1");
+
+        assert_eq!(
+            owner_of(&app, &nft, "book1").owner,
+            mock_addr("alice").as_str()
+        );
+        let resp: NftInfoResp = nft
+            .query(
+                &app,
+                &QueryMsg::NftInfo {
+                    token_id: "book1".to_owned(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.token_uri.as_deref(), Some("https://example.com/book1"));
+
+        let resp: ContractInfoResp = nft.query(&app, &QueryMsg::ContractInfo {}).unwrap();
+        assert_eq!(resp.symbol, "BOOKS");
+    }
+
+    #[test]
+    fn invalid_minting() {
+        let mut app = app();
+        let nft = nft(&mut app);
+        mint(&mut app, &nft, "book1", "alice");
+
+        let msg = |token_id: &str| ExecuteMsg::Mint {
+            token_id: token_id.to_owned(),
+            owner: mock_addr("alice").to_string(),
+            token_uri: None,
+        };
+
+        let err = nft
+            .execute(&mut app, "alice", &msg("book2"), &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("alice")
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = nft
+            .execute(&mut app, "minter", &msg("book1"), &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Claimed {
+                token_id: "book1".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn transfers() {
+        let mut app = app();
+        let nft = nft(&mut app);
+        mint(&mut app, &nft, "book1", "alice");
+
+        let err = transfer(&mut app, &nft, "bob", "bob", "book1").unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("bob")
+            },
+            err.downcast().unwrap()
+        );
+
+        transfer(&mut app, &nft, "alice", "bob", "book1").unwrap();
+        assert_eq!(
+            owner_of(&app, &nft, "book1").owner,
+            mock_addr("bob").as_str()
+        );
+        assert_eq!(tokens_of(&app, &nft, "alice", None), Vec::<String>::new());
+        assert_eq!(tokens_of(&app, &nft, "bob", None), ["book1"]);
+
+        transfer(&mut app, &nft, "alice", "alice", "missing").unwrap_err();
+    }
+
+    #[test]
+    fn approvals() {
+        let mut app = app();
+        let nft = nft(&mut app);
+        mint(&mut app, &nft, "book1", "alice");
+        mint(&mut app, &nft, "book2", "alice");
+
+        let approve = |app: &mut App, token_id: &str, expires| {
+            nft.execute(
+                app,
+                "alice",
+                &ExecuteMsg::Approve {
+                    spender: mock_addr("bob").to_string(),
+                    token_id: token_id.to_owned(),
+                    expires,
+                },
+                &[],
+            )
+        };
+
+        let expires = Expiration::AtHeight(app.block_info().height + 5);
+        approve(&mut app, "book1", Some(expires)).unwrap();
+        approve(&mut app, "book2", None).unwrap();
+        assert_eq!(
+            owner_of(&app, &nft, "book1").approvals,
+            [Approval {
+                spender: mock_addr("bob"),
+                expires
+            }]
+        );
+
+        let expired = Expiration::AtHeight(app.block_info().height);
+        let err = approve(&mut app, "book1", Some(expired)).unwrap_err();
+        assert_eq!(ContractError::Expired, err.downcast().unwrap());
+
+        // The approval is gone once expired
+        app.advance_blocks(5);
+        assert_eq!(owner_of(&app, &nft, "book1").approvals, []);
+        transfer(&mut app, &nft, "bob", "bob", "book1").unwrap_err();
+
+        // Transfer by the spender clears all approvals
+        transfer(&mut app, &nft, "bob", "carol", "book2").unwrap();
+        let resp = owner_of(&app, &nft, "book2");
+        assert_eq!(resp.owner, mock_addr("carol").as_str());
+        assert_eq!(resp.approvals, []);
+    }
+
+    #[test]
+    fn revoke() {
+        let mut app = app();
+        let nft = nft(&mut app);
+        mint(&mut app, &nft, "book1", "alice");
+
+        nft.execute(
+            &mut app,
+            "alice",
+            &ExecuteMsg::Approve {
+                spender: mock_addr("bob").to_string(),
+                token_id: "book1".to_owned(),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+        nft.execute(
+            &mut app,
+            "alice",
+            &ExecuteMsg::Revoke {
+                spender: mock_addr("bob").to_string(),
+                token_id: "book1".to_owned(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let err = transfer(&mut app, &nft, "bob", "bob", "book1").unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("bob")
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn pagination() {
+        let mut app = app();
+        let nft = nft(&mut app);
+        for (token_id, owner) in [
+            ("book5", "alice"),
+            ("book1", "alice"),
+            ("book3", "bob"),
+            ("book2", "alice"),
+            ("book4", "bob"),
+        ] {
+            mint(&mut app, &nft, token_id, owner);
+        }
+
+        assert_eq!(tokens_of(&app, &nft, "alice", None), ["book1", "book2"]);
+        assert_eq!(tokens_of(&app, &nft, "alice", Some("book2")), ["book5"]);
+        assert_eq!(
+            tokens_of(&app, &nft, "alice", Some("book5")),
+            Vec::<String>::new()
+        );
+        assert_eq!(tokens_of(&app, &nft, "bob", None), ["book3", "book4"]);
+
+        let mut all = vec![];
+        let mut start_after = None;
+        loop {
+            let resp: TokensResp = nft
+                .query(
+                    &app,
+                    &QueryMsg::AllTokens {
+                        start_after: start_after.clone(),
+                        limit: Some(2),
+                    },
+                )
+                .unwrap();
+            all.extend(resp.tokens);
+            start_after = resp.next_start_after;
+            if start_after.is_none() {
+                break;
+            }
+        }
+        assert_eq!(all, ["book1", "book2", "book3", "book4", "book5"]);
+    }
+}
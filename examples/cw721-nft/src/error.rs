@@ -0,0 +1,14 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not authorized to perform this action")]
+    Unauthorized { sender: Addr },
+    #[error("Token {token_id} is already minted")]
+    Claimed { token_id: String },
+    #[error("Approval is expired")]
+    Expired,
+}
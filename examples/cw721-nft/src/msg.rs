@@ -0,0 +1,93 @@
+use cosmwasm_schema::QueryResponses;
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::Approval;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub name: String,
+    pub symbol: String,
+    /// Address allowed to mint new tokens
+    pub minter: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Mint {
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+    },
+    /// Transfers the token, clearing all its approvals
+    TransferNft {
+        recipient: String,
+        token_id: String,
+    },
+    /// Allows the spender to transfer the token until the approval expires
+    Approve {
+        spender: String,
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    Revoke {
+        spender: String,
+        token_id: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ContractInfoResp {
+    pub name: String,
+    pub symbol: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OwnerOfResp {
+    pub owner: String,
+    /// Approvals which are not expired yet
+    pub approvals: Vec<Approval>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NftInfoResp {
+    pub token_uri: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TokensResp {
+    pub tokens: Vec<String>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(ContractInfoResp)]
+    ContractInfo {},
+    #[returns(OwnerOfResp)]
+    OwnerOf { token_id: String },
+    #[returns(NftInfoResp)]
+    NftInfo { token_id: String },
+    /// Tokens of the owner, ordered by id
+    #[returns(TokensResp)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// All tokens, ordered by id
+    #[returns(TokensResp)]
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
@@ -0,0 +1,52 @@
+use cosmwasm_std::{Addr, BlockInfo};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub name: String,
+    pub symbol: String,
+    pub minter: Addr,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Approval {
+    pub spender: Addr,
+    pub expires: Expiration,
+}
+
+impl Approval {
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.expires.is_expired(block)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TokenInfo {
+    pub owner: Addr,
+    pub approvals: Vec<Approval>,
+    pub token_uri: Option<String>,
+}
+
+pub struct TokenIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, TokenInfo, String>,
+}
+
+impl<'a> IndexList<TokenInfo> for TokenIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<TokenInfo>> + '_> {
+        let v: Vec<&dyn Index<TokenInfo>> = vec![&self.owner];
+        Box::new(v.into_iter())
+    }
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Tokens by their id, indexed by the owner so tokens of a single owner can be listed
+pub fn tokens<'a>() -> IndexedMap<&'a str, TokenInfo, TokenIndexes<'a>> {
+    let indexes = TokenIndexes {
+        owner: MultiIndex::new(|_pk, token| token.owner.clone(), "tokens", "tokens__owner"),
+    };
+    IndexedMap::new("tokens", indexes)
+}
@@ -0,0 +1,25 @@
+use cosmwasm_std::{Addr, CustomMsg, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Token factory messages of an example chain, sent as `CosmosMsg::Custom`. Any account can
+/// create denoms under its own address, and only the creator can mint them.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenFactoryMsg {
+    CreateDenom {
+        subdenom: String,
+    },
+    MintTokens {
+        denom: String,
+        amount: Uint128,
+        mint_to_address: String,
+    },
+}
+
+impl CustomMsg for TokenFactoryMsg {}
+
+/// Denom the chain gives to the `subdenom` created by `creator`.
+pub fn factory_denom(creator: &Addr, subdenom: &str) -> String {
+    format!("factory/{}/{}", creator, subdenom)
+}
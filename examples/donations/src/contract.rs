@@ -0,0 +1,291 @@
+use crate::chain::{factory_denom, TokenFactoryMsg};
+use crate::error::ContractError;
+use crate::msg::{ConfigResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{Config, CONFIG};
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response,
+    StdResult,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response<TokenFactoryMsg>> {
+    // The denom is created by the message below, but its name is known upfront
+    let receipt_denom = factory_denom(&env.contract.address, &msg.subdenom);
+    let config = Config {
+        owner: info.sender,
+        donation_denom: msg.donation_denom,
+        receipt_denom: receipt_denom.clone(),
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let create = TokenFactoryMsg::CreateDenom {
+        subdenom: msg.subdenom,
+    };
+    let resp = Response::new()
+        .add_message(CosmosMsg::Custom(create))
+        .add_attribute("receipt_denom", receipt_denom);
+
+    Ok(resp)
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Config {} => to_json_binary(&query::config(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response<TokenFactoryMsg>, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Donate {} => exec::donate(deps, info),
+        Withdraw {} => exec::withdraw(deps, env, info),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn donate(
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        let amount = match info.funds.as_slice() {
+            [donation] if donation.denom == config.donation_denom && !donation.amount.is_zero() => {
+                donation.amount
+            }
+            _ => {
+                return Err(ContractError::InvalidDonation {
+                    denom: config.donation_denom,
+                })
+            }
+        };
+
+        let mint = TokenFactoryMsg::MintTokens {
+            denom: config.receipt_denom,
+            amount,
+            mint_to_address: info.sender.to_string(),
+        };
+        let resp = Response::new()
+            .add_message(CosmosMsg::Custom(mint))
+            .add_attribute("action", "donate")
+            .add_attribute("donor", info.sender)
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+
+    pub fn withdraw(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+    ) -> Result<Response<TokenFactoryMsg>, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        if info.sender != config.owner {
+            return Err(ContractError::Unauthorized {
+                owner: config.owner,
+            });
+        }
+
+        let balance = deps
+            .querier
+            .query_balance(&env.contract.address, config.donation_denom)?;
+        if balance.amount.is_zero() {
+            return Err(ContractError::NothingToWithdraw);
+        }
+
+        let resp = Response::new()
+            .add_attribute("action", "withdraw")
+            .add_attribute("amount", balance.amount)
+            .add_message(BankMsg::Send {
+                to_address: config.owner.into_string(),
+                amount: vec![balance],
+            });
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn config(deps: Deps) -> StdResult<ConfigResp> {
+        let config = CONFIG.load(deps.storage)?;
+        Ok(ConfigResp {
+            owner: config.owner,
+            donation_denom: config.donation_denom,
+            receipt_denom: config.receipt_denom,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+    use cw_multi_test::{BankKeeper, MockApiBech32, WasmKeeper};
+    use testing::prelude::*;
+
+    use crate::multitest::TokenFactoryModule;
+
+    use super::*;
+
+    type ChainApp = cw_multi_test::App<
+        BankKeeper,
+        MockApiBech32,
+        MockStorage,
+        TokenFactoryModule,
+        WasmKeeper<TokenFactoryMsg, Empty>,
+    >;
+
+    /// App with 1000 atom and btc for every donor, and the contract accepting atom.
+    fn setup() -> (ChainApp, Addr) {
+        let mut app = app_builder::<TokenFactoryMsg, Empty>()
+            .with_custom(TokenFactoryModule)
+            .build(|router, _, storage| {
+                for donor in ["alice", "bob"] {
+                    router
+                        .bank
+                        .init_balance(
+                            storage,
+                            &mock_addr(donor),
+                            vec![coin(1000, "atom"), coin(1000, "btc")],
+                        )
+                        .unwrap();
+                }
+            });
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+        let contract = app
+            .instantiate_contract(
+                code_id,
+                addr::owner(),
+                &InstantiateMsg {
+                    donation_denom: "atom".to_owned(),
+                    subdenom: "receipt".to_owned(),
+                },
+                &[],
+                "Donations",
+                None,
+            )
+            .unwrap();
+
+        (app, contract)
+    }
+
+    fn donate(
+        app: &mut ChainApp,
+        contract: &Addr,
+        donor: &str,
+        funds: &[Coin],
+    ) -> anyhow::Result<AppResponse> {
+        app.execute_contract(
+            mock_addr(donor),
+            contract.clone(),
+            &ExecuteMsg::Donate {},
+            funds,
+        )
+    }
+
+    fn balance(app: &ChainApp, addr: &Addr, denom: &str) -> u128 {
+        app.wrap().query_balance(addr, denom).unwrap().amount.u128()
+    }
+
+    #[test]
+    fn receipts_for_donations() {
+        let (mut app, contract) = setup();
+        let config: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(&contract, &QueryMsg::Config {})
+            .unwrap();
+        assert_eq!(
+            config.receipt_denom,
+            format!("factory/{}/receipt", contract)
+        );
+
+        donate(&mut app, &contract, "alice", &coins(100, "atom")).unwrap();
+        donate(&mut app, &contract, "bob", &coins(30, "atom")).unwrap();
+        donate(&mut app, &contract, "alice", &coins(20, "atom")).unwrap();
+
+        assert_eq!(
+            balance(&app, &mock_addr("alice"), &config.receipt_denom),
+            120
+        );
+        assert_eq!(balance(&app, &mock_addr("bob"), &config.receipt_denom), 30);
+        assert_eq!(balance(&app, &contract, "atom"), 150);
+
+        for funds in [
+            vec![],
+            coins(10, "btc"),
+            vec![coin(10, "atom"), coin(10, "btc")],
+        ] {
+            let err = donate(&mut app, &contract, "alice", &funds).unwrap_err();
+            assert_eq!(
+                ContractError::InvalidDonation {
+                    denom: "atom".to_owned()
+                },
+                err.downcast().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn only_creator_mints() {
+        let (mut app, contract) = setup();
+        let config: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(&contract, &QueryMsg::Config {})
+            .unwrap();
+
+        // Receipts cannot be minted bypassing the contract
+        let mint = TokenFactoryMsg::MintTokens {
+            denom: config.receipt_denom.clone(),
+            amount: Uint128::new(100),
+            mint_to_address: mock_addr("alice").to_string(),
+        };
+        app.execute(mock_addr("alice"), CosmosMsg::Custom(mint))
+            .unwrap_err();
+        assert_eq!(balance(&app, &mock_addr("alice"), &config.receipt_denom), 0);
+    }
+
+    #[test]
+    fn withdraw_donations() {
+        let (mut app, contract) = setup();
+        let withdraw = |app: &mut ChainApp, sender: &str| {
+            app.execute_contract(
+                mock_addr(sender),
+                contract.clone(),
+                &ExecuteMsg::Withdraw {},
+                &[],
+            )
+        };
+
+        let err = withdraw(&mut app, "owner").unwrap_err();
+        assert_eq!(ContractError::NothingToWithdraw, err.downcast().unwrap());
+
+        donate(&mut app, &contract, "alice", &coins(100, "atom")).unwrap();
+        let err = withdraw(&mut app, "alice").unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                owner: addr::owner()
+            },
+            err.downcast().unwrap()
+        );
+
+        withdraw(&mut app, "owner").unwrap();
+        assert_eq!(balance(&app, &addr::owner(), "atom"), 100);
+        assert_eq!(balance(&app, &contract, "atom"), 0);
+    }
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Unauthorized - only {owner} can withdraw")]
+    Unauthorized { owner: Addr },
+    #[error("Donation has to be a non-zero amount of {denom} only")]
+    InvalidDonation { denom: String },
+    #[error("Nothing to withdraw")]
+    NothingToWithdraw,
+}
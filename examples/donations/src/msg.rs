@@ -0,0 +1,36 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub donation_denom: String,
+    /// Subdenom of the receipt token the contract creates
+    pub subdenom: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Donates the sent funds, minting the same amount of receipt tokens to the donor
+    Donate {},
+    /// Sends all donations to the owner
+    Withdraw {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ConfigResp {
+    pub owner: Addr,
+    pub donation_denom: String,
+    pub receipt_denom: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(ConfigResp)]
+    Config {},
+}
@@ -0,0 +1,89 @@
+use anyhow::{bail, ensure, Result as AnyResult};
+use cosmwasm_std::{
+    coin, Addr, Api, Binary, BlockInfo, CustomMsg, CustomQuery, Empty, Querier, Storage,
+};
+use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Module};
+use cw_storage_plus::Map;
+use serde::de::DeserializeOwned;
+
+use crate::chain::{factory_denom, TokenFactoryMsg};
+
+/// Creators of the denoms made by the module.
+const DENOMS: Map<&str, Addr> = Map::new("tokenfactory_denoms");
+
+/// Stands in for the chain token factory. It keeps track of the denom creators, and mints
+/// through the multitest bank - only the creator of the denom can mint it.
+pub struct TokenFactoryModule;
+
+impl Module for TokenFactoryModule {
+    type ExecT = TokenFactoryMsg;
+    type QueryT = Empty;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            TokenFactoryMsg::CreateDenom { subdenom } => {
+                let denom = factory_denom(&sender, &subdenom);
+                ensure!(
+                    !DENOMS.has(storage, &denom),
+                    "Denom {} already exists",
+                    denom
+                );
+                DENOMS.save(storage, &denom, &sender)?;
+                Ok(AppResponse::default())
+            }
+            TokenFactoryMsg::MintTokens {
+                denom,
+                amount,
+                mint_to_address,
+            } => {
+                let creator = DENOMS.may_load(storage, &denom)?;
+                ensure!(
+                    creator.as_ref() == Some(&sender),
+                    "{} is not the creator of {}",
+                    sender,
+                    denom
+                );
+                let mint = BankSudo::Mint {
+                    to_address: mint_to_address,
+                    amount: vec![coin(amount.u128(), denom)],
+                };
+                router.sudo(api, storage, block, mint.into())
+            }
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        msg: Self::SudoT,
+    ) -> AnyResult<AppResponse> {
+        bail!("Unexpected sudo msg {:?}", msg)
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        bail!("Unexpected custom query {:?}", request)
+    }
+}
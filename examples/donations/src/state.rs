@@ -0,0 +1,15 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub owner: Addr,
+    /// Denom accepted as donations
+    pub donation_denom: String,
+    /// Denom of the receipt token, created by the contract
+    pub receipt_denom: String,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
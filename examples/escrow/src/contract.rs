@@ -0,0 +1,299 @@
+use crate::error::ContractError;
+use crate::msg::{EscrowResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{Escrow, ESCROW};
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    if info.funds.is_empty() {
+        return Err(ContractError::NoFunds);
+    }
+
+    if let Some(expiration) = msg.expiration {
+        if expiration.is_expired(&env.block) {
+            return Err(ContractError::InvalidExpiration);
+        }
+    }
+
+    let escrow = Escrow {
+        arbiter: deps.api.addr_validate(&msg.arbiter)?,
+        recipient: deps.api.addr_validate(&msg.recipient)?,
+        source: info.sender,
+        expiration: msg.expiration,
+    };
+    ESCROW.save(deps.storage, &escrow)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Escrow {} => to_json_binary(&query::escrow(deps, env)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Approve {} => exec::approve(deps, env, info),
+        Refund {} => exec::refund(deps, env, info),
+    }
+}
+
+/// Sends everything the contract holds, failing if there is nothing left - the escrow was
+/// already resolved.
+fn release(deps: Deps, env: &Env, to: &Addr, action: &str) -> Result<Response, ContractError> {
+    let funds = deps.querier.query_all_balances(&env.contract.address)?;
+    if funds.is_empty() {
+        return Err(ContractError::Resolved);
+    }
+
+    let resp = Response::new()
+        .add_message(BankMsg::Send {
+            to_address: to.to_string(),
+            amount: funds,
+        })
+        .add_attribute("action", action)
+        .add_attribute("to", to.as_str());
+
+    Ok(resp)
+}
+
+mod exec {
+    use super::*;
+
+    pub fn approve(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let escrow = ESCROW.load(deps.storage)?;
+        if info.sender != escrow.arbiter {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        if let Some(expiration) = escrow.expiration {
+            if expiration.is_expired(&env.block) {
+                return Err(ContractError::Expired);
+            }
+        }
+
+        release(deps.as_ref(), &env, &escrow.recipient, "approve")
+    }
+
+    pub fn refund(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let escrow = ESCROW.load(deps.storage)?;
+        let expired = escrow
+            .expiration
+            .map(|expiration| expiration.is_expired(&env.block))
+            .unwrap_or(false);
+
+        if info.sender != escrow.arbiter && !expired {
+            return Err(ContractError::NotExpired);
+        }
+
+        release(deps.as_ref(), &env, &escrow.source, "refund")
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn escrow(deps: Deps, env: Env) -> StdResult<EscrowResp> {
+        let escrow = ESCROW.load(deps.storage)?;
+        let funds = deps.querier.query_all_balances(env.contract.address)?;
+
+        Ok(EscrowResp {
+            arbiter: escrow.arbiter,
+            recipient: escrow.recipient,
+            source: escrow.source,
+            expiration: escrow.expiration,
+            funds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cw_utils::Expiration;
+    use testing::prelude::*;
+
+    use super::*;
+
+    const ESCROW_TIME: u64 = 100;
+
+    /// Escrow of 100 eth and 50 atom funded by `source`, expiring `ESCROW_TIME` seconds from now.
+    fn escrow(app: &mut App) -> Proxy {
+        let code_id = store_code(app, ContractWrapper::new(execute, instantiate, query));
+        let expiration = Expiration::AtTime(app.block_info().time.plus_seconds(ESCROW_TIME));
+
+        Proxy::instantiate(
+            app,
+            code_id,
+            "source",
+            &InstantiateMsg {
+                arbiter: mock_addr("arbiter").to_string(),
+                recipient: mock_addr("recipient").to_string(),
+                expiration: Some(expiration),
+            },
+            &[coin(100, "eth"), coin(50, "atom")],
+            "Escrow",
+        )
+        .unwrap()
+    }
+
+    fn funded_app() -> App {
+        app_with_balances(&[("source", vec![coin(100, "eth"), coin(50, "atom")])])
+    }
+
+    #[test]
+    fn instantiation() {
+        let mut app = funded_app();
+        let contract = escrow(&mut app);
+
+        let resp: EscrowResp = contract.query(&app, &QueryMsg::Escrow {}).unwrap();
+
+        assert_eq!(
+            resp,
+            EscrowResp {
+                arbiter: mock_addr("arbiter"),
+                recipient: mock_addr("recipient"),
+                source: mock_addr("source"),
+                expiration: Some(Expiration::AtTime(
+                    app.block_info().time.plus_seconds(ESCROW_TIME)
+                )),
+                funds: vec![coin(50, "atom"), coin(100, "eth")],
+            }
+        );
+        assert_eq!(balance(&app, mock_addr("source"), "eth"), 0);
+    }
+
+    #[test]
+    fn invalid_instantiation() {
+        let mut app = funded_app();
+        let code_id = store_code(&mut app, ContractWrapper::new(execute, instantiate, query));
+        let msg = |expiration| InstantiateMsg {
+            arbiter: mock_addr("arbiter").to_string(),
+            recipient: mock_addr("recipient").to_string(),
+            expiration,
+        };
+
+        let err =
+            Proxy::instantiate(&mut app, code_id, "source", &msg(None), &[], "Escrow").unwrap_err();
+        assert_eq!(ContractError::NoFunds, err.downcast().unwrap());
+
+        let expired = Expiration::AtHeight(app.block_info().height);
+        let err = Proxy::instantiate(
+            &mut app,
+            code_id,
+            "source",
+            &msg(Some(expired)),
+            &coins(100, "eth"),
+            "Escrow",
+        )
+        .unwrap_err();
+        assert_eq!(ContractError::InvalidExpiration, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn approve() {
+        let mut app = funded_app();
+        let contract = escrow(&mut app);
+
+        let err = contract
+            .execute(&mut app, "recipient", &ExecuteMsg::Approve {}, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("recipient")
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp = contract
+            .execute(&mut app, "arbiter", &ExecuteMsg::Approve {}, &[])
+            .unwrap();
+        assert_eq!(wasm_attr(&resp, "action"), "approve");
+
+        assert_eq!(balance(&app, mock_addr("recipient"), "eth"), 100);
+        assert_eq!(balance(&app, mock_addr("recipient"), "atom"), 50);
+        assert_eq!(balance(&app, contract.addr(), "eth"), 0);
+
+        let err = contract
+            .execute(&mut app, "arbiter", &ExecuteMsg::Refund {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::Resolved, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn approve_after_expiration() {
+        let mut app = funded_app();
+        let contract = escrow(&mut app);
+
+        app.advance_time(ESCROW_TIME);
+
+        let err = contract
+            .execute(&mut app, "arbiter", &ExecuteMsg::Approve {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::Expired, err.downcast().unwrap());
+        assert_eq!(balance(&app, contract.addr(), "eth"), 100);
+    }
+
+    #[test]
+    fn refund_by_arbiter() {
+        let mut app = funded_app();
+        let contract = escrow(&mut app);
+
+        let resp = contract
+            .execute(&mut app, "arbiter", &ExecuteMsg::Refund {}, &[])
+            .unwrap();
+        assert_eq!(wasm_attr(&resp, "action"), "refund");
+
+        assert_eq!(balance(&app, mock_addr("source"), "eth"), 100);
+        assert_eq!(balance(&app, mock_addr("source"), "atom"), 50);
+
+        let err = contract
+            .execute(&mut app, "arbiter", &ExecuteMsg::Approve {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::Resolved, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn refund_after_expiration() {
+        let mut app = funded_app();
+        let contract = escrow(&mut app);
+
+        let err = contract
+            .execute(&mut app, "source", &ExecuteMsg::Refund {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::NotExpired, err.downcast().unwrap());
+
+        app.advance_time(ESCROW_TIME - 1);
+        let err = contract
+            .execute(&mut app, "source", &ExecuteMsg::Refund {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::NotExpired, err.downcast().unwrap());
+
+        // Once expired, anyone can trigger the refund - funds go back to the source anyway
+        app.advance_time(1);
+        contract
+            .execute(&mut app, "user", &ExecuteMsg::Refund {}, &[])
+            .unwrap();
+
+        assert_eq!(balance(&app, mock_addr("source"), "eth"), 100);
+        assert_eq!(balance(&app, mock_addr("source"), "atom"), 50);
+        assert_eq!(balance(&app, mock_addr("user"), "eth"), 0);
+    }
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not the escrow arbiter")]
+    Unauthorized { sender: Addr },
+    #[error("Escrow must be funded on instantiation")]
+    NoFunds,
+    #[error("Escrow expiration is already in the past")]
+    InvalidExpiration,
+    #[error("Escrow expired")]
+    Expired,
+    #[error("Escrow not expired yet")]
+    NotExpired,
+    #[error("Escrow is already resolved")]
+    Resolved,
+}
@@ -0,0 +1,42 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin};
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Funds sent with the message are locked in the escrow.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub arbiter: String,
+    pub recipient: String,
+    /// After expiration the escrow can't be approved anymore, and anyone can refund it
+    pub expiration: Option<Expiration>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Releases the funds to the recipient, arbiter only and only before expiration
+    Approve {},
+    /// Returns the funds to the source - any time by the arbiter, by anyone after expiration
+    Refund {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct EscrowResp {
+    pub arbiter: Addr,
+    pub recipient: Addr,
+    pub source: Addr,
+    pub expiration: Option<Expiration>,
+    /// Funds still locked, empty once the escrow is resolved
+    pub funds: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(EscrowResp)]
+    Escrow {},
+}
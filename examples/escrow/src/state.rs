@@ -0,0 +1,16 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+use cw_utils::Expiration;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Escrow {
+    pub arbiter: Addr,
+    pub recipient: Addr,
+    /// Funder of the escrow, gets the funds back on refund
+    pub source: Addr,
+    pub expiration: Option<Expiration>,
+}
+
+pub const ESCROW: Item<Escrow> = Item::new("escrow");
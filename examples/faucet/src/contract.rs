@@ -0,0 +1,237 @@
+use crate::error::ContractError;
+use crate::msg::{ConfigResp, ExecuteMsg, InstantiateMsg, NextClaimResp, QueryMsg, TokenMsg};
+use crate::state::{Config, Token, CONFIG, LAST_CLAIMS};
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, WasmMsg,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let token = match msg.token {
+        TokenMsg::Native { denom } => Token::Native { denom },
+        TokenMsg::Cw20 { address } => Token::Cw20 {
+            address: deps.api.addr_validate(&address)?,
+        },
+    };
+
+    let config = Config {
+        token,
+        amount: msg.amount,
+        window: msg.window,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Config {} => to_json_binary(&query::config(deps)?),
+        NextClaim { address } => to_json_binary(&query::next_claim(deps, address)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Claim {} => exec::claim(deps, env, info),
+    }
+}
+
+/// Message sending the dispensed tokens, either with the bank or with the cw20 contract.
+fn send_msg(config: &Config, recipient: &Addr) -> StdResult<CosmosMsg> {
+    let msg = match &config.token {
+        Token::Native { denom } => BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(config.amount.u128(), denom),
+        }
+        .into(),
+        Token::Cw20 { address } => WasmMsg::Execute {
+            contract_addr: address.to_string(),
+            msg: to_json_binary(&cw20_token::msg::ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount: config.amount,
+            })?,
+            funds: vec![],
+        }
+        .into(),
+    };
+
+    Ok(msg)
+}
+
+mod exec {
+    use super::*;
+
+    pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+
+        if let Some(last_claim) = LAST_CLAIMS.may_load(deps.storage, &info.sender)? {
+            let next_claim = last_claim.plus_seconds(config.window);
+            if env.block.time < next_claim {
+                return Err(ContractError::TooEarly { next_claim });
+            }
+        }
+        LAST_CLAIMS.save(deps.storage, &info.sender, &env.block.time)?;
+
+        let resp = Response::new()
+            .add_message(send_msg(&config, &info.sender)?)
+            .add_attribute("action", "claim")
+            .add_attribute("amount", config.amount.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn config(deps: Deps) -> StdResult<ConfigResp> {
+        let config = CONFIG.load(deps.storage)?;
+        Ok(ConfigResp {
+            token: config.token,
+            amount: config.amount,
+            window: config.window,
+        })
+    }
+
+    pub fn next_claim(deps: Deps, address: String) -> StdResult<NextClaimResp> {
+        let address = deps.api.addr_validate(&address)?;
+        let window = CONFIG.load(deps.storage)?.window;
+        let next_claim = LAST_CLAIMS
+            .may_load(deps.storage, &address)?
+            .map(|last_claim| last_claim.plus_seconds(window));
+        Ok(NextClaimResp { next_claim })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Uint128;
+    use cw20_token::msg::{BalanceResp, Cw20Coin};
+    use testing::prelude::*;
+
+    use super::*;
+
+    const WINDOW: u64 = 60 * 60;
+
+    fn faucet(app: &mut App, token: TokenMsg) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            token,
+            amount: Uint128::new(10),
+            window: WINDOW,
+        };
+        Proxy::deploy(app, code, "owner", &msg, "Faucet").unwrap()
+    }
+
+    fn claim(app: &mut App, faucet: &Proxy, sender: &str) -> anyhow::Result<AppResponse> {
+        faucet.execute(app, sender, &ExecuteMsg::Claim {}, &[])
+    }
+
+    #[test]
+    fn native_claims() {
+        let mut app = app();
+        let faucet = faucet(
+            &mut app,
+            TokenMsg::Native {
+                denom: "atom".to_owned(),
+            },
+        );
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, faucet.addr(), coins(30, "atom"))
+        })
+        .unwrap();
+
+        claim(&mut app, &faucet, "alice").unwrap();
+        claim(&mut app, &faucet, "bob").unwrap();
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 10);
+
+        let next_claim = app.block_info().time.plus_seconds(WINDOW);
+        let msg = QueryMsg::NextClaim {
+            address: mock_addr("alice").to_string(),
+        };
+        let resp: NextClaimResp = faucet.query(&app, &msg).unwrap();
+        assert_eq!(resp.next_claim, Some(next_claim));
+
+        app.advance_time(WINDOW - 1);
+        let err = claim(&mut app, &faucet, "alice").unwrap_err();
+        assert_eq!(
+            ContractError::TooEarly { next_claim },
+            err.downcast().unwrap()
+        );
+
+        app.advance_time(1);
+        claim(&mut app, &faucet, "alice").unwrap();
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 20);
+
+        // The faucet is empty now, and the claim is not recorded
+        app.advance_time(WINDOW);
+        claim(&mut app, &faucet, "bob").unwrap_err();
+        let msg = QueryMsg::NextClaim {
+            address: mock_addr("bob").to_string(),
+        };
+        let resp: NextClaimResp = faucet.query(&app, &msg).unwrap();
+        assert_eq!(resp.next_claim, Some(next_claim));
+    }
+
+    #[test]
+    fn cw20_claims() {
+        let mut app = app();
+        let code = ContractWrapper::new(
+            cw20_token::contract::execute,
+            cw20_token::contract::instantiate,
+            cw20_token::contract::query,
+        );
+        let msg = cw20_token::msg::InstantiateMsg {
+            name: "Faucet token".to_owned(),
+            symbol: "FCT".to_owned(),
+            decimals: 6,
+            initial_balances: vec![Cw20Coin {
+                address: addr::owner().to_string(),
+                amount: Uint128::new(100),
+            }],
+        };
+        let token = Proxy::deploy(&mut app, code, "owner", &msg, "Token").unwrap();
+
+        let faucet = faucet(
+            &mut app,
+            TokenMsg::Cw20 {
+                address: token.addr().to_string(),
+            },
+        );
+        let msg = cw20_token::msg::ExecuteMsg::Transfer {
+            recipient: faucet.addr().to_string(),
+            amount: Uint128::new(100),
+        };
+        token.execute(&mut app, "owner", &msg, &[]).unwrap();
+
+        claim(&mut app, &faucet, "alice").unwrap();
+        claim(&mut app, &faucet, "alice").unwrap_err();
+
+        let balance = |app: &App, addr: &Addr| {
+            let msg = cw20_token::msg::QueryMsg::Balance {
+                address: addr.to_string(),
+            };
+            let resp: BalanceResp = token.query(app, &msg).unwrap();
+            resp.balance.u128()
+        };
+        assert_eq!(balance(&app, &mock_addr("alice")), 10);
+        assert_eq!(balance(&app, faucet.addr()), 90);
+    }
+}
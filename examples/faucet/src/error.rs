@@ -0,0 +1,10 @@
+use cosmwasm_std::{StdError, Timestamp};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Next claim is possible at {next_claim}")]
+    TooEarly { next_claim: Timestamp },
+}
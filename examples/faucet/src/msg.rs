@@ -0,0 +1,53 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Timestamp, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::Token;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenMsg {
+    Native { denom: String },
+    Cw20 { address: String },
+}
+
+/// Tokens to dispense have to be sent to the contract separately.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub token: TokenMsg,
+    pub amount: Uint128,
+    pub window: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Sends `amount` tokens to the sender, once per `window`
+    Claim {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ConfigResp {
+    pub token: Token,
+    pub amount: Uint128,
+    pub window: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NextClaimResp {
+    /// `None` if the address never claimed
+    pub next_claim: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(ConfigResp)]
+    Config {},
+    #[returns(NextClaimResp)]
+    NextClaim { address: String },
+}
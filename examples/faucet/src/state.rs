@@ -0,0 +1,23 @@
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Token {
+    Native { denom: String },
+    Cw20 { address: Addr },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub token: Token,
+    /// Amount dispensed with every claim
+    pub amount: Uint128,
+    /// Seconds an address has to wait between claims
+    pub window: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const LAST_CLAIMS: Map<&Addr, Timestamp> = Map::new("last_claims");
@@ -0,0 +1,81 @@
+use cosmwasm_std::{Addr, Coin, CustomMsg, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The part of the chain message extension the contract relies on. Chains expose token factories
+/// with different messages, and derive denoms differently - any `CustomMsg` which can create and
+/// mint tokens can host the contract.
+pub trait TokenFactory: CustomMsg {
+    fn create_denom(subdenom: String) -> Self;
+    fn mint(denom: String, amount: Uint128, recipient: String) -> Self;
+    /// Denom of the token created by `creator`, as the chain derives it.
+    fn denom(creator: &Addr, subdenom: &str) -> String;
+}
+
+/// Message extension of the alpha chain, with flat token factory messages.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AlphaMsg {
+    CreateDenom {
+        subdenom: String,
+    },
+    MintTokens {
+        denom: String,
+        amount: Uint128,
+        mint_to_address: String,
+    },
+}
+
+impl CustomMsg for AlphaMsg {}
+
+impl TokenFactory for AlphaMsg {
+    fn create_denom(subdenom: String) -> Self {
+        AlphaMsg::CreateDenom { subdenom }
+    }
+
+    fn mint(denom: String, amount: Uint128, recipient: String) -> Self {
+        AlphaMsg::MintTokens {
+            denom,
+            amount,
+            mint_to_address: recipient,
+        }
+    }
+
+    fn denom(creator: &Addr, subdenom: &str) -> String {
+        format!("factory/{}/{}", creator, subdenom)
+    }
+}
+
+/// Token factory messages of the beta chain.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BetaTokenMsg {
+    Create { subdenom: String },
+    Mint { amount: Coin, recipient: String },
+}
+
+/// Message extension of the beta chain, grouping messages by module.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BetaMsg {
+    Token(BetaTokenMsg),
+}
+
+impl CustomMsg for BetaMsg {}
+
+impl TokenFactory for BetaMsg {
+    fn create_denom(subdenom: String) -> Self {
+        BetaMsg::Token(BetaTokenMsg::Create { subdenom })
+    }
+
+    fn mint(denom: String, amount: Uint128, recipient: String) -> Self {
+        BetaMsg::Token(BetaTokenMsg::Mint {
+            amount: Coin { denom, amount },
+            recipient,
+        })
+    }
+
+    fn denom(creator: &Addr, subdenom: &str) -> String {
+        format!("{}:{}", creator, subdenom)
+    }
+}
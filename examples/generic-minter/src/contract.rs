@@ -0,0 +1,186 @@
+use crate::chain::TokenFactory;
+use crate::error::ContractError;
+use crate::msg::{DenomResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{DENOM, OWNER};
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Uint128,
+};
+
+pub fn instantiate<C: TokenFactory>(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response<C>> {
+    OWNER.save(deps.storage, &info.sender)?;
+    let denom = C::denom(&env.contract.address, &msg.subdenom);
+    DENOM.save(deps.storage, &denom)?;
+
+    let resp = Response::new()
+        .add_message(CosmosMsg::Custom(C::create_denom(msg.subdenom)))
+        .add_attribute("denom", denom);
+
+    Ok(resp)
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Denom {} => to_json_binary(&query::denom(deps)?),
+    }
+}
+
+pub fn execute<C: TokenFactory>(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response<C>, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Mint { recipient, amount } => exec::mint(deps, info, recipient, amount),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn mint<C: TokenFactory>(
+        deps: DepsMut,
+        info: MessageInfo,
+        recipient: String,
+        amount: Uint128,
+    ) -> Result<Response<C>, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized { owner });
+        }
+        let recipient = deps.api.addr_validate(&recipient)?;
+        let denom = DENOM.load(deps.storage)?;
+
+        let resp = Response::new()
+            .add_message(CosmosMsg::Custom(C::mint(
+                denom,
+                amount,
+                recipient.to_string(),
+            )))
+            .add_attribute("action", "mint")
+            .add_attribute("recipient", recipient)
+            .add_attribute("amount", amount);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn denom(deps: Deps) -> StdResult<DenomResp> {
+        let denom = DENOM.load(deps.storage)?;
+        Ok(DenomResp { denom })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Coin;
+    use cw_multi_test::custom_handler::CachingCustomHandler;
+    use serde::de::DeserializeOwned;
+    use testing::prelude::*;
+
+    use crate::chain::{AlphaMsg, BetaMsg, BetaTokenMsg};
+
+    use super::*;
+
+    /// Runs the same scenario on a chain with the `C` extension, returning the custom messages
+    /// the chain received and the denom of the token.
+    fn mint_on_chain<C>() -> (Vec<C>, String)
+    where
+        C: TokenFactory + DeserializeOwned + 'static,
+    {
+        let handler = CachingCustomHandler::<C, Empty>::new();
+        let mut app = app_builder::<C, Empty>()
+            .with_custom(handler.clone())
+            .build(|_, _, _| {});
+
+        let code = ContractWrapper::new(execute::<C>, instantiate::<C>, query);
+        let code_id = app.store_code(Box::new(code));
+        let minter = app
+            .instantiate_contract(
+                code_id,
+                addr::owner(),
+                &InstantiateMsg {
+                    subdenom: "gold".to_owned(),
+                },
+                &[],
+                "Minter",
+                None,
+            )
+            .unwrap();
+
+        let mint = ExecuteMsg::Mint {
+            recipient: mock_addr("user").to_string(),
+            amount: Uint128::new(100),
+        };
+        let err = app
+            .execute_contract(mock_addr("user"), minter.clone(), &mint, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                owner: addr::owner()
+            },
+            err.downcast().unwrap()
+        );
+        app.execute_contract(addr::owner(), minter.clone(), &mint, &[])
+            .unwrap();
+
+        let resp: DenomResp = app
+            .wrap()
+            .query_wasm_smart(&minter, &QueryMsg::Denom {})
+            .unwrap();
+        assert_eq!(resp.denom, C::denom(&minter, "gold"));
+
+        let execs = handler.state().execs().to_vec();
+        (execs, resp.denom)
+    }
+
+    #[test]
+    fn alpha_chain() {
+        let (execs, denom) = mint_on_chain::<AlphaMsg>();
+        assert!(denom.starts_with("factory/"));
+        assert_eq!(
+            execs,
+            [
+                AlphaMsg::CreateDenom {
+                    subdenom: "gold".to_owned()
+                },
+                AlphaMsg::MintTokens {
+                    denom,
+                    amount: Uint128::new(100),
+                    mint_to_address: mock_addr("user").to_string(),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn beta_chain() {
+        let (execs, denom) = mint_on_chain::<BetaMsg>();
+        assert!(denom.ends_with(":gold"));
+        assert_eq!(
+            execs,
+            [
+                BetaMsg::Token(BetaTokenMsg::Create {
+                    subdenom: "gold".to_owned()
+                }),
+                BetaMsg::Token(BetaTokenMsg::Mint {
+                    amount: Coin::new(100u128, denom),
+                    recipient: mock_addr("user").to_string(),
+                })
+            ]
+        );
+    }
+}
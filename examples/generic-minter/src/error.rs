@@ -0,0 +1,10 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Unauthorized - only {owner} can mint")]
+    Unauthorized { owner: Addr },
+}
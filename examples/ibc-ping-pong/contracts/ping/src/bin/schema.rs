@@ -0,0 +1,20 @@
+use std::env::current_dir;
+
+use cosmwasm_schema::{export_schema_with_title, schema_for, write_api};
+
+use ping::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use ping::packet::{Ack, Packet};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg,
+    }
+
+    // Not entry point messages, so `write_api!` leaves them out
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    export_schema_with_title(&schema_for!(Packet), &out_dir, "Packet");
+    export_schema_with_title(&schema_for!(Ack), &out_dir, "Ack");
+}
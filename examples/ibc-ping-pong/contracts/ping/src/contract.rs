@@ -0,0 +1,96 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, RallyResp};
+use crate::packet::Packet;
+use crate::state::{Rally, Status, CHANNELS};
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+};
+
+pub fn instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Rally { channel } => to_json_binary(&query::rally(deps, channel)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Serve { channel, rounds } => exec::serve(deps, env, channel, rounds),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn serve(
+        deps: DepsMut,
+        env: Env,
+        channel: String,
+        rounds: u64,
+    ) -> Result<Response, ContractError> {
+        let rally = CHANNELS.may_load(deps.storage, &channel)?.ok_or_else(|| {
+            ContractError::UnknownChannel {
+                channel: channel.clone(),
+            }
+        })?;
+        if matches!(
+            rally,
+            Some(Rally {
+                status: Status::InPlay,
+                ..
+            })
+        ) {
+            return Err(ContractError::InPlay { channel });
+        }
+
+        if rounds == 0 {
+            return Err(ContractError::InvalidHit { count: 1, rounds });
+        }
+
+        let packet = Packet { count: 1, rounds };
+
+        let rally = Rally {
+            count: packet.count,
+            rounds,
+            status: Status::InPlay,
+        };
+        CHANNELS.save(deps.storage, &channel, &Some(rally))?;
+
+        let resp = Response::new()
+            .add_message(packet.send(&env, &channel)?)
+            .add_attribute("action", "serve")
+            .add_attribute("channel", channel)
+            .add_attribute("rounds", rounds.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn rally(deps: Deps, channel: String) -> StdResult<RallyResp> {
+        let rally = CHANNELS
+            .may_load(deps.storage, &channel)?
+            .ok_or_else(|| StdError::not_found(format!("channel {}", channel)))?;
+
+        Ok(RallyResp { rally })
+    }
+}
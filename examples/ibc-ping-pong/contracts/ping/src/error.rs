@@ -0,0 +1,20 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+use crate::packet::HandshakeError;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Handshake(#[from] HandshakeError),
+    #[error("Unknown channel {channel}")]
+    UnknownChannel { channel: String },
+    #[error("Rally on {channel} is still in play")]
+    InPlay { channel: String },
+    #[error("No rally in play on {channel}")]
+    NotInPlay { channel: String },
+    #[error("Invalid hit {count} of {rounds}")]
+    InvalidHit { count: u64, rounds: u64 },
+}
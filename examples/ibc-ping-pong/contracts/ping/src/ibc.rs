@@ -0,0 +1,150 @@
+//! IBC entry points of the serving side.
+
+use cosmwasm_std::{
+    from_json, DepsMut, Env, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
+    IbcReceiveResponse, StdResult, Storage,
+};
+
+use crate::error::ContractError;
+use crate::packet::{validate_channel, Ack, Packet};
+use crate::state::{Rally, Status, CHANNELS};
+
+pub fn channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<(), ContractError> {
+    validate_channel(msg.channel(), msg.counterparty_version())?;
+    Ok(())
+}
+
+pub fn channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    validate_channel(msg.channel(), msg.counterparty_version())?;
+    let channel = &msg.channel().endpoint.channel_id;
+    CHANNELS.save(deps.storage, channel, &None)?;
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "channel_connect")
+        .add_attribute("channel", channel);
+
+    Ok(resp)
+}
+
+pub fn channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = &msg.channel().endpoint.channel_id;
+    CHANNELS.remove(deps.storage, channel);
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "channel_close")
+        .add_attribute("channel", channel);
+
+    Ok(resp)
+}
+
+/// Errors are acknowledged instead of failing the relayer's transaction.
+pub fn packet_receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> StdResult<IbcReceiveResponse> {
+    match receive(deps, env, msg) {
+        Ok(resp) => Ok(resp),
+        Err(err) => Ok(IbcReceiveResponse::new(Ack::fail(&err)?)
+            .add_attribute("action", "receive")
+            .add_attribute("error", err.to_string())),
+    }
+}
+
+fn receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let channel = msg.packet.dest.channel_id;
+    let packet: Packet = from_json(&msg.packet.data)?;
+
+    let mut rally = match CHANNELS.may_load(deps.storage, &channel)? {
+        Some(Some(rally)) if rally.status == Status::InPlay => rally,
+        Some(_) => return Err(ContractError::NotInPlay { channel }),
+        None => return Err(ContractError::UnknownChannel { channel }),
+    };
+    // The opponent always returns the ball with the next hit
+    if packet.rounds != rally.rounds || packet.count != rally.count + 1 {
+        return Err(ContractError::InvalidHit {
+            count: packet.count,
+            rounds: packet.rounds,
+        });
+    }
+
+    let mut resp = IbcReceiveResponse::new(Ack::success(packet.count)?)
+        .add_attribute("action", "receive")
+        .add_attribute("count", packet.count.to_string());
+
+    if packet.returned() {
+        let next = packet.next();
+        resp = resp.add_message(next.send(&env, &channel)?);
+        rally.count = next.count;
+    } else {
+        rally.count = packet.count;
+        rally.status = Status::Finished;
+    }
+    CHANNELS.save(deps.storage, &channel, &Some(rally))?;
+
+    Ok(resp)
+}
+
+pub fn packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let resp = IbcBasicResponse::new().add_attribute("action", "ack");
+
+    match from_json(&msg.acknowledgement.data)? {
+        Ack::Result(count) => {
+            let count: u64 = from_json(&count)?;
+            // Pong keeps the last hit, this is the only way to learn the rally is over
+            let channel = msg.original_packet.src.channel_id;
+            if let Some(Some(rally)) = CHANNELS.may_load(deps.storage, &channel)? {
+                if rally.status == Status::InPlay && count == rally.rounds {
+                    end_rally(deps.storage, &channel, Status::Finished)?;
+                }
+            }
+            Ok(resp.add_attribute("count", count.to_string()))
+        }
+        Ack::Error(error) => {
+            let channel = msg.original_packet.src.channel_id;
+            end_rally(deps.storage, &channel, Status::Rejected { error })?;
+            Ok(resp.add_attribute("error", "rejected"))
+        }
+    }
+}
+
+pub fn packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    end_rally(deps.storage, &msg.packet.src.channel_id, Status::Dropped)?;
+
+    Ok(IbcBasicResponse::new().add_attribute("action", "timeout"))
+}
+
+/// Ends the rally on the channel, unless the channel was closed in the meantime.
+fn end_rally(storage: &mut dyn Storage, channel: &str, status: Status) -> StdResult<()> {
+    if let Some(Some(rally)) = CHANNELS.may_load(storage, channel)? {
+        let rally = Rally { status, ..rally };
+        CHANNELS.save(storage, channel, &Some(rally))?;
+    }
+
+    Ok(())
+}
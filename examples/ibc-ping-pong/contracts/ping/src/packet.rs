@@ -0,0 +1,97 @@
+//! Wire format shared by both players.
+//!
+//! Every packet carries the ball: the hit counter and the number of hits the rally lasts. Each
+//! side increments the counter when it hits the ball back, and whoever receives the last hit
+//! keeps the ball - so the counter in the last packet always equals `rounds`.
+
+use cosmwasm_std::{
+    to_json_binary, Binary, Env, IbcChannel, IbcMsg, IbcOrder, IbcTimeout, StdResult,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub const IBC_VERSION: &str = "ping-pong-1";
+pub const IBC_ORDER: IbcOrder = IbcOrder::Unordered;
+/// Seconds after which an unrelayed hit times out and the ball is dropped.
+pub const PACKET_TIMEOUT: u64 = 10 * 60;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Packet {
+    pub count: u64,
+    pub rounds: u64,
+}
+
+impl Packet {
+    /// Whether the receiver of the packet hits the ball back.
+    pub fn returned(&self) -> bool {
+        self.count < self.rounds
+    }
+
+    /// The packet hitting the ball back.
+    pub fn next(&self) -> Self {
+        Self {
+            count: self.count + 1,
+            rounds: self.rounds,
+        }
+    }
+
+    pub fn send(&self, env: &Env, channel: impl Into<String>) -> StdResult<IbcMsg> {
+        Ok(IbcMsg::SendPacket {
+            channel_id: channel.into(),
+            data: to_json_binary(self)?,
+            timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(PACKET_TIMEOUT)),
+        })
+    }
+}
+
+/// Acknowledgement envelope in the ICS-20 format. The result is the received hit counter.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Ack {
+    Result(Binary),
+    Error(String),
+}
+
+impl Ack {
+    pub fn success(count: u64) -> StdResult<Binary> {
+        to_json_binary(&Ack::Result(to_json_binary(&count)?))
+    }
+
+    pub fn fail(err: impl ToString) -> StdResult<Binary> {
+        to_json_binary(&Ack::Error(err.to_string()))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum HandshakeError {
+    #[error("Only {expected:?} channels are supported, got {order:?}")]
+    InvalidOrder { expected: IbcOrder, order: IbcOrder },
+    #[error("Invalid channel version {version}, expected {expected}")]
+    InvalidVersion { version: String, expected: String },
+}
+
+/// Checks the channel on every handshake step, on both sides.
+pub fn validate_channel(
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), HandshakeError> {
+    if channel.order != IBC_ORDER {
+        return Err(HandshakeError::InvalidOrder {
+            expected: IBC_ORDER,
+            order: channel.order.clone(),
+        });
+    }
+
+    for version in std::iter::once(channel.version.as_str()).chain(counterparty_version) {
+        if version != IBC_VERSION {
+            return Err(HandshakeError::InvalidVersion {
+                version: version.to_owned(),
+                expected: IBC_VERSION.to_owned(),
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    InPlay,
+    /// The last hit was received
+    Finished,
+    /// A hit timed out
+    Dropped,
+    /// The opponent failed to receive a hit
+    Rejected {
+        error: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Rally {
+    /// The last hit counter sent or received
+    pub count: u64,
+    pub rounds: u64,
+    pub status: Status,
+}
+
+/// Connected channels, by local channel id, with the rally played over the channel if any.
+/// Closed channels are removed.
+pub const CHANNELS: Map<&str, Option<Rally>> = Map::new("channels");
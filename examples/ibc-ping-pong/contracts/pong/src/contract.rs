@@ -0,0 +1,39 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, StatsResp};
+use crate::state::CHANNELS;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+pub fn instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Stats { channel } => to_json_binary(&query::stats(deps, channel)?),
+    }
+}
+
+pub fn execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {}
+}
+
+mod query {
+    use super::*;
+
+    pub fn stats(deps: Deps, channel: String) -> StdResult<StatsResp> {
+        let stats = CHANNELS.load(deps.storage, &channel)?;
+        Ok(StatsResp { stats })
+    }
+}
@@ -0,0 +1,15 @@
+use cosmwasm_std::StdError;
+use ping::packet::HandshakeError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Handshake(#[from] HandshakeError),
+    #[error("Unknown channel {channel}")]
+    UnknownChannel { channel: String },
+    #[error("Invalid hit {count} of {rounds}")]
+    InvalidHit { count: u64, rounds: u64 },
+}
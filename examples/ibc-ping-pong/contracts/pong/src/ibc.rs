@@ -0,0 +1,147 @@
+//! IBC entry points of the returning side.
+
+use cosmwasm_std::{
+    from_json, DepsMut, Env, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
+    IbcChannelOpenMsg, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
+    IbcReceiveResponse, StdResult,
+};
+use ping::packet::{validate_channel, Ack, Packet};
+
+use crate::error::ContractError;
+use crate::state::{ChannelStats, CHANNELS};
+
+pub fn channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<(), ContractError> {
+    validate_channel(msg.channel(), msg.counterparty_version())?;
+    Ok(())
+}
+
+pub fn channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    validate_channel(msg.channel(), msg.counterparty_version())?;
+    let channel = &msg.channel().endpoint.channel_id;
+    CHANNELS.save(deps.storage, channel, &ChannelStats::default())?;
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "channel_connect")
+        .add_attribute("channel", channel);
+
+    Ok(resp)
+}
+
+pub fn channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = &msg.channel().endpoint.channel_id;
+    CHANNELS.remove(deps.storage, channel);
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "channel_close")
+        .add_attribute("channel", channel);
+
+    Ok(resp)
+}
+
+/// Errors are acknowledged instead of failing the relayer's transaction.
+pub fn packet_receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> StdResult<IbcReceiveResponse> {
+    match receive(deps, env, msg) {
+        Ok(resp) => Ok(resp),
+        Err(err) => Ok(IbcReceiveResponse::new(Ack::fail(&err)?)
+            .add_attribute("action", "receive")
+            .add_attribute("error", err.to_string())),
+    }
+}
+
+fn receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let channel = msg.packet.dest.channel_id;
+    let packet: Packet = from_json(&msg.packet.data)?;
+
+    let mut stats = CHANNELS.may_load(deps.storage, &channel)?.ok_or_else(|| {
+        ContractError::UnknownChannel {
+            channel: channel.clone(),
+        }
+    })?;
+    // The server hits odd counters, so these are the only ones pong can receive
+    if packet.count.is_multiple_of(2) || packet.count > packet.rounds {
+        return Err(ContractError::InvalidHit {
+            count: packet.count,
+            rounds: packet.rounds,
+        });
+    }
+
+    stats.received += 1;
+    stats.last_count = Some(packet.count);
+
+    let mut resp = IbcReceiveResponse::new(Ack::success(packet.count)?)
+        .add_attribute("action", "receive")
+        .add_attribute("count", packet.count.to_string());
+
+    if packet.returned() {
+        resp = resp.add_message(packet.next().send(&env, &channel)?);
+        stats.returned += 1;
+    }
+    CHANNELS.save(deps.storage, &channel, &stats)?;
+
+    Ok(resp)
+}
+
+pub fn packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let resp = IbcBasicResponse::new().add_attribute("action", "ack");
+
+    match from_json(&msg.acknowledgement.data)? {
+        Ack::Result(count) => {
+            let count: u64 = from_json(&count)?;
+            Ok(resp.add_attribute("count", count.to_string()))
+        }
+        Ack::Error(error) => {
+            update_stats(deps, &msg.original_packet.src.channel_id, |stats| {
+                stats.rejected += 1
+            })?;
+            Ok(resp.add_attribute("error", error))
+        }
+    }
+}
+
+pub fn packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    update_stats(deps, &msg.packet.src.channel_id, |stats| stats.dropped += 1)?;
+
+    Ok(IbcBasicResponse::new().add_attribute("action", "timeout"))
+}
+
+/// Updates the channel stats, unless the channel was closed in the meantime.
+fn update_stats(
+    deps: DepsMut,
+    channel: &str,
+    update: impl FnOnce(&mut ChannelStats),
+) -> StdResult<()> {
+    if let Some(mut stats) = CHANNELS.may_load(deps.storage, channel)? {
+        update(&mut stats);
+        CHANNELS.save(deps.storage, channel, &stats)?;
+    }
+
+    Ok(())
+}
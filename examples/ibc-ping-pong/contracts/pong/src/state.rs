@@ -0,0 +1,21 @@
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelStats {
+    /// Hits received
+    pub received: u64,
+    /// Hits returned
+    pub returned: u64,
+    /// Returned hits which timed out
+    pub dropped: u64,
+    /// Returned hits the server failed to receive
+    pub rejected: u64,
+    /// The last hit counter received
+    pub last_count: Option<u64>,
+}
+
+/// Connected channels, by local channel id. Closed channels are removed.
+pub const CHANNELS: Map<&str, ChannelStats> = Map::new("channels");
@@ -0,0 +1,4 @@
+//! End-to-end scenarios for the IBC ping-pong example.
+//!
+//! This crate has no code of its own - it only exists to host the `tests/` suite, which connects
+//! the two contracts with a scripted relayer and lets them play.
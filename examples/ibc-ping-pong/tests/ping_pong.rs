@@ -0,0 +1,299 @@
+//! Ping and pong on two mock chains, connected by a scripted relayer.
+
+use std::collections::VecDeque;
+
+use cosmwasm_std::testing::{
+    message_info, mock_dependencies, mock_env, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    from_json, Addr, CosmosMsg, IbcAcknowledgement, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcEndpoint, IbcMsg, IbcPacket, IbcPacketAckMsg,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, OwnedDeps, SubMsg,
+};
+use ping::msg::{ExecuteMsg, QueryMsg, RallyResp};
+use ping::packet::{Ack, Packet, IBC_ORDER, IBC_VERSION};
+use ping::state::{Rally, Status};
+use pong::msg::{QueryMsg as PongQueryMsg, StatsResp};
+
+type Chain = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Side {
+    Ping,
+    Pong,
+}
+
+const PING_CHANNEL: &str = "channel-0";
+const PONG_CHANNEL: &str = "channel-5";
+
+fn endpoint(side: Side) -> IbcEndpoint {
+    match side {
+        Side::Ping => IbcEndpoint {
+            port_id: "wasm.ping".to_owned(),
+            channel_id: PING_CHANNEL.to_owned(),
+        },
+        Side::Pong => IbcEndpoint {
+            port_id: "wasm.pong".to_owned(),
+            channel_id: PONG_CHANNEL.to_owned(),
+        },
+    }
+}
+
+fn channel(side: Side) -> IbcChannel {
+    let other = match side {
+        Side::Ping => Side::Pong,
+        Side::Pong => Side::Ping,
+    };
+    IbcChannel::new(
+        endpoint(side),
+        endpoint(other),
+        IBC_ORDER,
+        IBC_VERSION,
+        "connection-0",
+    )
+}
+
+fn relayer() -> Addr {
+    MockApi::default().addr_make("relayer")
+}
+
+/// Both chains and the packets in flight between them, oldest first.
+struct Relayer {
+    ping: Chain,
+    pong: Chain,
+    pending: VecDeque<(Side, IbcPacket)>,
+    sequence: u64,
+}
+
+impl Relayer {
+    /// Instantiates both contracts and runs the channel handshake, started by ping.
+    fn connect() -> Self {
+        let mut ping = mock_dependencies();
+        let mut pong = mock_dependencies();
+        let env = mock_env();
+        let info = message_info(&MockApi::default().addr_make("owner"), &[]);
+
+        ping::instantiate(
+            ping.as_mut(),
+            env.clone(),
+            info.clone(),
+            ping::msg::InstantiateMsg {},
+        )
+        .unwrap();
+        pong::instantiate(
+            pong.as_mut(),
+            env.clone(),
+            info,
+            pong::msg::InstantiateMsg {},
+        )
+        .unwrap();
+
+        let open = IbcChannelOpenMsg::new_init(channel(Side::Ping));
+        ping::ibc_channel_open(ping.as_mut(), env.clone(), open).unwrap();
+        let open = IbcChannelOpenMsg::new_try(channel(Side::Pong), IBC_VERSION);
+        pong::ibc_channel_open(pong.as_mut(), env.clone(), open).unwrap();
+        let connect = IbcChannelConnectMsg::new_ack(channel(Side::Ping), IBC_VERSION);
+        ping::ibc_channel_connect(ping.as_mut(), env.clone(), connect).unwrap();
+        let connect = IbcChannelConnectMsg::new_confirm(channel(Side::Pong));
+        pong::ibc_channel_connect(pong.as_mut(), env, connect).unwrap();
+
+        Self {
+            ping,
+            pong,
+            pending: VecDeque::new(),
+            sequence: 0,
+        }
+    }
+
+    fn serve(&mut self, rounds: u64) -> Result<(), ping::error::ContractError> {
+        let msg = ExecuteMsg::Serve {
+            channel: PING_CHANNEL.to_owned(),
+            rounds,
+        };
+        let info = message_info(&MockApi::default().addr_make("user"), &[]);
+        let resp = ping::execute(self.ping.as_mut(), mock_env(), info, msg)?;
+        self.queue(Side::Ping, &resp.messages);
+        Ok(())
+    }
+
+    /// Picks up packets sent by the side.
+    fn queue(&mut self, from: Side, messages: &[SubMsg]) {
+        for msg in messages {
+            if let CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id,
+                data,
+                timeout,
+            }) = &msg.msg
+            {
+                assert_eq!(channel_id, &endpoint(from).channel_id);
+                self.sequence += 1;
+                let dest = match from {
+                    Side::Ping => endpoint(Side::Pong),
+                    Side::Pong => endpoint(Side::Ping),
+                };
+                let packet = IbcPacket::new(
+                    data.clone(),
+                    endpoint(from),
+                    dest,
+                    self.sequence,
+                    timeout.clone(),
+                );
+                self.pending.push_back((from, packet));
+            }
+        }
+    }
+
+    /// Delivers the oldest packet and its acknowledgement, returns the packet.
+    fn relay(&mut self) -> Option<Packet> {
+        let (from, packet) = self.pending.pop_front()?;
+        let env = mock_env();
+
+        let recv = IbcPacketReceiveMsg::new(packet.clone(), relayer());
+        let resp = match from {
+            Side::Ping => pong::ibc_packet_receive(self.pong.as_mut(), env.clone(), recv),
+            Side::Pong => ping::ibc_packet_receive(self.ping.as_mut(), env.clone(), recv),
+        }
+        .unwrap();
+        let to = match from {
+            Side::Ping => Side::Pong,
+            Side::Pong => Side::Ping,
+        };
+        self.queue(to, &resp.messages);
+
+        let data = from_json(&packet.data).unwrap();
+        let ack = IbcAcknowledgement::new(resp.acknowledgement.unwrap());
+        let ack = IbcPacketAckMsg::new(ack, packet, relayer());
+        match from {
+            Side::Ping => {
+                ping::ibc_packet_ack(self.ping.as_mut(), env, ack).unwrap();
+            }
+            Side::Pong => {
+                pong::ibc_packet_ack(self.pong.as_mut(), env, ack).unwrap();
+            }
+        }
+
+        Some(data)
+    }
+
+    /// Relays until no packets are left, returns the hit counters in the relayed order.
+    fn relay_all(&mut self) -> Vec<u64> {
+        std::iter::from_fn(|| self.relay())
+            .map(|packet| packet.count)
+            .collect()
+    }
+
+    /// Lets the oldest packet time out.
+    fn time_out(&mut self) {
+        let (from, packet) = self.pending.pop_front().unwrap();
+        let msg = IbcPacketTimeoutMsg::new(packet, relayer());
+        match from {
+            Side::Ping => {
+                ping::ibc_packet_timeout(self.ping.as_mut(), mock_env(), msg).unwrap();
+            }
+            Side::Pong => {
+                pong::ibc_packet_timeout(self.pong.as_mut(), mock_env(), msg).unwrap();
+            }
+        }
+    }
+
+    fn rally(&self) -> Rally {
+        let msg = QueryMsg::Rally {
+            channel: PING_CHANNEL.to_owned(),
+        };
+        let resp = ping::query(self.ping.as_ref(), mock_env(), msg).unwrap();
+        from_json::<RallyResp>(&resp).unwrap().rally.unwrap()
+    }
+
+    fn pong_stats(&self) -> pong::state::ChannelStats {
+        let msg = PongQueryMsg::Stats {
+            channel: PONG_CHANNEL.to_owned(),
+        };
+        let resp = pong::query(self.pong.as_ref(), mock_env(), msg).unwrap();
+        from_json::<StatsResp>(&resp).unwrap().stats
+    }
+}
+
+#[test]
+fn full_rally() {
+    let mut relayer = Relayer::connect();
+
+    // Odd number of hits - pong receives the last one, ping learns from the acknowledgement
+    relayer.serve(5).unwrap();
+    assert_eq!(relayer.relay_all(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(
+        relayer.rally(),
+        Rally {
+            count: 5,
+            rounds: 5,
+            status: Status::Finished,
+        }
+    );
+
+    let stats = relayer.pong_stats();
+    assert_eq!((stats.received, stats.returned), (3, 2));
+    assert_eq!(stats.last_count, Some(5));
+
+    // Even number of hits - ping receives the last one
+    relayer.serve(4).unwrap();
+    assert_eq!(relayer.relay_all(), vec![1, 2, 3, 4]);
+    assert_eq!(relayer.rally().status, Status::Finished);
+    assert_eq!(relayer.rally().count, 4);
+}
+
+#[test]
+fn dropped_ball() {
+    let mut relayer = Relayer::connect();
+
+    relayer.serve(10).unwrap();
+    let err = relayer.serve(10).unwrap_err();
+    assert_eq!(
+        err,
+        ping::error::ContractError::InPlay {
+            channel: PING_CHANNEL.to_owned()
+        }
+    );
+
+    relayer.relay();
+    relayer.relay();
+    // Ping's third hit is never relayed
+    relayer.time_out();
+    assert_eq!(relayer.rally().status, Status::Dropped);
+
+    // The dropped rally can be replaced
+    relayer.serve(2).unwrap();
+    relayer.relay();
+    // Pong's return is never relayed
+    relayer.time_out();
+    assert_eq!(relayer.pong_stats().dropped, 1);
+}
+
+#[test]
+fn rejected_hit() {
+    let mut relayer = Relayer::connect();
+    relayer.serve(3).unwrap();
+
+    // Pong closes its end while the ball is in the air
+    let close = IbcChannelCloseMsg::new_init(channel(Side::Pong));
+    pong::ibc_channel_close(relayer.pong.as_mut(), mock_env(), close).unwrap();
+
+    assert_eq!(relayer.relay_all(), vec![1]);
+    let error = pong::error::ContractError::UnknownChannel {
+        channel: PONG_CHANNEL.to_owned(),
+    }
+    .to_string();
+    assert_eq!(relayer.rally().status, Status::Rejected { error });
+}
+
+#[test]
+fn ack_format() {
+    // Acknowledgements use the ICS-20 JSON envelope
+    let ack: Ack = from_json(Ack::success(3).unwrap()).unwrap();
+    assert_eq!(
+        ack,
+        Ack::Result(cosmwasm_std::to_json_binary(&3u64).unwrap())
+    );
+    assert_eq!(
+        Ack::fail("boom").unwrap().as_slice(),
+        br#"{"error":"boom"}"#
+    );
+}
@@ -0,0 +1,109 @@
+use crate::error::ContractError;
+use crate::msg::{ChannelResp, ChannelsResp, ExecuteMsg, InstantiateMsg, Packet, QueryMsg};
+use crate::state::{CHANNELS, SKEW};
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, IbcMsg, IbcTimeout, MessageInfo, Order, Response,
+    StdResult,
+};
+
+/// Seconds after which an unrelayed greeting times out.
+pub const PACKET_TIMEOUT: u64 = 60 * 60;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    SKEW.save(deps.storage, &msg.skew)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Channels {} => to_json_binary(&query::channels(deps)?),
+        Channel { id } => to_json_binary(&query::channel(deps, id)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Greet { channel, message } => exec::greet(deps, env, info, channel, message),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn greet(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        channel: String,
+        message: String,
+    ) -> Result<Response, ContractError> {
+        // The receiving side rejects it anyway, failing early saves the relaying fees
+        if message.is_empty() {
+            return Err(ContractError::EmptyGreeting);
+        }
+
+        let mut channel_info = CHANNELS.may_load(deps.storage, &channel)?.ok_or_else(|| {
+            ContractError::UnknownChannel {
+                channel: channel.clone(),
+            }
+        })?;
+        channel_info.stats.sent += 1;
+        CHANNELS.save(deps.storage, &channel, &channel_info)?;
+
+        let packet = Packet::Greet {
+            sender: info.sender.to_string(),
+            message,
+            sent_at: env.block.time,
+        };
+        let msg = IbcMsg::SendPacket {
+            channel_id: channel.clone(),
+            data: to_json_binary(&packet)?,
+            timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(PACKET_TIMEOUT)),
+        };
+
+        let resp = Response::new()
+            .add_message(msg)
+            .add_attribute("action", "greet")
+            .add_attribute("channel", channel);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn channels(deps: Deps) -> StdResult<ChannelsResp> {
+        let channels = CHANNELS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+
+        Ok(ChannelsResp { channels })
+    }
+
+    pub fn channel(deps: Deps, id: String) -> StdResult<ChannelResp> {
+        let info = CHANNELS.load(deps.storage, &id)?;
+
+        Ok(ChannelResp {
+            id,
+            counterparty: info.counterparty,
+            stats: info.stats,
+            last_greeting: info.last_greeting,
+        })
+    }
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{IbcOrder, StdError, Timestamp};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Only {expected:?} channels are supported, got {order:?}")]
+    InvalidOrder { expected: IbcOrder, order: IbcOrder },
+    #[error("Invalid channel version {version}, expected {expected}")]
+    InvalidVersion { version: String, expected: String },
+    #[error("Unknown channel {channel}")]
+    UnknownChannel { channel: String },
+    #[error("Greeting cannot be empty")]
+    EmptyGreeting,
+    #[error("Timestamp {ts} is too old")]
+    TimestampTooOld { ts: Timestamp },
+    #[error("Timestamp {ts} is too far in the future")]
+    TimestampInFuture { ts: Timestamp },
+}
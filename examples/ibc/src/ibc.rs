@@ -0,0 +1,424 @@
+//! IBC entry points.
+//!
+//! The handshake accepts only unordered channels speaking [`IBC_VERSION`] - on an ordered
+//! channel a single timed out greeting would close it. Once connected, both sides may greet each
+//! other: the receiver acknowledges a greeting with a reply, and the sender counts how its
+//! greetings ended up - acknowledged, failed or timed out.
+//!
+//! Greetings carry the block time of the sending chain. The receiver only accepts them within
+//! its [`SkewWindow`](crate::time::SkewWindow), so a replayed greeting or one from a chain with a broken clock is
+//! acknowledged with an error instead of being recorded.
+
+use cosmwasm_std::{
+    from_json, to_json_binary, DepsMut, Env, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, StdResult,
+};
+
+use crate::error::ContractError;
+use crate::msg::{Ack, GreetAck, Packet};
+use crate::state::{ChannelInfo, ChannelStats, Greeting, CHANNELS, SKEW};
+use crate::time::accept_timestamp;
+
+pub const IBC_VERSION: &str = "greeter-1";
+pub const IBC_ORDER: IbcOrder = IbcOrder::Unordered;
+
+pub fn channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<(), ContractError> {
+    validate_channel(msg.channel(), msg.counterparty_version())
+}
+
+pub fn channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+    validate_channel(channel, msg.counterparty_version())?;
+
+    let info = ChannelInfo {
+        counterparty: channel.counterparty_endpoint.clone(),
+        stats: ChannelStats::default(),
+        last_greeting: None,
+    };
+    CHANNELS.save(deps.storage, &channel.endpoint.channel_id, &info)?;
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "channel_connect")
+        .add_attribute("channel", &channel.endpoint.channel_id);
+
+    Ok(resp)
+}
+
+pub fn channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = &msg.channel().endpoint.channel_id;
+    CHANNELS.remove(deps.storage, channel);
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "channel_close")
+        .add_attribute("channel", channel);
+
+    Ok(resp)
+}
+
+/// Never fails: a failing receive would revert the relayer's transaction and leave the packet
+/// to time out, so errors are reported back in a failure acknowledgement instead.
+pub fn packet_receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> StdResult<IbcReceiveResponse> {
+    let resp = match receive(deps, env, msg) {
+        Ok(resp) => resp,
+        Err(err) => IbcReceiveResponse::new(to_json_binary(&Ack::Error(err.to_string()))?)
+            .add_attribute("action", "receive")
+            .add_attribute("error", err.to_string()),
+    };
+
+    Ok(resp)
+}
+
+fn receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let channel = msg.packet.dest.channel_id;
+    let Packet::Greet {
+        sender,
+        message,
+        sent_at,
+    } = from_json(&msg.packet.data)?;
+    if message.is_empty() {
+        return Err(ContractError::EmptyGreeting);
+    }
+    let sent_at = accept_timestamp(env.block.time, sent_at, &SKEW.load(deps.storage)?)?;
+
+    let mut info = load_channel(&deps, &channel)?;
+    info.stats.received += 1;
+    info.last_greeting = Some(Greeting {
+        sender: sender.clone(),
+        message,
+        sent_at,
+    });
+    CHANNELS.save(deps.storage, &channel, &info)?;
+
+    let ack = GreetAck {
+        reply: format!("Hello {}", sender),
+    };
+    let resp = IbcReceiveResponse::new(to_json_binary(&Ack::Result(to_json_binary(&ack)?))?)
+        .add_attribute("action", "receive")
+        .add_attribute("sender", sender);
+
+    Ok(resp)
+}
+
+pub fn packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.original_packet.src.channel_id;
+    let mut info = load_channel(&deps, &channel)?;
+
+    let resp = IbcBasicResponse::new().add_attribute("action", "ack");
+    let resp = match from_json(&msg.acknowledgement.data)? {
+        Ack::Result(data) => {
+            let ack: GreetAck = from_json(&data)?;
+            info.stats.acked += 1;
+            resp.add_attribute("reply", ack.reply)
+        }
+        Ack::Error(err) => {
+            info.stats.failed += 1;
+            resp.add_attribute("error", err)
+        }
+    };
+    CHANNELS.save(deps.storage, &channel, &info)?;
+
+    Ok(resp)
+}
+
+pub fn packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.packet.src.channel_id;
+    let mut info = load_channel(&deps, &channel)?;
+    info.stats.timed_out += 1;
+    CHANNELS.save(deps.storage, &channel, &info)?;
+
+    Ok(IbcBasicResponse::new().add_attribute("action", "timeout"))
+}
+
+fn validate_channel(
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.order != IBC_ORDER {
+        return Err(ContractError::InvalidOrder {
+            expected: IBC_ORDER,
+            order: channel.order.clone(),
+        });
+    }
+
+    for version in std::iter::once(channel.version.as_str()).chain(counterparty_version) {
+        if version != IBC_VERSION {
+            return Err(ContractError::InvalidVersion {
+                version: version.to_owned(),
+                expected: IBC_VERSION.to_owned(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn load_channel(deps: &DepsMut, channel: &str) -> Result<ChannelInfo, ContractError> {
+    CHANNELS
+        .may_load(deps.storage, channel)?
+        .ok_or_else(|| ContractError::UnknownChannel {
+            channel: channel.to_owned(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{
+        message_info, mock_dependencies, mock_env, mock_ibc_channel_close_init,
+        mock_ibc_channel_connect_ack, mock_ibc_channel_open_init, mock_ibc_channel_open_try,
+        mock_ibc_packet_ack, mock_ibc_packet_recv, mock_ibc_packet_timeout, MockApi, MockQuerier,
+        MockStorage,
+    };
+    use cosmwasm_std::{CosmosMsg, IbcAcknowledgement, IbcMsg, OwnedDeps, Timestamp};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::msg::{ChannelResp, ChannelsResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+    use crate::time::SkewWindow;
+
+    use super::*;
+
+    type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    const WINDOW: SkewWindow = SkewWindow {
+        past: 600,
+        future: 5,
+    };
+
+    fn connected(channel: &str) -> Deps {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg { skew: WINDOW },
+        )
+        .unwrap();
+
+        let open = mock_ibc_channel_open_init(channel, IBC_ORDER, IBC_VERSION);
+        channel_open(deps.as_mut(), mock_env(), open).unwrap();
+        let connect = mock_ibc_channel_connect_ack(channel, IBC_ORDER, IBC_VERSION);
+        channel_connect(deps.as_mut(), mock_env(), connect).unwrap();
+
+        deps
+    }
+
+    fn channel_info(deps: &Deps, id: &str) -> ChannelResp {
+        let msg = QueryMsg::Channel { id: id.to_owned() };
+        from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    }
+
+    fn greet_packet(message: &str) -> Packet {
+        greet_packet_at(message, mock_env().block.time)
+    }
+
+    fn greet_packet_at(message: &str, sent_at: Timestamp) -> Packet {
+        Packet::Greet {
+            sender: MockApi::default().addr_make("user").into_string(),
+            message: message.to_owned(),
+            sent_at,
+        }
+    }
+
+    #[test]
+    fn handshake() {
+        let mut deps = mock_dependencies();
+
+        let open = mock_ibc_channel_open_init("channel-1", IbcOrder::Ordered, IBC_VERSION);
+        let err = channel_open(deps.as_mut(), mock_env(), open).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidOrder {
+                expected: IbcOrder::Unordered,
+                order: IbcOrder::Ordered,
+            }
+        );
+
+        let open = mock_ibc_channel_open_try("channel-1", IBC_ORDER, "ics20-1");
+        let err = channel_open(deps.as_mut(), mock_env(), open).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidVersion {
+                version: "ics20-1".to_owned(),
+                expected: IBC_VERSION.to_owned(),
+            }
+        );
+
+        let open = mock_ibc_channel_open_try("channel-1", IBC_ORDER, IBC_VERSION);
+        channel_open(deps.as_mut(), mock_env(), open).unwrap();
+        let connect = mock_ibc_channel_connect_ack("channel-1", IBC_ORDER, IBC_VERSION);
+        channel_connect(deps.as_mut(), mock_env(), connect).unwrap();
+
+        let channels: ChannelsResp =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::Channels {}).unwrap()).unwrap();
+        assert_eq!(channels.channels, vec!["channel-1".to_owned()]);
+        assert_eq!(
+            channel_info(&deps, "channel-1").counterparty.channel_id,
+            "channel-7"
+        );
+
+        let close = mock_ibc_channel_close_init("channel-1", IBC_ORDER, IBC_VERSION);
+        channel_close(deps.as_mut(), mock_env(), close).unwrap();
+        let channels: ChannelsResp =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::Channels {}).unwrap()).unwrap();
+        assert!(channels.channels.is_empty());
+    }
+
+    #[test]
+    fn greeting_round_trip() {
+        let mut deps = connected("channel-1");
+        let user = deps.api.addr_make("user");
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            ExecuteMsg::Greet {
+                channel: "channel-2".to_owned(),
+                message: "Hi".to_owned(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::UnknownChannel {
+                channel: "channel-2".to_owned()
+            }
+        );
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            ExecuteMsg::Greet {
+                channel: "channel-1".to_owned(),
+                message: "Hi".to_owned(),
+            },
+        )
+        .unwrap();
+        let data = match &resp.messages[0].msg {
+            CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id, data, ..
+            }) => {
+                assert_eq!(channel_id, "channel-1");
+                data.clone()
+            }
+            msg => panic!("Unexpected message: {:?}", msg),
+        };
+        assert_eq!(from_json::<Packet>(&data).unwrap(), greet_packet("Hi"));
+
+        // The receiving side - the same contract is fine, it only cares about the channel
+        let recv = mock_ibc_packet_recv("channel-1", &greet_packet("Hi")).unwrap();
+        let resp = packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+        let ack: Ack = from_json(resp.acknowledgement.unwrap()).unwrap();
+        let reply = GreetAck {
+            reply: format!("Hello {}", user),
+        };
+        assert_eq!(ack, Ack::Result(to_json_binary(&reply).unwrap()));
+
+        let info = channel_info(&deps, "channel-1");
+        assert_eq!(info.stats.received, 1);
+        assert_eq!(
+            info.last_greeting,
+            Some(Greeting {
+                sender: user.into_string(),
+                message: "Hi".to_owned(),
+                sent_at: mock_env().block.time,
+            })
+        );
+
+        let ack = IbcAcknowledgement::new(to_json_binary(&ack).unwrap());
+        let msg = mock_ibc_packet_ack("channel-1", &greet_packet("Hi"), ack).unwrap();
+        packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+
+        let stats = channel_info(&deps, "channel-1").stats;
+        assert_eq!((stats.sent, stats.acked, stats.failed), (1, 1, 0));
+    }
+
+    #[test]
+    fn failures() {
+        let mut deps = connected("channel-1");
+
+        // A bad packet is acknowledged with an error, the relayer's transaction still succeeds
+        let recv = mock_ibc_packet_recv("channel-1", &greet_packet("")).unwrap();
+        let resp = packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+        let ack: Ack = from_json(resp.acknowledgement.unwrap()).unwrap();
+        assert_eq!(ack, Ack::Error(ContractError::EmptyGreeting.to_string()));
+        assert_eq!(channel_info(&deps, "channel-1").stats.received, 0);
+
+        let ack = IbcAcknowledgement::new(to_json_binary(&ack).unwrap());
+        let msg = mock_ibc_packet_ack("channel-1", &greet_packet(""), ack).unwrap();
+        packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+
+        let msg = mock_ibc_packet_timeout("channel-1", &greet_packet("Hi")).unwrap();
+        packet_timeout(deps.as_mut(), mock_env(), msg).unwrap();
+
+        let stats = channel_info(&deps, "channel-1").stats;
+        assert_eq!((stats.acked, stats.failed, stats.timed_out), (0, 1, 1));
+    }
+
+    #[test]
+    fn counterparty_clock_skew() {
+        let mut deps = connected("channel-1");
+        let now = mock_env().block.time;
+
+        // Replayed greeting
+        let sent_at = now.minus_seconds(WINDOW.past).minus_nanos(1);
+        let recv = mock_ibc_packet_recv("channel-1", &greet_packet_at("Hi", sent_at)).unwrap();
+        let resp = packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+        let ack: Ack = from_json(resp.acknowledgement.unwrap()).unwrap();
+        let err = ContractError::TimestampTooOld { ts: sent_at };
+        assert_eq!(ack, Ack::Error(err.to_string()));
+
+        let sent_at = now.plus_seconds(WINDOW.future).plus_nanos(1);
+        let recv = mock_ibc_packet_recv("channel-1", &greet_packet_at("Hi", sent_at)).unwrap();
+        let resp = packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+        let ack: Ack = from_json(resp.acknowledgement.unwrap()).unwrap();
+        let err = ContractError::TimestampInFuture { ts: sent_at };
+        assert_eq!(ack, Ack::Error(err.to_string()));
+        assert_eq!(channel_info(&deps, "channel-1").stats.received, 0);
+
+        // Slightly ahead of the local clock is recorded as now
+        let sent_at = now.plus_seconds(WINDOW.future);
+        let recv = mock_ibc_packet_recv("channel-1", &greet_packet_at("Hi", sent_at)).unwrap();
+        packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+        let info = channel_info(&deps, "channel-1");
+        assert_eq!(info.stats.received, 1);
+        assert_eq!(info.last_greeting.unwrap().sent_at, now);
+
+        let sent_at = now.minus_seconds(WINDOW.past);
+        let recv = mock_ibc_packet_recv("channel-1", &greet_packet_at("Hi", sent_at)).unwrap();
+        packet_receive(deps.as_mut(), mock_env(), recv).unwrap();
+        let info = channel_info(&deps, "channel-1");
+        assert_eq!(info.stats.received, 2);
+        assert_eq!(info.last_greeting.unwrap().sent_at, sent_at);
+    }
+}
@@ -0,0 +1,75 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Binary, IbcEndpoint, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{ChannelStats, Greeting};
+use crate::time::SkewWindow;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    /// Tolerated difference between the counterparty's clock and the local one
+    pub skew: SkewWindow,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Sends the greeting to the contract on the other side of the channel
+    Greet { channel: String, message: String },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelResp {
+    pub id: String,
+    pub counterparty: IbcEndpoint,
+    pub stats: ChannelStats,
+    /// Most recent greeting received over the channel
+    pub last_greeting: Option<Greeting>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelsResp {
+    pub channels: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Connected channels, ordered by id
+    #[returns(ChannelsResp)]
+    Channels {},
+    #[returns(ChannelResp)]
+    Channel { id: String },
+}
+
+/// Data of every packet sent over a greeter channel.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Packet {
+    Greet {
+        sender: String,
+        message: String,
+        /// Block time of the sending chain
+        sent_at: Timestamp,
+    },
+}
+
+/// Successful `Packet::Greet` acknowledgement result.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct GreetAck {
+    pub reply: String,
+}
+
+/// Acknowledgement envelope, in the format ICS-20 uses: `{"result": <base64>}` on success and
+/// `{"error": <message>}` on failure, so relayers and explorers can tell them apart.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Ack {
+    Result(Binary),
+    Error(String),
+}
@@ -0,0 +1,40 @@
+use cosmwasm_std::{IbcEndpoint, Timestamp};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::time::SkewWindow;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelStats {
+    pub sent: u64,
+    pub received: u64,
+    /// Sent packets acknowledged with a result
+    pub acked: u64,
+    /// Sent packets acknowledged with an error
+    pub failed: u64,
+    pub timed_out: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Greeting {
+    pub sender: String,
+    pub message: String,
+    /// Sending time as reported by the counterparty, clamped to the local clock
+    pub sent_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ChannelInfo {
+    pub counterparty: IbcEndpoint,
+    pub stats: ChannelStats,
+    pub last_greeting: Option<Greeting>,
+}
+
+pub const SKEW: Item<SkewWindow> = Item::new("skew");
+
+/// Channels with a finished handshake, by local channel id. Closed channels are removed.
+pub const CHANNELS: Map<&str, ChannelInfo> = Map::new("channels");
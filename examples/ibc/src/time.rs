@@ -0,0 +1,111 @@
+//! Tolerance for timestamps reported by a counterparty chain.
+//!
+//! Clocks of two chains never agree exactly, so a timestamp carried in a packet may be slightly
+//! ahead of the local block time. Timestamps within the window are accepted, and the ones ahead
+//! of the local clock are clamped to it, so time-dependent state never records a moment that did
+//! not happen yet locally. Anything outside the window is rejected: too old means a likely
+//! replay, too far ahead means a misbehaving or misconfigured counterparty.
+
+use cosmwasm_std::Timestamp;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SkewWindow {
+    /// How far behind the local block time a timestamp may be, in seconds
+    pub past: u64,
+    /// How far ahead of the local block time a timestamp may be, in seconds
+    pub future: u64,
+}
+
+/// Returns the timestamp to use locally for the counterparty's `ts`, given the local `now`.
+///
+/// Both bounds are inclusive.
+pub fn accept_timestamp(
+    now: Timestamp,
+    ts: Timestamp,
+    window: &SkewWindow,
+) -> Result<Timestamp, ContractError> {
+    let earliest = now
+        .nanos()
+        .saturating_sub(window.past.saturating_mul(1_000_000_000));
+    let latest = now
+        .nanos()
+        .saturating_add(window.future.saturating_mul(1_000_000_000));
+
+    if ts.nanos() < earliest {
+        return Err(ContractError::TimestampTooOld { ts });
+    }
+    if ts.nanos() > latest {
+        return Err(ContractError::TimestampInFuture { ts });
+    }
+
+    Ok(ts.min(now))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: SkewWindow = SkewWindow {
+        past: 60,
+        future: 5,
+    };
+
+    fn now() -> Timestamp {
+        Timestamp::from_seconds(1_000)
+    }
+
+    #[test]
+    fn past_within_window() {
+        let ts = now().minus_seconds(30);
+        assert_eq!(accept_timestamp(now(), ts, &WINDOW).unwrap(), ts);
+
+        let ts = now().minus_seconds(60);
+        assert_eq!(accept_timestamp(now(), ts, &WINDOW).unwrap(), ts);
+
+        assert_eq!(accept_timestamp(now(), now(), &WINDOW).unwrap(), now());
+    }
+
+    #[test]
+    fn too_old() {
+        let ts = now().minus_seconds(60).minus_nanos(1);
+        assert_eq!(
+            accept_timestamp(now(), ts, &WINDOW).unwrap_err(),
+            ContractError::TimestampTooOld { ts }
+        );
+    }
+
+    #[test]
+    fn future_within_window_is_clamped() {
+        let ts = now().plus_nanos(1);
+        assert_eq!(accept_timestamp(now(), ts, &WINDOW).unwrap(), now());
+
+        let ts = now().plus_seconds(5);
+        assert_eq!(accept_timestamp(now(), ts, &WINDOW).unwrap(), now());
+    }
+
+    #[test]
+    fn too_far_ahead() {
+        let ts = now().plus_seconds(5).plus_nanos(1);
+        assert_eq!(
+            accept_timestamp(now(), ts, &WINDOW).unwrap_err(),
+            ContractError::TimestampInFuture { ts }
+        );
+    }
+
+    #[test]
+    fn window_at_clock_limits() {
+        let ts = Timestamp::from_nanos(0);
+        assert_eq!(
+            accept_timestamp(Timestamp::from_seconds(10), ts, &WINDOW).unwrap(),
+            ts
+        );
+
+        let now = Timestamp::from_nanos(u64::MAX);
+        assert_eq!(accept_timestamp(now, now, &WINDOW).unwrap(), now);
+    }
+}
@@ -0,0 +1,19 @@
+use std::env::current_dir;
+
+use cosmwasm_schema::{export_schema_with_title, schema_for, write_api};
+
+use ica_controller::msg::{Ack, ExecuteMsg, InstantiateMsg, PacketData, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        query: QueryMsg,
+    }
+
+    // Not entry point messages, so `write_api!` leaves them out
+    let mut out_dir = current_dir().unwrap();
+    out_dir.push("schema");
+    export_schema_with_title(&schema_for!(PacketData), &out_dir, "PacketData");
+    export_schema_with_title(&schema_for!(Ack), &out_dir, "Ack");
+}
@@ -0,0 +1,109 @@
+use crate::error::ContractError;
+use crate::msg::{AccountResp, AnyMsg, ExecuteMsg, InstantiateMsg, PacketData, QueryMsg, TxResp};
+use crate::proto::CosmosTx;
+use crate::state::{ACCOUNT, OWNER, TXS};
+use cosmwasm_std::{
+    to_json_binary, Binary, Deps, DepsMut, Env, IbcMsg, IbcTimeout, MessageInfo, Response,
+    StdResult,
+};
+use prost::Message;
+
+/// Seconds after which an unrelayed transaction times out. On the ordered ICS-27 channel a timeout
+/// closes the channel, so it is generous.
+pub const PACKET_TIMEOUT: u64 = 24 * 60 * 60;
+
+/// `InterchainAccountPacketData` type of packets executing a transaction.
+pub const TYPE_EXECUTE_TX: &str = "TYPE_EXECUTE_TX";
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    OWNER.save(deps.storage, &info.sender)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Account {} => to_json_binary(&query::account(deps)?),
+        Tx { sequence } => to_json_binary(&query::tx(deps, sequence)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        SendTx { msgs, memo } => exec::send_tx(deps, env, info, msgs, memo),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn send_tx(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msgs: Vec<AnyMsg>,
+        memo: String,
+    ) -> Result<Response, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+        if msgs.is_empty() {
+            return Err(ContractError::NoMessages);
+        }
+        let account = ACCOUNT
+            .may_load(deps.storage)?
+            .ok_or(ContractError::NotRegistered)?;
+
+        let tx = CosmosTx {
+            messages: msgs.into_iter().map(Into::into).collect(),
+        };
+        let packet = PacketData {
+            ty: TYPE_EXECUTE_TX.to_owned(),
+            data: tx.encode_to_vec().into(),
+            memo,
+        };
+        let msg = IbcMsg::SendPacket {
+            channel_id: account.channel_id.clone(),
+            data: to_json_binary(&packet)?,
+            timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(PACKET_TIMEOUT)),
+        };
+
+        let resp = Response::new()
+            .add_message(msg)
+            .add_attribute("action", "send_tx")
+            .add_attribute("channel", account.channel_id);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn account(deps: Deps) -> StdResult<AccountResp> {
+        let account = ACCOUNT.may_load(deps.storage)?;
+        Ok(AccountResp { account })
+    }
+
+    pub fn tx(deps: Deps, sequence: u64) -> StdResult<TxResp> {
+        let result = TXS.may_load(deps.storage, sequence)?;
+        Ok(TxResp { result })
+    }
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Addr, IbcOrder, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not contract owner")]
+    Unauthorized { sender: Addr },
+    #[error("Only {expected:?} channels are supported, got {order:?}")]
+    InvalidOrder { expected: IbcOrder, order: IbcOrder },
+    #[error("Invalid channel version: {reason}")]
+    InvalidVersion { reason: String },
+    #[error("Counterparty port has to be {expected}, got {port}")]
+    InvalidCounterpartyPort { port: String, expected: String },
+    #[error("Only the controller can start the handshake")]
+    HostInitiated,
+    #[error("Interchain account is already open on channel {channel}")]
+    AlreadyOpen { channel: String },
+    #[error("No interchain account is open")]
+    NotRegistered,
+    #[error("At least one message is required")]
+    NoMessages,
+}
@@ -0,0 +1,466 @@
+//! IBC entry points of the ICS-27 controller.
+//!
+//! The contract cannot start a channel handshake itself - a relayer opens the channel from the
+//! contract's port to the `icahost` port, passing the JSON [`Metadata`] as the version. The host
+//! creates the interchain account and returns its address in the version it acknowledges the
+//! handshake with. From then on the controller sends transactions over the channel, and the host
+//! acknowledges each of them with the result of its execution.
+//!
+//! ICS-27 channels are ordered, so a packet timing out closes the channel. The interchain account
+//! is not lost - opening a new channel over the same connection gives access to it again.
+
+use cosmwasm_std::{
+    from_json, DepsMut, Env, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcOrder, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, StdError, StdResult,
+};
+use prost::Message;
+
+use crate::error::ContractError;
+use crate::msg::{Ack, Metadata};
+use crate::proto::TxMsgData;
+use crate::state::{Account, TxResult, ACCOUNT, TXS};
+
+pub const ICA_VERSION: &str = "ics27-1";
+pub const ICA_ORDER: IbcOrder = IbcOrder::Ordered;
+pub const HOST_PORT: &str = "icahost";
+pub const ENCODING: &str = "proto3";
+pub const TX_TYPE: &str = "sdk_multi_msg";
+
+pub fn channel_open(deps: DepsMut, _env: Env, msg: IbcChannelOpenMsg) -> Result<(), ContractError> {
+    let channel = match msg {
+        IbcChannelOpenMsg::OpenInit { channel } => channel,
+        _ => return Err(ContractError::HostInitiated),
+    };
+
+    if let Some(account) = ACCOUNT.may_load(deps.storage)? {
+        return Err(ContractError::AlreadyOpen {
+            channel: account.channel_id,
+        });
+    }
+
+    validate_metadata(&channel, &channel.version)?;
+    Ok(())
+}
+
+pub fn channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let (channel, version) = match msg {
+        IbcChannelConnectMsg::OpenAck {
+            channel,
+            counterparty_version,
+        } => (channel, counterparty_version),
+        _ => return Err(ContractError::HostInitiated),
+    };
+
+    let metadata = validate_metadata(&channel, &version)?;
+    if metadata.address.is_empty() {
+        return Err(ContractError::InvalidVersion {
+            reason: "host did not return the account address".to_owned(),
+        });
+    }
+
+    let account = Account {
+        channel_id: channel.endpoint.channel_id,
+        address: metadata.address,
+    };
+    ACCOUNT.save(deps.storage, &account)?;
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "channel_connect")
+        .add_attribute("channel", account.channel_id)
+        .add_attribute("address", account.address);
+
+    Ok(resp)
+}
+
+pub fn channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = &msg.channel().endpoint.channel_id;
+    ACCOUNT.remove(deps.storage);
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "channel_close")
+        .add_attribute("channel", channel);
+
+    Ok(resp)
+}
+
+/// Hosts never send packets to the controller.
+pub fn packet_receive(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketReceiveMsg,
+) -> StdResult<IbcReceiveResponse> {
+    Err(StdError::generic_err("Controller does not receive packets"))
+}
+
+pub fn packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let sequence = msg.original_packet.sequence;
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "ack")
+        .add_attribute("sequence", sequence.to_string());
+    let (result, resp) = match from_json(&msg.acknowledgement.data)? {
+        Ack::Result(data) => {
+            let responses = parse_responses(&data)?;
+            let resp = resp.add_attribute("responses", responses.len().to_string());
+            (TxResult::Success { responses }, resp)
+        }
+        // The host reports only the error code - details would not be deterministic
+        Ack::Error(error) => {
+            let resp = resp.add_attribute("error", &error);
+            (TxResult::Error { error }, resp)
+        }
+    };
+    TXS.save(deps.storage, sequence, &result)?;
+
+    Ok(resp)
+}
+
+pub fn packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let sequence = msg.packet.sequence;
+    TXS.save(deps.storage, sequence, &TxResult::Timeout)?;
+    // The timeout closes the ordered channel, without calling `channel_close`
+    ACCOUNT.remove(deps.storage);
+
+    let resp = IbcBasicResponse::new()
+        .add_attribute("action", "timeout")
+        .add_attribute("sequence", sequence.to_string());
+
+    Ok(resp)
+}
+
+/// Type URLs of the responses of executed messages, from the protobuf `TxMsgData`.
+fn parse_responses(data: &[u8]) -> StdResult<Vec<String>> {
+    let tx_data = TxMsgData::decode(data).map_err(|err| StdError::parse_err("TxMsgData", err))?;
+
+    let responses = if tx_data.msg_responses.is_empty() {
+        tx_data.data.into_iter().map(|data| data.msg_type).collect()
+    } else {
+        tx_data
+            .msg_responses
+            .into_iter()
+            .map(|response| response.type_url)
+            .collect()
+    };
+
+    Ok(responses)
+}
+
+fn validate_metadata(channel: &IbcChannel, version: &str) -> Result<Metadata, ContractError> {
+    if channel.order != ICA_ORDER {
+        return Err(ContractError::InvalidOrder {
+            expected: ICA_ORDER,
+            order: channel.order.clone(),
+        });
+    }
+    if channel.counterparty_endpoint.port_id != HOST_PORT {
+        return Err(ContractError::InvalidCounterpartyPort {
+            port: channel.counterparty_endpoint.port_id.clone(),
+            expected: HOST_PORT.to_owned(),
+        });
+    }
+
+    let metadata: Metadata = from_json(version.as_bytes())?;
+    let invalid = |reason: String| Err(ContractError::InvalidVersion { reason });
+    if metadata.version != ICA_VERSION {
+        return invalid(format!("unsupported version {}", metadata.version));
+    }
+    if metadata.controller_connection_id != channel.connection_id {
+        return invalid(format!(
+            "controller connection {} does not match the channel connection {}",
+            metadata.controller_connection_id, channel.connection_id
+        ));
+    }
+    if metadata.encoding != ENCODING {
+        return invalid(format!("unsupported encoding {}", metadata.encoding));
+    }
+    if metadata.tx_type != TX_TYPE {
+        return invalid(format!("unsupported tx type {}", metadata.tx_type));
+    }
+
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{
+        message_info, mock_dependencies, mock_env, mock_ibc_channel, mock_ibc_packet_ack,
+        mock_ibc_packet_timeout, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{
+        to_json_binary, to_json_string, Binary, CosmosMsg, IbcAcknowledgement, IbcMsg, OwnedDeps,
+    };
+
+    use crate::contract::{execute, instantiate, query, TYPE_EXECUTE_TX};
+    use crate::msg::{
+        AccountResp, AnyMsg, ExecuteMsg, InstantiateMsg, PacketData, QueryMsg, TxResp,
+    };
+    use crate::proto::{Any, CosmosTx};
+
+    use super::*;
+
+    type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    const ICA_ADDRESS: &str = "cosmos1ica";
+
+    fn metadata(address: &str) -> String {
+        let metadata = Metadata {
+            version: ICA_VERSION.to_owned(),
+            controller_connection_id: "connection-2".to_owned(),
+            host_connection_id: "connection-5".to_owned(),
+            address: address.to_owned(),
+            encoding: ENCODING.to_owned(),
+            tx_type: TX_TYPE.to_owned(),
+        };
+        to_json_string(&metadata).unwrap()
+    }
+
+    fn ica_channel(version: &str) -> IbcChannel {
+        let mut channel = mock_ibc_channel("channel-1", ICA_ORDER, version);
+        channel.counterparty_endpoint.port_id = HOST_PORT.to_owned();
+        channel
+    }
+
+    fn account(deps: &Deps) -> Option<Account> {
+        let resp: AccountResp =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::Account {}).unwrap()).unwrap();
+        resp.account
+    }
+
+    fn registered() -> Deps {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let open = IbcChannelOpenMsg::new_init(ica_channel(&metadata("")));
+        channel_open(deps.as_mut(), mock_env(), open).unwrap();
+        let connect = IbcChannelConnectMsg::new_ack(ica_channel(""), metadata(ICA_ADDRESS));
+        channel_connect(deps.as_mut(), mock_env(), connect).unwrap();
+
+        deps
+    }
+
+    fn send_msg() -> AnyMsg {
+        AnyMsg {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_owned(),
+            value: Binary::from(b"encoded msg".as_slice()),
+        }
+    }
+
+    fn packet() -> PacketData {
+        let tx = CosmosTx {
+            messages: vec![send_msg().into()],
+        };
+        PacketData {
+            ty: TYPE_EXECUTE_TX.to_owned(),
+            data: tx.encode_to_vec().into(),
+            memo: String::new(),
+        }
+    }
+
+    fn tx_result(deps: &Deps, sequence: u64) -> Option<TxResult> {
+        let msg = QueryMsg::Tx { sequence };
+        let resp: TxResp = from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        resp.result
+    }
+
+    #[test]
+    fn handshake() {
+        let mut deps = mock_dependencies();
+
+        let mut channel = ica_channel(&metadata(""));
+        channel.order = IbcOrder::Unordered;
+        let err = channel_open(
+            deps.as_mut(),
+            mock_env(),
+            IbcChannelOpenMsg::new_init(channel),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidOrder {
+                expected: IbcOrder::Ordered,
+                order: IbcOrder::Unordered,
+            }
+        );
+
+        let channel = mock_ibc_channel("channel-1", ICA_ORDER, &metadata(""));
+        let err = channel_open(
+            deps.as_mut(),
+            mock_env(),
+            IbcChannelOpenMsg::new_init(channel),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidCounterpartyPort {
+                port: "their_port".to_owned(),
+                expected: HOST_PORT.to_owned(),
+            }
+        );
+
+        let version = metadata("").replace("proto3", "proto3json");
+        let open = IbcChannelOpenMsg::new_init(ica_channel(&version));
+        let err = channel_open(deps.as_mut(), mock_env(), open).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidVersion {
+                reason: "unsupported encoding proto3json".to_owned()
+            }
+        );
+
+        let open = IbcChannelOpenMsg::new_try(ica_channel(&metadata("")), metadata(""));
+        let err = channel_open(deps.as_mut(), mock_env(), open).unwrap_err();
+        assert_eq!(err, ContractError::HostInitiated);
+
+        let deps = registered();
+        assert_eq!(
+            account(&deps),
+            Some(Account {
+                channel_id: "channel-1".to_owned(),
+                address: ICA_ADDRESS.to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn channel_reopening() {
+        let mut deps = registered();
+
+        let open = IbcChannelOpenMsg::new_init(ica_channel(&metadata(ICA_ADDRESS)));
+        let err = channel_open(deps.as_mut(), mock_env(), open.clone()).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::AlreadyOpen {
+                channel: "channel-1".to_owned()
+            }
+        );
+
+        let close = IbcChannelCloseMsg::new_confirm(ica_channel(ICA_VERSION));
+        channel_close(deps.as_mut(), mock_env(), close).unwrap();
+        assert_eq!(account(&deps), None);
+
+        // The same account is available over a new channel
+        channel_open(deps.as_mut(), mock_env(), open).unwrap();
+        let connect = IbcChannelConnectMsg::new_ack(ica_channel(""), metadata(ICA_ADDRESS));
+        channel_connect(deps.as_mut(), mock_env(), connect).unwrap();
+        assert_eq!(account(&deps).unwrap().address, ICA_ADDRESS);
+    }
+
+    #[test]
+    fn send_tx() {
+        let mut deps = registered();
+        let owner = deps.api.addr_make("owner");
+        let user = deps.api.addr_make("user");
+
+        let msg = ExecuteMsg::SendTx {
+            msgs: vec![send_msg()],
+            memo: String::new(),
+        };
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            msg.clone(),
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { sender: user });
+
+        let resp = execute(deps.as_mut(), mock_env(), message_info(&owner, &[]), msg).unwrap();
+        let data = match &resp.messages[0].msg {
+            CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id, data, ..
+            }) => {
+                assert_eq!(channel_id, "channel-1");
+                data.clone()
+            }
+            msg => panic!("Unexpected message: {:?}", msg),
+        };
+        let sent: PacketData = from_json(&data).unwrap();
+        assert_eq!(sent, packet());
+        assert_eq!(
+            CosmosTx::decode(sent.data.as_slice()).unwrap().messages,
+            [Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_owned(),
+                value: b"encoded msg".to_vec(),
+            }]
+        );
+    }
+
+    #[test]
+    fn acknowledgements() {
+        let mut deps = registered();
+
+        let tx_data = TxMsgData {
+            data: vec![],
+            msg_responses: vec![Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSendResponse".to_owned(),
+                value: vec![],
+            }],
+        };
+        let ack = Ack::Result(tx_data.encode_to_vec().into());
+        let ack = IbcAcknowledgement::new(to_json_binary(&ack).unwrap());
+        let msg = mock_ibc_packet_ack("channel-1", &packet(), ack).unwrap();
+        let sequence = msg.original_packet.sequence;
+        packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(
+            tx_result(&deps, sequence),
+            Some(TxResult::Success {
+                responses: vec!["/cosmos.bank.v1beta1.MsgSendResponse".to_owned()]
+            })
+        );
+
+        let error = "ABCI code: 5: error handling packet: see events for details".to_owned();
+        let ack = IbcAcknowledgement::new(to_json_binary(&Ack::Error(error.clone())).unwrap());
+        let mut msg = mock_ibc_packet_ack("channel-1", &packet(), ack).unwrap();
+        msg.original_packet.sequence += 1;
+        packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(
+            tx_result(&deps, sequence + 1),
+            Some(TxResult::Error { error })
+        );
+
+        let mut msg = mock_ibc_packet_timeout("channel-1", &packet()).unwrap();
+        msg.packet.sequence += 2;
+        packet_timeout(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(tx_result(&deps, sequence + 2), Some(TxResult::Timeout));
+        assert_eq!(account(&deps), None);
+    }
+
+    #[test]
+    fn legacy_tx_msg_data() {
+        let tx_data = TxMsgData {
+            data: vec![crate::proto::MsgData {
+                msg_type: "/cosmos.bank.v1beta1.MsgSend".to_owned(),
+                data: vec![],
+            }],
+            msg_responses: vec![],
+        };
+        assert_eq!(
+            parse_responses(&tx_data.encode_to_vec()).unwrap(),
+            ["/cosmos.bank.v1beta1.MsgSend"]
+        );
+    }
+}
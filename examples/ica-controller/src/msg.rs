@@ -0,0 +1,80 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::Binary;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{Account, TxResult};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+/// Protobuf message to be executed on the host, encoded as `google.protobuf.Any`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AnyMsg {
+    pub type_url: String,
+    pub value: Binary,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Executes the messages on the host chain as the interchain account, in a single
+    /// transaction
+    SendTx { msgs: Vec<AnyMsg>, memo: String },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct AccountResp {
+    /// `None` until the channel handshake finishes, and after the channel closes
+    pub account: Option<Account>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TxResp {
+    /// `None` until the packet is acknowledged or times out
+    pub result: Option<TxResult>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(AccountResp)]
+    Account {},
+    #[returns(TxResp)]
+    Tx { sequence: u64 },
+}
+
+/// Channel version negotiated in the ICS-27 handshake, JSON encoded.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Metadata {
+    pub version: String,
+    pub controller_connection_id: String,
+    pub host_connection_id: String,
+    /// Interchain account address, filled by the host
+    pub address: String,
+    pub encoding: String,
+    pub tx_type: String,
+}
+
+/// `InterchainAccountPacketData` in its protobuf JSON form, which is what ICS-27 packets carry.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PacketData {
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Protobuf encoded `CosmosTx`
+    pub data: Binary,
+    pub memo: String,
+}
+
+/// Acknowledgement envelope of ICS-27: `{"result": <base64>}` on success and
+/// `{"error": <message>}` on failure.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Ack {
+    Result(Binary),
+    Error(String),
+}
@@ -0,0 +1,60 @@
+//! Hand-written prost types mirroring the protobuf definitions used by ICS-27. Only the fields
+//! are relevant for encoding - tags must match the `.proto` files exactly.
+
+use cosmwasm_std::Binary;
+
+use crate::msg::AnyMsg;
+
+/// `google.protobuf.Any`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Any {
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+}
+
+impl From<AnyMsg> for Any {
+    fn from(msg: AnyMsg) -> Self {
+        Any {
+            type_url: msg.type_url,
+            value: msg.value.into(),
+        }
+    }
+}
+
+impl From<Any> for AnyMsg {
+    fn from(any: Any) -> Self {
+        AnyMsg {
+            type_url: any.type_url,
+            value: Binary::from(any.value),
+        }
+    }
+}
+
+/// `ibc.applications.interchain_accounts.v1.CosmosTx` - messages executed by the host as a single
+/// transaction.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CosmosTx {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<Any>,
+}
+
+/// `cosmos.base.abci.v1beta1.MsgData`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgData {
+    #[prost(string, tag = "1")]
+    pub msg_type: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: Vec<u8>,
+}
+
+/// `cosmos.base.abci.v1beta1.TxMsgData` - the result of a successful host transaction. Hosts
+/// running Cosmos SDK 0.46 or newer fill `msg_responses`, older ones only `data`.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TxMsgData {
+    #[prost(message, repeated, tag = "1")]
+    pub data: Vec<MsgData>,
+    #[prost(message, repeated, tag = "2")]
+    pub msg_responses: Vec<Any>,
+}
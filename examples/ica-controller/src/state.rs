@@ -0,0 +1,31 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct Account {
+    pub channel_id: String,
+    /// Address of the interchain account on the host chain
+    pub address: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TxResult {
+    /// Type URLs of the responses of executed messages
+    Success {
+        responses: Vec<String>,
+    },
+    Error {
+        error: String,
+    },
+    Timeout,
+}
+
+pub const OWNER: Item<Addr> = Item::new("owner");
+/// Set when the handshake finishes, removed when the channel closes
+pub const ACCOUNT: Item<Account> = Item::new("account");
+/// Results of sent transactions, by the packet sequence
+pub const TXS: Map<u64, TxResult> = Map::new("txs");
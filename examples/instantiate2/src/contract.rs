@@ -0,0 +1,215 @@
+use crate::error::ContractError;
+use crate::msg::{ChildrenResp, ExecuteMsg, InstantiateMsg, PredictAddressResp, QueryMsg};
+use crate::state::{CHILDREN, CHILD_CODE_ID};
+use cosmwasm_std::{
+    instantiate2_address, to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdError, StdResult, WasmMsg,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    CHILD_CODE_ID.save(deps.storage, &msg.child_code_id)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        PredictAddress { salt } => to_json_binary(&query::predict_address(deps, env, salt)?),
+        Children {} => to_json_binary(&query::children(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        CreateChild { salt, msg, label } => exec::create_child(deps, env, salt, msg, label),
+    }
+}
+
+/// Address `WasmMsg::Instantiate2` assigns to a child created by this contract with the salt.
+///
+/// It depends only on the child code checksum, this contract address and the salt - not on the
+/// instantiation message or the number of contracts instantiated so far.
+fn child_address(deps: Deps, env: &Env, salt: &Binary) -> StdResult<Addr> {
+    let code_id = CHILD_CODE_ID.load(deps.storage)?;
+    let checksum = deps.querier.query_wasm_code_info(code_id)?.checksum;
+    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
+
+    let addr = instantiate2_address(checksum.as_slice(), &creator, salt)
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+    deps.api.addr_humanize(&addr)
+}
+
+mod exec {
+    use super::*;
+
+    pub fn create_child(
+        deps: DepsMut,
+        env: Env,
+        salt: Binary,
+        msg: Binary,
+        label: String,
+    ) -> Result<Response, ContractError> {
+        let addr = child_address(deps.as_ref(), &env, &salt)?;
+        if CHILDREN.has(deps.storage, &addr) {
+            return Err(ContractError::SaltUsed { salt });
+        }
+        // The child is registered before it exists - if the instantiation fails, the whole
+        // transaction is reverted anyway
+        CHILDREN.save(deps.storage, &addr, &salt)?;
+
+        let msg = WasmMsg::Instantiate2 {
+            admin: None,
+            code_id: CHILD_CODE_ID.load(deps.storage)?,
+            label,
+            msg,
+            funds: vec![],
+            salt,
+        };
+
+        let resp = Response::new()
+            .add_message(msg)
+            .add_attribute("action", "create_child")
+            .add_attribute("child", addr);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn predict_address(deps: Deps, env: Env, salt: Binary) -> StdResult<PredictAddressResp> {
+        let addr = child_address(deps, &env, &salt)?;
+        Ok(PredictAddressResp { addr })
+    }
+
+    pub fn children(deps: Deps) -> StdResult<ChildrenResp> {
+        let children = CHILDREN
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+
+        Ok(ChildrenResp { children })
+    }
+}
+
+// Real chains derive `Instantiate2` addresses from the code checksum, so the test needs an app
+// with bech32 addresses and the matching address generator - the default `App` uses sequential
+// `contractN` addresses which would never match the prediction.
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Empty;
+    use cw_multi_test::{
+        no_init, AppBuilder, ContractWrapper, Executor, MockApiBech32, SimpleAddressGenerator,
+        WasmKeeper,
+    };
+
+    use super::*;
+
+    fn child_instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn child_execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Err(StdError::generic_err("Not supported"))
+    }
+
+    /// The child answers any query with its own address.
+    fn child_query(_deps: Deps, env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_json_binary(&env.contract.address)
+    }
+
+    #[test]
+    fn child_at_predicted_address() {
+        let mut app = AppBuilder::default()
+            .with_api(MockApiBech32::new("juno"))
+            .with_wasm(WasmKeeper::default().with_address_generator(SimpleAddressGenerator))
+            .build(no_init);
+        let owner = app.api().addr_make("owner");
+        let user = app.api().addr_make("user");
+
+        let child_code = ContractWrapper::new(child_execute, child_instantiate, child_query);
+        let child_code_id = app.store_code(Box::new(child_code));
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let factory = app
+            .instantiate_contract(
+                code_id,
+                owner,
+                &InstantiateMsg { child_code_id },
+                &[],
+                "Factory",
+                None,
+            )
+            .unwrap();
+
+        let predict = |salt: &[u8]| QueryMsg::PredictAddress {
+            salt: Binary::from(salt),
+        };
+        let predicted: PredictAddressResp = app
+            .wrap()
+            .query_wasm_smart(&factory, &predict(b"first"))
+            .unwrap();
+        let other: PredictAddressResp = app
+            .wrap()
+            .query_wasm_smart(&factory, &predict(b"second"))
+            .unwrap();
+        let predicted = predicted.addr;
+        assert_ne!(predicted, other.addr);
+
+        let create_child = |salt: &[u8]| ExecuteMsg::CreateChild {
+            salt: Binary::from(salt),
+            msg: to_json_binary(&Empty {}).unwrap(),
+            label: "Child".to_owned(),
+        };
+
+        app.execute_contract(user.clone(), factory.clone(), &create_child(b"first"), &[])
+            .unwrap();
+
+        let info = app.wrap().query_wasm_contract_info(&predicted).unwrap();
+        assert_eq!(info.code_id, child_code_id);
+        let addr: Addr = app.wrap().query_wasm_smart(&predicted, &Empty {}).unwrap();
+        assert_eq!(addr, predicted);
+
+        let resp: ChildrenResp = app
+            .wrap()
+            .query_wasm_smart(&factory, &QueryMsg::Children {})
+            .unwrap();
+        assert_eq!(resp.children, vec![predicted]);
+
+        let err = app
+            .execute_contract(user, factory, &create_child(b"first"), &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::SaltUsed {
+                salt: Binary::from(b"first")
+            },
+            err.downcast().unwrap()
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use cosmwasm_std::{Binary, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Salt {salt} was already used")]
+    SaltUsed { salt: Binary },
+}
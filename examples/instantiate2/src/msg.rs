@@ -0,0 +1,42 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Binary};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub child_code_id: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Instantiates a child at the address `PredictAddress` returns for the salt
+    CreateChild {
+        salt: Binary,
+        msg: Binary,
+        label: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PredictAddressResp {
+    pub addr: Addr,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ChildrenResp {
+    pub children: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(PredictAddressResp)]
+    PredictAddress { salt: Binary },
+    #[returns(ChildrenResp)]
+    Children {},
+}
@@ -0,0 +1,6 @@
+use cosmwasm_std::{Addr, Binary};
+use cw_storage_plus::{Item, Map};
+
+pub const CHILD_CODE_ID: Item<u64> = Item::new("child_code_id");
+/// Children by address, with the salt they were created with
+pub const CHILDREN: Map<&Addr, Binary> = Map::new("children");
@@ -0,0 +1,375 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, JobResp, QueryMsg, QueueResp, SudoMsg};
+use crate::state::{Config, Job, CONFIG, NEXT_JOB_ID, QUEUE, RUNNING};
+use common::pagination;
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response,
+    StdResult, SubMsg, SubMsgResult, WasmMsg,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        max_gas_per_tick: msg.max_gas_per_tick,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    NEXT_JOB_ID.save(deps.storage, &0)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Queue { start_after, limit } => to_json_binary(&query::queue(deps, start_after, limit)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Enqueue {
+            contract,
+            msg,
+            gas_limit,
+        } => exec::enqueue(deps, info, contract, msg, gas_limit),
+        Cancel { id } => exec::cancel(deps, info, id),
+    }
+}
+
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    use SudoMsg::*;
+
+    match msg {
+        Tick {} => sudo::tick(deps, env),
+    }
+}
+
+/// Every job replies, with the job id as the reply id. Failed jobs are reverted, so their funds
+/// are still in the contract and go back to the job owner.
+pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
+    let job = RUNNING.load(deps.storage, reply.id)?;
+    RUNNING.remove(deps.storage, reply.id);
+
+    let resp = Response::new().add_attribute("id", reply.id.to_string());
+    let resp = match reply.result {
+        SubMsgResult::Ok(_) => resp.add_attribute("action", "job_done"),
+        SubMsgResult::Err(error) => {
+            let resp = resp
+                .add_attribute("action", "job_failed")
+                .add_attribute("error", error);
+            if job.funds.is_empty() {
+                resp
+            } else {
+                resp.add_message(BankMsg::Send {
+                    to_address: job.owner.into_string(),
+                    amount: job.funds,
+                })
+            }
+        }
+    };
+
+    Ok(resp)
+}
+
+mod exec {
+    use super::*;
+
+    pub fn enqueue(
+        deps: DepsMut,
+        info: MessageInfo,
+        contract: String,
+        msg: Binary,
+        gas_limit: u64,
+    ) -> Result<Response, ContractError> {
+        // A job over the tick budget would never be processed, and block the queue behind it
+        let max = CONFIG.load(deps.storage)?.max_gas_per_tick;
+        if gas_limit == 0 || gas_limit > max {
+            return Err(ContractError::InvalidGasLimit { max });
+        }
+
+        let job = Job {
+            owner: info.sender,
+            contract: deps.api.addr_validate(&contract)?,
+            msg,
+            funds: info.funds,
+            gas_limit,
+        };
+        let id = NEXT_JOB_ID.load(deps.storage)?;
+        NEXT_JOB_ID.save(deps.storage, &(id + 1))?;
+        QUEUE.save(deps.storage, id, &job)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "enqueue")
+            .add_attribute("id", id.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn cancel(deps: DepsMut, info: MessageInfo, id: u64) -> Result<Response, ContractError> {
+        let job = QUEUE.load(deps.storage, id)?;
+        if info.sender != job.owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+        QUEUE.remove(deps.storage, id);
+
+        let mut resp = Response::new()
+            .add_attribute("action", "cancel")
+            .add_attribute("id", id.to_string());
+        if !job.funds.is_empty() {
+            resp = resp.add_message(BankMsg::Send {
+                to_address: job.owner.into_string(),
+                amount: job.funds,
+            });
+        }
+
+        Ok(resp)
+    }
+}
+
+mod sudo {
+    use super::*;
+
+    /// Runs jobs from the front of the queue, as long as their gas limits fit the tick budget.
+    ///
+    /// Every job is a submessage with its own gas limit, so a job running out of gas fails alone,
+    /// and the whole tick never uses more than the budget. Jobs are taken strictly in order -
+    /// smaller jobs do not jump over one which does not fit anymore, or it could starve.
+    pub fn tick(deps: DepsMut, _env: Env) -> Result<Response, ContractError> {
+        let mut budget = CONFIG.load(deps.storage)?.max_gas_per_tick;
+
+        let mut batch = vec![];
+        for job in QUEUE.range(deps.storage, None, None, Order::Ascending) {
+            let (id, job) = job?;
+            if job.gas_limit > budget {
+                break;
+            }
+            budget -= job.gas_limit;
+            batch.push((id, job));
+        }
+
+        let mut submsgs = Vec::with_capacity(batch.len());
+        for (id, job) in batch {
+            QUEUE.remove(deps.storage, id);
+            RUNNING.save(deps.storage, id, &job)?;
+
+            let msg = WasmMsg::Execute {
+                contract_addr: job.contract.into_string(),
+                msg: job.msg,
+                funds: job.funds,
+            };
+            submsgs.push(SubMsg::reply_always(msg, id).with_gas_limit(job.gas_limit));
+        }
+
+        let resp = Response::new()
+            .add_attribute("action", "tick")
+            .add_attribute("jobs", submsgs.len().to_string())
+            .add_submessages(submsgs);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn queue(deps: Deps, start_after: Option<u64>, limit: Option<u32>) -> StdResult<QueueResp> {
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after);
+
+        let jobs: Vec<_> = QUEUE
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (id, job) = item?;
+                Ok(JobResp {
+                    id,
+                    owner: job.owner,
+                    contract: job.contract,
+                    msg: job.msg,
+                    funds: job.funds,
+                    gas_limit: job.gas_limit,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after = pagination::next_key(&jobs, limit, |job| job.id);
+
+        Ok(QueueResp {
+            jobs,
+            next_start_after,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Empty, StdError};
+    use serde::{Deserialize, Serialize};
+    use testing::prelude::*;
+
+    use super::*;
+
+    /// Message of the contract the jobs call.
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "snake_case")]
+    enum TargetMsg {
+        Ping {},
+        Fail {},
+    }
+
+    fn target_execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: TargetMsg,
+    ) -> StdResult<Response> {
+        match msg {
+            TargetMsg::Ping {} => Ok(Response::new()),
+            TargetMsg::Fail {} => Err(StdError::generic_err("Job failed")),
+        }
+    }
+
+    fn target_instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn target_query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        Err(StdError::generic_err("No queries"))
+    }
+
+    /// Keeper with a budget of 300k gas per tick, the target contract, and 1000 atom for alice.
+    fn setup() -> (App, Proxy, Proxy) {
+        let mut app = app_with_balances(&[("alice", coins(1000, "atom"))]);
+
+        let code = ContractWrapper::new(execute, instantiate, query)
+            .with_sudo(sudo)
+            .with_reply(reply);
+        let msg = InstantiateMsg {
+            max_gas_per_tick: 300_000,
+        };
+        let keeper = Proxy::deploy(&mut app, code, "owner", &msg, "Keeper").unwrap();
+
+        let code = ContractWrapper::new(target_execute, target_instantiate, target_query);
+        let target = Proxy::deploy(&mut app, code, "owner", &Empty {}, "Target").unwrap();
+
+        (app, keeper, target)
+    }
+
+    fn enqueue(
+        app: &mut App,
+        keeper: &Proxy,
+        target: &Proxy,
+        msg: TargetMsg,
+        gas_limit: u64,
+    ) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Enqueue {
+            contract: target.addr().to_string(),
+            msg: to_json_binary(&msg).unwrap(),
+            gas_limit,
+        };
+        keeper.execute(app, "alice", &msg, &coins(10, "atom"))
+    }
+
+    /// Stands in for the clock module, which calls the contract at the end of every block.
+    fn tick(app: &mut App, keeper: &Proxy) -> AppResponse {
+        app.wasm_sudo(keeper.addr().clone(), &SudoMsg::Tick {})
+            .unwrap()
+    }
+
+    fn queued(app: &App, keeper: &Proxy, start_after: Option<u64>, limit: u32) -> Vec<u64> {
+        let msg = QueryMsg::Queue {
+            start_after,
+            limit: Some(limit),
+        };
+        let resp: QueueResp = keeper.query(app, &msg).unwrap();
+        resp.jobs.iter().map(|job| job.id).collect()
+    }
+
+    #[test]
+    fn batches_bounded_by_gas() {
+        let (mut app, keeper, target) = setup();
+
+        for gas_limit in [100_000, 250_000, 50_000, 150_000] {
+            enqueue(&mut app, &keeper, &target, TargetMsg::Ping {}, gas_limit).unwrap();
+        }
+        assert_eq!(queued(&app, &keeper, None, 10), [0, 1, 2, 3]);
+        assert_eq!(queued(&app, &keeper, Some(1), 1), [2]);
+
+        // The second job does not fit after the first one, and the third waits behind it
+        let resp = tick(&mut app, &keeper);
+        assert_eq!(wasm_attr(&resp, "jobs"), "1");
+        assert_eq!(queued(&app, &keeper, None, 10), [1, 2, 3]);
+
+        let resp = tick(&mut app, &keeper);
+        assert_eq!(wasm_attr(&resp, "jobs"), "2");
+        let resp = tick(&mut app, &keeper);
+        assert_eq!(wasm_attr(&resp, "jobs"), "1");
+        assert_eq!(queued(&app, &keeper, None, 10), Vec::<u64>::new());
+
+        // Funds of every job went to the target
+        assert_eq!(balance(&app, target.addr().clone(), "atom"), 40);
+        assert_eq!(balance(&app, keeper.addr().clone(), "atom"), 0);
+    }
+
+    #[test]
+    fn failed_job_refunded() {
+        let (mut app, keeper, target) = setup();
+
+        enqueue(&mut app, &keeper, &target, TargetMsg::Fail {}, 100_000).unwrap();
+        enqueue(&mut app, &keeper, &target, TargetMsg::Ping {}, 100_000).unwrap();
+
+        tick(&mut app, &keeper);
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 990);
+        assert_eq!(balance(&app, target.addr().clone(), "atom"), 10);
+        assert_eq!(balance(&app, keeper.addr().clone(), "atom"), 0);
+    }
+
+    #[test]
+    fn enqueue_and_cancel() {
+        let (mut app, keeper, target) = setup();
+
+        for gas_limit in [0, 300_001] {
+            let err =
+                enqueue(&mut app, &keeper, &target, TargetMsg::Ping {}, gas_limit).unwrap_err();
+            assert_eq!(
+                ContractError::InvalidGasLimit { max: 300_000 },
+                err.downcast().unwrap()
+            );
+        }
+
+        enqueue(&mut app, &keeper, &target, TargetMsg::Ping {}, 300_000).unwrap();
+        let cancel = ExecuteMsg::Cancel { id: 0 };
+        let err = keeper.execute(&mut app, "bob", &cancel, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("bob")
+            },
+            err.downcast().unwrap()
+        );
+
+        keeper.execute(&mut app, "alice", &cancel, &[]).unwrap();
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 1000);
+
+        let resp = tick(&mut app, &keeper);
+        assert_eq!(wasm_attr(&resp, "jobs"), "0");
+    }
+}
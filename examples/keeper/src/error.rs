@@ -0,0 +1,12 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Unauthorized - {sender} cannot do this")]
+    Unauthorized { sender: Addr },
+    #[error("Gas limit has to be between 1 and {max}")]
+    InvalidGasLimit { max: u64 },
+}
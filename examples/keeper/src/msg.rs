@@ -0,0 +1,62 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Binary, Coin};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub max_gas_per_tick: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Queues execution of `contract` with `msg`, passing it the funds sent along. The job
+    /// cannot use more than `gas_limit` gas.
+    Enqueue {
+        contract: String,
+        msg: Binary,
+        gas_limit: u64,
+    },
+    /// Removes a queued job, refunding its funds - only by the account which queued it
+    Cancel { id: u64 },
+}
+
+/// Messages sent by the chain, not by any account.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    /// Sent by the clock or cron module at the end of every block
+    Tick {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct JobResp {
+    pub id: u64,
+    pub owner: Addr,
+    pub contract: Addr,
+    pub msg: Binary,
+    pub funds: Vec<Coin>,
+    pub gas_limit: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct QueueResp {
+    pub jobs: Vec<JobResp>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Lists queued jobs, in the order they are processed
+    #[returns(QueueResp)]
+    Queue {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
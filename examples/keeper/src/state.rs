@@ -0,0 +1,26 @@
+use cosmwasm_std::{Addr, Binary, Coin};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    /// Sum of gas limits of the jobs processed in a single tick
+    pub max_gas_per_tick: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Job {
+    pub owner: Addr,
+    pub contract: Addr,
+    pub msg: Binary,
+    pub funds: Vec<Coin>,
+    pub gas_limit: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// Queued jobs - ids grow, so iterating in ascending order is first in, first out
+pub const QUEUE: Map<u64, Job> = Map::new("queue");
+/// Jobs sent in the current tick, waiting for their reply
+pub const RUNNING: Map<u64, Job> = Map::new("running");
+pub const NEXT_JOB_ID: Item<u64> = Item::new("next_job_id");
@@ -0,0 +1,146 @@
+//! Checked token arithmetic shared by the examples.
+//!
+//! Every function rounds down, so amounts computed from a balance never add up to more than the
+//! balance - the contract keeps the dust, instead of failing to send tokens it does not have.
+//! Products are computed on 256 bits before dividing, so `value * numerator` may exceed
+//! `Uint128` as long as the final result fits.
+
+use cosmwasm_std::{CheckedMultiplyRatioError, Decimal, Uint128};
+use thiserror::Error;
+
+/// Basis points in a whole - 1 bps is 0.01%.
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MathError {
+    #[error("Division by zero")]
+    DivideByZero,
+    #[error("Result does not fit into Uint128")]
+    Overflow,
+    #[error("Fee of {bps} basis points is over 100%")]
+    InvalidBps { bps: u16 },
+}
+
+impl From<CheckedMultiplyRatioError> for MathError {
+    fn from(err: CheckedMultiplyRatioError) -> Self {
+        match err {
+            CheckedMultiplyRatioError::DivideByZero => MathError::DivideByZero,
+            CheckedMultiplyRatioError::Overflow => MathError::Overflow,
+        }
+    }
+}
+
+/// `value * numerator / denominator`, rounded down.
+pub fn mul_div(
+    value: Uint128,
+    numerator: impl Into<u128>,
+    denominator: impl Into<u128>,
+) -> Result<Uint128, MathError> {
+    Ok(value.checked_multiply_ratio(numerator, denominator)?)
+}
+
+/// `value * ratio`, rounded down.
+pub fn mul_decimal(value: Uint128, ratio: Decimal) -> Result<Uint128, MathError> {
+    mul_div(value, ratio.atomics(), Decimal::one().atomics())
+}
+
+/// Fee of `bps` basis points taken from `amount`, rounded down.
+pub fn bps_fee(amount: Uint128, bps: u16) -> Result<Uint128, MathError> {
+    if bps > BPS_DENOMINATOR {
+        return Err(MathError::InvalidBps { bps });
+    }
+    mul_div(amount, bps, BPS_DENOMINATOR)
+}
+
+/// Splits `amount` proportionally to `weights`, rounding every part down.
+///
+/// The parts add up to at most `amount` - the remainder is less than the number of weights.
+/// Computing every part from the weights directly, instead of from `Decimal` ratios, keeps the
+/// result exact for any amount.
+pub fn split(amount: Uint128, weights: &[u64]) -> Result<Vec<Uint128>, MathError> {
+    let total: u128 = weights.iter().map(|weight| u128::from(*weight)).sum();
+    weights
+        .iter()
+        .map(|weight| mul_div(amount, *weight, total))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul_div_rounding() {
+        assert_eq!(mul_div(Uint128::new(10), 1u8, 3u8), Ok(Uint128::new(3)));
+        assert_eq!(mul_div(Uint128::new(10), 2u8, 3u8), Ok(Uint128::new(6)));
+        assert_eq!(
+            mul_div(Uint128::new(10), 1u8, 0u8),
+            Err(MathError::DivideByZero)
+        );
+    }
+
+    #[test]
+    fn mul_div_overflow() {
+        // The intermediate product does not fit into 128 bits, the result does
+        assert_eq!(
+            mul_div(Uint128::MAX, u128::MAX, u128::MAX),
+            Ok(Uint128::MAX)
+        );
+        assert_eq!(mul_div(Uint128::MAX, 2u8, 1u8), Err(MathError::Overflow));
+    }
+
+    #[test]
+    fn decimal_product() {
+        let third = Decimal::from_ratio(1u8, 3u8);
+        assert_eq!(mul_decimal(Uint128::new(100), third), Ok(Uint128::new(33)));
+        assert_eq!(
+            mul_decimal(Uint128::MAX, Decimal::percent(50)),
+            Ok(Uint128::new(u128::MAX / 2))
+        );
+        assert_eq!(
+            mul_decimal(Uint128::MAX, Decimal::percent(200)),
+            Err(MathError::Overflow)
+        );
+    }
+
+    #[test]
+    fn fees() {
+        assert_eq!(bps_fee(Uint128::new(10_000), 25), Ok(Uint128::new(25)));
+        // 0.25% of 399 is 0.9975, rounded down to nothing
+        assert_eq!(bps_fee(Uint128::new(399), 25), Ok(Uint128::zero()));
+        assert_eq!(bps_fee(Uint128::MAX, BPS_DENOMINATOR), Ok(Uint128::MAX));
+        assert_eq!(
+            bps_fee(Uint128::new(100), 10_001),
+            Err(MathError::InvalidBps { bps: 10_001 })
+        );
+    }
+
+    #[test]
+    fn proportional_split() {
+        let parts = split(Uint128::new(100), &[1, 1, 1]).unwrap();
+        assert_eq!(parts, [Uint128::new(33); 3]);
+
+        let parts = split(Uint128::new(1000), &[1, 3]).unwrap();
+        assert_eq!(parts, [Uint128::new(250), Uint128::new(750)]);
+
+        // `Decimal` ratios lose precision on large amounts, weights do not
+        let amount = Uint128::new(3 * 10u128.pow(30));
+        let parts = split(amount, &[1, 2]).unwrap();
+        assert_eq!(
+            parts,
+            [
+                Uint128::new(10u128.pow(30)),
+                Uint128::new(2 * 10u128.pow(30))
+            ]
+        );
+
+        assert_eq!(
+            split(Uint128::MAX, &[u64::MAX, u64::MAX]).unwrap()[0],
+            Uint128::MAX / Uint128::new(2)
+        );
+        assert_eq!(
+            split(Uint128::new(100), &[0, 0]),
+            Err(MathError::DivideByZero)
+        );
+    }
+}
@@ -0,0 +1,364 @@
+use crate::error::ContractError;
+use crate::msg::{BatchResp, ConfigResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{Batch, BATCHES, NEXT_BATCH_ID, SIGNERS, THRESHOLD};
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult, Storage,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    for signer in msg.signers {
+        let signer = deps.api.addr_validate(&signer)?;
+        SIGNERS.save(deps.storage, &signer, &Empty {})?;
+    }
+
+    // Counted after saving, so duplicated signers are not counted twice
+    let signers = SIGNERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count() as u32;
+    if msg.threshold == 0 || msg.threshold > signers {
+        return Err(ContractError::InvalidThreshold {
+            threshold: msg.threshold,
+            signers,
+        });
+    }
+
+    THRESHOLD.save(deps.storage, &msg.threshold)?;
+    NEXT_BATCH_ID.save(deps.storage, &0)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Config {} => to_json_binary(&query::config(deps)?),
+        Batch { id } => to_json_binary(&query::batch(deps, id)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Propose { msgs } => exec::propose(deps, info, msgs),
+        Approve { id } => exec::approve(deps, info, id),
+    }
+}
+
+/// Sends the batch if it has enough approvals, otherwise stores it as pending.
+fn approved(
+    storage: &mut dyn Storage,
+    id: u64,
+    batch: Batch,
+    resp: Response,
+) -> Result<Response, ContractError> {
+    let threshold = THRESHOLD.load(storage)?;
+    if batch.approvals.len() < threshold as usize {
+        BATCHES.save(storage, id, &batch)?;
+        return Ok(resp.add_attribute("executed", "false"));
+    }
+
+    BATCHES.remove(storage, id);
+    let resp = resp
+        .add_messages(batch.msgs)
+        .add_attribute("executed", "true");
+
+    Ok(resp)
+}
+
+mod exec {
+    use super::*;
+
+    fn ensure_signer(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+        if !SIGNERS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn propose(
+        deps: DepsMut,
+        info: MessageInfo,
+        msgs: Vec<CosmosMsg>,
+    ) -> Result<Response, ContractError> {
+        ensure_signer(deps.as_ref(), &info)?;
+
+        let id = NEXT_BATCH_ID.load(deps.storage)?;
+        NEXT_BATCH_ID.save(deps.storage, &(id + 1))?;
+
+        let resp = Response::new()
+            .add_attribute("action", "propose")
+            .add_attribute("sender", info.sender.as_str())
+            .add_attribute("batch_id", id.to_string());
+
+        let batch = Batch {
+            msgs,
+            approvals: vec![info.sender],
+        };
+        approved(deps.storage, id, batch, resp)
+    }
+
+    pub fn approve(deps: DepsMut, info: MessageInfo, id: u64) -> Result<Response, ContractError> {
+        ensure_signer(deps.as_ref(), &info)?;
+
+        let mut batch = BATCHES.load(deps.storage, id)?;
+        if batch.approvals.contains(&info.sender) {
+            return Err(ContractError::AlreadyApproved {
+                id,
+                signer: info.sender,
+            });
+        }
+
+        let resp = Response::new()
+            .add_attribute("action", "approve")
+            .add_attribute("sender", info.sender.as_str())
+            .add_attribute("batch_id", id.to_string());
+
+        batch.approvals.push(info.sender);
+        approved(deps.storage, id, batch, resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn config(deps: Deps) -> StdResult<ConfigResp> {
+        let signers = SIGNERS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        let threshold = THRESHOLD.load(deps.storage)?;
+
+        Ok(ConfigResp { signers, threshold })
+    }
+
+    pub fn batch(deps: Deps, id: u64) -> StdResult<BatchResp> {
+        let batch = BATCHES.load(deps.storage, id)?;
+        Ok(BatchResp {
+            id,
+            msgs: batch.msgs,
+            approvals: batch.approvals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::BankMsg;
+    use testing::prelude::*;
+
+    use super::*;
+
+    /// Multisig of `admin1..=admin<signers>`, funded with 100 atom.
+    fn multisig(app: &mut App, signers: u32, threshold: u32) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            signers: (1..=signers).map(|n| addr::admin(n).to_string()).collect(),
+            threshold,
+        };
+        let contract = Proxy::deploy(app, code, "owner", &msg, "Multisig").unwrap();
+
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, contract.addr(), coins(100, "atom"))
+        })
+        .unwrap();
+
+        contract
+    }
+
+    fn pay_user(amount: u128) -> Vec<CosmosMsg> {
+        vec![BankMsg::Send {
+            to_address: mock_addr("user").to_string(),
+            amount: coins(amount, "atom"),
+        }
+        .into()]
+    }
+
+    fn propose(app: &mut App, multisig: &Proxy, sender: &str) -> anyhow::Result<AppResponse> {
+        multisig.execute(
+            app,
+            sender,
+            &ExecuteMsg::Propose { msgs: pay_user(10) },
+            &[],
+        )
+    }
+
+    fn approve(
+        app: &mut App,
+        multisig: &Proxy,
+        sender: &str,
+        id: u64,
+    ) -> anyhow::Result<AppResponse> {
+        multisig.execute(app, sender, &ExecuteMsg::Approve { id }, &[])
+    }
+
+    #[test]
+    fn invalid_threshold() {
+        let mut app = app();
+        let code_id = store_code(&mut app, ContractWrapper::new(execute, instantiate, query));
+        let instantiate = |app: &mut App, signers: &[&str], threshold| {
+            let msg = InstantiateMsg {
+                signers: signers.iter().map(|s| mock_addr(s).to_string()).collect(),
+                threshold,
+            };
+            Proxy::instantiate(app, code_id, "owner", &msg, &[], "Multisig")
+        };
+
+        let err = instantiate(&mut app, &["admin1", "admin2"], 0).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidThreshold {
+                threshold: 0,
+                signers: 2
+            },
+            err.downcast().unwrap()
+        );
+
+        // Duplicated signer is counted once
+        let err = instantiate(&mut app, &["admin1", "admin1"], 2).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidThreshold {
+                threshold: 2,
+                signers: 1
+            },
+            err.downcast().unwrap()
+        );
+
+        instantiate(&mut app, &["admin1", "admin2"], 2).unwrap();
+    }
+
+    #[test]
+    fn single_approval_executes_immediately() {
+        let mut app = app();
+        let multisig = multisig(&mut app, 3, 1);
+
+        let resp = propose(&mut app, &multisig, "admin2").unwrap();
+        assert_eq!(wasm_attr(&resp, "executed"), "true");
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 10);
+
+        // Sent batches are removed
+        multisig
+            .query::<BatchResp>(&app, &QueryMsg::Batch { id: 0 })
+            .unwrap_err();
+    }
+
+    #[test]
+    fn batch_executes_at_threshold() {
+        let mut app = app();
+        let multisig = multisig(&mut app, 3, 2);
+
+        let resp = propose(&mut app, &multisig, "admin1").unwrap();
+        assert_eq!(wasm_attr(&resp, "executed"), "false");
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 0);
+
+        let resp: BatchResp = multisig.query(&app, &QueryMsg::Batch { id: 0 }).unwrap();
+        assert_eq!(
+            resp,
+            BatchResp {
+                id: 0,
+                msgs: pay_user(10),
+                approvals: vec![addr::admin(1)],
+            }
+        );
+
+        let resp = approve(&mut app, &multisig, "admin3", 0).unwrap();
+        assert_eq!(wasm_attr(&resp, "executed"), "true");
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 10);
+
+        // The third signer is too late - the batch is already gone
+        approve(&mut app, &multisig, "admin2", 0).unwrap_err();
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 10);
+    }
+
+    #[test]
+    fn all_signers_required() {
+        let mut app = app();
+        let multisig = multisig(&mut app, 3, 3);
+
+        propose(&mut app, &multisig, "admin1").unwrap();
+        approve(&mut app, &multisig, "admin2", 0).unwrap();
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 0);
+
+        approve(&mut app, &multisig, "admin3", 0).unwrap();
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 10);
+    }
+
+    #[test]
+    fn duplicate_approval() {
+        let mut app = app();
+        let multisig = multisig(&mut app, 3, 2);
+
+        propose(&mut app, &multisig, "admin1").unwrap();
+        let err = approve(&mut app, &multisig, "admin1", 0).unwrap_err();
+        assert_eq!(
+            ContractError::AlreadyApproved {
+                id: 0,
+                signer: addr::admin(1)
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp: BatchResp = multisig.query(&app, &QueryMsg::Batch { id: 0 }).unwrap();
+        assert_eq!(resp.approvals, [addr::admin(1)]);
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 0);
+    }
+
+    #[test]
+    fn unauthorized() {
+        let mut app = app();
+        let multisig = multisig(&mut app, 2, 2);
+
+        let err = propose(&mut app, &multisig, "user").unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        propose(&mut app, &multisig, "admin1").unwrap();
+        let err = approve(&mut app, &multisig, "user", 0).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn failed_batch_stays_pending() {
+        let mut app = app();
+        let multisig = multisig(&mut app, 2, 2);
+
+        multisig
+            .execute(
+                &mut app,
+                "admin1",
+                &ExecuteMsg::Propose {
+                    msgs: pay_user(1000),
+                },
+                &[],
+            )
+            .unwrap();
+        // Not enough funds - the approval is reverted together with the failed send
+        approve(&mut app, &multisig, "admin2", 0).unwrap_err();
+
+        let resp: BatchResp = multisig.query(&app, &QueryMsg::Batch { id: 0 }).unwrap();
+        assert_eq!(resp.approvals, [addr::admin(1)]);
+    }
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not a signer")]
+    Unauthorized { sender: Addr },
+    #[error("Threshold {threshold} is out of 1..={signers} range")]
+    InvalidThreshold { threshold: u32, signers: u32 },
+    #[error("{signer} already approved batch {id}")]
+    AlreadyApproved { id: u64, signer: Addr },
+}
@@ -0,0 +1,46 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, CosmosMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub signers: Vec<String>,
+    /// Number of approvals needed to send a batch
+    pub threshold: u32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Creates a new batch, approved by the sender
+    Propose { msgs: Vec<CosmosMsg> },
+    /// Approves the pending batch, sending it if the threshold is reached
+    Approve { id: u64 },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ConfigResp {
+    pub signers: Vec<Addr>,
+    pub threshold: u32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct BatchResp {
+    pub id: u64,
+    pub msgs: Vec<CosmosMsg>,
+    pub approvals: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(ConfigResp)]
+    Config {},
+    /// Pending batch - sent batches are not stored anymore
+    #[returns(BatchResp)]
+    Batch { id: u64 },
+}
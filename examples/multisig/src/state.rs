@@ -0,0 +1,18 @@
+use cosmwasm_std::{Addr, CosmosMsg, Empty};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Messages waiting for enough approvals to be sent.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Batch {
+    pub msgs: Vec<CosmosMsg>,
+    pub approvals: Vec<Addr>,
+}
+
+/// Number of approvals needed to send a batch
+pub const THRESHOLD: Item<u32> = Item::new("threshold");
+pub const SIGNERS: Map<&Addr, Empty> = Map::new("signers");
+pub const NEXT_BATCH_ID: Item<u64> = Item::new("next_batch_id");
+/// Pending batches, removed once they are sent
+pub const BATCHES: Map<u64, Batch> = Map::new("batches");
@@ -0,0 +1,412 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, NameResp, NamesResp, QueryMsg, ResolveResp};
+use crate::state::{Config, Record, CONFIG, NAMES};
+use common::pagination;
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult,
+};
+
+const MIN_NAME_LENGTH: usize = 3;
+const MAX_NAME_LENGTH: usize = 64;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        treasury: info.sender,
+        purchase_price: msg.purchase_price,
+        renewal_price: msg.renewal_price,
+        period: msg.period,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Resolve { name } => to_json_binary(&query::resolve(deps, env, name)?),
+        Names { start_after, limit } => to_json_binary(&query::names(deps, start_after, limit)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Register { name } => exec::register(deps, env, info, name),
+        Renew { name } => exec::renew(deps, env, info, name),
+        Transfer { name, to } => exec::transfer(deps, env, info, name, to),
+    }
+}
+
+/// Names are limited to lowercase letters, digits and dashes, so visually identical names cannot
+/// be registered by different owners.
+fn validate_name(name: &str) -> Result<(), ContractError> {
+    let valid_char = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-';
+    if !(MIN_NAME_LENGTH..=MAX_NAME_LENGTH).contains(&name.len()) || !name.chars().all(valid_char) {
+        return Err(ContractError::InvalidName {
+            name: name.to_owned(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks the exact price was paid and forwards it to the treasury.
+fn pay(info: &MessageInfo, price: &Coin, config: &Config) -> Result<BankMsg, ContractError> {
+    let amount = cw_utils::must_pay(info, &price.denom)?;
+    if amount != price.amount {
+        return Err(ContractError::InvalidPayment {
+            price: price.clone(),
+        });
+    }
+
+    Ok(BankMsg::Send {
+        to_address: config.treasury.to_string(),
+        amount: vec![price.clone()],
+    })
+}
+
+/// Loads the record of a name, treating expired ones as not registered.
+fn active_record(deps: Deps, env: &Env, name: &str) -> Result<Record, ContractError> {
+    NAMES
+        .may_load(deps.storage, name)?
+        .filter(|record| !record.is_expired(&env.block))
+        .ok_or_else(|| ContractError::NotRegistered {
+            name: name.to_owned(),
+        })
+}
+
+mod exec {
+    use super::*;
+
+    pub fn register(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        name: String,
+    ) -> Result<Response, ContractError> {
+        validate_name(&name)?;
+        if active_record(deps.as_ref(), &env, &name).is_ok() {
+            return Err(ContractError::NameTaken { name });
+        }
+
+        let config = CONFIG.load(deps.storage)?;
+        let fee = pay(&info, &config.purchase_price, &config)?;
+
+        let record = Record {
+            owner: info.sender.clone(),
+            expires: env.block.time.plus_seconds(config.period),
+        };
+        NAMES.save(deps.storage, &name, &record)?;
+
+        let resp = Response::new()
+            .add_message(fee)
+            .add_attribute("action", "register")
+            .add_attribute("name", name)
+            .add_attribute("owner", info.sender.as_str());
+
+        Ok(resp)
+    }
+
+    pub fn renew(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        name: String,
+    ) -> Result<Response, ContractError> {
+        let mut record = active_record(deps.as_ref(), &env, &name)?;
+        if info.sender != record.owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let config = CONFIG.load(deps.storage)?;
+        let fee = pay(&info, &config.renewal_price, &config)?;
+
+        record.expires = record.expires.plus_seconds(config.period);
+        NAMES.save(deps.storage, &name, &record)?;
+
+        let resp = Response::new()
+            .add_message(fee)
+            .add_attribute("action", "renew")
+            .add_attribute("name", name)
+            .add_attribute("expires", record.expires.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn transfer(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        name: String,
+        to: String,
+    ) -> Result<Response, ContractError> {
+        let mut record = active_record(deps.as_ref(), &env, &name)?;
+        if info.sender != record.owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        record.owner = deps.api.addr_validate(&to)?;
+        NAMES.save(deps.storage, &name, &record)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "transfer")
+            .add_attribute("name", name)
+            .add_attribute("owner", record.owner.as_str());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn resolve(deps: Deps, env: Env, name: String) -> StdResult<ResolveResp> {
+        let address = NAMES
+            .may_load(deps.storage, &name)?
+            .filter(|record| !record.is_expired(&env.block))
+            .map(|record| record.owner);
+        Ok(ResolveResp { address })
+    }
+
+    pub fn names(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<NamesResp> {
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after.as_deref());
+
+        let names: Vec<_> = NAMES
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (name, record) = item?;
+                Ok(NameResp {
+                    name,
+                    owner: record.owner,
+                    expires: record.expires,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after = pagination::next_key(&names, limit, |name| name.name.clone());
+
+        Ok(NamesResp {
+            names,
+            next_start_after,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    const YEAR: u64 = 365 * 24 * 60 * 60;
+
+    /// Names cost 100 atom, and 10 atom for every following year.
+    fn registry(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            purchase_price: coin(100, "atom"),
+            renewal_price: coin(10, "atom"),
+            period: YEAR,
+        };
+        Proxy::deploy(app, code, "treasury", &msg, "Names").unwrap()
+    }
+
+    fn funded_app() -> App {
+        app_with_balances(&[("alice", coins(1000, "atom")), ("bob", coins(1000, "atom"))])
+    }
+
+    fn register(
+        app: &mut App,
+        registry: &Proxy,
+        sender: &str,
+        name: &str,
+    ) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Register {
+            name: name.to_owned(),
+        };
+        registry.execute(app, sender, &msg, &coins(100, "atom"))
+    }
+
+    fn resolve(app: &App, registry: &Proxy, name: &str) -> Option<Addr> {
+        let msg = QueryMsg::Resolve {
+            name: name.to_owned(),
+        };
+        let resp: ResolveResp = registry.query(app, &msg).unwrap();
+        resp.address
+    }
+
+    #[test]
+    fn register_and_resolve() {
+        let mut app = funded_app();
+        let registry = registry(&mut app);
+
+        assert_eq!(resolve(&app, &registry, "alice"), None);
+        register(&mut app, &registry, "alice", "alice").unwrap();
+        assert_eq!(resolve(&app, &registry, "alice"), Some(mock_addr("alice")));
+        assert_eq!(balance(&app, mock_addr("treasury"), "atom"), 100);
+
+        let err = register(&mut app, &registry, "bob", "alice").unwrap_err();
+        assert_eq!(
+            ContractError::NameTaken {
+                name: "alice".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+
+        let msg = ExecuteMsg::Register {
+            name: "bob".to_owned(),
+        };
+        let err = registry
+            .execute(&mut app, "bob", &msg, &coins(50, "atom"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::InvalidPayment {
+                price: coin(100, "atom")
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_names() {
+        let mut app = funded_app();
+        let registry = registry(&mut app);
+
+        let long = "a".repeat(MAX_NAME_LENGTH + 1);
+        for name in ["al", "Alice", "alice.bob", "ąlice", &long] {
+            let err = register(&mut app, &registry, "alice", name).unwrap_err();
+            assert_eq!(
+                ContractError::InvalidName {
+                    name: name.to_owned()
+                },
+                err.downcast().unwrap()
+            );
+        }
+
+        register(&mut app, &registry, "alice", "alice-42").unwrap();
+    }
+
+    #[test]
+    fn expiration_and_renewal() {
+        let mut app = funded_app();
+        let registry = registry(&mut app);
+
+        register(&mut app, &registry, "alice", "alice").unwrap();
+        app.advance_time(YEAR - 1);
+
+        let renew = ExecuteMsg::Renew {
+            name: "alice".to_owned(),
+        };
+        let err = registry
+            .execute(&mut app, "bob", &renew, &coins(10, "atom"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("bob")
+            },
+            err.downcast().unwrap()
+        );
+        registry
+            .execute(&mut app, "alice", &renew, &coins(10, "atom"))
+            .unwrap();
+        assert_eq!(balance(&app, mock_addr("treasury"), "atom"), 110);
+
+        // Renewal extends the current registration, not the time of renewal
+        app.advance_time(YEAR);
+        assert_eq!(resolve(&app, &registry, "alice"), Some(mock_addr("alice")));
+
+        // Once expired, the name is free for anyone, and cannot be renewed anymore
+        app.advance_time(1);
+        assert_eq!(resolve(&app, &registry, "alice"), None);
+        let err = registry
+            .execute(&mut app, "alice", &renew, &coins(10, "atom"))
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotRegistered {
+                name: "alice".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+
+        register(&mut app, &registry, "bob", "alice").unwrap();
+        assert_eq!(resolve(&app, &registry, "alice"), Some(mock_addr("bob")));
+    }
+
+    #[test]
+    fn transfer() {
+        let mut app = funded_app();
+        let registry = registry(&mut app);
+
+        register(&mut app, &registry, "alice", "alice").unwrap();
+
+        let msg = ExecuteMsg::Transfer {
+            name: "alice".to_owned(),
+            to: mock_addr("bob").to_string(),
+        };
+        let err = registry.execute(&mut app, "bob", &msg, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("bob")
+            },
+            err.downcast().unwrap()
+        );
+
+        registry.execute(&mut app, "alice", &msg, &[]).unwrap();
+        assert_eq!(resolve(&app, &registry, "alice"), Some(mock_addr("bob")));
+        registry.execute(&mut app, "alice", &msg, &[]).unwrap_err();
+    }
+
+    #[test]
+    fn names_pagination() {
+        let mut app = app_with_balances(&[("alice", coins(10000, "atom"))]);
+        let registry = registry(&mut app);
+
+        let names = ["abc", "alice", "bob", "carol", "dave"];
+        for name in names {
+            register(&mut app, &registry, "alice", name).unwrap();
+        }
+
+        let msg = QueryMsg::Names {
+            start_after: None,
+            limit: Some(2),
+        };
+        let resp: NamesResp = registry.query(&app, &msg).unwrap();
+        let listed: Vec<_> = resp.names.iter().map(|name| name.name.as_str()).collect();
+        assert_eq!(listed, ["abc", "alice"]);
+        assert_eq!(resp.next_start_after.as_deref(), Some("alice"));
+
+        let msg = QueryMsg::Names {
+            start_after: Some("alice".to_owned()),
+            limit: None,
+        };
+        let resp: NamesResp = registry.query(&app, &msg).unwrap();
+        let listed: Vec<_> = resp.names.iter().map(|name| name.name.as_str()).collect();
+        assert_eq!(listed, ["bob", "carol", "dave"]);
+        assert_eq!(resp.next_start_after, None);
+        assert_eq!(resp.names[0].owner, mock_addr("alice"));
+    }
+}
@@ -0,0 +1,21 @@
+use cosmwasm_std::{Addr, Coin, StdError};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+    #[error("Invalid name: {name}")]
+    InvalidName { name: String },
+    #[error("Name {name} is already taken")]
+    NameTaken { name: String },
+    #[error("Name {name} is not registered")]
+    NotRegistered { name: String },
+    #[error("Expected payment of exactly {price}")]
+    InvalidPayment { price: Coin },
+    #[error("{sender} is not the name owner")]
+    Unauthorized { sender: Addr },
+}
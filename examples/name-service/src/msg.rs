@@ -0,0 +1,66 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The sender becomes the treasury receiving the fees.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub purchase_price: Coin,
+    pub renewal_price: Coin,
+    pub period: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Registers a free or expired name for the sender, paying the purchase price
+    Register {
+        name: String,
+    },
+    /// Extends the registration by one period, paying the renewal price
+    Renew {
+        name: String,
+    },
+    Transfer {
+        name: String,
+        to: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ResolveResp {
+    /// `None` if the name is not registered or expired
+    pub address: Option<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NameResp {
+    pub name: String,
+    pub owner: Addr,
+    pub expires: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct NamesResp {
+    pub names: Vec<NameResp>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(ResolveResp)]
+    Resolve { name: String },
+    /// Lists all the records, including expired ones
+    #[returns(NamesResp)]
+    Names {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
@@ -0,0 +1,30 @@
+use cosmwasm_std::{Addr, BlockInfo, Coin, Timestamp};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    /// Receives all the fees paid
+    pub treasury: Addr,
+    pub purchase_price: Coin,
+    pub renewal_price: Coin,
+    /// Seconds a registration or renewal is valid for
+    pub period: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Record {
+    pub owner: Addr,
+    pub expires: Timestamp,
+}
+
+impl Record {
+    /// Expired records are kept in the storage, but anyone can register the name again.
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        block.time >= self.expires
+    }
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const NAMES: Map<&str, Record> = Map::new("names");
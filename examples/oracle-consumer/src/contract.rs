@@ -0,0 +1,206 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ValueResp};
+use crate::state::{Config, CONFIG, VALUES};
+use cosmwasm_std::{
+    to_json_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+use oracle::helpers::OracleContract;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        oracle: OracleContract(deps.api.addr_validate(&msg.oracle)?),
+        pair: msg.pair,
+        denom: msg.denom,
+        max_age: msg.max_age,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Value { address } => to_json_binary(&query::value(deps, address)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Deposit {} => exec::deposit(deps, env, info),
+    }
+}
+
+/// Queries the oracle for the price, rejecting it if it was not updated recently enough.
+fn fresh_price(deps: Deps, env: &Env, config: &Config) -> Result<Decimal, ContractError> {
+    let resp = config.oracle.price(&deps.querier, &config.pair)?;
+
+    let age = env
+        .block
+        .time
+        .seconds()
+        .saturating_sub(resp.updated_at.seconds());
+    if age > config.max_age {
+        return Err(ContractError::StalePrice {
+            updated_at: resp.updated_at,
+            max_age: config.max_age,
+        });
+    }
+
+    Ok(resp.price)
+}
+
+mod exec {
+    use super::*;
+
+    pub fn deposit(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        let amount = cw_utils::must_pay(&info, &config.denom)?;
+        let price = fresh_price(deps.as_ref(), &env, &config)?;
+        let value = amount.mul_floor(price);
+
+        VALUES.update(deps.storage, &info.sender, |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default() + value)
+        })?;
+
+        let resp = Response::new()
+            .add_attribute("action", "deposit")
+            .add_attribute("amount", amount.to_string())
+            .add_attribute("price", price.to_string())
+            .add_attribute("value", value.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn value(deps: Deps, address: String) -> StdResult<ValueResp> {
+        let address = deps.api.addr_validate(&address)?;
+        let value = VALUES.may_load(deps.storage, &address)?.unwrap_or_default();
+        Ok(ValueResp { value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    const MAX_AGE: u64 = 60;
+
+    /// Oracle with `feeder` and the consumer of its `ATOM/USD` price.
+    fn contracts(app: &mut App) -> (Proxy, Proxy) {
+        let code = ContractWrapper::new(
+            oracle::contract::execute,
+            oracle::contract::instantiate,
+            oracle::contract::query,
+        );
+        let msg = oracle::msg::InstantiateMsg {
+            feeders: vec![mock_addr("feeder").to_string()],
+        };
+        let oracle = Proxy::deploy(app, code, "owner", &msg, "Oracle").unwrap();
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            oracle: oracle.addr().to_string(),
+            pair: "ATOM/USD".to_owned(),
+            denom: "atom".to_owned(),
+            max_age: MAX_AGE,
+        };
+        let consumer = Proxy::deploy(app, code, "owner", &msg, "Consumer").unwrap();
+
+        (oracle, consumer)
+    }
+
+    fn set_price(app: &mut App, oracle: &Proxy, price: Decimal) {
+        let msg = oracle::msg::ExecuteMsg::SetPrice {
+            pair: "ATOM/USD".to_owned(),
+            price,
+        };
+        oracle.execute(app, "feeder", &msg, &[]).unwrap();
+    }
+
+    fn deposit(app: &mut App, consumer: &Proxy, amount: u128) -> anyhow::Result<AppResponse> {
+        consumer.execute(app, "user", &ExecuteMsg::Deposit {}, &coins(amount, "atom"))
+    }
+
+    fn value(app: &App, consumer: &Proxy) -> u128 {
+        let msg = QueryMsg::Value {
+            address: mock_addr("user").to_string(),
+        };
+        let resp: ValueResp = consumer.query(app, &msg).unwrap();
+        resp.value.u128()
+    }
+
+    #[test]
+    fn deposits_are_valued_at_current_price() {
+        let mut app = app_with_balances(&[("user", coins(1000, "atom"))]);
+        let (oracle, consumer) = contracts(&mut app);
+
+        set_price(&mut app, &oracle, Decimal::percent(1050));
+        deposit(&mut app, &consumer, 100).unwrap();
+        assert_eq!(value(&app, &consumer), 1050);
+
+        app.advance_time(MAX_AGE);
+        set_price(&mut app, &oracle, Decimal::percent(900));
+        let resp = deposit(&mut app, &consumer, 10).unwrap();
+        assert_eq!(wasm_attr(&resp, "value"), "90");
+        assert_eq!(value(&app, &consumer), 1140);
+    }
+
+    #[test]
+    fn stale_price_is_rejected() {
+        let mut app = app_with_balances(&[("user", coins(1000, "atom"))]);
+        let (oracle, consumer) = contracts(&mut app);
+
+        set_price(&mut app, &oracle, Decimal::one());
+        let updated_at = app.block_info().time;
+
+        app.advance_time(MAX_AGE);
+        deposit(&mut app, &consumer, 100).unwrap();
+
+        app.advance_time(1);
+        let err = deposit(&mut app, &consumer, 100).unwrap_err();
+        assert_eq!(
+            ContractError::StalePrice {
+                updated_at,
+                max_age: MAX_AGE
+            },
+            err.downcast().unwrap()
+        );
+        // Failed deposit is reverted, funds stay with the user
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 900);
+
+        set_price(&mut app, &oracle, Decimal::one());
+        deposit(&mut app, &consumer, 100).unwrap();
+        assert_eq!(value(&app, &consumer), 200);
+    }
+
+    #[test]
+    fn missing_price() {
+        let mut app = app_with_balances(&[("user", coins(1000, "atom"))]);
+        let (_, consumer) = contracts(&mut app);
+
+        let err = deposit(&mut app, &consumer, 100).unwrap_err();
+        assert!(matches!(
+            err.downcast().unwrap(),
+            ContractError::StdError(_)
+        ));
+    }
+}
@@ -0,0 +1,13 @@
+use cosmwasm_std::{StdError, Timestamp};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+    #[error("Price updated at {updated_at} is older than {max_age} seconds")]
+    StalePrice { updated_at: Timestamp, max_age: u64 },
+}
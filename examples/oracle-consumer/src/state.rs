@@ -0,0 +1,19 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+use oracle::helpers::OracleContract;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub oracle: OracleContract,
+    /// Oracle pair pricing `denom`
+    pub pair: String,
+    pub denom: String,
+    /// Oldest price accepted, in seconds
+    pub max_age: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// Value of deposits by depositor, at the prices from the time of deposit
+pub const VALUES: Map<&Addr, Uint128> = Map::new("values");
@@ -0,0 +1,214 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, FeedersResp, InstantiateMsg, PriceResp, QueryMsg};
+use crate::state::{Price, FEEDERS, OWNER, PRICES};
+use cosmwasm_std::{
+    to_json_binary, Binary, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    OWNER.save(deps.storage, &info.sender)?;
+    for feeder in msg.feeders {
+        let feeder = deps.api.addr_validate(&feeder)?;
+        FEEDERS.save(deps.storage, &feeder, &Empty {})?;
+    }
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Price { pair } => to_json_binary(&query::price(deps, pair)?),
+        Feeders {} => to_json_binary(&query::feeders(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        UpdateFeeders { add, remove } => exec::update_feeders(deps, info, add, remove),
+        SetPrice { pair, price } => exec::set_price(deps, env, info, pair, price),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn update_feeders(
+        deps: DepsMut,
+        info: MessageInfo,
+        add: Vec<String>,
+        remove: Vec<String>,
+    ) -> Result<Response, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        for feeder in remove {
+            let feeder = deps.api.addr_validate(&feeder)?;
+            FEEDERS.remove(deps.storage, &feeder);
+        }
+        for feeder in add {
+            let feeder = deps.api.addr_validate(&feeder)?;
+            FEEDERS.save(deps.storage, &feeder, &Empty {})?;
+        }
+
+        Ok(Response::new().add_attribute("action", "update_feeders"))
+    }
+
+    pub fn set_price(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        pair: String,
+        price: Decimal,
+    ) -> Result<Response, ContractError> {
+        if !FEEDERS.has(deps.storage, &info.sender) {
+            return Err(ContractError::NotFeeder {
+                sender: info.sender,
+            });
+        }
+
+        let update = Price {
+            price,
+            updated_at: env.block.time,
+            feeder: info.sender,
+        };
+        PRICES.save(deps.storage, &pair, &update)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "set_price")
+            .add_attribute("pair", pair)
+            .add_attribute("price", price.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn price(deps: Deps, pair: String) -> StdResult<PriceResp> {
+        let price = PRICES.load(deps.storage, &pair)?;
+        Ok(PriceResp {
+            price: price.price,
+            updated_at: price.updated_at,
+        })
+    }
+
+    pub fn feeders(deps: Deps) -> StdResult<FeedersResp> {
+        let feeders = FEEDERS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        Ok(FeedersResp { feeders })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    fn oracle(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            feeders: vec![mock_addr("feeder").to_string()],
+        };
+        Proxy::deploy(app, code, "owner", &msg, "Oracle").unwrap()
+    }
+
+    fn set_price(
+        app: &mut App,
+        oracle: &Proxy,
+        sender: &str,
+        price: Decimal,
+    ) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::SetPrice {
+            pair: "ATOM/USD".to_owned(),
+            price,
+        };
+        oracle.execute(app, sender, &msg, &[])
+    }
+
+    fn price(app: &App, oracle: &Proxy) -> PriceResp {
+        let msg = QueryMsg::Price {
+            pair: "ATOM/USD".to_owned(),
+        };
+        oracle.query(app, &msg).unwrap()
+    }
+
+    #[test]
+    fn latest_price_is_stored() {
+        let mut app = app();
+        let oracle = oracle(&mut app);
+
+        set_price(&mut app, &oracle, "feeder", Decimal::percent(1050)).unwrap();
+        app.advance_blocks(3);
+        set_price(&mut app, &oracle, "feeder", Decimal::percent(1120)).unwrap();
+
+        assert_eq!(
+            price(&app, &oracle),
+            PriceResp {
+                price: Decimal::percent(1120),
+                updated_at: app.block_info().time,
+            }
+        );
+
+        let msg = QueryMsg::Price {
+            pair: "BTC/USD".to_owned(),
+        };
+        oracle.query::<PriceResp>(&app, &msg).unwrap_err();
+    }
+
+    #[test]
+    fn only_feeders_set_prices() {
+        let mut app = app();
+        let oracle = oracle(&mut app);
+
+        let err = set_price(&mut app, &oracle, "user", Decimal::one()).unwrap_err();
+        assert_eq!(
+            ContractError::NotFeeder {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        let update = ExecuteMsg::UpdateFeeders {
+            add: vec![mock_addr("user").to_string()],
+            remove: vec![mock_addr("feeder").to_string()],
+        };
+        let err = oracle
+            .execute(&mut app, "feeder", &update, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("feeder")
+            },
+            err.downcast().unwrap()
+        );
+
+        oracle.execute(&mut app, "owner", &update, &[]).unwrap();
+        let resp: FeedersResp = oracle.query(&app, &QueryMsg::Feeders {}).unwrap();
+        assert_eq!(resp.feeders, [mock_addr("user")]);
+
+        set_price(&mut app, &oracle, "user", Decimal::one()).unwrap();
+        set_price(&mut app, &oracle, "feeder", Decimal::one()).unwrap_err();
+    }
+}
@@ -0,0 +1,23 @@
+//! Typed access to a deployed oracle contract for its consumers.
+
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::msg::{PriceResp, QueryMsg};
+
+/// Address of an oracle contract. Serialized as the bare address, so it can be stored in place
+/// of an `Addr`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct OracleContract(pub Addr);
+
+impl OracleContract {
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    pub fn price(&self, querier: &QuerierWrapper, pair: impl Into<String>) -> StdResult<PriceResp> {
+        let msg = QueryMsg::Price { pair: pair.into() };
+        querier.query_wasm_smart(&self.0, &msg)
+    }
+}
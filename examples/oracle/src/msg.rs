@@ -0,0 +1,45 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Decimal, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub feeders: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Changes the feeders whitelist, owner only
+    UpdateFeeders {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Sets the latest price of the pair, feeders only
+    SetPrice { pair: String, price: Decimal },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct PriceResp {
+    pub price: Decimal,
+    /// Block time of the last update - consumers decide themselves how old a price may be
+    pub updated_at: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FeedersResp {
+    pub feeders: Vec<Addr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(PriceResp)]
+    Price { pair: String },
+    #[returns(FeedersResp)]
+    Feeders {},
+}
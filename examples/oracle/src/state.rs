@@ -0,0 +1,16 @@
+use cosmwasm_std::{Addr, Decimal, Empty, Timestamp};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Price {
+    pub price: Decimal,
+    pub updated_at: Timestamp,
+    pub feeder: Addr,
+}
+
+pub const OWNER: Item<Addr> = Item::new("owner");
+pub const FEEDERS: Map<&Addr, Empty> = Map::new("feeders");
+/// Latest prices by the pair name, like `ATOM/USD`
+pub const PRICES: Map<&str, Price> = Map::new("prices");
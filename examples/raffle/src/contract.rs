@@ -0,0 +1,325 @@
+use std::collections::BTreeMap;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, RaffleResp};
+use crate::rand;
+use crate::state::{Config, Outcome, CONFIG, ENTROPY, OUTCOME, TICKETS, TICKETS_COUNT};
+use cosmwasm_std::{
+    coins, to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    if msg.reveal_deadline <= msg.sales_end {
+        return Err(ContractError::InvalidDeadlines);
+    }
+
+    let config = Config {
+        ticket_price: msg.ticket_price,
+        commitment: msg.commitment,
+        sales_end: msg.sales_end,
+        reveal_deadline: msg.reveal_deadline,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    TICKETS_COUNT.save(deps.storage, &0)?;
+    ENTROPY.save(deps.storage, &Binary::default())?;
+    OUTCOME.save(deps.storage, &Outcome::Open)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Raffle {} => to_json_binary(&query::raffle(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        BuyTicket { entropy } => exec::buy_ticket(deps, env, info, entropy),
+        Reveal { secret } => exec::reveal(deps, env, secret),
+        Refund {} => exec::refund(deps, env),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn buy_ticket(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        entropy: String,
+    ) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        if env.block.time >= config.sales_end {
+            return Err(ContractError::SalesClosed);
+        }
+        if info.funds != [config.ticket_price.clone()] {
+            return Err(ContractError::InvalidPayment {
+                price: config.ticket_price,
+            });
+        }
+
+        let ticket = TICKETS_COUNT.load(deps.storage)?;
+        TICKETS.save(deps.storage, ticket, &info.sender)?;
+        TICKETS_COUNT.save(deps.storage, &(ticket + 1))?;
+
+        let mixed = rand::mix(&ENTROPY.load(deps.storage)?, &entropy);
+        ENTROPY.save(deps.storage, &Binary::from(mixed.as_slice()))?;
+
+        let resp = Response::new()
+            .add_attribute("action", "buy_ticket")
+            .add_attribute("buyer", info.sender.as_str())
+            .add_attribute("ticket", ticket.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn reveal(deps: DepsMut, env: Env, secret: String) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        if OUTCOME.load(deps.storage)? != Outcome::Open {
+            return Err(ContractError::Settled);
+        }
+        if env.block.time < config.sales_end {
+            return Err(ContractError::SalesOpen);
+        }
+        if env.block.time >= config.reveal_deadline {
+            return Err(ContractError::RevealClosed);
+        }
+        if rand::commitment(&secret) != config.commitment.as_slice() {
+            return Err(ContractError::InvalidSecret);
+        }
+
+        let tickets = TICKETS_COUNT.load(deps.storage)?;
+        if tickets == 0 {
+            OUTCOME.save(deps.storage, &Outcome::Refunded)?;
+            return Ok(Response::new().add_attribute("action", "reveal"));
+        }
+
+        let entropy = ENTROPY.load(deps.storage)?;
+        let ticket = rand::winning_ticket(&secret, &entropy, tickets);
+        let winner = TICKETS.load(deps.storage, ticket)?;
+        OUTCOME.save(
+            deps.storage,
+            &Outcome::Won {
+                ticket,
+                winner: winner.clone(),
+            },
+        )?;
+
+        let pot = config.ticket_price.amount.u128() * tickets as u128;
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: winner.to_string(),
+                amount: coins(pot, &config.ticket_price.denom),
+            })
+            .add_attribute("action", "reveal")
+            .add_attribute("ticket", ticket.to_string())
+            .add_attribute("winner", winner.as_str());
+
+        Ok(resp)
+    }
+
+    pub fn refund(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        if OUTCOME.load(deps.storage)? != Outcome::Open {
+            return Err(ContractError::Settled);
+        }
+        if env.block.time < config.reveal_deadline {
+            return Err(ContractError::RevealOpen);
+        }
+
+        // Iterating over all tickets is fine for a raffle of limited size; a bigger one would let
+        // every buyer withdraw on their own instead
+        let mut refunds: BTreeMap<_, u128> = BTreeMap::new();
+        for ticket in TICKETS.range(deps.storage, None, None, Order::Ascending) {
+            let (_, buyer) = ticket?;
+            *refunds.entry(buyer).or_default() += config.ticket_price.amount.u128();
+        }
+        OUTCOME.save(deps.storage, &Outcome::Refunded)?;
+
+        let msgs = refunds.into_iter().map(|(buyer, amount)| BankMsg::Send {
+            to_address: buyer.into_string(),
+            amount: coins(amount, &config.ticket_price.denom),
+        });
+        let resp = Response::new()
+            .add_messages(msgs)
+            .add_attribute("action", "refund");
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn raffle(deps: Deps) -> StdResult<RaffleResp> {
+        let config = CONFIG.load(deps.storage)?;
+        Ok(RaffleResp {
+            ticket_price: config.ticket_price,
+            sales_end: config.sales_end,
+            reveal_deadline: config.reveal_deadline,
+            tickets: TICKETS_COUNT.load(deps.storage)?,
+            outcome: OUTCOME.load(deps.storage)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    const SALES_TIME: u64 = 100;
+    const REVEAL_TIME: u64 = 50;
+    const SECRET: &str = "owner secret";
+
+    fn raffle(app: &mut App) -> Proxy {
+        let now = app.block_info().time;
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            ticket_price: coin(10, "atom"),
+            commitment: Binary::from(rand::commitment(SECRET).as_slice()),
+            sales_end: now.plus_seconds(SALES_TIME),
+            reveal_deadline: now.plus_seconds(SALES_TIME + REVEAL_TIME),
+        };
+        Proxy::deploy(app, code, "owner", &msg, "Raffle").unwrap()
+    }
+
+    fn funded_app() -> App {
+        app_with_balances(&[("alice", coins(100, "atom")), ("bob", coins(100, "atom"))])
+    }
+
+    fn buy(app: &mut App, raffle: &Proxy, buyer: &str) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::BuyTicket {
+            entropy: format!("{} luck", buyer),
+        };
+        raffle.execute(app, buyer, &msg, &coins(10, "atom"))
+    }
+
+    fn reveal(app: &mut App, raffle: &Proxy, secret: &str) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Reveal {
+            secret: secret.to_owned(),
+        };
+        raffle.execute(app, "owner", &msg, &[])
+    }
+
+    fn outcome(app: &App, raffle: &Proxy) -> Outcome {
+        let resp: RaffleResp = raffle.query(app, &QueryMsg::Raffle {}).unwrap();
+        resp.outcome
+    }
+
+    #[test]
+    fn winner_takes_the_pot() {
+        let mut app = funded_app();
+        let raffle = raffle(&mut app);
+
+        buy(&mut app, &raffle, "alice").unwrap();
+        buy(&mut app, &raffle, "bob").unwrap();
+        buy(&mut app, &raffle, "bob").unwrap();
+
+        let err = reveal(&mut app, &raffle, SECRET).unwrap_err();
+        assert_eq!(ContractError::SalesOpen, err.downcast().unwrap());
+
+        app.advance_time(SALES_TIME);
+        let err = buy(&mut app, &raffle, "alice").unwrap_err();
+        assert_eq!(ContractError::SalesClosed, err.downcast().unwrap());
+
+        let err = reveal(&mut app, &raffle, "guess").unwrap_err();
+        assert_eq!(ContractError::InvalidSecret, err.downcast().unwrap());
+
+        reveal(&mut app, &raffle, SECRET).unwrap();
+
+        // Winner is deterministic for the secret and the buyers' entropy
+        let (ticket, winner) = match outcome(&app, &raffle) {
+            Outcome::Won { ticket, winner } => (ticket, winner),
+            outcome => panic!("Unexpected outcome: {:?}", outcome),
+        };
+        let entropy = ["alice", "bob", "bob"]
+            .iter()
+            .fold(vec![], |entropy, buyer| {
+                rand::mix(&entropy, &format!("{} luck", buyer)).to_vec()
+            });
+        assert_eq!(ticket, rand::winning_ticket(SECRET, &entropy, 3));
+        // Alice bought the first ticket, bob the other two
+        let (expected, spent) = if ticket == 0 {
+            ("alice", 10)
+        } else {
+            ("bob", 20)
+        };
+        assert_eq!(winner, mock_addr(expected));
+
+        assert_eq!(balance(&app, winner, "atom"), 100 - spent + 30);
+        assert_eq!(balance(&app, raffle.addr(), "atom"), 0);
+
+        let err = reveal(&mut app, &raffle, SECRET).unwrap_err();
+        assert_eq!(ContractError::Settled, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn exact_ticket_price() {
+        let mut app = funded_app();
+        let raffle = raffle(&mut app);
+
+        for funds in [coins(9, "atom"), coins(20, "atom"), vec![]] {
+            let msg = ExecuteMsg::BuyTicket {
+                entropy: String::new(),
+            };
+            let err = raffle.execute(&mut app, "alice", &msg, &funds).unwrap_err();
+            assert_eq!(
+                ContractError::InvalidPayment {
+                    price: coin(10, "atom")
+                },
+                err.downcast().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn refund_without_reveal() {
+        let mut app = funded_app();
+        let raffle = raffle(&mut app);
+
+        buy(&mut app, &raffle, "alice").unwrap();
+        buy(&mut app, &raffle, "bob").unwrap();
+        buy(&mut app, &raffle, "bob").unwrap();
+
+        app.advance_time(SALES_TIME);
+        let err = raffle
+            .execute(&mut app, "alice", &ExecuteMsg::Refund {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::RevealOpen, err.downcast().unwrap());
+
+        app.advance_time(REVEAL_TIME);
+        let err = reveal(&mut app, &raffle, SECRET).unwrap_err();
+        assert_eq!(ContractError::RevealClosed, err.downcast().unwrap());
+
+        raffle
+            .execute(&mut app, "alice", &ExecuteMsg::Refund {}, &[])
+            .unwrap();
+        assert_eq!(outcome(&app, &raffle), Outcome::Refunded);
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 100);
+        assert_eq!(balance(&app, mock_addr("bob"), "atom"), 100);
+
+        let err = raffle
+            .execute(&mut app, "alice", &ExecuteMsg::Refund {}, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::Settled, err.downcast().unwrap());
+    }
+}
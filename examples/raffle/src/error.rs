@@ -0,0 +1,24 @@
+use cosmwasm_std::{Coin, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Reveal deadline has to be after the sales end")]
+    InvalidDeadlines,
+    #[error("Ticket costs exactly {price}")]
+    InvalidPayment { price: Coin },
+    #[error("Ticket sales are closed")]
+    SalesClosed,
+    #[error("Ticket sales are still open")]
+    SalesOpen,
+    #[error("Secret does not match the commitment")]
+    InvalidSecret,
+    #[error("Reveal deadline has passed")]
+    RevealClosed,
+    #[error("Secret can still be revealed")]
+    RevealOpen,
+    #[error("Raffle is already settled")]
+    Settled,
+}
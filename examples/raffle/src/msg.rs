@@ -0,0 +1,44 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Binary, Coin, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::Outcome;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub ticket_price: Coin,
+    /// `sha256` of the secret revealed after the sales end
+    pub commitment: Binary,
+    pub sales_end: Timestamp,
+    pub reveal_deadline: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Buys a single ticket, mixing the entropy into the randomness
+    BuyTicket { entropy: String },
+    /// Reveals the committed secret, drawing the winner and paying the pot out
+    Reveal { secret: String },
+    /// Returns ticket payments if the secret was not revealed in time
+    Refund {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct RaffleResp {
+    pub ticket_price: Coin,
+    pub sales_end: Timestamp,
+    pub reveal_deadline: Timestamp,
+    pub tickets: u32,
+    pub outcome: Outcome,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(RaffleResp)]
+    Raffle {},
+}
@@ -0,0 +1,142 @@
+//! Commit-reveal randomness.
+//!
+//! The owner commits to `sha256(secret)` when creating the raffle. Every ticket buyer mixes their
+//! own entropy into a running hash:
+//!
+//! ```text
+//! entropy' = sha256(entropy || buyer entropy)
+//! ```
+//!
+//! After the sales end, the owner reveals the secret, and the winning ticket is picked from
+//! `sha256(secret || entropy)`. Buyers cannot predict the outcome without knowing the secret, and
+//! the owner cannot change the secret after the commitment. The owner could still skew the result
+//! by buying the last ticket with chosen entropy, or by not revealing an unfavorable secret - the
+//! latter is why the raffle is refunded if the secret is not revealed in time.
+//!
+//! Deriving the winner from block data, like the height or the transaction index, would be
+//! simpler, but validators know it in advance and can influence it, so it is unfit for deciding
+//! anything of value. `block_seed` does exactly that, for the cases where nothing is at stake.
+
+use cosmwasm_std::Env;
+use sha2::{Digest, Sha256};
+
+pub fn commitment(secret: &str) -> [u8; 32] {
+    Sha256::digest(secret.as_bytes()).into()
+}
+
+pub fn mix(entropy: &[u8], buyer_entropy: &str) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(entropy);
+    hasher.update(buyer_entropy.as_bytes());
+    hasher.finalize().into()
+}
+
+/// Deterministic seed derived from the block alone:
+///
+/// ```text
+/// sha256(height as u64 big-endian || tx_index as u32 big-endian || counter as u64 big-endian)
+/// ```
+///
+/// `tx_index` is `0` when the environment carries no transaction info, and `counter` lets a
+/// single transaction draw multiple independent values.
+///
+/// Every input is public before the transaction is executed. Anyone can compute the seed for an
+/// upcoming block, a block proposer can choose the height and the position of a transaction in
+/// it, and the sender picks the counter if it comes from a message. Use it only where guessing
+/// or steering the outcome gains nothing - never for picking winners or anything of value.
+pub fn block_seed(env: &Env, counter: u64) -> [u8; 32] {
+    let tx_index = env.transaction.as_ref().map(|tx| tx.index).unwrap_or(0);
+
+    let mut hasher = Sha256::new();
+    hasher.update(env.block.height.to_be_bytes());
+    hasher.update(tx_index.to_be_bytes());
+    hasher.update(counter.to_be_bytes());
+    hasher.finalize().into()
+}
+
+/// Picks the winning ticket in `0..tickets`. `tickets` must be non-zero.
+pub fn winning_ticket(secret: &str, entropy: &[u8], tickets: u32) -> u32 {
+    let mut hasher = Sha256::new();
+    hasher.update(secret.as_bytes());
+    hasher.update(entropy);
+    let seed: [u8; 32] = hasher.finalize().into();
+
+    let mut value = [0; 8];
+    value.copy_from_slice(&seed[..8]);
+    (u64::from_be_bytes(value) % tickets as u64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{BlockInfo, Timestamp, TransactionInfo};
+
+    use super::*;
+
+    /// Environment of the fourth transaction in block 12345.
+    fn fixed_env() -> Env {
+        let mut env = mock_env();
+        env.block = BlockInfo {
+            height: 12_345,
+            time: Timestamp::from_seconds(1_700_000_000),
+            chain_id: "raffle-1".to_owned(),
+        };
+        env.transaction = Some(TransactionInfo { index: 3 });
+        env
+    }
+
+    #[test]
+    fn block_seed_is_deterministic() {
+        let mut input = Vec::new();
+        input.extend_from_slice(&12_345u64.to_be_bytes());
+        input.extend_from_slice(&3u32.to_be_bytes());
+        input.extend_from_slice(&42u64.to_be_bytes());
+        let expected: [u8; 32] = Sha256::digest(&input).into();
+
+        assert_eq!(block_seed(&fixed_env(), 42), expected);
+        assert_eq!(block_seed(&fixed_env(), 42), block_seed(&fixed_env(), 42));
+
+        // Only the height, the transaction index and the counter are hashed
+        let mut env = fixed_env();
+        env.block.time = env.block.time.plus_seconds(5);
+        env.block.chain_id = "raffle-2".to_owned();
+        assert_eq!(block_seed(&env, 42), expected);
+
+        // No transaction info is the same as the first transaction
+        env.transaction = None;
+        let mut first_tx = fixed_env();
+        first_tx.transaction = Some(TransactionInfo { index: 0 });
+        assert_eq!(block_seed(&env, 42), block_seed(&first_tx, 42));
+    }
+
+    #[test]
+    fn block_seed_unique_per_counter() {
+        let env = fixed_env();
+
+        let seeds: HashSet<_> = (0..1000).map(|counter| block_seed(&env, counter)).collect();
+        assert_eq!(seeds.len(), 1000);
+
+        let mut next_block = fixed_env();
+        next_block.block.height += 1;
+        assert!(!seeds.contains(&block_seed(&next_block, 0)));
+
+        let mut next_tx = fixed_env();
+        next_tx.transaction = Some(TransactionInfo { index: 4 });
+        assert!(!seeds.contains(&block_seed(&next_tx, 0)));
+    }
+
+    #[test]
+    fn every_input_changes_outcome() {
+        let entropy = mix(&[], "first");
+        assert_ne!(entropy, mix(&[], "second"));
+        assert_ne!(entropy, mix(&entropy, "first"));
+
+        let picks: Vec<_> = (0..20)
+            .map(|n| winning_ticket(&format!("secret{}", n), &entropy, 1000))
+            .collect();
+        assert!(picks.iter().any(|pick| *pick != picks[0]));
+        assert!(picks.iter().all(|pick| *pick < 1000));
+    }
+}
@@ -0,0 +1,30 @@
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub ticket_price: Coin,
+    /// `sha256` of the owner's secret
+    pub commitment: Binary,
+    pub sales_end: Timestamp,
+    /// If the secret is not revealed before this time, tickets are refunded
+    pub reveal_deadline: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Open,
+    Won { ticket: u32, winner: Addr },
+    Refunded,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+/// Buyers by the ticket number
+pub const TICKETS: Map<u32, Addr> = Map::new("tickets");
+pub const TICKETS_COUNT: Item<u32> = Item::new("tickets_count");
+/// Running hash of the entropy provided by buyers
+pub const ENTROPY: Item<Binary> = Item::new("entropy");
+pub const OUTCOME: Item<Outcome> = Item::new("outcome");
@@ -0,0 +1,12 @@
+use cosmwasm_schema::write_api;
+
+use reflect::chain::ChainMsg;
+use reflect::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg<ChainMsg>,
+        query: QueryMsg,
+    }
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::{Binary, CustomMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Message extension of an example chain. The reflect contract only forwards it, so it does not
+/// need to know what the messages do - it is enough they are valid `CosmosMsg::Custom` payloads.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainMsg {
+    Debug(String),
+    Raw(Binary),
+}
+
+impl CustomMsg for ChainMsg {}
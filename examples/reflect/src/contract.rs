@@ -0,0 +1,209 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, OwnerResp, QueryMsg};
+use crate::state::OWNER;
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, CustomMsg, Deps, DepsMut, Env, MessageInfo, Response,
+    StdResult,
+};
+
+pub fn instantiate<C: CustomMsg>(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response<C>> {
+    OWNER.save(deps.storage, &info.sender)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Owner {} => to_json_binary(&query::owner(deps)?),
+    }
+}
+
+pub fn execute<C: CustomMsg>(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg<C>,
+) -> Result<Response<C>, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        ReflectMsgs { msgs } => exec::reflect_msgs(deps, info, msgs),
+        ChangeOwner { owner } => exec::change_owner(deps, info, owner),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    fn ensure_owner(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized { owner });
+        }
+        Ok(())
+    }
+
+    pub fn reflect_msgs<C: CustomMsg>(
+        deps: DepsMut,
+        info: MessageInfo,
+        msgs: Vec<CosmosMsg<C>>,
+    ) -> Result<Response<C>, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+        if msgs.is_empty() {
+            return Err(ContractError::NoMessages);
+        }
+
+        // Sent by the contract - the chain sees it as the sender, not the owner
+        let resp = Response::new()
+            .add_attribute("action", "reflect_msgs")
+            .add_attribute("count", msgs.len().to_string())
+            .add_messages(msgs);
+
+        Ok(resp)
+    }
+
+    pub fn change_owner<C: CustomMsg>(
+        deps: DepsMut,
+        info: MessageInfo,
+        owner: String,
+    ) -> Result<Response<C>, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+        let owner = deps.api.addr_validate(&owner)?;
+        OWNER.save(deps.storage, &owner)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "change_owner")
+            .add_attribute("owner", owner);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn owner(deps: Deps) -> StdResult<OwnerResp> {
+        let owner = OWNER.load(deps.storage)?;
+        Ok(OwnerResp { owner })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::BankMsg;
+    use cw_multi_test::custom_handler::CachingCustomHandler;
+    use cw_multi_test::{BankKeeper, MockApiBech32, WasmKeeper};
+    use testing::prelude::*;
+
+    use crate::chain::ChainMsg;
+
+    use super::*;
+
+    /// App handling `ChainMsg` by recording it, so tests can check what was forwarded.
+    type ChainApp = cw_multi_test::App<
+        BankKeeper,
+        MockApiBech32,
+        MockStorage,
+        CachingCustomHandler<ChainMsg, Empty>,
+        WasmKeeper<ChainMsg, Empty>,
+    >;
+
+    fn reflect(app: &mut ChainApp) -> Addr {
+        let code = ContractWrapper::new(execute::<ChainMsg>, instantiate::<ChainMsg>, query);
+        let code_id = app.store_code(Box::new(code));
+        app.instantiate_contract(
+            code_id,
+            addr::owner(),
+            &InstantiateMsg {},
+            &[],
+            "Reflect",
+            None,
+        )
+        .unwrap()
+    }
+
+    fn reflect_msgs(
+        app: &mut ChainApp,
+        reflect: &Addr,
+        sender: &str,
+        msgs: Vec<CosmosMsg<ChainMsg>>,
+        funds: &[Coin],
+    ) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::ReflectMsgs { msgs };
+        app.execute_contract(mock_addr(sender), reflect.clone(), &msg, funds)
+    }
+
+    #[test]
+    fn forward_messages() {
+        let handler = CachingCustomHandler::<ChainMsg, Empty>::new();
+        let mut app = app_builder::<ChainMsg, Empty>()
+            .with_custom(handler.clone())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &addr::owner(), coins(100, "atom"))
+                    .unwrap()
+            });
+        let reflect = reflect(&mut app);
+
+        let msgs = vec![
+            CosmosMsg::Custom(ChainMsg::Debug("reflected".to_owned())),
+            BankMsg::Send {
+                to_address: mock_addr("user").to_string(),
+                amount: coins(60, "atom"),
+            }
+            .into(),
+        ];
+        let resp = reflect_msgs(&mut app, &reflect, "owner", msgs, &coins(100, "atom")).unwrap();
+        assert_eq!(wasm_attr(&resp, "count"), "2");
+
+        assert_eq!(
+            *handler.state().execs(),
+            [ChainMsg::Debug("reflected".to_owned())]
+        );
+        let balance = |addr: &Addr| app.wrap().query_balance(addr, "atom").unwrap().amount;
+        assert_eq!(balance(&mock_addr("user")).u128(), 60);
+        assert_eq!(balance(&reflect).u128(), 40);
+    }
+
+    #[test]
+    fn owner_only() {
+        let mut app = app_builder::<ChainMsg, Empty>()
+            .with_custom(CachingCustomHandler::<ChainMsg, Empty>::new())
+            .build(|_, _, _| {});
+        let reflect = reflect(&mut app);
+
+        let msgs = vec![CosmosMsg::Custom(ChainMsg::Debug("hello".to_owned()))];
+        let err = reflect_msgs(&mut app, &reflect, "user", msgs.clone(), &[]).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                owner: addr::owner()
+            },
+            err.downcast().unwrap()
+        );
+        let err = reflect_msgs(&mut app, &reflect, "owner", vec![], &[]).unwrap_err();
+        assert_eq!(ContractError::NoMessages, err.downcast().unwrap());
+
+        let msg = ExecuteMsg::<ChainMsg>::ChangeOwner {
+            owner: mock_addr("user").to_string(),
+        };
+        app.execute_contract(addr::owner(), reflect.clone(), &msg, &[])
+            .unwrap();
+        let resp: OwnerResp = app
+            .wrap()
+            .query_wasm_smart(&reflect, &QueryMsg::Owner {})
+            .unwrap();
+        assert_eq!(resp.owner, mock_addr("user"));
+
+        reflect_msgs(&mut app, &reflect, "user", msgs.clone(), &[]).unwrap();
+        reflect_msgs(&mut app, &reflect, "owner", msgs, &[]).unwrap_err();
+    }
+}
@@ -0,0 +1,29 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, CosmosMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+/// Generic over the custom message of the chain, so the same contract can forward any of them.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg<C> {
+    ReflectMsgs { msgs: Vec<CosmosMsg<C>> },
+    ChangeOwner { owner: String },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OwnerResp {
+    pub owner: Addr,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(OwnerResp)]
+    Owner {},
+}
@@ -0,0 +1,376 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, FailureResp, InstantiateMsg, JobResp, JobsResp, QueryMsg, SudoMsg};
+use crate::state::{Config, Job, CONFIG, FAILURES, JOBS, NEXT_JOB_ID, QUEUE};
+use common::pagination;
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
+    Response, StdResult, SubMsg, WasmMsg,
+};
+use cw_storage_plus::Bound;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        owner: info.sender,
+        max_jobs_per_tick: msg.max_jobs_per_tick,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    NEXT_JOB_ID.save(deps.storage, &0)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Jobs { start_after, limit } => to_json_binary(&query::jobs(deps, start_after, limit)?),
+        Failure { id } => to_json_binary(&query::failure(deps, id)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Schedule { msgs, height } => exec::schedule(deps, env, info, msgs, height),
+        Cancel { id } => exec::cancel(deps, info, id),
+        Run { msgs } => exec::run(env, info, msgs),
+    }
+}
+
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> Result<Response, ContractError> {
+    use SudoMsg::*;
+
+    match msg {
+        Tick {} => sudo::tick(deps, env),
+    }
+}
+
+/// Only failed jobs reply, with the job id as the reply id.
+pub fn reply(deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
+    let error = reply.result.unwrap_err();
+    FAILURES.save(deps.storage, reply.id, &error)?;
+
+    let resp = Response::new()
+        .add_attribute("action", "job_failed")
+        .add_attribute("id", reply.id.to_string())
+        .add_attribute("error", error);
+
+    Ok(resp)
+}
+
+mod exec {
+    use super::*;
+
+    fn ensure_owner(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+        let owner = CONFIG.load(deps.storage)?.owner;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn schedule(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msgs: Vec<CosmosMsg>,
+        height: u64,
+    ) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+        if msgs.is_empty() {
+            return Err(ContractError::NoMessages);
+        }
+        if height <= env.block.height {
+            return Err(ContractError::InvalidHeight {
+                current: env.block.height,
+            });
+        }
+
+        let id = NEXT_JOB_ID.load(deps.storage)?;
+        NEXT_JOB_ID.save(deps.storage, &(id + 1))?;
+        JOBS.save(deps.storage, id, &Job { msgs, height })?;
+        QUEUE.save(deps.storage, (height, id), &Empty {})?;
+
+        let resp = Response::new()
+            .add_attribute("action", "schedule")
+            .add_attribute("id", id.to_string())
+            .add_attribute("height", height.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn cancel(deps: DepsMut, info: MessageInfo, id: u64) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+        let job = JOBS.load(deps.storage, id)?;
+        JOBS.remove(deps.storage, id);
+        QUEUE.remove(deps.storage, (job.height, id));
+
+        let resp = Response::new()
+            .add_attribute("action", "cancel")
+            .add_attribute("id", id.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn run(
+        env: Env,
+        info: MessageInfo,
+        msgs: Vec<CosmosMsg>,
+    ) -> Result<Response, ContractError> {
+        if info.sender != env.contract.address {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let resp = Response::new()
+            .add_messages(msgs)
+            .add_attribute("action", "run");
+
+        Ok(resp)
+    }
+}
+
+mod sudo {
+    use super::*;
+
+    /// Runs the jobs due at the current height.
+    ///
+    /// Every job is sent as a `Run` submessage to the contract itself - messages of a job succeed
+    /// or fail together, and a failing job only reverts itself, not the whole tick. Otherwise a
+    /// single broken job would block the queue forever.
+    pub fn tick(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        let end = Bound::exclusive((env.block.height + 1, 0));
+
+        let due: Vec<_> = QUEUE
+            .keys(deps.storage, None, Some(end), Order::Ascending)
+            .take(config.max_jobs_per_tick as usize)
+            .collect::<StdResult<_>>()?;
+
+        let mut submsgs = Vec::with_capacity(due.len());
+        for (height, id) in due {
+            let job = JOBS.load(deps.storage, id)?;
+            JOBS.remove(deps.storage, id);
+            QUEUE.remove(deps.storage, (height, id));
+
+            let run = WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_json_binary(&ExecuteMsg::Run { msgs: job.msgs })?,
+                funds: vec![],
+            };
+            submsgs.push(SubMsg::reply_on_error(run, id));
+        }
+
+        let resp = Response::new()
+            .add_attribute("action", "tick")
+            .add_attribute("jobs", submsgs.len().to_string())
+            .add_submessages(submsgs);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn jobs(deps: Deps, start_after: Option<u64>, limit: Option<u32>) -> StdResult<JobsResp> {
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after);
+
+        let jobs: Vec<_> = JOBS
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (id, job) = item?;
+                Ok(JobResp {
+                    id,
+                    msgs: job.msgs,
+                    height: job.height,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after = pagination::next_key(&jobs, limit, |job| job.id);
+
+        Ok(JobsResp {
+            jobs,
+            next_start_after,
+        })
+    }
+
+    pub fn failure(deps: Deps, id: u64) -> StdResult<FailureResp> {
+        let error = FAILURES.may_load(deps.storage, id)?;
+        Ok(FailureResp { error })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::BankMsg;
+    use testing::prelude::*;
+
+    use super::*;
+
+    /// Scheduler running two jobs per tick, with 1000 atom to send.
+    fn scheduler(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query)
+            .with_sudo(sudo)
+            .with_reply(reply);
+        let msg = InstantiateMsg {
+            max_jobs_per_tick: 2,
+        };
+        let contract = Proxy::deploy(app, code, "owner", &msg, "Scheduler").unwrap();
+
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, contract.addr(), coins(1000, "atom"))
+        })
+        .unwrap();
+
+        contract
+    }
+
+    fn schedule_payment(
+        app: &mut App,
+        scheduler: &Proxy,
+        amount: u128,
+        blocks: u64,
+    ) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Schedule {
+            msgs: vec![BankMsg::Send {
+                to_address: mock_addr("user").to_string(),
+                amount: coins(amount, "atom"),
+            }
+            .into()],
+            height: app.block_info().height + blocks,
+        };
+        scheduler.execute(app, "owner", &msg, &[])
+    }
+
+    /// Stands in for the clock module, which calls the contract at the end of every block.
+    fn tick(app: &mut App, scheduler: &Proxy) -> AppResponse {
+        app.wasm_sudo(scheduler.addr().clone(), &SudoMsg::Tick {})
+            .unwrap()
+    }
+
+    fn pending(app: &App, scheduler: &Proxy) -> Vec<u64> {
+        let msg = QueryMsg::Jobs {
+            start_after: None,
+            limit: None,
+        };
+        let resp: JobsResp = scheduler.query(app, &msg).unwrap();
+        resp.jobs.iter().map(|job| job.id).collect()
+    }
+
+    #[test]
+    fn run_due_jobs() {
+        let mut app = app();
+        let scheduler = scheduler(&mut app);
+
+        schedule_payment(&mut app, &scheduler, 100, 2).unwrap();
+        schedule_payment(&mut app, &scheduler, 200, 1).unwrap();
+
+        let resp = tick(&mut app, &scheduler);
+        assert_eq!(wasm_attr(&resp, "jobs"), "0");
+
+        app.advance_blocks(1);
+        tick(&mut app, &scheduler);
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 200);
+        assert_eq!(pending(&app, &scheduler), [0]);
+
+        app.advance_blocks(1);
+        tick(&mut app, &scheduler);
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 300);
+        assert_eq!(pending(&app, &scheduler), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn tick_limit_and_failures() {
+        let mut app = app();
+        let scheduler = scheduler(&mut app);
+
+        schedule_payment(&mut app, &scheduler, 2000, 1).unwrap();
+        schedule_payment(&mut app, &scheduler, 100, 1).unwrap();
+        schedule_payment(&mut app, &scheduler, 100, 1).unwrap();
+
+        // The failing job does not stop the other one
+        app.advance_blocks(1);
+        let resp = tick(&mut app, &scheduler);
+        assert_eq!(wasm_attr(&resp, "jobs"), "2");
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 100);
+        assert_eq!(pending(&app, &scheduler), [2]);
+
+        let resp: FailureResp = scheduler.query(&app, &QueryMsg::Failure { id: 0 }).unwrap();
+        assert!(resp.error.is_some());
+        let resp: FailureResp = scheduler.query(&app, &QueryMsg::Failure { id: 1 }).unwrap();
+        assert_eq!(resp.error, None);
+
+        // Jobs left over run in the next block
+        app.advance_blocks(1);
+        tick(&mut app, &scheduler);
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 200);
+    }
+
+    #[test]
+    fn owner_only() {
+        let mut app = app();
+        let scheduler = scheduler(&mut app);
+
+        let err = schedule_payment(&mut app, &scheduler, 100, 0).unwrap_err();
+        assert_eq!(
+            ContractError::InvalidHeight {
+                current: app.block_info().height
+            },
+            err.downcast().unwrap()
+        );
+
+        schedule_payment(&mut app, &scheduler, 100, 1).unwrap();
+        let err = scheduler
+            .execute(&mut app, "user", &ExecuteMsg::Cancel { id: 0 }, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        // Jobs are run by the scheduler only
+        let run = ExecuteMsg::Run {
+            msgs: vec![BankMsg::Send {
+                to_address: mock_addr("user").to_string(),
+                amount: coins(100, "atom"),
+            }
+            .into()],
+        };
+        let err = scheduler.execute(&mut app, "user", &run, &[]).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        scheduler
+            .execute(&mut app, "owner", &ExecuteMsg::Cancel { id: 0 }, &[])
+            .unwrap();
+        app.advance_blocks(1);
+        tick(&mut app, &scheduler);
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 0);
+    }
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Unauthorized - {sender} cannot do this")]
+    Unauthorized { sender: Addr },
+    #[error("Job has to be scheduled after the current height {current}")]
+    InvalidHeight { current: u64 },
+    #[error("Job has no messages")]
+    NoMessages,
+}
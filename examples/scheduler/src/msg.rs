@@ -0,0 +1,65 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::CosmosMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub max_jobs_per_tick: u32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Queues messages to be sent by the contract at the given block height, only by the owner
+    Schedule { msgs: Vec<CosmosMsg>, height: u64 },
+    /// Removes a pending job, only by the owner
+    Cancel { id: u64 },
+    /// Sends the messages of a due job, only by the contract itself while processing a tick
+    Run { msgs: Vec<CosmosMsg> },
+}
+
+/// Messages sent by the chain, not by any account.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    /// Sent by the clock module at the end of every block
+    Tick {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct JobResp {
+    pub id: u64,
+    pub msgs: Vec<CosmosMsg>,
+    pub height: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct JobsResp {
+    pub jobs: Vec<JobResp>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct FailureResp {
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Lists jobs waiting to be run
+    #[returns(JobsResp)]
+    Jobs {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Error of a job which failed when run
+    #[returns(FailureResp)]
+    Failure { id: u64 },
+}
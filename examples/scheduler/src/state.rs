@@ -0,0 +1,25 @@
+use cosmwasm_std::{Addr, CosmosMsg, Empty};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub owner: Addr,
+    /// Bounds the gas a single tick can use - the remaining due jobs wait for the next block
+    pub max_jobs_per_tick: u32,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Job {
+    pub msgs: Vec<CosmosMsg>,
+    pub height: u64,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const JOBS: Map<u64, Job> = Map::new("jobs");
+/// Pending jobs keyed by `(height, id)`, so the due ones are at the front
+pub const QUEUE: Map<(u64, u64), Empty> = Map::new("queue");
+/// Errors of jobs which failed when run
+pub const FAILURES: Map<u64, String> = Map::new("failures");
+pub const NEXT_JOB_ID: Item<u64> = Item::new("next_job_id");
@@ -0,0 +1,252 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ShareResp, SharesResp};
+use crate::state::{Share, SHARES, TREASURY};
+use cosmwasm_std::{
+    coin, to_json_binary, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Uint128,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    if msg.shares.is_empty() {
+        return Err(ContractError::NoShares);
+    }
+
+    let shares = msg
+        .shares
+        .into_iter()
+        .map(|share| {
+            if share.weight == 0 {
+                return Err(ContractError::ZeroWeight {
+                    address: share.address,
+                });
+            }
+            Ok(Share {
+                address: deps.api.addr_validate(&share.address)?,
+                weight: share.weight,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    SHARES.save(deps.storage, &shares)?;
+    TREASURY.save(deps.storage, &deps.api.addr_validate(&msg.treasury)?)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Shares {} => to_json_binary(&query::shares(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Distribute {} => exec::distribute(deps, env),
+    }
+}
+
+/// Parts of the distribution every share receives, in the order of shares.
+fn ratios(shares: &[Share]) -> Vec<Decimal> {
+    let total: u64 = shares.iter().map(|share| share.weight).sum();
+    shares
+        .iter()
+        .map(|share| Decimal::from_ratio(share.weight, total))
+        .collect()
+}
+
+mod exec {
+    use super::*;
+
+    pub fn distribute(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+        let balances = deps.querier.query_all_balances(&env.contract.address)?;
+        if balances.is_empty() {
+            return Err(ContractError::NothingToDistribute);
+        }
+
+        let shares = SHARES.load(deps.storage)?;
+        let weights: Vec<_> = shares.iter().map(|share| share.weight).collect();
+        let mut payouts: Vec<Vec<Coin>> = vec![vec![]; shares.len()];
+        let mut remainders = vec![];
+
+        for balance in balances {
+            let mut distributed = Uint128::zero();
+            // Rounded down, so the sum never exceeds the balance
+            let amounts = math::split(balance.amount, &weights)?;
+            for (payout, amount) in payouts.iter_mut().zip(amounts) {
+                if !amount.is_zero() {
+                    payout.push(coin(amount.u128(), &balance.denom));
+                    distributed += amount;
+                }
+            }
+
+            let remainder = balance.amount - distributed;
+            if !remainder.is_zero() {
+                remainders.push(coin(remainder.u128(), &balance.denom));
+            }
+        }
+
+        let treasury = TREASURY.load(deps.storage)?;
+        let msgs = shares
+            .into_iter()
+            .map(|share| share.address)
+            .zip(payouts)
+            .chain(std::iter::once((treasury, remainders)))
+            .filter(|(_, amount)| !amount.is_empty())
+            .map(|(address, amount)| BankMsg::Send {
+                to_address: address.into_string(),
+                amount,
+            });
+
+        let resp = Response::new()
+            .add_messages(msgs)
+            .add_attribute("action", "distribute");
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn shares(deps: Deps) -> StdResult<SharesResp> {
+        let shares = SHARES.load(deps.storage)?;
+        let ratios = ratios(&shares);
+
+        let shares = shares
+            .into_iter()
+            .zip(ratios)
+            .map(|(share, ratio)| ShareResp {
+                address: share.address,
+                weight: share.weight,
+                ratio,
+            })
+            .collect();
+
+        Ok(SharesResp {
+            shares,
+            treasury: TREASURY.load(deps.storage)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use crate::msg::ShareMsg;
+
+    use super::*;
+
+    fn splitter(app: &mut App, weights: &[(&str, u64)]) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            shares: weights
+                .iter()
+                .map(|(name, weight)| ShareMsg {
+                    address: mock_addr(name).to_string(),
+                    weight: *weight,
+                })
+                .collect(),
+            treasury: mock_addr("treasury").to_string(),
+        };
+        Proxy::deploy(app, code, "owner", &msg, "Splitter").unwrap()
+    }
+
+    fn fund(app: &mut App, contract: &Proxy, funds: Vec<Coin>) {
+        app.init_modules(|router, _, storage| {
+            router.bank.init_balance(storage, contract.addr(), funds)
+        })
+        .unwrap();
+    }
+
+    fn distribute(app: &mut App, splitter: &Proxy) -> anyhow::Result<AppResponse> {
+        splitter.execute(app, "anyone", &ExecuteMsg::Distribute {}, &[])
+    }
+
+    #[test]
+    fn weighted_distribution() {
+        let mut app = app();
+        let splitter = splitter(&mut app, &[("alice", 1), ("bob", 3)]);
+
+        let resp: SharesResp = splitter.query(&app, &QueryMsg::Shares {}).unwrap();
+        assert_eq!(resp.shares[0].ratio, Decimal::percent(25));
+        assert_eq!(resp.shares[1].ratio, Decimal::percent(75));
+
+        fund(
+            &mut app,
+            &splitter,
+            vec![coin(1000, "atom"), coin(40, "btc")],
+        );
+        distribute(&mut app, &splitter).unwrap();
+
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 250);
+        assert_eq!(balance(&app, mock_addr("alice"), "btc"), 10);
+        assert_eq!(balance(&app, mock_addr("bob"), "atom"), 750);
+        assert_eq!(balance(&app, mock_addr("bob"), "btc"), 30);
+        assert_eq!(balance(&app, mock_addr("treasury"), "atom"), 0);
+        assert_eq!(balance(&app, splitter.addr(), "atom"), 0);
+
+        let err = distribute(&mut app, &splitter).unwrap_err();
+        assert_eq!(ContractError::NothingToDistribute, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn remainder_goes_to_treasury() {
+        let mut app = app();
+        let splitter = splitter(&mut app, &[("alice", 1), ("bob", 1), ("carol", 1)]);
+
+        fund(&mut app, &splitter, coins(100, "atom"));
+        distribute(&mut app, &splitter).unwrap();
+
+        for name in ["alice", "bob", "carol"] {
+            assert_eq!(balance(&app, mock_addr(name), "atom"), 33);
+        }
+        assert_eq!(balance(&app, mock_addr("treasury"), "atom"), 1);
+
+        // Too little to split - everything goes to the treasury
+        fund(&mut app, &splitter, coins(2, "atom"));
+        distribute(&mut app, &splitter).unwrap();
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 33);
+        assert_eq!(balance(&app, mock_addr("treasury"), "atom"), 3);
+    }
+
+    #[test]
+    fn invalid_shares() {
+        let mut app = app();
+        let code_id = store_code(&mut app, ContractWrapper::new(execute, instantiate, query));
+        let msg = |shares| InstantiateMsg {
+            shares,
+            treasury: mock_addr("treasury").to_string(),
+        };
+
+        let err = Proxy::instantiate(&mut app, code_id, "owner", &msg(vec![]), &[], "Splitter")
+            .unwrap_err();
+        assert_eq!(ContractError::NoShares, err.downcast().unwrap());
+
+        let shares = vec![ShareMsg {
+            address: mock_addr("alice").to_string(),
+            weight: 0,
+        }];
+        let err = Proxy::instantiate(&mut app, code_id, "owner", &msg(shares), &[], "Splitter")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::ZeroWeight {
+                address: mock_addr("alice").to_string()
+            },
+            err.downcast().unwrap()
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::StdError;
+use math::MathError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Math(#[from] MathError),
+    #[error("At least one share is required")]
+    NoShares,
+    #[error("Share of {address} has zero weight")]
+    ZeroWeight { address: String },
+    #[error("Nothing to distribute")]
+    NothingToDistribute,
+}
@@ -0,0 +1,48 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Decimal};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ShareMsg {
+    pub address: String,
+    pub weight: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub shares: Vec<ShareMsg>,
+    pub treasury: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Splits all the tokens the contract holds between the shares
+    Distribute {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ShareResp {
+    pub address: Addr,
+    pub weight: u64,
+    /// Part of every distribution this share receives
+    pub ratio: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct SharesResp {
+    pub shares: Vec<ShareResp>,
+    pub treasury: Addr,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(SharesResp)]
+    Shares {},
+}
@@ -0,0 +1,14 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Share {
+    pub address: Addr,
+    pub weight: u64,
+}
+
+pub const SHARES: Item<Vec<Share>> = Item::new("shares");
+/// Receives what is left after rounding every share down
+pub const TREASURY: Item<Addr> = Item::new("treasury");
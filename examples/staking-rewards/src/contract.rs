@@ -0,0 +1,326 @@
+use crate::error::ContractError;
+use crate::msg::{DistributionResp, ExecuteMsg, InstantiateMsg, QueryMsg, StakerResp};
+use crate::state::{Config, Distribution, Staker, CONFIG, DISTRIBUTION, STAKERS};
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, BankMsg, Binary, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, StdResult, Storage, Uint128,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        bond_denom: msg.bond_denom,
+        reward_denom: msg.reward_denom,
+        reward_per_block: msg.reward_per_block,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let distribution = Distribution {
+        total_bonded: Uint128::zero(),
+        reward_index: Decimal::zero(),
+        updated_at: env.block.height,
+    };
+    DISTRIBUTION.save(deps.storage, &distribution)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Staker { address } => to_json_binary(&query::staker(deps, env, address)?),
+        Distribution {} => to_json_binary(&query::distribution(deps, env)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Bond {} => exec::bond(deps, env, info),
+        Unbond { amount } => exec::unbond(deps, env, info, amount),
+        Claim {} => exec::claim(deps, env, info),
+    }
+}
+
+/// Brings the distribution to the current block and adds rewards earned by the staker since their
+/// last action. Has to be done before the staker's bond changes.
+fn settle(
+    storage: &dyn Storage,
+    config: &Config,
+    env: &Env,
+    addr: &Addr,
+) -> StdResult<(Distribution, Staker)> {
+    let mut distribution = DISTRIBUTION.load(storage)?;
+    distribution.update(config, env.block.height);
+
+    let mut staker = STAKERS.may_load(storage, addr)?.unwrap_or_default();
+    staker.settle(distribution.reward_index);
+
+    Ok((distribution, staker))
+}
+
+mod exec {
+    use super::*;
+
+    pub fn bond(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        let amount = cw_utils::must_pay(&info, &config.bond_denom)?;
+        let (mut distribution, mut staker) = settle(deps.storage, &config, &env, &info.sender)?;
+
+        staker.bonded += amount;
+        distribution.total_bonded += amount;
+        STAKERS.save(deps.storage, &info.sender, &staker)?;
+        DISTRIBUTION.save(deps.storage, &distribution)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "bond")
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn unbond(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        let (mut distribution, mut staker) = settle(deps.storage, &config, &env, &info.sender)?;
+
+        if staker.bonded < amount {
+            return Err(ContractError::InsufficientBond {
+                bonded: staker.bonded,
+                amount,
+            });
+        }
+
+        staker.bonded -= amount;
+        distribution.total_bonded -= amount;
+        STAKERS.save(deps.storage, &info.sender, &staker)?;
+        DISTRIBUTION.save(deps.storage, &distribution)?;
+
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(amount.u128(), &config.bond_denom),
+            })
+            .add_attribute("action", "unbond")
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let config = CONFIG.load(deps.storage)?;
+        let (distribution, mut staker) = settle(deps.storage, &config, &env, &info.sender)?;
+
+        let rewards = staker.pending;
+        if rewards.is_zero() {
+            return Err(ContractError::NothingToClaim);
+        }
+
+        staker.pending = Uint128::zero();
+        STAKERS.save(deps.storage, &info.sender, &staker)?;
+        DISTRIBUTION.save(deps.storage, &distribution)?;
+
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(rewards.u128(), &config.reward_denom),
+            })
+            .add_attribute("action", "claim")
+            .add_attribute("amount", rewards.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn staker(deps: Deps, env: Env, address: String) -> StdResult<StakerResp> {
+        let address = deps.api.addr_validate(&address)?;
+        let config = CONFIG.load(deps.storage)?;
+        let (_, staker) = settle(deps.storage, &config, &env, &address)?;
+
+        Ok(StakerResp {
+            bonded: staker.bonded,
+            pending_rewards: staker.pending,
+        })
+    }
+
+    pub fn distribution(deps: Deps, env: Env) -> StdResult<DistributionResp> {
+        let config = CONFIG.load(deps.storage)?;
+        let mut distribution = DISTRIBUTION.load(deps.storage)?;
+        distribution.update(&config, env.block.height);
+
+        Ok(DistributionResp {
+            total_bonded: distribution.total_bonded,
+            reward_index: distribution.reward_index,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    /// Distributes 100 reward tokens per block, holding 10000 of them.
+    fn staking(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            bond_denom: "atom".to_owned(),
+            reward_denom: "reward".to_owned(),
+            reward_per_block: Uint128::new(100),
+        };
+        let contract = Proxy::deploy(app, code, "owner", &msg, "Staking").unwrap();
+
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, contract.addr(), coins(10000, "reward"))
+        })
+        .unwrap();
+
+        contract
+    }
+
+    fn funded_app() -> App {
+        app_with_balances(&[("alice", coins(1000, "atom")), ("bob", coins(1000, "atom"))])
+    }
+
+    fn bond(app: &mut App, staking: &Proxy, sender: &str, amount: u128) {
+        staking
+            .execute(app, sender, &ExecuteMsg::Bond {}, &coins(amount, "atom"))
+            .unwrap();
+    }
+
+    fn unbond(
+        app: &mut App,
+        staking: &Proxy,
+        sender: &str,
+        amount: u128,
+    ) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Unbond {
+            amount: Uint128::new(amount),
+        };
+        staking.execute(app, sender, &msg, &[])
+    }
+
+    fn claim(app: &mut App, staking: &Proxy, sender: &str) -> anyhow::Result<AppResponse> {
+        staking.execute(app, sender, &ExecuteMsg::Claim {}, &[])
+    }
+
+    fn pending(app: &App, staking: &Proxy, addr: &str) -> u128 {
+        let msg = QueryMsg::Staker {
+            address: mock_addr(addr).to_string(),
+        };
+        let resp: StakerResp = staking.query(app, &msg).unwrap();
+        resp.pending_rewards.u128()
+    }
+
+    #[test]
+    fn single_staker_gets_everything() {
+        let mut app = funded_app();
+        let staking = staking(&mut app);
+
+        bond(&mut app, &staking, "alice", 10);
+        app.advance_blocks(5);
+        assert_eq!(pending(&app, &staking, "alice"), 500);
+
+        let resp = claim(&mut app, &staking, "alice").unwrap();
+        assert_eq!(wasm_attr(&resp, "amount"), "500");
+        assert_eq!(balance(&app, mock_addr("alice"), "reward"), 500);
+        assert_eq!(pending(&app, &staking, "alice"), 0);
+
+        let err = claim(&mut app, &staking, "alice").unwrap_err();
+        assert_eq!(ContractError::NothingToClaim, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn rewards_are_proportional_to_bond() {
+        let mut app = funded_app();
+        let staking = staking(&mut app);
+
+        bond(&mut app, &staking, "alice", 100);
+        app.advance_blocks(2);
+        // From now on bob has three quarters of the bond
+        bond(&mut app, &staking, "bob", 300);
+        app.advance_blocks(4);
+
+        assert_eq!(pending(&app, &staking, "alice"), 200 + 100);
+        assert_eq!(pending(&app, &staking, "bob"), 300);
+
+        let resp: DistributionResp = staking.query(&app, &QueryMsg::Distribution {}).unwrap();
+        assert_eq!(resp.total_bonded, Uint128::new(400));
+        assert_eq!(resp.reward_index, Decimal::from_ratio(3u32, 1u32));
+    }
+
+    #[test]
+    fn unbonding_stops_rewards() {
+        let mut app = funded_app();
+        let staking = staking(&mut app);
+
+        bond(&mut app, &staking, "alice", 100);
+        bond(&mut app, &staking, "bob", 100);
+        app.advance_blocks(2);
+
+        unbond(&mut app, &staking, "alice", 100).unwrap();
+        assert_eq!(balance(&app, mock_addr("alice"), "atom"), 1000);
+        app.advance_blocks(3);
+
+        // Rewards earned before unbonding are kept
+        assert_eq!(pending(&app, &staking, "alice"), 100);
+        assert_eq!(pending(&app, &staking, "bob"), 100 + 300);
+
+        let err = unbond(&mut app, &staking, "alice", 1).unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientBond {
+                bonded: Uint128::zero(),
+                amount: Uint128::new(1)
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn nothing_accrues_without_stakers() {
+        let mut app = funded_app();
+        let staking = staking(&mut app);
+
+        app.advance_blocks(10);
+        bond(&mut app, &staking, "alice", 100);
+        assert_eq!(pending(&app, &staking, "alice"), 0);
+
+        app.advance_blocks(1);
+        assert_eq!(pending(&app, &staking, "alice"), 100);
+    }
+
+    #[test]
+    fn invalid_bond() {
+        let mut app = funded_app();
+        let staking = staking(&mut app);
+
+        let err = staking
+            .execute(&mut app, "alice", &ExecuteMsg::Bond {}, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Payment(cw_utils::PaymentError::NoFunds {}),
+            err.downcast().unwrap()
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use cosmwasm_std::{StdError, Uint128};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+    #[error("Cannot unbond {amount}, only {bonded} bonded")]
+    InsufficientBond { bonded: Uint128, amount: Uint128 },
+    #[error("Nothing to claim")]
+    NothingToClaim,
+}
@@ -0,0 +1,47 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Decimal, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Rewards have to be sent to the contract separately, it only distributes what it holds.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub bond_denom: String,
+    pub reward_denom: String,
+    pub reward_per_block: Uint128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Bonds the `bond_denom` tokens sent
+    Bond {},
+    /// Sends bonded tokens back
+    Unbond { amount: Uint128 },
+    /// Sends all pending rewards
+    Claim {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct StakerResp {
+    pub bonded: Uint128,
+    pub pending_rewards: Uint128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DistributionResp {
+    pub total_bonded: Uint128,
+    pub reward_index: Decimal,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(StakerResp)]
+    Staker { address: String },
+    #[returns(DistributionResp)]
+    Distribution {},
+}
@@ -0,0 +1,54 @@
+use cosmwasm_std::{Addr, Decimal, Uint128};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub bond_denom: String,
+    pub reward_denom: String,
+    /// Rewards distributed between all stakers every block
+    pub reward_per_block: Uint128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Distribution {
+    pub total_bonded: Uint128,
+    /// Rewards earned by a single bonded token since the contract creation
+    pub reward_index: Decimal,
+    /// Height the index was last updated at
+    pub updated_at: u64,
+}
+
+impl Distribution {
+    /// Moves the index to the given height. Blocks when nothing is bonded are not rewarded.
+    pub fn update(&mut self, config: &Config, height: u64) {
+        let blocks = height - self.updated_at;
+        if !self.total_bonded.is_zero() {
+            let rewards = config.reward_per_block * Uint128::from(blocks);
+            self.reward_index += Decimal::from_ratio(rewards, self.total_bonded);
+        }
+        self.updated_at = height;
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default, JsonSchema)]
+pub struct Staker {
+    pub bonded: Uint128,
+    /// Reward index the pending rewards were last calculated at
+    pub reward_index: Decimal,
+    /// Rewards earned, but not claimed yet
+    pub pending: Uint128,
+}
+
+impl Staker {
+    /// Adds rewards earned since the last settlement.
+    pub fn settle(&mut self, reward_index: Decimal) {
+        self.pending += self.bonded.mul_floor(reward_index - self.reward_index);
+        self.reward_index = reward_index;
+    }
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const DISTRIBUTION: Item<Distribution> = Item::new("distribution");
+pub const STAKERS: Map<&Addr, Staker> = Map::new("stakers");
@@ -0,0 +1,473 @@
+use crate::error::ContractError;
+use crate::msg::{DelegationResp, DelegationsResp, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{OWNER, VALIDATOR};
+use cosmwasm_std::{
+    coin, to_json_binary, Binary, Deps, DepsMut, DistributionMsg, Env, MessageInfo, Response,
+    StakingMsg, StdResult, Uint128,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    OWNER.save(deps.storage, &info.sender)?;
+    VALIDATOR.save(deps.storage, &msg.validator)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Delegation {} => to_json_binary(&query::delegation(deps, env)?),
+        Delegations {} => to_json_binary(&query::delegations(deps, env)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Delegate {} => exec::delegate(deps, info),
+        Undelegate { amount } => exec::undelegate(deps, info, amount),
+        Redelegate { validator } => exec::redelegate(deps, env, info, validator),
+        WithdrawRewards {} => exec::withdraw_rewards(deps),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    fn ensure_owner(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn delegate(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+
+        let amount = cw_utils::one_coin(&info)?;
+        let validator = VALIDATOR.load(deps.storage)?;
+
+        let resp = Response::new()
+            .add_message(StakingMsg::Delegate {
+                validator: validator.clone(),
+                amount: amount.clone(),
+            })
+            .add_attribute("action", "delegate")
+            .add_attribute("validator", validator)
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn undelegate(
+        deps: DepsMut,
+        info: MessageInfo,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+
+        // Tokens come back to the contract once the unbonding period of the chain is over
+        let validator = VALIDATOR.load(deps.storage)?;
+        let amount = coin(amount.u128(), deps.querier.query_bonded_denom()?);
+
+        let resp = Response::new()
+            .add_message(StakingMsg::Undelegate {
+                validator: validator.clone(),
+                amount: amount.clone(),
+            })
+            .add_attribute("action", "undelegate")
+            .add_attribute("validator", validator)
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn redelegate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        dst_validator: String,
+    ) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+
+        let src_validator = VALIDATOR.load(deps.storage)?;
+        if src_validator == dst_validator {
+            return Err(ContractError::SameValidator {
+                validator: dst_validator,
+            });
+        }
+        let amount = match deps
+            .querier
+            .query_delegation(&env.contract.address, &src_validator)?
+        {
+            Some(delegation) if !delegation.amount.amount.is_zero() => delegation.amount,
+            _ => {
+                return Err(ContractError::NoDelegation {
+                    validator: src_validator,
+                })
+            }
+        };
+        VALIDATOR.save(deps.storage, &dst_validator)?;
+
+        // Redelegation skips unbonding, the stake keeps earning rewards with the new validator
+        let resp = Response::new()
+            .add_message(StakingMsg::Redelegate {
+                src_validator: src_validator.clone(),
+                dst_validator: dst_validator.clone(),
+                amount: amount.clone(),
+            })
+            .add_attribute("action", "redelegate")
+            .add_attribute("src_validator", src_validator)
+            .add_attribute("dst_validator", dst_validator)
+            .add_attribute("amount", amount.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn withdraw_rewards(deps: DepsMut) -> Result<Response, ContractError> {
+        let validator = VALIDATOR.load(deps.storage)?;
+
+        let resp = Response::new()
+            .add_message(DistributionMsg::WithdrawDelegatorReward {
+                validator: validator.clone(),
+            })
+            .add_attribute("action", "withdraw_rewards")
+            .add_attribute("validator", validator);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn delegation(deps: Deps, env: Env) -> StdResult<DelegationResp> {
+        let validator = VALIDATOR.load(deps.storage)?;
+        let delegation = deps
+            .querier
+            .query_delegation(env.contract.address, validator)?;
+
+        let resp = match delegation {
+            Some(delegation) => DelegationResp {
+                amount: delegation.amount,
+                rewards: delegation.accumulated_rewards,
+            },
+            None => DelegationResp {
+                amount: coin(0, deps.querier.query_bonded_denom()?),
+                rewards: vec![],
+            },
+        };
+
+        Ok(resp)
+    }
+
+    pub fn delegations(deps: Deps, env: Env) -> StdResult<DelegationsResp> {
+        let delegations = deps.querier.query_all_delegations(env.contract.address)?;
+        Ok(DelegationsResp { delegations })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env};
+    use cosmwasm_std::{CosmosMsg, Decimal256};
+    use cw_multi_test::StakingSudo;
+    use cw_utils::PaymentError;
+    use testing::prelude::*;
+
+    use crate::multitest::{staking_app, BONDED_DENOM, UNBONDING_TIME};
+
+    use super::*;
+
+    const VALIDATOR_ADDR: &str = "cosmwasmvaloper1validator";
+    const OTHER_VALIDATOR_ADDR: &str = "cosmwasmvaloper1other";
+    const YEAR: u64 = 365 * 24 * 60 * 60;
+
+    fn deploy(app: &mut App) -> Addr {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = store_code(app, code);
+
+        app.instantiate_contract(
+            code_id,
+            addr::owner(),
+            &InstantiateMsg {
+                validator: VALIDATOR_ADDR.to_owned(),
+            },
+            &[],
+            "Delegator",
+            None,
+        )
+        .unwrap()
+    }
+
+    fn delegation(app: &App, addr: &Addr) -> DelegationResp {
+        app.wrap()
+            .query_wasm_smart(addr, &QueryMsg::Delegation {})
+            .unwrap()
+    }
+
+    #[test]
+    fn delegate() {
+        let mut app = staking_app(&[VALIDATOR_ADDR], &[("owner", coins(1000, "atom"))]);
+        let addr = deploy(&mut app);
+
+        assert_eq!(
+            delegation(&app, &addr),
+            DelegationResp {
+                amount: coin(0, "atom"),
+                rewards: vec![],
+            }
+        );
+
+        app.execute_contract(
+            addr::owner(),
+            addr.clone(),
+            &ExecuteMsg::Delegate {},
+            &coins(600, "atom"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            delegation(&app, &addr),
+            DelegationResp {
+                amount: coin(600, "atom"),
+                rewards: vec![],
+            }
+        );
+
+        let delegations = app.wrap().query_all_delegations(&addr).unwrap();
+        assert_eq!(delegations.len(), 1);
+        assert_eq!(delegations[0].validator, VALIDATOR_ADDR);
+
+        assert_eq!(balance(&app, addr::owner(), "atom"), 400);
+        assert_eq!(balance(&app, &addr, "atom"), 0);
+    }
+
+    #[test]
+    fn unauthorized_delegation() {
+        let mut app = staking_app(&[VALIDATOR_ADDR], &[]);
+        let addr = deploy(&mut app);
+        let user = mock_addr("user");
+
+        let err = app
+            .execute_contract(user.clone(), addr.clone(), &ExecuteMsg::Delegate {}, &[])
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized { sender: user },
+            err.downcast().unwrap()
+        );
+
+        let err = app
+            .execute_contract(addr::owner(), addr, &ExecuteMsg::Delegate {}, &[])
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Payment(PaymentError::NoFunds {}),
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn rewards_accrue() {
+        let mut app = staking_app(&[VALIDATOR_ADDR], &[("owner", coins(1000, "atom"))]);
+        let addr = deploy(&mut app);
+
+        app.execute_contract(
+            addr::owner(),
+            addr.clone(),
+            &ExecuteMsg::Delegate {},
+            &coins(600, "atom"),
+        )
+        .unwrap();
+
+        app.advance_time(YEAR / 2);
+
+        assert_eq!(
+            delegation(&app, &addr),
+            DelegationResp {
+                amount: coin(600, "atom"),
+                rewards: coins(30, "atom"),
+            }
+        );
+
+        // Delegating more keeps rewards accrued so far
+        app.execute_contract(
+            addr::owner(),
+            addr.clone(),
+            &ExecuteMsg::Delegate {},
+            &coins(400, "atom"),
+        )
+        .unwrap();
+
+        app.advance_time(YEAR / 2);
+
+        assert_eq!(
+            delegation(&app, &addr),
+            DelegationResp {
+                amount: coin(1000, "atom"),
+                rewards: coins(80, "atom"),
+            }
+        );
+
+        // Anyone can have the rewards paid out, always to the contract
+        app.execute_contract(
+            mock_addr("user"),
+            addr.clone(),
+            &ExecuteMsg::WithdrawRewards {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(balance(&app, &addr, "atom"), 80);
+        assert_eq!(delegation(&app, &addr).rewards, vec![]);
+    }
+
+    #[test]
+    fn delegation_lifecycle() {
+        let mut app = staking_app(
+            &[VALIDATOR_ADDR, OTHER_VALIDATOR_ADDR],
+            &[("owner", coins(1000, "atom"))],
+        );
+        let addr = deploy(&mut app);
+        let owner = addr::owner();
+
+        let redelegate = ExecuteMsg::Redelegate {
+            validator: OTHER_VALIDATOR_ADDR.to_owned(),
+        };
+        let err = app
+            .execute_contract(owner.clone(), addr.clone(), &redelegate, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NoDelegation {
+                validator: VALIDATOR_ADDR.to_owned()
+            },
+            err.downcast().unwrap()
+        );
+
+        app.execute_contract(
+            owner.clone(),
+            addr.clone(),
+            &ExecuteMsg::Delegate {},
+            &coins(600, "atom"),
+        )
+        .unwrap();
+
+        let undelegate = ExecuteMsg::Undelegate {
+            amount: Uint128::new(100),
+        };
+        let user = mock_addr("user");
+        let err = app
+            .execute_contract(user.clone(), addr.clone(), &undelegate, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized { sender: user },
+            err.downcast().unwrap()
+        );
+
+        // Undelegated tokens are unbonding, they come back once the period is over
+        app.execute_contract(owner.clone(), addr.clone(), &undelegate, &[])
+            .unwrap();
+        assert_eq!(balance(&app, &addr, BONDED_DENOM), 0);
+        assert_eq!(delegation(&app, &addr).amount, coin(500, "atom"));
+
+        app.advance_time(UNBONDING_TIME);
+        assert_eq!(balance(&app, &addr, BONDED_DENOM), 100);
+
+        app.execute_contract(owner.clone(), addr.clone(), &redelegate, &[])
+            .unwrap();
+        let err = app
+            .execute_contract(owner, addr.clone(), &redelegate, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::SameValidator {
+                validator: OTHER_VALIDATOR_ADDR.to_owned()
+            },
+            err.downcast().unwrap()
+        );
+
+        // The whole stake follows the contract to the new validator
+        let resp: DelegationsResp = app
+            .wrap()
+            .query_wasm_smart(&addr, &QueryMsg::Delegations {})
+            .unwrap();
+        assert_eq!(resp.delegations.len(), 1);
+        assert_eq!(resp.delegations[0].validator, OTHER_VALIDATOR_ADDR);
+        assert_eq!(resp.delegations[0].amount, coin(500, "atom"));
+
+        assert_eq!(delegation(&app, &addr).amount, coin(500, "atom"));
+    }
+
+    #[test]
+    fn slashed_delegation() {
+        let mut app = staking_app(&[VALIDATOR_ADDR], &[("owner", coins(1000, "atom"))]);
+        let addr = deploy(&mut app);
+
+        app.execute_contract(
+            addr::owner(),
+            addr.clone(),
+            &ExecuteMsg::Delegate {},
+            &coins(600, "atom"),
+        )
+        .unwrap();
+
+        // The contract keeps no amounts of its own, it sees the slashed stake right away
+        app.sudo(
+            StakingSudo::Slash {
+                validator: VALIDATOR_ADDR.to_owned(),
+                percentage: Decimal256::percent(50),
+            }
+            .into(),
+        )
+        .unwrap();
+
+        assert_eq!(delegation(&app, &addr).amount, coin(300, "atom"));
+    }
+
+    #[test]
+    fn withdraw_rewards() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let user = deps.api.addr_make("user");
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {
+                validator: VALIDATOR_ADDR.to_owned(),
+            },
+        )
+        .unwrap();
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            ExecuteMsg::WithdrawRewards {},
+        )
+        .unwrap();
+
+        assert_eq!(
+            resp.messages[0].msg,
+            CosmosMsg::Distribution(DistributionMsg::WithdrawDelegatorReward {
+                validator: VALIDATOR_ADDR.to_owned(),
+            })
+        );
+    }
+}
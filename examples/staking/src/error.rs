@@ -0,0 +1,17 @@
+use cosmwasm_std::{Addr, StdError};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+    #[error("{sender} is not contract owner")]
+    Unauthorized { sender: Addr },
+    #[error("Nothing is delegated to {validator}")]
+    NoDelegation { validator: String },
+    #[error("Already delegating to {validator}")]
+    SameValidator { validator: String },
+}
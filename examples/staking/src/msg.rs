@@ -0,0 +1,49 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Coin, Delegation, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub validator: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Delegate {},
+    /// Undelegates from the current validator, only by the owner
+    Undelegate {
+        amount: Uint128,
+    },
+    /// Moves the whole delegation to another validator, only by the owner
+    Redelegate {
+        validator: String,
+    },
+    WithdrawRewards {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DelegationResp {
+    pub amount: Coin,
+    pub rewards: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct DelegationsResp {
+    pub delegations: Vec<Delegation>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Delegation to the current validator, with accrued rewards
+    #[returns(DelegationResp)]
+    Delegation {},
+    /// Delegations of the contract to all validators
+    #[returns(DelegationsResp)]
+    Delegations {},
+}
@@ -0,0 +1,49 @@
+//! Test chain running the staking and distribution modules of `cw-multi-test`.
+//!
+//! The stake keeper keeps delegations and an unbonding queue, processed whenever the block is
+//! moved, and the distribution keeper pays out the rewards the stake keeper accrues.
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{Coin, Validator};
+use cw_multi_test::{DistributionKeeper, StakeKeeper, StakingInfo};
+use testing::prelude::*;
+
+pub const BONDED_DENOM: &str = "atom";
+/// Seconds between undelegating and getting the tokens back
+pub const UNBONDING_TIME: u64 = 60 * 60;
+
+/// App staking `BONDED_DENOM` with the validators, at the default 10% yearly rate. Validators
+/// take no commission, so the whole reward goes to delegators.
+pub fn staking_app(validators: &[&str], balances: &[(&str, Vec<Coin>)]) -> App {
+    app_builder()
+        .with_staking(StakeKeeper::new())
+        .with_distribution(DistributionKeeper::new())
+        .build(|router, api, storage| {
+            let info = StakingInfo {
+                bonded_denom: BONDED_DENOM.to_owned(),
+                unbonding_time: UNBONDING_TIME,
+                ..StakingInfo::default()
+            };
+            router.staking.setup(storage, info).unwrap();
+
+            for validator in validators {
+                let validator = Validator::create(
+                    validator.to_string(),
+                    Decimal::zero(),
+                    Decimal::one(),
+                    Decimal::one(),
+                );
+                router
+                    .staking
+                    .add_validator(api, storage, &mock_env().block, validator)
+                    .unwrap();
+            }
+
+            for (addr, coins) in balances {
+                router
+                    .bank
+                    .init_balance(storage, &mock_addr(addr), coins.clone())
+                    .unwrap();
+            }
+        })
+}
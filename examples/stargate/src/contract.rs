@@ -0,0 +1,579 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, OwnerResp, QueryMsg};
+use crate::proto::{
+    Any, BasicAllowance, MsgGrantAllowance, MsgSend, MsgSubmitProposal, MsgTransfer, TextProposal,
+};
+use crate::state::OWNER;
+use cosmwasm_std::{
+    from_json, to_json_binary, to_json_vec, AnyMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
+    GovMsg, MessageInfo, Response, StdResult, Timestamp, VoteOption,
+};
+use prost::Message;
+use serde::de::IgnoredAny;
+
+/// Port of the ICS-20 transfer module.
+pub const TRANSFER_PORT: &str = "transfer";
+
+/// Seconds after which an unrelayed transfer times out and the tokens are refunded.
+pub const TRANSFER_TIMEOUT: u64 = 10 * 60;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    OWNER.save(deps.storage, &info.sender)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Owner {} => to_json_binary(&query::owner(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Send { to_address, amount } => exec::send(deps, env, info, to_address, amount),
+        TransferWithHook {
+            channel_id,
+            contract,
+            msg,
+            amount,
+        } => exec::transfer_with_hook(deps, env, info, channel_id, contract, msg, amount),
+        Vote { proposal_id, vote } => exec::vote(deps, info, proposal_id, vote),
+        SubmitProposal {
+            title,
+            description,
+            deposit,
+        } => exec::submit_proposal(deps, env, info, title, description, deposit),
+        GrantAllowance {
+            grantee,
+            spend_limit,
+            expiration,
+        } => exec::grant_allowance(deps, env, info, grantee, spend_limit, expiration),
+    }
+}
+
+/// ibc-hooks memo executing `contract` with `msg` once the tokens arrive.
+fn hook_memo(contract: &str, msg: &Binary) -> Result<String, ContractError> {
+    // Only checked to be a JSON object - it is embedded verbatim, without re-encoding. The JSON
+    // library of contracts cannot deserialize maps, but it can skip over any valid value.
+    let is_object = msg.iter().find(|c| !c.is_ascii_whitespace()) == Some(&b'{');
+    if !is_object || from_json::<IgnoredAny>(msg).is_err() {
+        return Err(ContractError::InvalidHookMsg);
+    }
+    let msg = std::str::from_utf8(msg).map_err(|_| ContractError::InvalidHookMsg)?;
+    let contract = String::from_utf8_lossy(&to_json_vec(contract)?).into_owned();
+
+    Ok(format!(
+        r#"{{"wasm":{{"contract":{},"msg":{}}}}}"#,
+        contract, msg
+    ))
+}
+
+mod exec {
+    use super::*;
+
+    fn ensure_owner(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if info.sender != owner {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn send(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        to_address: String,
+        amount: Vec<Coin>,
+    ) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+
+        let to_address = deps.api.addr_validate(&to_address)?;
+        let msg_send = MsgSend {
+            from_address: env.contract.address.to_string(),
+            to_address: to_address.to_string(),
+            amount: amount.into_iter().map(Into::into).collect(),
+        };
+
+        let msg = CosmosMsg::Any(AnyMsg {
+            type_url: MsgSend::TYPE_URL.to_owned(),
+            value: msg_send.encode_to_vec().into(),
+        });
+
+        let resp = Response::new()
+            .add_message(msg)
+            .add_attribute("action", "send")
+            .add_attribute("to_address", to_address);
+
+        Ok(resp)
+    }
+
+    pub fn transfer_with_hook(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        channel_id: String,
+        contract: String,
+        msg: Binary,
+        amount: Coin,
+    ) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+
+        // The remote address cannot be validated here - it has the prefix of the other chain
+        let transfer = MsgTransfer {
+            source_port: TRANSFER_PORT.to_owned(),
+            source_channel: channel_id.clone(),
+            token: Some(amount.into()),
+            sender: env.contract.address.to_string(),
+            // ibc-hooks requires the tokens to be sent to the contract it calls
+            receiver: contract.clone(),
+            timeout_height: None,
+            timeout_timestamp: env.block.time.plus_seconds(TRANSFER_TIMEOUT).nanos(),
+            memo: hook_memo(&contract, &msg)?,
+        };
+
+        let msg = CosmosMsg::Any(AnyMsg {
+            type_url: MsgTransfer::TYPE_URL.to_owned(),
+            value: transfer.encode_to_vec().into(),
+        });
+
+        let resp = Response::new()
+            .add_message(msg)
+            .add_attribute("action", "transfer_with_hook")
+            .add_attribute("channel", channel_id)
+            .add_attribute("contract", contract);
+
+        Ok(resp)
+    }
+
+    pub fn vote(
+        deps: DepsMut,
+        info: MessageInfo,
+        proposal_id: u64,
+        vote: VoteOption,
+    ) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+
+        // The vote weight is the stake delegated by the contract, not by the owner
+        let resp = Response::new()
+            .add_message(GovMsg::Vote { proposal_id, vote })
+            .add_attribute("action", "vote")
+            .add_attribute("proposal_id", proposal_id.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn submit_proposal(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        title: String,
+        description: String,
+        deposit: Vec<Coin>,
+    ) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+
+        // `GovMsg` has no proposal submission - the content is packed into an `Any` by hand
+        let content = TextProposal { title, description };
+        let submit = MsgSubmitProposal {
+            content: Some(Any {
+                type_url: TextProposal::TYPE_URL.to_owned(),
+                value: content.encode_to_vec(),
+            }),
+            initial_deposit: deposit.into_iter().map(Into::into).collect(),
+            proposer: env.contract.address.to_string(),
+        };
+
+        let msg = CosmosMsg::Any(AnyMsg {
+            type_url: MsgSubmitProposal::TYPE_URL.to_owned(),
+            value: submit.encode_to_vec().into(),
+        });
+
+        let resp = Response::new()
+            .add_message(msg)
+            .add_attribute("action", "submit_proposal");
+
+        Ok(resp)
+    }
+
+    pub fn grant_allowance(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        grantee: String,
+        spend_limit: Vec<Coin>,
+        expiration: Option<Timestamp>,
+    ) -> Result<Response, ContractError> {
+        ensure_owner(deps.as_ref(), &info)?;
+
+        let grantee = deps.api.addr_validate(&grantee)?;
+        let allowance = BasicAllowance {
+            spend_limit: spend_limit.into_iter().map(Into::into).collect(),
+            expiration: expiration.map(Into::into),
+        };
+        // The allowance is an `Any` - the chain supports other allowance types too
+        let grant = MsgGrantAllowance {
+            granter: env.contract.address.to_string(),
+            grantee: grantee.to_string(),
+            allowance: Some(Any {
+                type_url: BasicAllowance::TYPE_URL.to_owned(),
+                value: allowance.encode_to_vec(),
+            }),
+        };
+
+        let msg = CosmosMsg::Any(AnyMsg {
+            type_url: MsgGrantAllowance::TYPE_URL.to_owned(),
+            value: grant.encode_to_vec().into(),
+        });
+
+        let resp = Response::new()
+            .add_message(msg)
+            .add_attribute("action", "grant_allowance")
+            .add_attribute("grantee", grantee);
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn owner(deps: Deps) -> StdResult<OwnerResp> {
+        let owner = OWNER.load(deps.storage)?;
+        Ok(OwnerResp { owner })
+    }
+}
+
+// `cw-multi-test` cannot execute `CosmosMsg::Any` - the chain decodes the bytes, not the
+// contract. Tests check the produced message instead: the type URL, a decoding round trip, and
+// the exact bytes so encoding regressions are caught before reaching a real chain.
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coin;
+    use cosmwasm_std::testing::{message_info, mock_dependencies, mock_env, MOCK_CONTRACT_ADDR};
+
+    use crate::proto::{ProtoCoin, ProtoTimestamp};
+
+    use super::*;
+
+    #[test]
+    fn send_encodes_msg_send() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let user = deps.api.addr_make("user");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            ExecuteMsg::Send {
+                to_address: user.to_string(),
+                amount: vec![coin(100, "atom")],
+            },
+        )
+        .unwrap();
+
+        assert_eq!(resp.messages.len(), 1);
+        let (type_url, value) = match &resp.messages[0].msg {
+            CosmosMsg::Any(AnyMsg { type_url, value }) => (type_url, value),
+            msg => panic!("Unexpected message: {:?}", msg),
+        };
+        assert_eq!(type_url, "/cosmos.bank.v1beta1.MsgSend");
+
+        let decoded = MsgSend::decode(value.as_slice()).unwrap();
+        assert_eq!(
+            decoded,
+            MsgSend {
+                from_address: MOCK_CONTRACT_ADDR.to_owned(),
+                to_address: user.to_string(),
+                amount: vec![ProtoCoin {
+                    denom: "atom".to_owned(),
+                    amount: "100".to_owned(),
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn msg_send_bytes() {
+        let msg = MsgSend {
+            from_address: "contract".to_owned(),
+            to_address: "user".to_owned(),
+            amount: vec![coin(100, "atom").into()],
+        };
+
+        let expected = [
+            &[0x0a, 8][..],
+            b"contract",
+            &[0x12, 4],
+            b"user",
+            &[0x1a, 11, 0x0a, 4],
+            b"atom",
+            &[0x12, 3],
+            b"100",
+        ]
+        .concat();
+
+        assert_eq!(msg.encode_to_vec(), expected);
+    }
+
+    #[test]
+    fn transfer_with_hook_memo() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let transfer = |msg: &[u8]| ExecuteMsg::TransferWithHook {
+            channel_id: "channel-0".to_owned(),
+            contract: "osmo1contract".to_owned(),
+            msg: Binary::from(msg),
+            amount: coin(100, "atom"),
+        };
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            transfer(br#"{"deposit":{}}"#),
+        )
+        .unwrap();
+
+        let (type_url, value) = match &resp.messages[0].msg {
+            CosmosMsg::Any(AnyMsg { type_url, value }) => (type_url, value),
+            msg => panic!("Unexpected message: {:?}", msg),
+        };
+        assert_eq!(type_url, "/ibc.applications.transfer.v1.MsgTransfer");
+
+        let decoded = MsgTransfer::decode(value.as_slice()).unwrap();
+        assert_eq!(
+            decoded,
+            MsgTransfer {
+                source_port: "transfer".to_owned(),
+                source_channel: "channel-0".to_owned(),
+                token: Some(coin(100, "atom").into()),
+                sender: MOCK_CONTRACT_ADDR.to_owned(),
+                receiver: "osmo1contract".to_owned(),
+                timeout_height: None,
+                timeout_timestamp: mock_env().block.time.plus_seconds(TRANSFER_TIMEOUT).nanos(),
+                memo: r#"{"wasm":{"contract":"osmo1contract","msg":{"deposit":{}}}}"#.to_owned(),
+            }
+        );
+
+        for msg in [&b"[1, 2]"[..], b"{\"deposit\":", b"\"deposit\""] {
+            let err = execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&owner, &[]),
+                transfer(msg),
+            )
+            .unwrap_err();
+            assert_eq!(err, ContractError::InvalidHookMsg);
+        }
+    }
+
+    #[test]
+    fn vote_on_proposal() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let user = deps.api.addr_make("user");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let vote = ExecuteMsg::Vote {
+            proposal_id: 7,
+            vote: VoteOption::NoWithVeto,
+        };
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            vote.clone(),
+        )
+        .unwrap();
+        assert_eq!(
+            resp.messages[0].msg,
+            CosmosMsg::Gov(GovMsg::Vote {
+                proposal_id: 7,
+                vote: VoteOption::NoWithVeto,
+            })
+        );
+
+        let err = execute(deps.as_mut(), mock_env(), message_info(&user, &[]), vote).unwrap_err();
+        assert_eq!(err, ContractError::Unauthorized { sender: user });
+    }
+
+    #[test]
+    fn submit_text_proposal() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            ExecuteMsg::SubmitProposal {
+                title: "Title".to_owned(),
+                description: "Description".to_owned(),
+                deposit: vec![coin(1000, "atom")],
+            },
+        )
+        .unwrap();
+
+        let (type_url, value) = match &resp.messages[0].msg {
+            CosmosMsg::Any(AnyMsg { type_url, value }) => (type_url, value),
+            msg => panic!("Unexpected message: {:?}", msg),
+        };
+        assert_eq!(type_url, "/cosmos.gov.v1beta1.MsgSubmitProposal");
+
+        let decoded = MsgSubmitProposal::decode(value.as_slice()).unwrap();
+        assert_eq!(decoded.proposer, MOCK_CONTRACT_ADDR);
+        assert_eq!(decoded.initial_deposit, [coin(1000, "atom").into()]);
+
+        let content = decoded.content.unwrap();
+        assert_eq!(content.type_url, "/cosmos.gov.v1beta1.TextProposal");
+        assert_eq!(
+            TextProposal::decode(content.value.as_slice()).unwrap(),
+            TextProposal {
+                title: "Title".to_owned(),
+                description: "Description".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn grant_basic_allowance() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let user = deps.api.addr_make("user");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let expiration = Timestamp::from_nanos(1_700_000_000_500_000_000);
+        let resp = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            ExecuteMsg::GrantAllowance {
+                grantee: user.to_string(),
+                spend_limit: vec![coin(500, "atom")],
+                expiration: Some(expiration),
+            },
+        )
+        .unwrap();
+
+        let (type_url, value) = match &resp.messages[0].msg {
+            CosmosMsg::Any(AnyMsg { type_url, value }) => (type_url, value),
+            msg => panic!("Unexpected message: {:?}", msg),
+        };
+        assert_eq!(type_url, "/cosmos.feegrant.v1beta1.MsgGrantAllowance");
+
+        let decoded = MsgGrantAllowance::decode(value.as_slice()).unwrap();
+        assert_eq!(decoded.granter, MOCK_CONTRACT_ADDR);
+        assert_eq!(decoded.grantee, user.as_str());
+
+        let allowance = decoded.allowance.unwrap();
+        assert_eq!(
+            allowance.type_url,
+            "/cosmos.feegrant.v1beta1.BasicAllowance"
+        );
+        assert_eq!(
+            BasicAllowance::decode(allowance.value.as_slice()).unwrap(),
+            BasicAllowance {
+                spend_limit: vec![coin(500, "atom").into()],
+                expiration: Some(ProtoTimestamp {
+                    seconds: 1_700_000_000,
+                    nanos: 500_000_000,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn basic_allowance_bytes() {
+        // No limit and no expiration - both fields are left out of the encoding
+        assert_eq!(BasicAllowance::default().encode_to_vec(), b"");
+
+        let allowance = BasicAllowance {
+            spend_limit: vec![],
+            expiration: Some(ProtoTimestamp {
+                seconds: 1,
+                nanos: 0,
+            }),
+        };
+        assert_eq!(allowance.encode_to_vec(), [0x12, 2, 0x08, 1]);
+    }
+
+    #[test]
+    fn send_unauthorized() {
+        let mut deps = mock_dependencies();
+        let owner = deps.api.addr_make("owner");
+        let user = deps.api.addr_make("user");
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&user, &[]),
+            ExecuteMsg::Send {
+                to_address: user.to_string(),
+                amount: vec![coin(100, "atom")],
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::Unauthorized { sender: user });
+    }
+}
@@ -0,0 +1,58 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp, VoteOption};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Sends contract funds using a protobuf-encoded `MsgSend`
+    Send {
+        to_address: String,
+        amount: Vec<Coin>,
+    },
+    /// Sends contract funds over IBC to a contract on the remote chain, calling it with `msg`
+    /// through the ibc-hooks transfer memo
+    TransferWithHook {
+        channel_id: String,
+        /// Address of the contract on the remote chain
+        contract: String,
+        /// JSON object the remote contract is executed with
+        msg: Binary,
+        amount: Coin,
+    },
+    /// Votes on a governance proposal with `GovMsg::Vote`
+    Vote { proposal_id: u64, vote: VoteOption },
+    /// Submits a text proposal using a protobuf-encoded `MsgSubmitProposal`, paying the initial
+    /// deposit from contract funds
+    SubmitProposal {
+        title: String,
+        description: String,
+        deposit: Vec<Coin>,
+    },
+    /// Lets `grantee` pay transaction fees from contract funds, using a protobuf-encoded
+    /// `MsgGrantAllowance`
+    GrantAllowance {
+        grantee: String,
+        /// Total fees the grantee can spend, unlimited if empty
+        spend_limit: Vec<Coin>,
+        expiration: Option<Timestamp>,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OwnerResp {
+    pub owner: Addr,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(OwnerResp)]
+    Owner {},
+}
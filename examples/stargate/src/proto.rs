@@ -0,0 +1,161 @@
+//! Hand-written prost types mirroring the Cosmos SDK protobuf definitions. Only the fields are
+//! relevant for encoding - tags must match the `.proto` files exactly.
+
+use cosmwasm_std::{Coin, Timestamp};
+
+/// `cosmos.base.v1beta1.Coin`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ProtoCoin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+impl From<Coin> for ProtoCoin {
+    fn from(coin: Coin) -> Self {
+        ProtoCoin {
+            denom: coin.denom,
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
+/// `cosmos.bank.v1beta1.MsgSend`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSend {
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<ProtoCoin>,
+}
+
+impl MsgSend {
+    pub const TYPE_URL: &'static str = "/cosmos.bank.v1beta1.MsgSend";
+}
+
+/// `ibc.core.client.v1.Height`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Height {
+    #[prost(uint64, tag = "1")]
+    pub revision_number: u64,
+    #[prost(uint64, tag = "2")]
+    pub revision_height: u64,
+}
+
+/// `ibc.applications.transfer.v1.MsgTransfer`
+///
+/// Unlike `IbcMsg::Transfer`, it carries the `memo` field (added in ibc-go v5), which the
+/// receiving chain can act on.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgTransfer {
+    #[prost(string, tag = "1")]
+    pub source_port: String,
+    #[prost(string, tag = "2")]
+    pub source_channel: String,
+    #[prost(message, optional, tag = "3")]
+    pub token: Option<ProtoCoin>,
+    #[prost(string, tag = "4")]
+    pub sender: String,
+    #[prost(string, tag = "5")]
+    pub receiver: String,
+    #[prost(message, optional, tag = "6")]
+    pub timeout_height: Option<Height>,
+    /// Nanoseconds since the epoch, zero to disable
+    #[prost(uint64, tag = "7")]
+    pub timeout_timestamp: u64,
+    #[prost(string, tag = "8")]
+    pub memo: String,
+}
+
+impl MsgTransfer {
+    pub const TYPE_URL: &'static str = "/ibc.applications.transfer.v1.MsgTransfer";
+}
+
+/// `google.protobuf.Any`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Any {
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub value: Vec<u8>,
+}
+
+/// `cosmos.gov.v1beta1.TextProposal`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TextProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+}
+
+impl TextProposal {
+    pub const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.TextProposal";
+}
+
+/// `cosmos.gov.v1beta1.MsgSubmitProposal`
+///
+/// The proposal content is an `Any`, so the chain can tell which proposal type it decodes.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSubmitProposal {
+    #[prost(message, optional, tag = "1")]
+    pub content: Option<Any>,
+    #[prost(message, repeated, tag = "2")]
+    pub initial_deposit: Vec<ProtoCoin>,
+    #[prost(string, tag = "3")]
+    pub proposer: String,
+}
+
+impl MsgSubmitProposal {
+    pub const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgSubmitProposal";
+}
+
+/// `google.protobuf.Timestamp`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ProtoTimestamp {
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+    #[prost(int32, tag = "2")]
+    pub nanos: i32,
+}
+
+impl From<Timestamp> for ProtoTimestamp {
+    fn from(time: Timestamp) -> Self {
+        ProtoTimestamp {
+            seconds: time.seconds() as i64,
+            nanos: time.subsec_nanos() as i32,
+        }
+    }
+}
+
+/// `cosmos.feegrant.v1beta1.BasicAllowance`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BasicAllowance {
+    /// No limit if empty
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: Vec<ProtoCoin>,
+    #[prost(message, optional, tag = "2")]
+    pub expiration: Option<ProtoTimestamp>,
+}
+
+impl BasicAllowance {
+    pub const TYPE_URL: &'static str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+}
+
+/// `cosmos.feegrant.v1beta1.MsgGrantAllowance`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgGrantAllowance {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub allowance: Option<Any>,
+}
+
+impl MsgGrantAllowance {
+    pub const TYPE_URL: &'static str = "/cosmos.feegrant.v1beta1.MsgGrantAllowance";
+}
@@ -0,0 +1,4 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+
+pub const OWNER: Item<Addr> = Item::new("owner");
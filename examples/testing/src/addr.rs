@@ -0,0 +1,76 @@
+//! Addresses of the accounts in the tests.
+//!
+//! Every address is the `mock_addr` of a name. Accounts tests keep coming back to have helpers
+//! labeling them - `owner()`, `admin(1)`, `user(2)` are `mock_addr` of `owner`, `admin1` and
+//! `user2` - so they match accounts referred to by name, like `Proxy` senders or
+//! `app_with_balances` accounts.
+
+use cosmwasm_std::Addr;
+use cw_multi_test::MockApiBech32;
+
+use crate::app::ADDR_PREFIX;
+
+/// Address of the name, for places with no app at hand - it is the one `app.api().addr_make`
+/// returns.
+///
+/// The address is derived from the name only, so the same name always gives the same address,
+/// whichever app asks for it.
+pub fn mock_addr(name: &str) -> Addr {
+    MockApiBech32::new(ADDR_PREFIX).addr_make(name)
+}
+
+pub fn mock_addrs(names: &[&str]) -> Vec<Addr> {
+    names.iter().map(|name| mock_addr(name)).collect()
+}
+
+/// Addresses of the names in the order contracts keep them - sorted by address, which is not the
+/// order of the names.
+pub fn sorted_addrs(names: &[&str]) -> Vec<Addr> {
+    let mut addrs = mock_addrs(names);
+    addrs.sort();
+    addrs
+}
+
+pub fn owner() -> Addr {
+    mock_addr("owner")
+}
+
+pub fn admin(n: u32) -> Addr {
+    mock_addr(&format!("admin{}", n))
+}
+
+pub fn user(n: u32) -> Addr {
+    mock_addr(&format!("user{}", n))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::Api;
+
+    use super::*;
+    use crate::app::app;
+
+    #[test]
+    fn same_as_app() {
+        let app = app();
+        let owner = app.api().addr_make("owner");
+
+        assert_eq!(
+            owner,
+            "cosmwasm1fsgzj6t7udv8zhf6zj32mkqhcjcpv52yph5qsdcl0qt94jgdckqs2g053y"
+        );
+        assert_eq!(app.api().addr_validate(owner.as_str()).unwrap(), owner);
+        assert_eq!(mock_addr("owner"), owner);
+        assert_eq!(MockApi::default().addr_make("owner"), owner);
+        assert_ne!(mock_addr("user"), owner);
+    }
+
+    #[test]
+    fn labeled() {
+        assert_eq!(owner(), mock_addr("owner"));
+        assert_eq!(admin(1), mock_addr("admin1"));
+        assert_eq!(user(2), mock_addr("user2"));
+        assert_ne!(admin(1), user(1));
+    }
+}
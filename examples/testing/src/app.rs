@@ -0,0 +1,104 @@
+use std::fmt::Debug;
+
+use cosmwasm_std::testing::MockStorage;
+use cosmwasm_std::{Coin, CustomMsg, CustomQuery, Empty};
+use cw_multi_test::{
+    no_init, BankKeeper, BasicAppBuilder, Contract, DistributionKeeper, FailingModule,
+    GovFailingModule, IbcFailingModule, MockApiBech32, StakeKeeper, StargateFailing, WasmKeeper,
+};
+use serde::de::DeserializeOwned;
+
+use crate::addr::mock_addr;
+
+/// Bech32 prefix of the addresses in the tests. It is the prefix of `MockApi` as well, so the
+/// same addresses are valid in unit tests running on `mock_dependencies`.
+pub const ADDR_PREFIX: &str = "cosmwasm";
+
+/// Seconds between blocks when moving the chain forward block by block.
+pub const BLOCK_TIME: u64 = 5;
+
+/// Chain the examples are tested on - the default one, validating addresses as `ADDR_PREFIX`
+/// bech32 addresses.
+pub type App<ExecC = Empty, QueryC = Empty> = cw_multi_test::App<
+    BankKeeper,
+    MockApiBech32,
+    MockStorage,
+    FailingModule<ExecC, QueryC, Empty>,
+    WasmKeeper<ExecC, QueryC>,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcFailingModule,
+    GovFailingModule,
+    StargateFailing,
+>;
+
+pub type AppBuilder<ExecC = Empty, QueryC = Empty> = cw_multi_test::AppBuilder<
+    BankKeeper,
+    MockApiBech32,
+    MockStorage,
+    FailingModule<ExecC, QueryC, Empty>,
+    WasmKeeper<ExecC, QueryC>,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcFailingModule,
+    GovFailingModule,
+    StargateFailing,
+>;
+
+/// Builder of the test chain, for tests replacing some of its modules. Every app in the examples
+/// is built from it, so they all agree on the addresses.
+pub fn app_builder<ExecC, QueryC>() -> AppBuilder<ExecC, QueryC>
+where
+    ExecC: CustomMsg + DeserializeOwned + 'static,
+    QueryC: Debug + CustomQuery + DeserializeOwned + 'static,
+{
+    BasicAppBuilder::new_custom().with_api(MockApiBech32::new(ADDR_PREFIX))
+}
+
+pub fn app() -> App {
+    app_builder().build(no_init)
+}
+
+/// App with initial bank balances, given by account name.
+pub fn app_with_balances(balances: &[(&str, Vec<Coin>)]) -> App {
+    app_builder().build(|router, _, storage| {
+        for (addr, coins) in balances {
+            router
+                .bank
+                .init_balance(storage, &mock_addr(addr), coins.clone())
+                .unwrap();
+        }
+    })
+}
+
+/// Stores the contract code, sparing the boxing.
+pub fn store_code(app: &mut App, code: impl Contract<Empty> + 'static) -> u64 {
+    app.store_code(Box::new(code))
+}
+
+pub fn balance(app: &App, addr: impl Into<String>, denom: &str) -> u128 {
+    app.wrap().query_balance(addr, denom).unwrap().amount.u128()
+}
+
+/// Moves the chain clock. Contracts only ever see the block they are executed in, so this is
+/// the only way to cross deadlines and expirations in tests.
+pub trait TimeMachine {
+    /// Advances the time, keeping the block height.
+    fn advance_time(&mut self, secs: u64);
+
+    /// Advances the height, with `BLOCK_TIME` seconds per block.
+    fn advance_blocks(&mut self, blocks: u64);
+}
+
+impl TimeMachine for App {
+    fn advance_time(&mut self, secs: u64) {
+        self.update_block(|block| block.time = block.time.plus_seconds(secs));
+    }
+
+    fn advance_blocks(&mut self, blocks: u64) {
+        self.update_block(|block| {
+            block.height += blocks;
+            block.time = block.time.plus_seconds(blocks * BLOCK_TIME);
+        });
+    }
+}
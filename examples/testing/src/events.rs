@@ -0,0 +1,95 @@
+use cosmwasm_std::Event;
+use cw_multi_test::AppResponse;
+
+/// Custom events emitted by contracts are prefixed with `wasm-`, `ty` is without the prefix.
+pub fn events<'a>(resp: &'a AppResponse, ty: &str) -> Vec<&'a Event> {
+    let ty = format!("wasm-{}", ty);
+    resp.events.iter().filter(|ev| ev.ty == ty).collect()
+}
+
+/// The first event of the type, panics if there is none.
+pub fn event<'a>(resp: &'a AppResponse, ty: &str) -> &'a Event {
+    events(resp, ty)
+        .into_iter()
+        .next()
+        .unwrap_or_else(|| panic!("No {} event in {:?}", ty, resp.events))
+}
+
+/// Attribute of the event, panics if it is missing.
+pub fn attr<'a>(event: &'a Event, key: &str) -> &'a str {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .unwrap_or_else(|| panic!("No {} attribute in {:?}", key, event))
+        .value
+        .as_str()
+}
+
+/// The `wasm` event with the `action` attribute - the attributes one handler added with
+/// `Response::add_attribute`, when the transaction went through several of them.
+pub fn wasm_event<'a>(resp: &'a AppResponse, action: &str) -> &'a Event {
+    resp.events
+        .iter()
+        .find(|ev| {
+            ev.ty == "wasm"
+                && ev
+                    .attributes
+                    .iter()
+                    .any(|attr| attr.key == "action" && attr.value == action)
+        })
+        .unwrap_or_else(|| panic!("No wasm event of {} in {:?}", action, resp.events))
+}
+
+/// Attribute added with `Response::add_attribute` by the first executed contract.
+pub fn wasm_attr<'a>(resp: &'a AppResponse, key: &str) -> &'a str {
+    let wasm = resp
+        .events
+        .iter()
+        .find(|ev| ev.ty == "wasm")
+        .unwrap_or_else(|| panic!("No wasm event in {:?}", resp.events));
+    attr(wasm, key)
+}
+
+/// Value of the attribute on the first event of the type having it. `ty` is the full event type,
+/// e.g. `transfer` or `wasm-admin_added`.
+pub fn find_attr<'a>(resp: &'a AppResponse, ty: &str, key: &str) -> Option<&'a str> {
+    resp.events
+        .iter()
+        .filter(|ev| ev.ty == ty)
+        .flat_map(|ev| &ev.attributes)
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+}
+
+/// Whether any event of the type has all the attributes.
+pub fn has_event(resp: &AppResponse, ty: &str, attrs: &[(&str, &str)]) -> bool {
+    resp.events.iter().filter(|ev| ev.ty == ty).any(|ev| {
+        attrs.iter().all(|(key, value)| {
+            ev.attributes
+                .iter()
+                .any(|attr| attr.key == *key && attr.value == *value)
+        })
+    })
+}
+
+/// Asserts that the response has an event of the type with all the attributes given. Other
+/// attributes of the event are not checked.
+///
+/// ```ignore
+/// assert_event!(resp, "transfer", "recipient" => admin, "amount" => "2eth");
+/// ```
+#[macro_export]
+macro_rules! assert_event {
+    ($resp:expr, $ty:expr $(, $key:expr => $value:expr)* $(,)?) => {{
+        let attrs: &[(&str, String)] = &[$(($key, $value.to_string())),*];
+        let attrs: Vec<(&str, &str)> = attrs.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        assert!(
+            $crate::events::has_event(&$resp, $ty, &attrs),
+            "No {} event with {:?} in {:?}",
+            $ty,
+            attrs,
+            $resp.events
+        );
+    }};
+}
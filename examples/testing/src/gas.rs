@@ -0,0 +1,109 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{Empty, Order, OwnedDeps, Record, Storage};
+
+/// Gas costs of the Cosmos SDK KV store (`storetypes.KVGasConfig`).
+pub const HAS_COST: u64 = 1000;
+pub const DELETE_COST: u64 = 1000;
+pub const READ_COST_FLAT: u64 = 1000;
+pub const READ_COST_PER_BYTE: u64 = 3;
+pub const WRITE_COST_FLAT: u64 = 2000;
+pub const WRITE_COST_PER_BYTE: u64 = 30;
+pub const ITER_NEXT_COST_FLAT: u64 = 30;
+
+/// Mock storage charging gas for every access like the chain store does. Storage dominates the
+/// cost of most contract calls, so it is a good enough proxy to catch regressions without
+/// building and running the Wasm binary.
+#[derive(Default)]
+pub struct GasStorage {
+    inner: MockStorage,
+    gas: Cell<u64>,
+}
+
+impl GasStorage {
+    pub fn gas_used(&self) -> u64 {
+        self.gas.get()
+    }
+
+    pub fn reset(&self) {
+        self.gas.set(0);
+    }
+
+    fn charge(&self, gas: u64) {
+        self.gas.set(self.gas.get() + gas);
+    }
+}
+
+impl Storage for GasStorage {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        let value = self.inner.get(key);
+        let len = value.as_ref().map(Vec::len).unwrap_or_default();
+        self.charge(READ_COST_FLAT + READ_COST_PER_BYTE * (key.len() + len) as u64);
+        value
+    }
+
+    fn range<'a>(
+        &'a self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'a> {
+        let iter = self.inner.range(start, end, order).inspect(|(key, value)| {
+            self.charge(ITER_NEXT_COST_FLAT + READ_COST_PER_BYTE * (key.len() + value.len()) as u64)
+        });
+        Box::new(iter)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.charge(WRITE_COST_FLAT + WRITE_COST_PER_BYTE * (key.len() + value.len()) as u64);
+        self.inner.set(key, value);
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.charge(DELETE_COST);
+        self.inner.remove(key);
+    }
+}
+
+/// `mock_dependencies` with gas metered storage.
+pub fn gas_dependencies() -> OwnedDeps<GasStorage, MockApi, MockQuerier, Empty> {
+    OwnedDeps {
+        storage: GasStorage::default(),
+        api: MockApi::default(),
+        querier: MockQuerier::default(),
+        custom_query_type: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_access_is_charged() {
+        let mut storage = GasStorage::default();
+
+        storage.set(b"key", b"value");
+        assert_eq!(
+            storage.gas_used(),
+            WRITE_COST_FLAT + 8 * WRITE_COST_PER_BYTE
+        );
+
+        storage.reset();
+        storage.get(b"key");
+        assert_eq!(storage.gas_used(), READ_COST_FLAT + 8 * READ_COST_PER_BYTE);
+
+        storage.reset();
+        assert_eq!(storage.range(None, None, Order::Ascending).count(), 1);
+        assert_eq!(
+            storage.gas_used(),
+            ITER_NEXT_COST_FLAT + 8 * READ_COST_PER_BYTE
+        );
+
+        storage.reset();
+        storage.remove(b"key");
+        assert_eq!(storage.gas_used(), DELETE_COST);
+    }
+}
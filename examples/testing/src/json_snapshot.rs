@@ -0,0 +1,75 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+/// Compares the value serialized as pretty JSON with `snapshots/<name>.json` of the crate under
+/// test. Missing snapshots are written and accepted - review and commit them. Set
+/// `UPDATE_SNAPSHOTS=1` to overwrite snapshots after an intended change.
+#[macro_export]
+macro_rules! assert_json_snapshot {
+    ($name:expr, $value:expr) => {
+        $crate::json_snapshot::assert_json_snapshot(
+            ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots"),
+            $name,
+            &$value,
+        )
+    };
+}
+
+pub fn assert_json_snapshot(dir: impl AsRef<Path>, name: &str, value: &impl Serialize) {
+    let path = dir.as_ref().join(format!("{}.json", name));
+    let actual = serde_json::to_string_pretty(value).unwrap() + "\n";
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(_) => {
+            fs::create_dir_all(dir.as_ref()).unwrap();
+            fs::write(&path, &actual).unwrap();
+            eprintln!("New snapshot written to {}", path.display());
+            return;
+        }
+    };
+
+    if actual == expected {
+        return;
+    }
+
+    if env::var("UPDATE_SNAPSHOTS").as_deref() == Ok("1") {
+        fs::write(&path, &actual).unwrap();
+        eprintln!("Snapshot {} updated", path.display());
+        return;
+    }
+
+    panic!(
+        "Snapshot {} does not match, rerun with UPDATE_SNAPSHOTS=1 if the change is intended\n\
+         expected:\n{}\nactual:\n{}",
+        path.display(),
+        expected,
+        actual
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_roundtrip() {
+        let dir = env::temp_dir().join(format!("json-snapshot-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_json_snapshot(&dir, "value", &vec![1, 2]);
+        assert_eq!(
+            fs::read_to_string(dir.join("value.json")).unwrap(),
+            "[\n  1,\n  2\n]\n"
+        );
+        assert_json_snapshot(&dir, "value", &vec![1, 2]);
+
+        let mismatch = std::panic::catch_unwind(|| assert_json_snapshot(&dir, "value", &vec![3]));
+        assert!(mismatch.is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,30 @@
+//! Helpers shared by the example test suites.
+//!
+//! Everything is meant to be used through the prelude:
+//!
+//! ```
+//! use testing::prelude::*;
+//! ```
+
+pub mod addr;
+pub mod app;
+pub mod events;
+pub mod gas;
+pub mod json_snapshot;
+pub mod proxy;
+pub mod snapshot;
+
+pub mod prelude {
+    pub use cosmwasm_std::{coin, coins, Addr, Coin, Decimal, Empty, Timestamp, Uint128};
+    pub use cw_multi_test::{AppResponse, ContractWrapper, Executor};
+
+    pub use crate::addr::{self, mock_addr, mock_addrs, sorted_addrs};
+    pub use crate::app::{
+        app, app_builder, app_with_balances, balance, store_code, App, TimeMachine, BLOCK_TIME,
+    };
+    pub use crate::events::{attr, event, events, find_attr, has_event, wasm_attr, wasm_event};
+    pub use crate::gas::{gas_dependencies, GasStorage};
+    pub use crate::proxy::Proxy;
+    pub use crate::snapshot::Snapshot;
+    pub use crate::{assert_event, assert_json_snapshot};
+}
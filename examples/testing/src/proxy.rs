@@ -0,0 +1,63 @@
+use anyhow::Result as AnyResult;
+use cosmwasm_std::{Addr, Coin, Empty, StdResult};
+use cw_multi_test::{AppResponse, Contract, Executor};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+use crate::addr::mock_addr;
+use crate::app::{store_code, App};
+
+/// Instantiated contract, sparing tests the `Addr` cloning of the `Executor` calls.
+///
+/// Senders are account names, turned into addresses with `mock_addr`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proxy(Addr);
+
+impl Proxy {
+    pub fn new(addr: Addr) -> Self {
+        Self(addr)
+    }
+
+    pub fn instantiate(
+        app: &mut App,
+        code_id: u64,
+        sender: &str,
+        msg: &impl Serialize,
+        funds: &[Coin],
+        label: &str,
+    ) -> AnyResult<Self> {
+        app.instantiate_contract(code_id, mock_addr(sender), msg, funds, label, None)
+            .map(Self)
+    }
+
+    /// Stores the code and instantiates it, for tests which need the code id only once.
+    pub fn deploy(
+        app: &mut App,
+        code: impl Contract<Empty> + 'static,
+        sender: &str,
+        msg: &impl Serialize,
+        label: &str,
+    ) -> AnyResult<Self> {
+        let code_id = store_code(app, code);
+        Self::instantiate(app, code_id, sender, msg, &[], label)
+    }
+
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    pub fn execute(
+        &self,
+        app: &mut App,
+        sender: &str,
+        msg: &(impl Serialize + Debug),
+        funds: &[Coin],
+    ) -> AnyResult<AppResponse> {
+        app.execute_contract(mock_addr(sender), self.0.clone(), msg, funds)
+    }
+
+    pub fn query<T: DeserializeOwned>(&self, app: &App, msg: &impl Serialize) -> StdResult<T> {
+        app.wrap().query_wasm_smart(&self.0, msg)
+    }
+}
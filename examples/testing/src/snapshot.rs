@@ -0,0 +1,103 @@
+use cosmwasm_std::{BlockInfo, Order, Record};
+
+use crate::app::{app, App};
+
+/// Chain state set up once and handed out as fresh apps.
+///
+/// Contract and bank state lives in the app storage, so it is copied record by record. Code is
+/// kept by the wasm keeper itself, which is why `codes` is replayed on every restored app -
+/// storing the same codes in the same order gives the same code ids, counting from 1.
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use testing::prelude::*;
+///
+/// fn setup() -> App {
+///     static SNAPSHOT: OnceLock<Snapshot> = OnceLock::new();
+///     SNAPSHOT
+///         .get_or_init(|| Snapshot::new(|_| (), |app| app.advance_blocks(10)).0)
+///         .app()
+/// }
+///
+/// assert_eq!(setup().block_info().height, setup().block_info().height);
+/// ```
+pub struct Snapshot {
+    codes: fn(&mut App),
+    block: BlockInfo,
+    records: Vec<Record>,
+}
+
+impl Snapshot {
+    /// Stores `codes` in a new app and runs `setup` on it, capturing the resulting state. Whatever
+    /// `setup` returns - typically the instantiated addresses - is passed back along.
+    pub fn new<T>(codes: fn(&mut App), setup: impl FnOnce(&mut App) -> T) -> (Self, T) {
+        let mut app = app();
+        codes(&mut app);
+        let out = setup(&mut app);
+
+        let records =
+            app.read_module(|_, _, storage| storage.range(None, None, Order::Ascending).collect());
+
+        let snapshot = Self {
+            codes,
+            block: app.block_info(),
+            records,
+        };
+
+        (snapshot, out)
+    }
+
+    /// New app in the captured state. Apps are independent of each other.
+    pub fn app(&self) -> App {
+        let mut app = app();
+        (self.codes)(&mut app);
+        app.set_block(self.block.clone());
+        app.init_modules(|_, _, storage| {
+            for (key, value) in &self.records {
+                storage.set(key, value);
+            }
+        });
+        app
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+
+    use super::*;
+    use crate::addr::owner;
+    use crate::app::{balance, TimeMachine};
+
+    #[test]
+    fn restored_apps_are_independent() {
+        let (snapshot, _) = Snapshot::new(
+            |_| (),
+            |app| {
+                app.init_modules(|router, _, storage| {
+                    router
+                        .bank
+                        .init_balance(storage, &owner(), coins(100, "eth"))
+                        .unwrap()
+                });
+                app.advance_blocks(3);
+            },
+        );
+
+        let mut first = snapshot.app();
+        let second = snapshot.app();
+
+        first.advance_blocks(1);
+        first
+            .init_modules(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &owner(), coins(50, "eth"))
+            })
+            .unwrap();
+
+        assert_eq!(balance(&first, owner(), "eth"), 50);
+        assert_eq!(balance(&second, owner(), "eth"), 100);
+        assert_eq!(first.block_info().height, second.block_info().height + 1);
+    }
+}
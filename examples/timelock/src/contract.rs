@@ -0,0 +1,313 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, OperationResp, OperationsResp, QueryMsg};
+use crate::state::{Operation, MIN_DELAY, NEXT_OPERATION_ID, OPERATIONS, PROPOSERS};
+use common::pagination;
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult, Timestamp,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    for proposer in msg.proposers {
+        let proposer = deps.api.addr_validate(&proposer)?;
+        PROPOSERS.save(deps.storage, &proposer, &Empty {})?;
+    }
+    MIN_DELAY.save(deps.storage, &msg.min_delay)?;
+    NEXT_OPERATION_ID.save(deps.storage, &0)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Operation { id } => to_json_binary(&query::operation(deps, id)?),
+        Operations { start_after, limit } => {
+            to_json_binary(&query::operations(deps, start_after, limit)?)
+        }
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Schedule {
+            msgs,
+            execute_after,
+        } => exec::schedule(deps, env, info, msgs, execute_after),
+        Cancel { id } => exec::cancel(deps, info, id),
+        Execute { id } => exec::execute(deps, env, id),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn schedule(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msgs: Vec<CosmosMsg>,
+        execute_after: Timestamp,
+    ) -> Result<Response, ContractError> {
+        if !PROPOSERS.has(deps.storage, &info.sender) {
+            return Err(ContractError::NotProposer {
+                sender: info.sender,
+            });
+        }
+
+        let earliest = env.block.time.plus_seconds(MIN_DELAY.load(deps.storage)?);
+        if execute_after < earliest {
+            return Err(ContractError::DelayTooShort { earliest });
+        }
+
+        let id = NEXT_OPERATION_ID.load(deps.storage)?;
+        NEXT_OPERATION_ID.save(deps.storage, &(id + 1))?;
+
+        let operation = Operation {
+            proposer: info.sender,
+            msgs,
+            execute_after,
+        };
+        OPERATIONS.save(deps.storage, id, &operation)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "schedule")
+            .add_attribute("id", id.to_string())
+            .add_attribute("execute_after", execute_after.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn cancel(deps: DepsMut, info: MessageInfo, id: u64) -> Result<Response, ContractError> {
+        let operation = OPERATIONS.load(deps.storage, id)?;
+        if info.sender != operation.proposer {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+        OPERATIONS.remove(deps.storage, id);
+
+        let resp = Response::new()
+            .add_attribute("action", "cancel")
+            .add_attribute("id", id.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn execute(deps: DepsMut, env: Env, id: u64) -> Result<Response, ContractError> {
+        let operation = OPERATIONS.load(deps.storage, id)?;
+        if env.block.time < operation.execute_after {
+            return Err(ContractError::NotReady {
+                execute_after: operation.execute_after,
+            });
+        }
+        // Removed before the messages are sent, so the operation can never run twice
+        OPERATIONS.remove(deps.storage, id);
+
+        let resp = Response::new()
+            .add_messages(operation.msgs)
+            .add_attribute("action", "execute")
+            .add_attribute("id", id.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    fn operation_resp(id: u64, operation: Operation) -> OperationResp {
+        OperationResp {
+            id,
+            proposer: operation.proposer,
+            msgs: operation.msgs,
+            execute_after: operation.execute_after,
+        }
+    }
+
+    pub fn operation(deps: Deps, id: u64) -> StdResult<OperationResp> {
+        let operation = OPERATIONS.load(deps.storage, id)?;
+        Ok(operation_resp(id, operation))
+    }
+
+    pub fn operations(
+        deps: Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<OperationsResp> {
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after);
+
+        let operations: Vec<_> = OPERATIONS
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (id, operation) = item?;
+                Ok(operation_resp(id, operation))
+            })
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after = pagination::next_key(&operations, limit, |operation| operation.id);
+
+        Ok(OperationsResp {
+            operations,
+            next_start_after,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::BankMsg;
+    use testing::prelude::*;
+
+    use super::*;
+
+    const DELAY: u64 = 24 * 60 * 60;
+
+    /// Timelock with `proposer` and 1000 atom to send.
+    fn timelock(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            proposers: vec![mock_addr("proposer").to_string()],
+            min_delay: DELAY,
+        };
+        let contract = Proxy::deploy(app, code, "owner", &msg, "Timelock").unwrap();
+
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, contract.addr(), coins(1000, "atom"))
+        })
+        .unwrap();
+
+        contract
+    }
+
+    fn schedule_payment(
+        app: &mut App,
+        timelock: &Proxy,
+        sender: &str,
+        delay: u64,
+    ) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Schedule {
+            msgs: vec![BankMsg::Send {
+                to_address: mock_addr("user").to_string(),
+                amount: coins(100, "atom"),
+            }
+            .into()],
+            execute_after: app.block_info().time.plus_seconds(delay),
+        };
+        timelock.execute(app, sender, &msg, &[])
+    }
+
+    fn pending(app: &App, timelock: &Proxy) -> Vec<u64> {
+        let msg = QueryMsg::Operations {
+            start_after: None,
+            limit: None,
+        };
+        let resp: OperationsResp = timelock.query(app, &msg).unwrap();
+        resp.operations
+            .iter()
+            .map(|operation| operation.id)
+            .collect()
+    }
+
+    #[test]
+    fn execute_after_delay() {
+        let mut app = app();
+        let timelock = timelock(&mut app);
+
+        let resp = schedule_payment(&mut app, &timelock, "proposer", DELAY).unwrap();
+        assert_eq!(wasm_attr(&resp, "id"), "0");
+        let execute_after = app.block_info().time.plus_seconds(DELAY);
+
+        app.advance_time(DELAY - 1);
+        let err = timelock
+            .execute(&mut app, "user", &ExecuteMsg::Execute { id: 0 }, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotReady { execute_after },
+            err.downcast().unwrap()
+        );
+
+        // Once matured, anyone can execute the operation
+        app.advance_time(1);
+        timelock
+            .execute(&mut app, "user", &ExecuteMsg::Execute { id: 0 }, &[])
+            .unwrap();
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 100);
+        assert_eq!(pending(&app, &timelock), Vec::<u64>::new());
+
+        timelock
+            .execute(&mut app, "user", &ExecuteMsg::Execute { id: 0 }, &[])
+            .unwrap_err();
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 100);
+    }
+
+    #[test]
+    fn invalid_schedule() {
+        let mut app = app();
+        let timelock = timelock(&mut app);
+
+        let err = schedule_payment(&mut app, &timelock, "user", DELAY).unwrap_err();
+        assert_eq!(
+            ContractError::NotProposer {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = schedule_payment(&mut app, &timelock, "proposer", DELAY - 1).unwrap_err();
+        assert_eq!(
+            ContractError::DelayTooShort {
+                earliest: app.block_info().time.plus_seconds(DELAY)
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn cancel() {
+        let mut app = app();
+        let timelock = timelock(&mut app);
+
+        schedule_payment(&mut app, &timelock, "proposer", DELAY).unwrap();
+        schedule_payment(&mut app, &timelock, "proposer", 2 * DELAY).unwrap();
+        assert_eq!(pending(&app, &timelock), [0, 1]);
+
+        let err = timelock
+            .execute(&mut app, "user", &ExecuteMsg::Cancel { id: 0 }, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        timelock
+            .execute(&mut app, "proposer", &ExecuteMsg::Cancel { id: 0 }, &[])
+            .unwrap();
+        assert_eq!(pending(&app, &timelock), [1]);
+
+        app.advance_time(DELAY);
+        timelock
+            .execute(&mut app, "user", &ExecuteMsg::Execute { id: 0 }, &[])
+            .unwrap_err();
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 0);
+    }
+}
@@ -0,0 +1,16 @@
+use cosmwasm_std::{Addr, StdError, Timestamp};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not a proposer")]
+    NotProposer { sender: Addr },
+    #[error("{sender} did not schedule the operation")]
+    Unauthorized { sender: Addr },
+    #[error("Operation cannot be executed before {earliest}")]
+    DelayTooShort { earliest: Timestamp },
+    #[error("Operation is not ready until {execute_after}")]
+    NotReady { execute_after: Timestamp },
+}
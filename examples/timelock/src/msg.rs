@@ -0,0 +1,55 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, CosmosMsg, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub proposers: Vec<String>,
+    pub min_delay: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Queues messages to be sent by the contract, at least `min_delay` seconds from now
+    Schedule {
+        msgs: Vec<CosmosMsg>,
+        execute_after: Timestamp,
+    },
+    /// Removes the operation from the queue, only by its proposer
+    Cancel { id: u64 },
+    /// Sends the messages of a matured operation, by anyone
+    Execute { id: u64 },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OperationResp {
+    pub id: u64,
+    pub proposer: Addr,
+    pub msgs: Vec<CosmosMsg>,
+    pub execute_after: Timestamp,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct OperationsResp {
+    pub operations: Vec<OperationResp>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(OperationResp)]
+    Operation { id: u64 },
+    /// Lists operations waiting for execution
+    #[returns(OperationsResp)]
+    Operations {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
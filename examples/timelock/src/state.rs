@@ -0,0 +1,17 @@
+use cosmwasm_std::{Addr, CosmosMsg, Empty, Timestamp};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Operation {
+    pub proposer: Addr,
+    pub msgs: Vec<CosmosMsg>,
+    pub execute_after: Timestamp,
+}
+
+/// Minimal number of seconds between scheduling and executing an operation
+pub const MIN_DELAY: Item<u64> = Item::new("min_delay");
+pub const PROPOSERS: Map<&Addr, Empty> = Map::new("proposers");
+pub const OPERATIONS: Map<u64, Operation> = Map::new("operations");
+pub const NEXT_OPERATION_ID: Item<u64> = Item::new("next_operation_id");
@@ -0,0 +1,547 @@
+use crate::error::ContractError;
+use crate::msg::{ConfigResp, ExecuteMsg, InstantiateMsg, QueryMsg, TokenResp, TokensResp};
+use crate::state::{tokens, Config, TokenEntry, CONFIG};
+use common::pagination;
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdError, StdResult,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let config = Config {
+        admin: info.sender,
+        registration_fee: msg.registration_fee,
+    };
+    CONFIG.save(deps.storage, &config, env.block.height)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Config {} => to_json_binary(&query::config(deps)?),
+        ConfigAt { height } => to_json_binary(&query::config_at(deps, height)?),
+        Token { denom } => to_json_binary(&query::token(deps, denom)?),
+        TokenBySymbol { symbol } => to_json_binary(&query::token_by_symbol(deps, symbol)?),
+        Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_json_binary(&query::owner_tokens(deps, owner, start_after, limit)?),
+        AllTokens { start_after, limit } => {
+            to_json_binary(&query::all_tokens(deps, start_after, limit)?)
+        }
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Register {
+            denom,
+            symbol,
+            decimals,
+        } => exec::register(deps, info, denom, symbol, decimals),
+        Transfer { denom, owner } => exec::transfer(deps, info, denom, owner),
+        Unregister { denom } => exec::unregister(deps, info, denom),
+        UpdateFee { fee } => exec::update_fee(deps, env, info, fee),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    fn owned_entry(
+        deps: Deps,
+        info: &MessageInfo,
+        denom: &str,
+    ) -> Result<TokenEntry, ContractError> {
+        let entry = tokens().load(deps.storage, denom)?;
+        if info.sender != entry.owner {
+            return Err(ContractError::Unauthorized { owner: entry.owner });
+        }
+        Ok(entry)
+    }
+
+    pub fn register(
+        deps: DepsMut,
+        info: MessageInfo,
+        denom: String,
+        symbol: String,
+        decimals: u8,
+    ) -> Result<Response, ContractError> {
+        if tokens().has(deps.storage, &denom) {
+            return Err(ContractError::AlreadyRegistered { denom });
+        }
+
+        let config = CONFIG.load(deps.storage)?;
+        let fee = config.registration_fee;
+        let paid = info
+            .funds
+            .iter()
+            .find(|coin| coin.denom == fee.denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_default();
+        if paid < fee.amount {
+            return Err(ContractError::InsufficientFee { fee });
+        }
+
+        // A taken symbol fails the save, with the index error
+        let entry = TokenEntry {
+            owner: info.sender.clone(),
+            symbol,
+            decimals,
+        };
+        tokens().save(deps.storage, &denom, &entry)?;
+
+        let mut resp = Response::new()
+            .add_attribute("action", "register")
+            .add_attribute("denom", denom);
+        if !info.funds.is_empty() {
+            resp = resp.add_message(BankMsg::Send {
+                to_address: config.admin.into_string(),
+                amount: info.funds,
+            });
+        }
+
+        Ok(resp)
+    }
+
+    pub fn transfer(
+        deps: DepsMut,
+        info: MessageInfo,
+        denom: String,
+        owner: String,
+    ) -> Result<Response, ContractError> {
+        let mut entry = owned_entry(deps.as_ref(), &info, &denom)?;
+        entry.owner = deps.api.addr_validate(&owner)?;
+        // Saving moves the entry in the owner index as well
+        tokens().save(deps.storage, &denom, &entry)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "transfer")
+            .add_attribute("denom", denom)
+            .add_attribute("owner", entry.owner);
+
+        Ok(resp)
+    }
+
+    pub fn unregister(
+        deps: DepsMut,
+        info: MessageInfo,
+        denom: String,
+    ) -> Result<Response, ContractError> {
+        owned_entry(deps.as_ref(), &info, &denom)?;
+        tokens().remove(deps.storage, &denom)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "unregister")
+            .add_attribute("denom", denom);
+
+        Ok(resp)
+    }
+
+    pub fn update_fee(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        fee: Coin,
+    ) -> Result<Response, ContractError> {
+        let mut config = CONFIG.load(deps.storage)?;
+        if info.sender != config.admin {
+            return Err(ContractError::NotAdmin {
+                admin: config.admin,
+            });
+        }
+        config.registration_fee = fee;
+        // The height is where the change is recorded in the config history
+        CONFIG.save(deps.storage, &config, env.block.height)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "update_fee")
+            .add_attribute("fee", config.registration_fee.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    fn config_resp(config: Config) -> ConfigResp {
+        ConfigResp {
+            admin: config.admin,
+            registration_fee: config.registration_fee,
+        }
+    }
+
+    pub fn config(deps: Deps) -> StdResult<ConfigResp> {
+        CONFIG.load(deps.storage).map(config_resp)
+    }
+
+    pub fn config_at(deps: Deps, height: u64) -> StdResult<ConfigResp> {
+        CONFIG
+            .may_load_at_height(deps.storage, height)?
+            .map(config_resp)
+            .ok_or_else(|| StdError::not_found(format!("config at height {}", height)))
+    }
+
+    fn token_resp(denom: String, entry: TokenEntry) -> TokenResp {
+        TokenResp {
+            denom,
+            owner: entry.owner,
+            symbol: entry.symbol,
+            decimals: entry.decimals,
+        }
+    }
+
+    fn tokens_resp(tokens: Vec<TokenResp>, limit: usize) -> TokensResp {
+        let next_start_after = pagination::next_key(&tokens, limit, |token| token.denom.clone());
+        TokensResp {
+            tokens,
+            next_start_after,
+        }
+    }
+
+    pub fn token(deps: Deps, denom: String) -> StdResult<TokenResp> {
+        let entry = tokens().load(deps.storage, &denom)?;
+        Ok(token_resp(denom, entry))
+    }
+
+    pub fn token_by_symbol(deps: Deps, symbol: String) -> StdResult<TokenResp> {
+        // The unique index stores the whole value, so a single read finds the entry
+        let (denom, entry) = tokens()
+            .idx
+            .symbol
+            .item(deps.storage, symbol.clone())?
+            .ok_or_else(|| StdError::not_found(format!("token with symbol {}", symbol)))?;
+        let denom = String::from_utf8(denom)?;
+        Ok(token_resp(denom, entry))
+    }
+
+    pub fn all_tokens(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokensResp> {
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after.as_deref());
+
+        let tokens: Vec<_> = tokens()
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(denom, entry)| token_resp(denom, entry)))
+            .collect::<StdResult<_>>()?;
+
+        Ok(tokens_resp(tokens, limit))
+    }
+
+    /// Ranges over the owner prefix of the index - the primary keys under it are denoms, so
+    /// pagination works exactly like for `all_tokens`.
+    pub fn owner_tokens(
+        deps: Deps,
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<TokensResp> {
+        let owner = deps.api.addr_validate(&owner)?;
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after);
+
+        let tokens: Vec<_> = tokens()
+            .idx
+            .owner
+            .prefix(owner)
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| item.map(|(denom, entry)| token_resp(denom, entry)))
+            .collect::<StdResult<_>>()?;
+
+        Ok(tokens_resp(tokens, limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    fn registry(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            registration_fee: coin(0, "atom"),
+        };
+        Proxy::deploy(app, code, "owner", &msg, "Registry").unwrap()
+    }
+
+    fn register(
+        app: &mut App,
+        registry: &Proxy,
+        sender: &str,
+        denom: &str,
+    ) -> anyhow::Result<AppResponse> {
+        let msg = ExecuteMsg::Register {
+            denom: denom.to_owned(),
+            symbol: denom
+                .trim_start_matches("ibc/")
+                .trim_start_matches('u')
+                .to_uppercase(),
+            decimals: 6,
+        };
+        registry.execute(app, sender, &msg, &[])
+    }
+
+    fn denoms(app: &App, registry: &Proxy, msg: &QueryMsg) -> Vec<String> {
+        let resp: TokensResp = registry.query(app, msg).unwrap();
+        resp.tokens.into_iter().map(|token| token.denom).collect()
+    }
+
+    fn owner_denoms(
+        app: &App,
+        registry: &Proxy,
+        owner: &str,
+        start_after: Option<&str>,
+    ) -> Vec<String> {
+        let msg = QueryMsg::Tokens {
+            owner: mock_addr(owner).to_string(),
+            start_after: start_after.map(str::to_owned),
+            limit: Some(2),
+        };
+        denoms(app, registry, &msg)
+    }
+
+    /// alice registers `uatom`, `ujuno` and `uluna`, bob registers `uosmo`.
+    fn setup() -> (App, Proxy) {
+        let mut app = app();
+        let registry = registry(&mut app);
+        for (sender, denom) in [
+            ("alice", "ujuno"),
+            ("bob", "uosmo"),
+            ("alice", "uatom"),
+            ("alice", "uluna"),
+        ] {
+            register(&mut app, &registry, sender, denom).unwrap();
+        }
+        (app, registry)
+    }
+
+    #[test]
+    fn query_by_denom() {
+        let (mut app, registry) = setup();
+
+        let resp: TokenResp = registry
+            .query(
+                &app,
+                &QueryMsg::Token {
+                    denom: "uosmo".to_owned(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            resp,
+            TokenResp {
+                denom: "uosmo".to_owned(),
+                owner: mock_addr("bob"),
+                symbol: "OSMO".to_owned(),
+                decimals: 6,
+            }
+        );
+
+        let all = |start_after: Option<&str>| QueryMsg::AllTokens {
+            start_after: start_after.map(str::to_owned),
+            limit: Some(3),
+        };
+        assert_eq!(
+            denoms(&app, &registry, &all(None)),
+            ["uatom", "ujuno", "uluna"]
+        );
+        assert_eq!(denoms(&app, &registry, &all(Some("uluna"))), ["uosmo"]);
+
+        let err = register(&mut app, &registry, "bob", "uatom").unwrap_err();
+        assert_eq!(
+            ContractError::AlreadyRegistered {
+                denom: "uatom".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn query_by_owner() {
+        let (app, registry) = setup();
+
+        assert_eq!(
+            owner_denoms(&app, &registry, "alice", None),
+            ["uatom", "ujuno"]
+        );
+        assert_eq!(
+            owner_denoms(&app, &registry, "alice", Some("ujuno")),
+            ["uluna"]
+        );
+        assert_eq!(owner_denoms(&app, &registry, "bob", None), ["uosmo"]);
+        assert_eq!(
+            owner_denoms(&app, &registry, "carol", None),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn unique_symbols() {
+        let (mut app, registry) = setup();
+
+        let resp: TokenResp = registry
+            .query(
+                &app,
+                &QueryMsg::TokenBySymbol {
+                    symbol: "JUNO".to_owned(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.denom, "ujuno");
+        assert_eq!(resp.owner, mock_addr("alice"));
+
+        let by_symbol = QueryMsg::TokenBySymbol {
+            symbol: "ATOM".to_owned(),
+        };
+        let resp: TokenResp = registry.query(&app, &by_symbol).unwrap();
+        assert_eq!(resp.denom, "uatom");
+
+        // `atom` on another chain, with a symbol already taken
+        let err = register(&mut app, &registry, "bob", "ibc/atom").unwrap_err();
+        assert_eq!(
+            ContractError::StdError(StdError::generic_err("Violates unique constraint on index")),
+            err.downcast().unwrap()
+        );
+
+        // Removing the entry frees the symbol
+        let unregister = ExecuteMsg::Unregister {
+            denom: "uatom".to_owned(),
+        };
+        registry
+            .execute(&mut app, "alice", &unregister, &[])
+            .unwrap();
+        registry.query::<TokenResp>(&app, &by_symbol).unwrap_err();
+        register(&mut app, &registry, "bob", "ibc/atom").unwrap();
+        let resp: TokenResp = registry.query(&app, &by_symbol).unwrap();
+        assert_eq!(resp.denom, "ibc/atom");
+    }
+
+    #[test]
+    fn fee_history() {
+        let (mut app, registry) = setup();
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &mock_addr("carol"), coins(1000, "atom"))
+        })
+        .unwrap();
+        let start = app.block_info().height;
+
+        let update = |fee| ExecuteMsg::UpdateFee {
+            fee: coin(fee, "atom"),
+        };
+        let err = registry
+            .execute(&mut app, "alice", &update(100), &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::NotAdmin {
+                admin: addr::owner()
+            },
+            err.downcast().unwrap()
+        );
+
+        app.advance_blocks(1);
+        registry
+            .execute(&mut app, "owner", &update(100), &[])
+            .unwrap();
+        app.advance_blocks(1);
+        registry
+            .execute(&mut app, "owner", &update(200), &[])
+            .unwrap();
+
+        let err = register(&mut app, &registry, "carol", "uscrt").unwrap_err();
+        assert_eq!(
+            ContractError::InsufficientFee {
+                fee: coin(200, "atom")
+            },
+            err.downcast().unwrap()
+        );
+        let msg = ExecuteMsg::Register {
+            denom: "uscrt".to_owned(),
+            symbol: "SCRT".to_owned(),
+            decimals: 6,
+        };
+        registry
+            .execute(&mut app, "carol", &msg, &coins(200, "atom"))
+            .unwrap();
+        assert_eq!(balance(&app, addr::owner(), "atom"), 200);
+
+        // A change is visible from the next block on
+        let fee_at = |height| {
+            let resp: ConfigResp = registry
+                .query(&app, &QueryMsg::ConfigAt { height })
+                .unwrap();
+            resp.registration_fee.amount.u128()
+        };
+        assert_eq!(fee_at(start + 1), 0);
+        assert_eq!(fee_at(start + 2), 100);
+        assert_eq!(fee_at(start + 3), 200);
+
+        let resp: ConfigResp = registry.query(&app, &QueryMsg::Config {}).unwrap();
+        assert_eq!(resp.registration_fee, coin(200, "atom"));
+
+        // There was no config before the contract was instantiated
+        registry
+            .query::<ConfigResp>(&app, &QueryMsg::ConfigAt { height: start })
+            .unwrap_err();
+    }
+
+    #[test]
+    fn index_follows_changes() {
+        let (mut app, registry) = setup();
+
+        let transfer = ExecuteMsg::Transfer {
+            denom: "ujuno".to_owned(),
+            owner: mock_addr("bob").to_string(),
+        };
+        let err = registry
+            .execute(&mut app, "bob", &transfer, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                owner: mock_addr("alice")
+            },
+            err.downcast().unwrap()
+        );
+        registry.execute(&mut app, "alice", &transfer, &[]).unwrap();
+
+        assert_eq!(
+            owner_denoms(&app, &registry, "alice", None),
+            ["uatom", "uluna"]
+        );
+        assert_eq!(
+            owner_denoms(&app, &registry, "bob", None),
+            ["ujuno", "uosmo"]
+        );
+
+        let unregister = ExecuteMsg::Unregister {
+            denom: "uosmo".to_owned(),
+        };
+        registry.execute(&mut app, "bob", &unregister, &[]).unwrap();
+        assert_eq!(owner_denoms(&app, &registry, "bob", None), ["ujuno"]);
+    }
+}
@@ -0,0 +1,16 @@
+use cosmwasm_std::{Addr, Coin, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("Token {denom} is already registered")]
+    AlreadyRegistered { denom: String },
+    #[error("Unauthorized - only {owner} can change the token")]
+    Unauthorized { owner: Addr },
+    #[error("Unauthorized - only {admin} can change the config")]
+    NotAdmin { admin: Addr },
+    #[error("Registration fee of {fee} has to be paid")]
+    InsufficientFee { fee: Coin },
+}
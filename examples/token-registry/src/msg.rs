@@ -0,0 +1,79 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Coin};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub registration_fee: Coin,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Registers metadata of a token, owned by the sender. Symbols are unique.
+    Register {
+        denom: String,
+        symbol: String,
+        decimals: u8,
+    },
+    /// Passes the entry to another owner, only by the current one
+    Transfer { denom: String, owner: String },
+    /// Removes the entry, only by the owner
+    Unregister { denom: String },
+    /// Changes the fee for new registrations, only by the admin
+    UpdateFee { fee: Coin },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ConfigResp {
+    pub admin: Addr,
+    pub registration_fee: Coin,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TokenResp {
+    pub denom: String,
+    pub owner: Addr,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct TokensResp {
+    pub tokens: Vec<TokenResp>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(ConfigResp)]
+    Config {},
+    /// Config as it was at the beginning of the block at `height`, before any changes in it
+    #[returns(ConfigResp)]
+    ConfigAt { height: u64 },
+    #[returns(TokenResp)]
+    Token { denom: String },
+    /// Token registered with the symbol
+    #[returns(TokenResp)]
+    TokenBySymbol { symbol: String },
+    /// Lists tokens of a single owner by denom
+    #[returns(TokensResp)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Lists all tokens by denom
+    #[returns(TokensResp)]
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
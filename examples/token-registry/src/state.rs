@@ -0,0 +1,51 @@
+use cosmwasm_std::{Addr, Coin};
+use cw_storage_plus::{
+    Index, IndexList, IndexedMap, MultiIndex, SnapshotItem, Strategy, UniqueIndex,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    pub admin: Addr,
+    /// Paid on registration, zero amount for free registrations
+    pub registration_fee: Coin,
+}
+
+/// Config with its history - every change is recorded, so the config in effect at any past
+/// height can be read back
+pub const CONFIG: SnapshotItem<Config> = SnapshotItem::new(
+    "config",
+    "config__checkpoints",
+    "config__changelog",
+    Strategy::EveryBlock,
+);
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TokenEntry {
+    pub owner: Addr,
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+pub struct TokenIndexes<'a> {
+    pub owner: MultiIndex<'a, Addr, TokenEntry, String>,
+    /// Saving a second token with the same symbol fails
+    pub symbol: UniqueIndex<'a, String, TokenEntry, String>,
+}
+
+impl<'a> IndexList<TokenEntry> for TokenIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<TokenEntry>> + '_> {
+        let v: Vec<&dyn Index<TokenEntry>> = vec![&self.owner, &self.symbol];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Registered tokens by denom, indexed by the account which registered them and by the symbol
+pub fn tokens<'a>() -> IndexedMap<&'a str, TokenEntry, TokenIndexes<'a>> {
+    let indexes = TokenIndexes {
+        owner: MultiIndex::new(|_pk, token| token.owner.clone(), "tokens", "tokens__owner"),
+        symbol: UniqueIndex::new(|token| token.symbol.clone(), "tokens__symbol"),
+    };
+    IndexedMap::new("tokens", indexes)
+}
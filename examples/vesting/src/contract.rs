@@ -0,0 +1,287 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, VestingResp};
+use crate::state::{Vesting, CLAIMED, VESTING};
+use cosmwasm_std::{
+    coins, to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Uint128,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let funds = cw_utils::one_coin(&info)?;
+    if !msg.schedule.is_valid() {
+        return Err(ContractError::InvalidSchedule);
+    }
+
+    let vesting = Vesting {
+        recipient: deps.api.addr_validate(&msg.recipient)?,
+        denom: funds.denom,
+        total: funds.amount,
+        schedule: msg.schedule,
+    };
+    VESTING.save(deps.storage, &vesting)?;
+    CLAIMED.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Vesting {} => to_json_binary(&query::vesting(deps, env)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Claim {} => exec::claim(deps, env, info),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let vesting = VESTING.load(deps.storage)?;
+        if info.sender != vesting.recipient {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let vested = vesting.schedule.vested(vesting.total, env.block.time);
+        let claimed = CLAIMED.load(deps.storage)?;
+        let claimable = vested - claimed;
+        if claimable.is_zero() {
+            return Err(ContractError::NothingToClaim);
+        }
+        CLAIMED.save(deps.storage, &vested)?;
+
+        let resp = Response::new()
+            .add_message(BankMsg::Send {
+                to_address: vesting.recipient.to_string(),
+                amount: coins(claimable.u128(), &vesting.denom),
+            })
+            .add_attribute("action", "claim")
+            .add_attribute("amount", claimable.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn vesting(deps: Deps, env: Env) -> StdResult<VestingResp> {
+        let vesting = VESTING.load(deps.storage)?;
+        let claimed = CLAIMED.load(deps.storage)?;
+        let vested = vesting.schedule.vested(vesting.total, env.block.time);
+
+        Ok(VestingResp {
+            recipient: vesting.recipient,
+            denom: vesting.denom,
+            schedule: vesting.schedule,
+            total: vesting.total,
+            vested,
+            unvested: vesting.total - vested,
+            claimed,
+            claimable: vested - claimed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Timestamp;
+    use cw_utils::PaymentError;
+    use testing::prelude::*;
+
+    use crate::state::Schedule;
+
+    use super::*;
+
+    const DAY: u64 = 24 * 60 * 60;
+
+    /// Vesting of 1000 atom for `recipient`, with the schedule built relative to the current time.
+    fn vesting(app: &mut App, schedule: impl FnOnce(Timestamp) -> Schedule) -> Proxy {
+        let code_id = store_code(app, ContractWrapper::new(execute, instantiate, query));
+        let msg = InstantiateMsg {
+            recipient: mock_addr("recipient").to_string(),
+            schedule: schedule(app.block_info().time),
+        };
+        Proxy::instantiate(app, code_id, "owner", &msg, &coins(1000, "atom"), "Vesting").unwrap()
+    }
+
+    fn linear(now: Timestamp) -> Schedule {
+        Schedule::Linear {
+            start_time: now.plus_seconds(DAY),
+            end_time: now.plus_seconds(11 * DAY),
+        }
+    }
+
+    fn cliff(now: Timestamp) -> Schedule {
+        Schedule::Cliff {
+            start_time: now,
+            cliff_time: now.plus_seconds(4 * DAY),
+            end_time: now.plus_seconds(10 * DAY),
+        }
+    }
+
+    fn state(app: &App, vesting: &Proxy) -> VestingResp {
+        vesting.query(app, &QueryMsg::Vesting {}).unwrap()
+    }
+
+    fn claim(app: &mut App, vesting: &Proxy) -> anyhow::Result<AppResponse> {
+        vesting.execute(app, "recipient", &ExecuteMsg::Claim {}, &[])
+    }
+
+    fn funded_app() -> App {
+        app_with_balances(&[("owner", coins(1000, "atom"))])
+    }
+
+    #[test]
+    fn schedule_math() {
+        let now = Timestamp::from_seconds(0);
+        let total = Uint128::new(1000);
+        let vested =
+            |schedule: &Schedule, days| schedule.vested(total, now.plus_seconds(days * DAY));
+
+        let linear = linear(now);
+        assert_eq!(vested(&linear, 0), Uint128::zero());
+        assert_eq!(vested(&linear, 1), Uint128::zero());
+        assert_eq!(vested(&linear, 2), Uint128::new(100));
+        assert_eq!(vested(&linear, 6), Uint128::new(500));
+        assert_eq!(vested(&linear, 11), total);
+        assert_eq!(vested(&linear, 100), total);
+
+        let cliff = cliff(now);
+        assert_eq!(vested(&cliff, 3), Uint128::zero());
+        assert_eq!(vested(&cliff, 4), Uint128::new(400));
+        assert_eq!(vested(&cliff, 5), Uint128::new(500));
+        assert_eq!(vested(&cliff, 10), total);
+
+        // Fractions of a token vested so far are rounded down
+        assert_eq!(
+            linear.vested(total, now.plus_seconds(DAY + 1)),
+            Uint128::zero()
+        );
+    }
+
+    #[test]
+    fn invalid_instantiation() {
+        let mut app = funded_app();
+        let code_id = store_code(&mut app, ContractWrapper::new(execute, instantiate, query));
+        let now = app.block_info().time;
+        let msg = |schedule| InstantiateMsg {
+            recipient: mock_addr("recipient").to_string(),
+            schedule,
+        };
+
+        let err = Proxy::instantiate(&mut app, code_id, "owner", &msg(linear(now)), &[], "V")
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Payment(PaymentError::NoFunds {}),
+            err.downcast().unwrap()
+        );
+
+        let schedules = [
+            Schedule::Linear {
+                start_time: now,
+                end_time: now,
+            },
+            Schedule::Cliff {
+                start_time: now,
+                cliff_time: now.plus_seconds(2 * DAY),
+                end_time: now.plus_seconds(DAY),
+            },
+        ];
+        for schedule in schedules {
+            let err = Proxy::instantiate(
+                &mut app,
+                code_id,
+                "owner",
+                &msg(schedule),
+                &coins(100, "atom"),
+                "V",
+            )
+            .unwrap_err();
+            assert_eq!(ContractError::InvalidSchedule, err.downcast().unwrap());
+        }
+    }
+
+    #[test]
+    fn linear_claims() {
+        let mut app = funded_app();
+        let vesting = vesting(&mut app, linear);
+
+        let err = claim(&mut app, &vesting).unwrap_err();
+        assert_eq!(ContractError::NothingToClaim, err.downcast().unwrap());
+
+        app.advance_time(3 * DAY);
+        let resp = claim(&mut app, &vesting).unwrap();
+        assert_eq!(wasm_attr(&resp, "amount"), "200");
+        assert_eq!(balance(&app, mock_addr("recipient"), "atom"), 200);
+
+        // Claiming twice in the same block gives nothing
+        claim(&mut app, &vesting).unwrap_err();
+
+        app.advance_time(2 * DAY);
+        let resp = state(&app, &vesting);
+        assert_eq!(resp.vested, Uint128::new(400));
+        assert_eq!(resp.unvested, Uint128::new(600));
+        assert_eq!(resp.claimed, Uint128::new(200));
+        assert_eq!(resp.claimable, Uint128::new(200));
+
+        app.advance_time(100 * DAY);
+        claim(&mut app, &vesting).unwrap();
+        assert_eq!(balance(&app, mock_addr("recipient"), "atom"), 1000);
+        assert_eq!(balance(&app, vesting.addr(), "atom"), 0);
+
+        let resp = state(&app, &vesting);
+        assert_eq!(resp.unvested, Uint128::zero());
+        assert_eq!(resp.claimable, Uint128::zero());
+    }
+
+    #[test]
+    fn cliff_claims() {
+        let mut app = funded_app();
+        let vesting = vesting(&mut app, cliff);
+
+        app.advance_time(4 * DAY - 1);
+        assert_eq!(state(&app, &vesting).vested, Uint128::zero());
+        claim(&mut app, &vesting).unwrap_err();
+
+        app.advance_time(1);
+        claim(&mut app, &vesting).unwrap();
+        assert_eq!(balance(&app, mock_addr("recipient"), "atom"), 400);
+    }
+
+    #[test]
+    fn unauthorized_claim() {
+        let mut app = funded_app();
+        let vesting = vesting(&mut app, linear);
+        app.advance_time(5 * DAY);
+
+        let err = vesting
+            .execute(&mut app, "owner", &ExecuteMsg::Claim {}, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: addr::owner()
+            },
+            err.downcast().unwrap()
+        );
+    }
+}
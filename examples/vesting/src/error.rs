@@ -0,0 +1,17 @@
+use cosmwasm_std::{Addr, StdError};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+    #[error("Schedule has to start before it ends, with the cliff in between")]
+    InvalidSchedule,
+    #[error("{sender} is not the vesting recipient")]
+    Unauthorized { sender: Addr },
+    #[error("Nothing to claim")]
+    NothingToClaim,
+}
@@ -0,0 +1,42 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{Addr, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::Schedule;
+
+/// Tokens to vest are sent with the instantiation.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub recipient: String,
+    pub schedule: Schedule,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Sends all vested and not yet claimed tokens to the recipient
+    Claim {},
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VestingResp {
+    pub recipient: Addr,
+    pub denom: String,
+    pub schedule: Schedule,
+    pub total: Uint128,
+    pub vested: Uint128,
+    pub unvested: Uint128,
+    pub claimed: Uint128,
+    /// Vested tokens not claimed yet
+    pub claimable: Uint128,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(VestingResp)]
+    Vesting {},
+}
@@ -0,0 +1,78 @@
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Schedule {
+    /// Tokens vest linearly from `start_time` to `end_time`
+    Linear {
+        start_time: Timestamp,
+        end_time: Timestamp,
+    },
+    /// Like `Linear`, but nothing can be claimed before `cliff_time` - then everything vested
+    /// since `start_time` unlocks at once
+    Cliff {
+        start_time: Timestamp,
+        cliff_time: Timestamp,
+        end_time: Timestamp,
+    },
+}
+
+impl Schedule {
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Schedule::Linear {
+                start_time,
+                end_time,
+            } => start_time < end_time,
+            Schedule::Cliff {
+                start_time,
+                cliff_time,
+                end_time,
+            } => start_time < end_time && start_time <= cliff_time && cliff_time <= end_time,
+        }
+    }
+
+    /// Part of `total` vested at `now`.
+    pub fn vested(&self, total: Uint128, now: Timestamp) -> Uint128 {
+        let (start_time, end_time) = match *self {
+            Schedule::Linear {
+                start_time,
+                end_time,
+            } => (start_time, end_time),
+            Schedule::Cliff {
+                start_time,
+                cliff_time,
+                end_time,
+            } => {
+                if now < cliff_time {
+                    return Uint128::zero();
+                }
+                (start_time, end_time)
+            }
+        };
+
+        if now <= start_time {
+            Uint128::zero()
+        } else if now >= end_time {
+            total
+        } else {
+            let elapsed = now.seconds() - start_time.seconds();
+            let duration = end_time.seconds() - start_time.seconds();
+            total.multiply_ratio(elapsed, duration)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Vesting {
+    pub recipient: Addr,
+    pub denom: String,
+    pub total: Uint128,
+    pub schedule: Schedule,
+}
+
+pub const VESTING: Item<Vesting> = Item::new("vesting");
+pub const CLAIMED: Item<Uint128> = Item::new("claimed");
@@ -0,0 +1,511 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, ProposalResp, ProposalsResp, QueryMsg, VoteResp};
+use crate::state::{
+    Config, Proposal, Status, Vote, ADMINS, ADMINS_COUNT, BALLOTS, CONFIG, NEXT_PROPOSAL_ID,
+    PROPOSALS,
+};
+use common::pagination;
+use cosmwasm_std::{
+    to_json_binary, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order,
+    Response, StdResult, Uint128,
+};
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    if msg.threshold.is_zero() || msg.threshold > Decimal::one() {
+        return Err(ContractError::InvalidThreshold);
+    }
+
+    for admin in msg.admins {
+        let admin = deps.api.addr_validate(&admin)?;
+        ADMINS.save(deps.storage, &admin, &Empty {})?;
+    }
+    // Counted after saving, so duplicated admins are not counted twice
+    let admins_count = ADMINS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count() as u64;
+    if admins_count == 0 {
+        return Err(ContractError::NoAdmins);
+    }
+    ADMINS_COUNT.save(deps.storage, &admins_count)?;
+
+    let config = Config {
+        threshold: msg.threshold,
+        voting_period: msg.voting_period,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    NEXT_PROPOSAL_ID.save(deps.storage, &0)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Proposal { proposal_id } => to_json_binary(&query::proposal(deps, env, proposal_id)?),
+        ListProposals { start_after, limit } => {
+            to_json_binary(&query::list_proposals(deps, env, start_after, limit)?)
+        }
+        Vote { proposal_id, voter } => to_json_binary(&query::vote(deps, proposal_id, voter)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Propose {
+            title,
+            description,
+            msgs,
+        } => exec::propose(deps, env, info, title, description, msgs),
+        Vote { proposal_id, vote } => exec::vote(deps, env, info, proposal_id, vote),
+        Execute { proposal_id } => exec::execute(deps, env, proposal_id),
+    }
+}
+
+/// Number of yes votes out of `count` needed to reach the threshold, rounded up.
+fn required_votes(threshold: Decimal, count: u64) -> u64 {
+    let floor = Uint128::from(count).mul_floor(threshold).u128() as u64;
+    if Decimal::from_ratio(floor, count) < threshold {
+        floor + 1
+    } else {
+        floor
+    }
+}
+
+mod exec {
+    use super::*;
+
+    fn ensure_admin(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+        if !ADMINS.has(deps.storage, &info.sender) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn propose(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg>,
+    ) -> Result<Response, ContractError> {
+        ensure_admin(deps.as_ref(), &info)?;
+
+        let config = CONFIG.load(deps.storage)?;
+        let admins_count = ADMINS_COUNT.load(deps.storage)?;
+        let proposal = Proposal {
+            title,
+            description,
+            msgs,
+            expires: config.voting_period.after(&env.block),
+            yes: 0,
+            no: 0,
+            required: required_votes(config.threshold, admins_count),
+            executed: false,
+        };
+
+        let id = NEXT_PROPOSAL_ID.load(deps.storage)?;
+        PROPOSALS.save(deps.storage, id, &proposal)?;
+        NEXT_PROPOSAL_ID.save(deps.storage, &(id + 1))?;
+
+        let resp = Response::new()
+            .add_attribute("action", "propose")
+            .add_attribute("sender", info.sender.as_str())
+            .add_attribute("proposal_id", id.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn vote(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        proposal_id: u64,
+        vote: Vote,
+    ) -> Result<Response, ContractError> {
+        ensure_admin(deps.as_ref(), &info)?;
+
+        let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+        if proposal.expires.is_expired(&env.block) {
+            return Err(ContractError::Expired { id: proposal_id });
+        }
+
+        let ballot = (proposal_id, &info.sender);
+        if BALLOTS.has(deps.storage, ballot) {
+            return Err(ContractError::AlreadyVoted {
+                id: proposal_id,
+                voter: info.sender,
+            });
+        }
+        BALLOTS.save(deps.storage, ballot, &vote)?;
+
+        match vote {
+            Vote::Yes => proposal.yes += 1,
+            Vote::No => proposal.no += 1,
+        }
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "vote")
+            .add_attribute("sender", info.sender.as_str())
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("status", format!("{:?}", proposal.status(&env.block)));
+
+        Ok(resp)
+    }
+
+    pub fn execute(deps: DepsMut, env: Env, proposal_id: u64) -> Result<Response, ContractError> {
+        let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+        if proposal.status(&env.block) != Status::Passed {
+            return Err(ContractError::NotPassed { id: proposal_id });
+        }
+
+        proposal.executed = true;
+        PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+        // Messages are dispatched after this call returns - if any of them fails, the whole
+        // execution is reverted and the proposal stays passed
+        let resp = Response::new()
+            .add_messages(proposal.msgs)
+            .add_attribute("action", "execute")
+            .add_attribute("proposal_id", proposal_id.to_string());
+
+        Ok(resp)
+    }
+}
+
+mod query {
+    use super::*;
+
+    fn proposal_resp(env: &Env, id: u64, proposal: Proposal) -> ProposalResp {
+        ProposalResp {
+            id,
+            status: proposal.status(&env.block),
+            title: proposal.title,
+            description: proposal.description,
+            msgs: proposal.msgs,
+            expires: proposal.expires,
+            yes: proposal.yes,
+            no: proposal.no,
+            required: proposal.required,
+        }
+    }
+
+    pub fn proposal(deps: Deps, env: Env, proposal_id: u64) -> StdResult<ProposalResp> {
+        let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+        Ok(proposal_resp(&env, proposal_id, proposal))
+    }
+
+    pub fn list_proposals(
+        deps: Deps,
+        env: Env,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<ProposalsResp> {
+        let limit = pagination::limit(limit);
+        let start = pagination::start_after(start_after);
+
+        let proposals: Vec<_> = PROPOSALS
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .map(|item| {
+                let (id, proposal) = item?;
+                Ok(proposal_resp(&env, id, proposal))
+            })
+            .collect::<StdResult<_>>()?;
+
+        let next_start_after = pagination::next_key(&proposals, limit, |proposal| proposal.id);
+
+        Ok(ProposalsResp {
+            proposals,
+            next_start_after,
+        })
+    }
+
+    pub fn vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<VoteResp> {
+        let voter = deps.api.addr_validate(&voter)?;
+        let vote = BALLOTS.may_load(deps.storage, (proposal_id, &voter))?;
+        Ok(VoteResp { vote })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::BankMsg;
+    use cw_utils::Duration;
+    use testing::prelude::*;
+
+    use super::*;
+
+    const VOTING_PERIOD: u64 = 100;
+
+    /// Voting of `admin1..=admin3`, requiring two thirds of yes votes, funded with 100 atom.
+    fn voting(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let msg = InstantiateMsg {
+            admins: (1..=3).map(|n| addr::admin(n).to_string()).collect(),
+            threshold: Decimal::from_ratio(2u32, 3u32),
+            voting_period: Duration::Time(VOTING_PERIOD),
+        };
+        let contract = Proxy::deploy(app, code, "owner", &msg, "Voting").unwrap();
+
+        app.init_modules(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, contract.addr(), coins(100, "atom"))
+        })
+        .unwrap();
+
+        contract
+    }
+
+    fn propose(app: &mut App, voting: &Proxy, sender: &str) -> anyhow::Result<u64> {
+        let resp = voting.execute(
+            app,
+            sender,
+            &ExecuteMsg::Propose {
+                title: "Pay the user".to_owned(),
+                description: "The user did a great job".to_owned(),
+                msgs: vec![BankMsg::Send {
+                    to_address: mock_addr("user").to_string(),
+                    amount: coins(60, "atom"),
+                }
+                .into()],
+            },
+            &[],
+        )?;
+        Ok(wasm_attr(&resp, "proposal_id").parse().unwrap())
+    }
+
+    fn vote(
+        app: &mut App,
+        voting: &Proxy,
+        sender: &str,
+        proposal_id: u64,
+        vote: Vote,
+    ) -> anyhow::Result<AppResponse> {
+        voting.execute(app, sender, &ExecuteMsg::Vote { proposal_id, vote }, &[])
+    }
+
+    fn execute_proposal(
+        app: &mut App,
+        voting: &Proxy,
+        proposal_id: u64,
+    ) -> anyhow::Result<AppResponse> {
+        voting.execute(app, "user", &ExecuteMsg::Execute { proposal_id }, &[])
+    }
+
+    fn status(app: &App, voting: &Proxy, proposal_id: u64) -> Status {
+        let resp: ProposalResp = voting
+            .query(app, &QueryMsg::Proposal { proposal_id })
+            .unwrap();
+        resp.status
+    }
+
+    #[test]
+    fn required_votes_round_up() {
+        let two_thirds = Decimal::from_ratio(2u32, 3u32);
+        assert_eq!(required_votes(two_thirds, 3), 2);
+        assert_eq!(required_votes(two_thirds, 4), 3);
+        assert_eq!(required_votes(Decimal::percent(50), 4), 2);
+        assert_eq!(required_votes(Decimal::percent(50), 5), 3);
+        assert_eq!(required_votes(Decimal::one(), 5), 5);
+    }
+
+    #[test]
+    fn invalid_instantiation() {
+        let mut app = app();
+        let code_id = store_code(&mut app, ContractWrapper::new(execute, instantiate, query));
+
+        for threshold in [Decimal::zero(), Decimal::percent(101)] {
+            let msg = InstantiateMsg {
+                admins: vec![addr::admin(1).to_string()],
+                threshold,
+                voting_period: Duration::Height(10),
+            };
+            let err =
+                Proxy::instantiate(&mut app, code_id, "owner", &msg, &[], "Voting").unwrap_err();
+            assert_eq!(ContractError::InvalidThreshold, err.downcast().unwrap());
+        }
+
+        let msg = InstantiateMsg {
+            admins: vec![],
+            threshold: Decimal::one(),
+            voting_period: Duration::Height(10),
+        };
+        let err = Proxy::instantiate(&mut app, code_id, "owner", &msg, &[], "Voting").unwrap_err();
+        assert_eq!(ContractError::NoAdmins, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn passed_proposal_is_executed() {
+        let mut app = app();
+        let voting = voting(&mut app);
+
+        let id = propose(&mut app, &voting, "admin1").unwrap();
+        assert_eq!(status(&app, &voting, id), Status::Open);
+
+        vote(&mut app, &voting, "admin1", id, Vote::Yes).unwrap();
+        vote(&mut app, &voting, "admin2", id, Vote::No).unwrap();
+
+        let err = execute_proposal(&mut app, &voting, id).unwrap_err();
+        assert_eq!(ContractError::NotPassed { id }, err.downcast().unwrap());
+
+        let resp = vote(&mut app, &voting, "admin3", id, Vote::Yes).unwrap();
+        assert_eq!(wasm_attr(&resp, "status"), "Passed");
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 0);
+
+        execute_proposal(&mut app, &voting, id).unwrap();
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 60);
+        assert_eq!(status(&app, &voting, id), Status::Executed);
+
+        let err = execute_proposal(&mut app, &voting, id).unwrap_err();
+        assert_eq!(ContractError::NotPassed { id }, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn failed_messages_revert_execution() {
+        let mut app = app();
+        let voting = voting(&mut app);
+
+        // Two proposals each paying 60 atom - only one can be funded
+        let first = propose(&mut app, &voting, "admin1").unwrap();
+        let second = propose(&mut app, &voting, "admin1").unwrap();
+        for id in [first, second] {
+            vote(&mut app, &voting, "admin1", id, Vote::Yes).unwrap();
+            vote(&mut app, &voting, "admin2", id, Vote::Yes).unwrap();
+        }
+
+        execute_proposal(&mut app, &voting, first).unwrap();
+        execute_proposal(&mut app, &voting, second).unwrap_err();
+
+        assert_eq!(status(&app, &voting, second), Status::Passed);
+        assert_eq!(balance(&app, mock_addr("user"), "atom"), 60);
+    }
+
+    #[test]
+    fn voting_period() {
+        let mut app = app();
+        let voting = voting(&mut app);
+
+        let id = propose(&mut app, &voting, "admin1").unwrap();
+        vote(&mut app, &voting, "admin1", id, Vote::Yes).unwrap();
+
+        app.advance_time(VOTING_PERIOD);
+        assert_eq!(status(&app, &voting, id), Status::Rejected);
+
+        let err = vote(&mut app, &voting, "admin2", id, Vote::Yes).unwrap_err();
+        assert_eq!(ContractError::Expired { id }, err.downcast().unwrap());
+
+        let err = execute_proposal(&mut app, &voting, id).unwrap_err();
+        assert_eq!(ContractError::NotPassed { id }, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn passed_proposal_survives_expiration() {
+        let mut app = app();
+        let voting = voting(&mut app);
+
+        let id = propose(&mut app, &voting, "admin1").unwrap();
+        vote(&mut app, &voting, "admin1", id, Vote::Yes).unwrap();
+        vote(&mut app, &voting, "admin2", id, Vote::Yes).unwrap();
+
+        app.advance_time(VOTING_PERIOD);
+        assert_eq!(status(&app, &voting, id), Status::Passed);
+        execute_proposal(&mut app, &voting, id).unwrap();
+    }
+
+    #[test]
+    fn unauthorized() {
+        let mut app = app();
+        let voting = voting(&mut app);
+
+        let err = propose(&mut app, &voting, "user").unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        let id = propose(&mut app, &voting, "admin1").unwrap();
+        let err = vote(&mut app, &voting, "user", id, Vote::Yes).unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: mock_addr("user")
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn double_vote() {
+        let mut app = app();
+        let voting = voting(&mut app);
+
+        let id = propose(&mut app, &voting, "admin1").unwrap();
+        vote(&mut app, &voting, "admin1", id, Vote::No).unwrap();
+
+        let err = vote(&mut app, &voting, "admin1", id, Vote::Yes).unwrap_err();
+        assert_eq!(
+            ContractError::AlreadyVoted {
+                id,
+                voter: addr::admin(1)
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp: VoteResp = voting
+            .query(
+                &app,
+                &QueryMsg::Vote {
+                    proposal_id: id,
+                    voter: addr::admin(1).to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(resp.vote, Some(Vote::No));
+
+        let resp: ProposalResp = voting
+            .query(&app, &QueryMsg::Proposal { proposal_id: id })
+            .unwrap();
+        assert_eq!((resp.yes, resp.no, resp.required), (0, 1, 2));
+    }
+
+    #[test]
+    fn list_proposals() {
+        let mut app = app();
+        let voting = voting(&mut app);
+
+        for _ in 0..3 {
+            propose(&mut app, &voting, "admin1").unwrap();
+        }
+
+        let resp: ProposalsResp = voting
+            .query(
+                &app,
+                &QueryMsg::ListProposals {
+                    start_after: Some(0),
+                    limit: None,
+                },
+            )
+            .unwrap();
+        let ids: Vec<_> = resp.proposals.iter().map(|p| p.id).collect();
+        assert_eq!(ids, [1, 2]);
+    }
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{Addr, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not an admin")]
+    Unauthorized { sender: Addr },
+    #[error("Threshold has to be greater than zero and at most one")]
+    InvalidThreshold,
+    #[error("At least one admin is required")]
+    NoAdmins,
+    #[error("Voting on proposal {id} is closed")]
+    Expired { id: u64 },
+    #[error("{voter} already voted on proposal {id}")]
+    AlreadyVoted { id: u64, voter: Addr },
+    #[error("Proposal {id} is not passed")]
+    NotPassed { id: u64 },
+}
@@ -0,0 +1,76 @@
+use cosmwasm_schema::QueryResponses;
+use cosmwasm_std::{CosmosMsg, Decimal};
+use cw_utils::{Duration, Expiration};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{Status, Vote};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct InstantiateMsg {
+    pub admins: Vec<String>,
+    /// Part of all admins which has to vote yes for a proposal to pass, in `(0, 1]` range
+    pub threshold: Decimal,
+    pub voting_period: Duration,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    Propose {
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg>,
+    },
+    Vote {
+        proposal_id: u64,
+        vote: Vote,
+    },
+    /// Sends messages of the passed proposal, can be called by anyone
+    Execute {
+        proposal_id: u64,
+    },
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ProposalResp {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg>,
+    pub status: Status,
+    pub expires: Expiration,
+    pub yes: u64,
+    pub no: u64,
+    pub required: u64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ProposalsResp {
+    pub proposals: Vec<ProposalResp>,
+    /// `start_after` of the next page, `None` if this one is the last
+    pub next_start_after: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct VoteResp {
+    pub vote: Option<Vote>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    #[returns(ProposalResp)]
+    Proposal { proposal_id: u64 },
+    #[returns(ProposalsResp)]
+    ListProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(VoteResp)]
+    Vote { proposal_id: u64, voter: String },
+}
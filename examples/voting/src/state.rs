@@ -0,0 +1,67 @@
+use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty};
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    /// Part of all admins which has to vote yes for a proposal to pass
+    pub threshold: Decimal,
+    pub voting_period: Duration,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Vote {
+    Yes,
+    No,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// Still collecting votes
+    Open,
+    /// Enough yes votes, can be executed
+    Passed,
+    /// Voting period ended without enough yes votes
+    Rejected,
+    Executed,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Proposal {
+    pub title: String,
+    pub description: String,
+    /// Messages sent by the contract when the proposal is executed
+    pub msgs: Vec<CosmosMsg>,
+    pub expires: Expiration,
+    pub yes: u64,
+    pub no: u64,
+    /// Yes votes needed to pass, fixed when the proposal is created
+    pub required: u64,
+    pub executed: bool,
+}
+
+impl Proposal {
+    pub fn status(&self, block: &BlockInfo) -> Status {
+        if self.executed {
+            Status::Executed
+        } else if self.yes >= self.required {
+            Status::Passed
+        } else if self.expires.is_expired(block) {
+            Status::Rejected
+        } else {
+            Status::Open
+        }
+    }
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const ADMINS: Map<&Addr, Empty> = Map::new("admins");
+pub const ADMINS_COUNT: Item<u64> = Item::new("admins_count");
+pub const NEXT_PROPOSAL_ID: Item<u64> = Item::new("next_proposal_id");
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+/// Votes by proposal id and voter
+pub const BALLOTS: Map<(u64, &Addr), Vote> = Map::new("ballots");
@@ -0,0 +1,211 @@
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, HeadResp, InstantiateMsg, LenResp, QueryMsg};
+use crate::state::{Task, PROCESSED, TASKS, WORKER};
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+/// Most tasks taken in a single `Process`, so it always fits into a block.
+pub const MAX_BATCH: u32 = 30;
+
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    WORKER.save(deps.storage, &info.sender)?;
+    PROCESSED.save(deps.storage, &0)?;
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    use QueryMsg::*;
+
+    match msg {
+        Len {} => to_json_binary(&query::len(deps)?),
+        Head {} => to_json_binary(&query::head(deps)?),
+    }
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    use ExecuteMsg::*;
+
+    match msg {
+        Push { payload } => exec::push(deps, info, payload),
+        Process { limit } => exec::process(deps, info, limit),
+    }
+}
+
+mod exec {
+    use super::*;
+
+    pub fn push(
+        deps: DepsMut,
+        info: MessageInfo,
+        payload: String,
+    ) -> Result<Response, ContractError> {
+        let task = Task {
+            submitter: info.sender,
+            payload,
+        };
+        TASKS.push_back(deps.storage, &task)?;
+
+        let resp = Response::new()
+            .add_attribute("action", "push")
+            .add_attribute("len", TASKS.len(deps.storage)?.to_string());
+
+        Ok(resp)
+    }
+
+    pub fn process(
+        deps: DepsMut,
+        info: MessageInfo,
+        limit: u32,
+    ) -> Result<Response, ContractError> {
+        let worker = WORKER.load(deps.storage)?;
+        if info.sender != worker {
+            return Err(ContractError::Unauthorized { worker });
+        }
+
+        // Popping stops early on an empty queue - processing nothing is not an error, the worker
+        // just polls
+        let mut resp = Response::new().add_attribute("action", "process");
+        let mut count = 0u64;
+        for _ in 0..limit.min(MAX_BATCH) {
+            let task = match TASKS.pop_front(deps.storage)? {
+                Some(task) => task,
+                None => break,
+            };
+            resp = resp.add_attribute("task", task.payload);
+            count += 1;
+        }
+        PROCESSED.update(deps.storage, |processed| -> StdResult<_> {
+            Ok(processed + count)
+        })?;
+
+        Ok(resp.add_attribute("count", count.to_string()))
+    }
+}
+
+mod query {
+    use super::*;
+
+    pub fn len(deps: Deps) -> StdResult<LenResp> {
+        Ok(LenResp {
+            len: TASKS.len(deps.storage)?,
+            processed: PROCESSED.load(deps.storage)?,
+        })
+    }
+
+    pub fn head(deps: Deps) -> StdResult<HeadResp> {
+        let task = TASKS.front(deps.storage)?;
+        Ok(HeadResp { task })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use testing::prelude::*;
+
+    use super::*;
+
+    fn work_queue(app: &mut App) -> Proxy {
+        let code = ContractWrapper::new(execute, instantiate, query);
+        Proxy::deploy(app, code, "worker", &InstantiateMsg {}, "Work queue").unwrap()
+    }
+
+    fn push(app: &mut App, queue: &Proxy, sender: &str, payload: &str) {
+        let msg = ExecuteMsg::Push {
+            payload: payload.to_owned(),
+        };
+        queue.execute(app, sender, &msg, &[]).unwrap();
+    }
+
+    fn process(app: &mut App, queue: &Proxy, limit: u32) -> Vec<String> {
+        let resp = queue
+            .execute(app, "worker", &ExecuteMsg::Process { limit }, &[])
+            .unwrap();
+        resp.events
+            .iter()
+            .filter(|event| event.ty == "wasm")
+            .flat_map(|event| &event.attributes)
+            .filter(|attr| attr.key == "task")
+            .map(|attr| attr.value.clone())
+            .collect()
+    }
+
+    fn len(app: &App, queue: &Proxy) -> LenResp {
+        queue.query(app, &QueryMsg::Len {}).unwrap()
+    }
+
+    #[test]
+    fn process_in_batches() {
+        let mut app = app();
+        let queue = work_queue(&mut app);
+
+        for (sender, payload) in [("alice", "a1"), ("bob", "b1"), ("alice", "a2")] {
+            push(&mut app, &queue, sender, payload);
+        }
+        assert_eq!(
+            len(&app, &queue),
+            LenResp {
+                len: 3,
+                processed: 0
+            }
+        );
+
+        let resp: HeadResp = queue.query(&app, &QueryMsg::Head {}).unwrap();
+        assert_eq!(
+            resp.task,
+            Some(Task {
+                submitter: mock_addr("alice"),
+                payload: "a1".to_owned(),
+            })
+        );
+
+        assert_eq!(process(&mut app, &queue, 2), ["a1", "b1"]);
+        push(&mut app, &queue, "bob", "b2");
+        assert_eq!(process(&mut app, &queue, 5), ["a2", "b2"]);
+        assert_eq!(process(&mut app, &queue, 5), Vec::<String>::new());
+
+        assert_eq!(
+            len(&app, &queue),
+            LenResp {
+                len: 0,
+                processed: 4
+            }
+        );
+        let resp: HeadResp = queue.query(&app, &QueryMsg::Head {}).unwrap();
+        assert_eq!(resp.task, None);
+    }
+
+    #[test]
+    fn batch_limit() {
+        let mut app = app();
+        let queue = work_queue(&mut app);
+
+        for i in 0..MAX_BATCH + 5 {
+            push(&mut app, &queue, "alice", &i.to_string());
+        }
+        assert_eq!(
+            process(&mut app, &queue, u32::MAX).len(),
+            MAX_BATCH as usize
+        );
+        assert_eq!(len(&app, &queue).len, 5);
+
+        let err = queue
+            .execute(&mut app, "alice", &ExecuteMsg::Process { limit: 1 }, &[])
+            .unwrap_err();
+        assert_eq!(
+            ContractError::Unauthorized {
+                worker: mock_addr("worker")
+            },
+            err.downcast().unwrap()
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Deque, Item};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Task {
+    pub submitter: Addr,
+    pub payload: String,
+}
+
+/// Account processing the queue
+pub const WORKER: Item<Addr> = Item::new("worker");
+pub const TASKS: Deque<Task> = Deque::new("tasks");
+/// Number of tasks processed so far
+pub const PROCESSED: Item<u64> = Item::new("processed");
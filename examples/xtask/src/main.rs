@@ -0,0 +1,135 @@
+//! Maintenance tasks for the examples, run with `cargo xtask <task>` from the `examples`
+//! directory.
+//!
+//! Tasks:
+//!
+//! * `check-wasm [example...]` - builds every contract of the given examples (all of them by
+//!   default) to `wasm32-unknown-unknown` and verifies the artifacts with `cosmwasm-check`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+type Result<T> = std::result::Result<T, String>;
+
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("check-wasm") => check_wasm(args.collect()),
+        Some(task) => Err(format!("Unknown task: {}", task)),
+        None => Err("Usage: cargo xtask check-wasm [example...]".to_owned()),
+    };
+
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        exit(1);
+    }
+}
+
+fn examples_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn check_wasm(examples: Vec<String>) -> Result<()> {
+    let root = examples_dir();
+    let examples = if examples.is_empty() {
+        subdirs(&root)?
+    } else {
+        examples.iter().map(|example| root.join(example)).collect()
+    };
+
+    let target_dir = root.join("target");
+    let mut artifacts = vec![];
+    for example in examples {
+        for contract in contracts(&example)? {
+            let manifest = contract.join("Cargo.toml");
+            println!("Building {}", contract.display());
+            run(Command::new(env!("CARGO"))
+                .args(["build", "--release", "--lib", "--target", WASM_TARGET])
+                .arg("--manifest-path")
+                .arg(&manifest)
+                .arg("--target-dir")
+                .arg(&target_dir)
+                .env("RUSTFLAGS", "-C link-arg=-s"))?;
+
+            let name = package_name(&manifest)?.replace('-', "_");
+            artifacts.push(
+                target_dir
+                    .join(WASM_TARGET)
+                    .join("release")
+                    .join(format!("{}.wasm", name)),
+            );
+        }
+    }
+
+    if artifacts.is_empty() {
+        return Err("No contracts found".to_owned());
+    }
+
+    run(Command::new("cosmwasm-check").args(&artifacts)).map_err(|err| {
+        format!(
+            "{}\nInstall the checker with `cargo install cosmwasm-check` if it is missing",
+            err
+        )
+    })
+}
+
+/// Contract crates of the example - the example itself, or the members in its `contracts`
+/// directory. Contracts are told apart from helper crates by the `cdylib` crate type.
+fn contracts(example: &Path) -> Result<Vec<PathBuf>> {
+    let mut candidates = vec![example.to_path_buf()];
+    let nested = example.join("contracts");
+    if nested.is_dir() {
+        candidates.extend(subdirs(&nested)?);
+    }
+
+    let mut contracts = vec![];
+    for candidate in candidates {
+        let manifest = candidate.join("Cargo.toml");
+        if manifest.is_file() && read(&manifest)?.contains("cdylib") {
+            contracts.push(candidate);
+        }
+    }
+    Ok(contracts)
+}
+
+fn package_name(manifest: &Path) -> Result<String> {
+    read(manifest)?
+        .lines()
+        .filter_map(|line| line.strip_prefix("name"))
+        .find_map(|rest| rest.trim().strip_prefix('=')?.trim().strip_prefix('"'))
+        .and_then(|rest| rest.strip_suffix('"'))
+        .map(str::to_owned)
+        .ok_or_else(|| format!("No package name in {}", manifest.display()))
+}
+
+fn subdirs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let entries = fs::read_dir(dir).map_err(|err| format!("{}: {}", dir.display(), err))?;
+    let mut dirs: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_dir())
+        .collect();
+    dirs.sort();
+    Ok(dirs)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {}", path.display(), err))
+}
+
+fn run(cmd: &mut Command) -> Result<()> {
+    let status = cmd
+        .status()
+        .map_err(|err| format!("Failed to run {:?}: {}", cmd, err))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{:?} failed with {}", cmd, status))
+    }
+}